@@ -1,11 +1,19 @@
 use bencher::{benchmark_group, benchmark_main, Bencher};
 use rand::Rng;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange,
+    AccelerationBands, AcceleratorOscillator, AccumulationDistribution, Alligator, Aroon,
+    AverageTrueRange, AwesomeOscillator, BollingerBands, ChandeKrollStop, ChandelierExit,
+    CommodityChannelIndex, CoppockCurve, CumulativeSum, Drawdown, EfficiencyRatio,
+    ExponentialMovingAverage, FastStochastic, FractalDimensionIndex, GarmanKlassVolatility,
+    KalmanFilter, KaufmanAdaptiveMovingAverage, KeltnerChannel, Lag, LinearRegression,
+    LinearRegressionChannel, LogReturns, Maximum, McGinleyDynamic, MeanAbsoluteDeviation,
+    MedianPrice, MidPrice, Minimum, Momentum, MoneyFlowIndex, MovingAverageConvergenceDivergence,
+    NormalizedAverageTrueRange, OnBalanceVolume, ParkinsonVolatility, PercentRank,
+    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, RollingKurtosis, RollingMedian,
+    RollingPercentile, RollingRSquared, RollingSkewness, RollingSortino, SampleEntropy,
+    SavitzkyGolay, SimpleMovingAverage, SimpleReturns, SlowStochastic, SmoothedMovingAverage,
+    StandardDeviation, StochasticMomentumIndex, StochasticRsi, T3MovingAverage, TrueRange,
+    UltimateOscillator, VolatilityStop, WeightedMovingAverage, YangZhangVolatility, ZScore,
 };
 use ta::{DataItem, Next};
 
@@ -72,5 +80,48 @@ bench_indicators!(
     SimpleMovingAverage,
     SlowStochastic,
     StandardDeviation,
-    TrueRange
+    StochasticRsi,
+    TrueRange,
+    Aroon,
+    KaufmanAdaptiveMovingAverage,
+    AccumulationDistribution,
+    UltimateOscillator,
+    AwesomeOscillator,
+    AcceleratorOscillator,
+    WeightedMovingAverage,
+    CoppockCurve,
+    StochasticMomentumIndex,
+    McGinleyDynamic,
+    T3MovingAverage,
+    SmoothedMovingAverage,
+    Alligator,
+    LinearRegression,
+    LinearRegressionChannel,
+    RollingRSquared,
+    ZScore,
+    PercentRank,
+    RollingMedian,
+    RollingPercentile,
+    RollingSkewness,
+    RollingKurtosis,
+    ParkinsonVolatility,
+    GarmanKlassVolatility,
+    YangZhangVolatility,
+    NormalizedAverageTrueRange,
+    ChandeKrollStop,
+    VolatilityStop,
+    AccelerationBands,
+    MidPrice,
+    MedianPrice,
+    KalmanFilter,
+    SavitzkyGolay,
+    FractalDimensionIndex,
+    SampleEntropy,
+    Drawdown,
+    RollingSortino,
+    SimpleReturns,
+    LogReturns,
+    CumulativeSum,
+    Lag,
+    Momentum
 );
@@ -0,0 +1,55 @@
+use bencher::{benchmark_group, benchmark_main, Bencher};
+use rand::Rng;
+use ta::indicators::{
+    ExponentialMovingAverage, Maximum, Minimum, RateOfChange, SimpleMovingAverage,
+    StandardDeviation,
+};
+use ta::{Next, NextSlice};
+
+const INPUTS_COUNT: usize = 100_000;
+
+fn rand_inputs() -> Vec<f64> {
+    let mut rng = rand::thread_rng();
+    (0..INPUTS_COUNT)
+        .map(|_| rng.gen_range(0.0, 1000.0))
+        .collect()
+}
+
+// Compares next_slice over the whole batch with next called for every input
+macro_rules! bench_next_slice {
+    ($($indicator:ident => ($next:ident, $next_slice:ident)), *) => {
+        $(
+            fn $next(bench: &mut Bencher) {
+                let inputs = rand_inputs();
+                let mut out = vec![0.0; inputs.len()];
+                let mut indicator = $indicator::default();
+
+                bench.iter(|| {
+                    for (out, &input) in out.iter_mut().zip(inputs.iter()) {
+                        *out = indicator.next(input);
+                    }
+                })
+            }
+
+            fn $next_slice(bench: &mut Bencher) {
+                let inputs = rand_inputs();
+                let mut out = vec![0.0; inputs.len()];
+                let mut indicator = $indicator::default();
+
+                bench.iter(|| indicator.next_slice(&inputs, &mut out))
+            }
+        )*
+
+        benchmark_group!(benches, $($next, $next_slice,)*);
+        benchmark_main!(benches);
+    }
+}
+
+bench_next_slice!(
+    SimpleMovingAverage => (sma_next, sma_next_slice),
+    ExponentialMovingAverage => (ema_next, ema_next_slice),
+    RateOfChange => (roc_next, roc_next_slice),
+    Maximum => (maximum_next, maximum_next_slice),
+    Minimum => (minimum_next, minimum_next_slice),
+    StandardDeviation => (sd_next, sd_next_slice)
+);
@@ -0,0 +1,58 @@
+// Computes indicators on a Binance kline stream read from the standard input, one message per
+// line, e.g.
+//
+//   websocat wss://stream.binance.com:9443/ws/btcusdt@kline_1m \
+//     | cargo run --example binance_klines --features kline
+//
+// The RSI follows the 1 minute kline in progress, while the closed klines are resampled into
+// 5 minute bars, fed to a live engine.
+use std::io::{self, BufRead};
+
+use ta::bars::{Resampler, Timeframe};
+use ta::indicators::RelativeStrengthIndex as Rsi;
+use ta::live::{Backpressure, Kline, KlineIndicator, LiveEngine};
+use ta::{Close, Next, TryNext};
+
+fn main() {
+    let mut rsi = KlineIndicator::new(Rsi::new(14).unwrap());
+    let mut resampler = Resampler::new(Timeframe::Minutes(5)).unwrap();
+    let mut engine = LiveEngine::new(&["sma(20)", "bb(20, 2)"]).unwrap();
+    let subscription = engine.subscribe(64, Backpressure::DropNewest).unwrap();
+
+    for line in io::stdin().lock().lines() {
+        let kline = match Kline::from_binance_json(&line.unwrap()) {
+            Ok(kline) => kline,
+            Err(err) => {
+                eprintln!("skipping message: {}", err);
+                continue;
+            }
+        };
+
+        match rsi.try_next(&kline) {
+            Ok(value) => println!(
+                "{} {} close {} {}: {:.2}{}",
+                kline.symbol,
+                kline.start,
+                kline.close,
+                rsi.indicator(),
+                value,
+                if kline.closed { "" } else { " (in progress)" }
+            ),
+            Err(err) => eprintln!("skipping kline: {}", err),
+        }
+
+        if kline.closed {
+            if let Ok(bar) = kline.bar() {
+                if let Some((start, bar)) = resampler.next((kline.start, &bar)) {
+                    println!("5m bar {} closed at {}", start, bar.close());
+                    engine.push(bar);
+                }
+            }
+        }
+        while let Ok(update) = subscription.try_recv() {
+            for output in update.outputs.iter() {
+                println!("  {} = {:?}", output.label, output.values);
+            }
+        }
+    }
+}
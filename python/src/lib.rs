@@ -0,0 +1,8 @@
+//! The `ta` Python module, built with `maturin build --release`.
+
+use pyo3::prelude::*;
+
+#[pymodule]
+fn ta(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    ::ta::python::register(module)
+}
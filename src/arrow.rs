@@ -0,0 +1,226 @@
+//! Integration with [Apache Arrow](https://docs.rs/arrow) record batches.
+//!
+//! Requires the `arrow` feature.
+
+use std::sync::Arc;
+
+use ::arrow::array::{Array, ArrayRef, Float64Array, Float64Builder};
+use ::arrow::compute::cast;
+use ::arrow::datatypes::{DataType, Field, Schema};
+use ::arrow::error::ArrowError;
+use ::arrow::record_batch::RecordBatch;
+
+use crate::errors::Result;
+use crate::registry::{DynIndicator, IndicatorRegistry};
+use crate::{DataItem, Next, Reset};
+
+/// Processor running a set of indicators over a stream of OHLCV record batches.
+///
+/// The indicators are given by their [specifications](../registry/struct.IndicatorSpec.html),
+/// e.g. `"rsi(14)"`. Every processed batch is returned with a `Float64` column appended for
+/// every field of every indicator, named after the indicator, e.g. `"RSI(14)"` or
+/// `"BB(20, 2).upper"`, the columns of the batch being shared, not copied. The indicators keep
+/// their state from one batch to the next, so a series can be processed in chunks.
+///
+/// The bars are read from the `open`, `high`, `low`, `close` and `volume` columns, cast to
+/// `Float64`, only the `close` column being required: the other prices default to the close,
+/// and the volume to 0. Rows with a null value are skipped, giving null outputs.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{Array, Float64Array};
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::record_batch::RecordBatch;
+/// use ta::arrow::RecordBatchProcessor;
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new("close", DataType::Float64, false)]));
+/// let batch = RecordBatch::try_new(
+///     schema,
+///     vec![Arc::new(Float64Array::from(vec![10.0, 11.0, 12.0]))],
+/// ).unwrap();
+///
+/// let mut processor = RecordBatchProcessor::new(&["sma(2)", "bb(2, 1)"]).unwrap();
+/// let output = processor.process(&batch).unwrap();
+///
+/// assert_eq!(output.num_columns(), 5);
+/// assert_eq!(output.schema().field(1).name(), "SMA(2)");
+/// assert_eq!(output.schema().field(3).name(), "BB(2, 1).upper");
+/// let sma = output.column(1).as_any().downcast_ref::<Float64Array>().unwrap();
+/// assert_eq!(sma.value(2), 11.5);
+/// ```
+#[derive(Debug)]
+pub struct RecordBatchProcessor {
+    indicators: Vec<DynIndicator>,
+}
+
+impl RecordBatchProcessor {
+    /// Creates the indicators of the specifications with the built-in
+    /// [registry](../registry/struct.IndicatorRegistry.html).
+    pub fn new(specs: &[&str]) -> Result<Self> {
+        Self::with_registry(&IndicatorRegistry::new(), specs)
+    }
+
+    /// Creates the indicators of the specifications with the given registry.
+    pub fn with_registry(registry: &IndicatorRegistry, specs: &[&str]) -> Result<Self> {
+        let indicators = specs
+            .iter()
+            .map(|spec| registry.create(spec))
+            .collect::<Result<_>>()?;
+        Ok(Self { indicators })
+    }
+
+    /// Returns the batch with the outputs of the indicators appended.
+    pub fn process(&mut self, batch: &RecordBatch) -> std::result::Result<RecordBatch, ArrowError> {
+        let [open, high, low, close, volume] = ohlcv_columns(batch)?;
+
+        let mut builders: Vec<Vec<Float64Builder>> = self
+            .indicators
+            .iter()
+            .map(|indicator| {
+                indicator
+                    .fields()
+                    .iter()
+                    .map(|_| Float64Builder::with_capacity(batch.num_rows()))
+                    .collect()
+            })
+            .collect();
+
+        let columns = [&open, &high, &low, &close, &volume];
+        for row in 0..batch.num_rows() {
+            if columns.iter().any(|column| column.is_null(row)) {
+                for builder in builders.iter_mut().flatten() {
+                    builder.append_null();
+                }
+                continue;
+            }
+
+            let bar = DataItem::unchecked(
+                open.value(row),
+                high.value(row),
+                low.value(row),
+                close.value(row),
+                volume.value(row),
+            );
+            for (indicator, builders) in self.indicators.iter_mut().zip(builders.iter_mut()) {
+                for (builder, value) in builders.iter_mut().zip(indicator.next(&bar)) {
+                    builder.append_value(value);
+                }
+            }
+        }
+
+        let schema = batch.schema();
+        let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+        let mut arrays = batch.columns().to_vec();
+        for (indicator, builders) in self.indicators.iter().zip(builders) {
+            for (label, mut builder) in indicator.field_labels().into_iter().zip(builders) {
+                fields.push(Field::new(label, DataType::Float64, true));
+                arrays.push(Arc::new(builder.finish()) as ArrayRef);
+            }
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+    }
+}
+
+impl Reset for RecordBatchProcessor {
+    fn reset(&mut self) {
+        for indicator in self.indicators.iter_mut() {
+            indicator.reset();
+        }
+    }
+}
+
+// Open, high, low, close and volume columns of the batch, the close being required
+pub(crate) fn ohlcv_columns(
+    batch: &RecordBatch,
+) -> std::result::Result<[Float64Array; 5], ArrowError> {
+    let close = float_column(batch, "close")?
+        .ok_or_else(|| ArrowError::SchemaError("close column is required".to_string()))?;
+    let column = |name| -> std::result::Result<Float64Array, ArrowError> {
+        Ok(float_column(batch, name)?.unwrap_or_else(|| close.clone()))
+    };
+    let (open, high, low) = (column("open")?, column("high")?, column("low")?);
+    let volume = float_column(batch, "volume")?
+        .unwrap_or_else(|| Float64Array::from(vec![0.0; batch.num_rows()]));
+    Ok([open, high, low, close, volume])
+}
+
+// Column of the batch cast to Float64, if any
+fn float_column(
+    batch: &RecordBatch,
+    name: &str,
+) -> std::result::Result<Option<Float64Array>, ArrowError> {
+    batch
+        .column_by_name(name)
+        .map(|column| {
+            let column = cast(column, &DataType::Float64)?;
+            Ok(column
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .expect("cast to Float64")
+                .clone())
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::arrow::array::Int32Array;
+
+    fn batch(columns: Vec<(&str, ArrayRef)>) -> RecordBatch {
+        RecordBatch::try_from_iter(columns).unwrap()
+    }
+
+    fn values(batch: &RecordBatch, index: usize) -> Vec<Option<f64>> {
+        let column = batch.column(index);
+        let column = column.as_any().downcast_ref::<Float64Array>().unwrap();
+        column.iter().collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RecordBatchProcessor::new(&[]).is_ok());
+        assert!(RecordBatchProcessor::new(&["sma(3)", "atr"]).is_ok());
+        assert!(RecordBatchProcessor::new(&["sma(3)", "foo(3)"]).is_err());
+    }
+
+    #[test]
+    fn test_process() {
+        let mut processor = RecordBatchProcessor::new(&["atr(3)", "sma(2)"]).unwrap();
+
+        let first = batch(vec![
+            ("high", Arc::new(Float64Array::from(vec![11.0, 12.0]))),
+            ("low", Arc::new(Float64Array::from(vec![9.0, 9.0]))),
+            ("close", Arc::new(Int32Array::from(vec![Some(10), None]))),
+        ]);
+        let output = processor.process(&first).unwrap();
+        assert_eq!(output.num_columns(), 5);
+        assert_eq!(output.schema().field(3).name(), "ATR(3)");
+        assert_eq!(values(&output, 3), [Some(2.0), None]);
+        assert_eq!(values(&output, 4), [Some(10.0), None]);
+
+        // The state carries over to the next batch
+        let second = batch(vec![
+            ("high", Arc::new(Float64Array::from(vec![13.0]))),
+            ("low", Arc::new(Float64Array::from(vec![10.0]))),
+            ("close", Arc::new(Float64Array::from(vec![12.0]))),
+        ]);
+        let output = processor.process(&second).unwrap();
+        assert_eq!(values(&output, 3), [Some(2.5)]);
+        assert_eq!(values(&output, 4), [Some(11.0)]);
+
+        processor.reset();
+        let output = processor.process(&second).unwrap();
+        assert_eq!(values(&output, 4), [Some(12.0)]);
+    }
+
+    #[test]
+    fn test_process_without_close() {
+        let mut processor = RecordBatchProcessor::new(&["sma(2)"]).unwrap();
+        let open = batch(vec![("open", Arc::new(Float64Array::from(vec![1.0])))]);
+        assert!(processor.process(&open).is_err());
+    }
+}
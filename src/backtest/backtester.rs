@@ -0,0 +1,483 @@
+use crate::backtest::{BacktestReport, OrderType, Trade};
+use crate::errors::{Result, TaError};
+use crate::{Action, Close, High, Low, Next, Open};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Backtester of long-only strategies.
+///
+/// For every bar, the strategy is given the bar and returns an [Action](../enum.Action.html).
+/// A buy signal places an order investing all the cash when there is no open position,
+/// and a sell signal places an order closing the position. The orders are filled on the
+/// following bars, depending on the [order type](enum.OrderType.html). A signal in the opposite
+/// direction cancels a pending order, e.g. a sell signal before a buy limit order is filled.
+///
+/// The equity is recorded at the close of every bar. A position open at the end of the
+/// backtest is not closed, and is included in the equity at the last close.
+///
+/// # Parameters
+///
+/// * _initial_cash_ - cash at the start of the backtest (number greater than 0)
+///
+/// Optional parameters, set with the `with_` methods:
+///
+/// * _commission_ - commission in percent of the value of every fill (number in range [0, 100)). Default is 0.
+/// * _slippage_ - price change against the market orders in percent (number in range [0, 100)). Default is 0.
+/// * _order_type_ - type of the orders. Default is `OrderType::Market`.
+///
+/// # Example
+///
+/// ```
+/// use ta::backtest::Backtester;
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::strategy::{Strategy, Threshold};
+/// use ta::DataItem;
+///
+/// let bar = |open, close| {
+///     DataItem::builder()
+///         .open(open).high(open.max(close)).low(open.min(close)).close(close).volume(0.0)
+///         .build().unwrap()
+/// };
+/// let bars = vec![bar(10.0, 10.0), bar(10.0, 11.0), bar(11.0, 12.0), bar(12.5, 12.8)];
+///
+/// let mut strategy = Strategy::new(
+///     Threshold::below(Sma::new(1).unwrap(), 10.5),
+///     Threshold::above(Sma::new(1).unwrap(), 11.5),
+/// );
+/// let report = Backtester::new(1000.0).unwrap().run(&mut strategy, &bars);
+///
+/// assert_eq!(report.trades.len(), 1);
+/// assert_eq!(report.trades[0].entry_price, 10.0);
+/// assert_eq!(report.trades[0].exit_price, 12.5);
+/// assert_eq!(report.trades[0].profit, 250.0);
+/// assert_eq!(report.equity, vec![1000.0, 1100.0, 1200.0, 1250.0]);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Backtester {
+    initial_cash: f64,
+    commission: f64,
+    slippage: f64,
+    order_type: OrderType,
+}
+
+// Pending order, buying when there is no position and selling the position otherwise
+#[derive(Debug, Clone, Copy)]
+struct Order {
+    is_buy: bool,
+    limit: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    entry_index: usize,
+    entry_price: f64,
+    quantity: f64,
+    commission: f64,
+}
+
+fn is_valid_percentage(value: f64) -> bool {
+    (0.0..100.0).contains(&value)
+}
+
+impl Backtester {
+    pub fn new(initial_cash: f64) -> Result<Self> {
+        if !(initial_cash > 0.0 && initial_cash.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            initial_cash,
+            commission: 0.0,
+            slippage: 0.0,
+            order_type: OrderType::Market,
+        })
+    }
+
+    /// Sets the commission in percent of the value of every fill.
+    pub fn with_commission(mut self, commission: f64) -> Result<Self> {
+        if !is_valid_percentage(commission) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.commission = commission;
+        Ok(self)
+    }
+
+    /// Sets the slippage of the market orders in percent.
+    pub fn with_slippage(mut self, slippage: f64) -> Result<Self> {
+        if !is_valid_percentage(slippage) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.slippage = slippage;
+        Ok(self)
+    }
+
+    /// Sets the type of the orders.
+    pub fn with_order_type(mut self, order_type: OrderType) -> Result<Self> {
+        if !order_type.is_valid() {
+            return Err(TaError::InvalidParameter);
+        }
+        self.order_type = order_type;
+        Ok(self)
+    }
+
+    pub fn initial_cash(&self) -> f64 {
+        self.initial_cash
+    }
+
+    pub fn commission(&self) -> f64 {
+        self.commission
+    }
+
+    pub fn slippage(&self) -> f64 {
+        self.slippage
+    }
+
+    pub fn order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    // Copy of the backtester with another initial cash, used to chain backtests
+    pub(crate) fn starting_with(&self, initial_cash: f64) -> Self {
+        Self {
+            initial_cash,
+            ..self.clone()
+        }
+    }
+
+    fn place_order(&self, is_buy: bool, close: f64) -> Order {
+        let limit = match self.order_type {
+            OrderType::Market => None,
+            OrderType::Limit(offset) if is_buy => Some(close * (1.0 - offset / 100.0)),
+            OrderType::Limit(offset) => Some(close * (1.0 + offset / 100.0)),
+        };
+        Order { is_buy, limit }
+    }
+
+    fn fill_price<T: Open + High + Low>(&self, order: Order, bar: &T) -> Option<f64> {
+        let slippage = self.slippage / 100.0;
+
+        match (order.limit, order.is_buy) {
+            (None, true) => Some(bar.open() * (1.0 + slippage)),
+            (None, false) => Some(bar.open() * (1.0 - slippage)),
+            (Some(limit), true) if bar.low() <= limit => Some(bar.open().min(limit)),
+            (Some(limit), false) if bar.high() >= limit => Some(bar.open().max(limit)),
+            _ => None,
+        }
+    }
+
+    /// Runs the _strategy_ over the _bars_.
+    ///
+    /// The strategy is not reset before the backtest.
+    pub fn run<S, T>(&self, strategy: &mut S, bars: &[T]) -> BacktestReport
+    where
+        S: for<'a> Next<&'a T, Output = Action>,
+        T: Open + High + Low + Close,
+    {
+        self.simulate(strategy, bars).0
+    }
+
+    // Runs the backtest and closes a position still open at the last close, without
+    // commission, recording it as a trade. Used to chain backtests, which value the open
+    // position at the last close.
+    pub(crate) fn run_closing<S, T>(&self, strategy: &mut S, bars: &[T]) -> BacktestReport
+    where
+        S: for<'a> Next<&'a T, Output = Action>,
+        T: Open + High + Low + Close,
+    {
+        let (mut report, position) = self.simulate(strategy, bars);
+        if let (Some(open), Some(last)) = (position, bars.last()) {
+            let value = open.quantity * last.close();
+            report.cash += value;
+            report.trades.push(Trade {
+                entry_index: open.entry_index,
+                entry_price: open.entry_price,
+                exit_index: bars.len() - 1,
+                exit_price: last.close(),
+                quantity: open.quantity,
+                commission: open.commission,
+                profit: value - open.quantity * open.entry_price - open.commission,
+            });
+            report.position = 0.0;
+            report.position_entry_index = None;
+        }
+        report
+    }
+
+    // Runs the backtest, returning the position still open at the end
+    fn simulate<S, T>(&self, strategy: &mut S, bars: &[T]) -> (BacktestReport, Option<Position>)
+    where
+        S: for<'a> Next<&'a T, Output = Action>,
+        T: Open + High + Low + Close,
+    {
+        let commission_rate = self.commission / 100.0;
+        let mut cash = self.initial_cash;
+        let mut position: Option<Position> = None;
+        let mut order: Option<Order> = None;
+        let mut trades = vec![];
+        let mut equity = Vec::with_capacity(bars.len());
+
+        for (index, bar) in bars.iter().enumerate() {
+            if let Some(price) = order.and_then(|order| self.fill_price(order, bar)) {
+                order = None;
+
+                match position.take() {
+                    None => {
+                        let quantity = cash / (price * (1.0 + commission_rate));
+                        let commission = quantity * price * commission_rate;
+                        cash -= quantity * price + commission;
+                        position = Some(Position {
+                            entry_index: index,
+                            entry_price: price,
+                            quantity,
+                            commission,
+                        });
+                    }
+                    Some(open) => {
+                        let value = open.quantity * price;
+                        let commission = value * commission_rate;
+                        cash += value - commission;
+                        trades.push(Trade {
+                            entry_index: open.entry_index,
+                            entry_price: open.entry_price,
+                            exit_index: index,
+                            exit_price: price,
+                            quantity: open.quantity,
+                            commission: open.commission + commission,
+                            profit: value
+                                - commission
+                                - open.quantity * open.entry_price
+                                - open.commission,
+                        });
+                    }
+                }
+            }
+
+            let position_value = position.map_or(0.0, |open| open.quantity * bar.close());
+            equity.push(cash + position_value);
+
+            order = match strategy.next(bar) {
+                Action::Buy if position.is_none() => Some(self.place_order(true, bar.close())),
+                Action::Sell if position.is_some() => Some(self.place_order(false, bar.close())),
+                Action::Buy | Action::Sell => None,
+                Action::Neutral => order,
+            };
+        }
+
+        let report = BacktestReport {
+            trades,
+            equity,
+            cash,
+            position: position.map_or(0.0, |open| open.quantity),
+            position_entry_index: position.map(|open| open.entry_index),
+        };
+        (report, position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::strategy::{Strategy, Threshold};
+    use crate::DataItem;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> DataItem {
+        DataItem::builder()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(0.0)
+            .build()
+            .unwrap()
+    }
+
+    fn bars() -> Vec<DataItem> {
+        vec![
+            bar(10.0, 10.0, 10.0, 10.0),
+            bar(10.2, 11.0, 10.0, 11.0),
+            bar(11.0, 12.5, 11.0, 12.0),
+            bar(12.5, 13.0, 12.0, 12.8),
+            bar(12.0, 12.0, 12.0, 12.0),
+        ]
+    }
+
+    // Buys below 10.5 and sells above 11.5
+    fn strategy() -> impl for<'a> Next<&'a DataItem, Output = Action> {
+        Strategy::new(
+            Threshold::below(Sma::new(1).unwrap(), 10.5),
+            Threshold::above(Sma::new(1).unwrap(), 11.5),
+        )
+    }
+
+    fn round(num: f64) -> f64 {
+        (num * 1000.0).round() / 1000.0
+    }
+
+    fn round_all(nums: &[f64]) -> Vec<f64> {
+        nums.iter().map(|&num| round(num)).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Backtester::new(0.0).is_err());
+        assert!(Backtester::new(f64::INFINITY).is_err());
+        assert!(Backtester::new(1.0).is_ok());
+
+        let backtester = Backtester::new(1000.0).unwrap();
+        assert!(backtester.clone().with_commission(-0.1).is_err());
+        assert!(backtester.clone().with_commission(100.0).is_err());
+        assert!(backtester.clone().with_slippage(-0.1).is_err());
+        assert!(backtester
+            .clone()
+            .with_order_type(OrderType::Limit(-1.0))
+            .is_err());
+
+        let backtester = backtester
+            .with_commission(0.1)
+            .unwrap()
+            .with_slippage(0.5)
+            .unwrap()
+            .with_order_type(OrderType::Limit(1.0))
+            .unwrap();
+        assert_eq!(backtester.initial_cash(), 1000.0);
+        assert_eq!(backtester.commission(), 0.1);
+        assert_eq!(backtester.slippage(), 0.5);
+        assert_eq!(backtester.order_type(), OrderType::Limit(1.0));
+    }
+
+    #[test]
+    fn test_run_market() {
+        let report = Backtester::new(1000.0)
+            .unwrap()
+            .run(&mut strategy(), &bars());
+
+        assert_eq!(report.trades.len(), 1);
+        let trade = &report.trades[0];
+        assert_eq!((trade.entry_index, trade.entry_price), (1, 10.2));
+        assert_eq!((trade.exit_index, trade.exit_price), (3, 12.5));
+        assert_eq!(round(trade.quantity), 98.039);
+        assert_eq!(trade.commission, 0.0);
+        assert_eq!(round(trade.profit), 225.49);
+        assert_eq!(round(trade.return_pct()), 22.549);
+
+        assert_eq!(
+            round_all(&report.equity),
+            vec![1000.0, 1078.431, 1176.471, 1225.49, 1225.49]
+        );
+        assert_eq!(round(report.cash), 1225.49);
+        assert_eq!(report.position, 0.0);
+    }
+
+    #[test]
+    fn test_run_commission_and_slippage() {
+        let report = Backtester::new(1000.0)
+            .unwrap()
+            .with_commission(0.1)
+            .unwrap()
+            .with_slippage(0.5)
+            .unwrap()
+            .run(&mut strategy(), &bars());
+
+        assert_eq!(report.trades.len(), 1);
+        let trade = &report.trades[0];
+        assert_eq!(round(trade.entry_price), 10.251);
+        assert_eq!(round(trade.exit_price), 12.438);
+        assert_eq!(round(trade.quantity), 97.454);
+        assert_eq!(round(trade.commission), 2.211);
+        assert_eq!(round(trade.profit), 210.872);
+
+        assert_eq!(
+            round_all(&report.equity),
+            vec![1000.0, 1071.994, 1169.448, 1210.872, 1210.872]
+        );
+    }
+
+    #[test]
+    fn test_run_limit() {
+        let bars = vec![
+            bar(10.0, 10.0, 10.0, 10.0),
+            bar(9.6, 10.5, 9.5, 10.2),
+            bar(12.0, 12.5, 11.5, 12.0),
+            bar(12.1, 12.3, 11.9, 12.0),
+        ];
+        let report = Backtester::new(1000.0)
+            .unwrap()
+            .with_slippage(0.5)
+            .unwrap()
+            .with_order_type(OrderType::Limit(2.0))
+            .unwrap()
+            .run(&mut strategy(), &bars);
+
+        assert_eq!(report.trades.len(), 1);
+        let trade = &report.trades[0];
+        // The buy limit at 9.8 is filled at the lower open, the sell limit at 12.24
+        assert_eq!((trade.entry_index, trade.entry_price), (1, 9.6));
+        assert_eq!((trade.exit_index, round(trade.exit_price)), (3, 12.24));
+        assert_eq!(round(trade.profit), 275.0);
+
+        assert_eq!(
+            round_all(&report.equity),
+            vec![1000.0, 1062.5, 1250.0, 1275.0]
+        );
+    }
+
+    #[test]
+    fn test_run_cancelled_order() {
+        let bars = vec![
+            bar(10.0, 10.0, 10.0, 10.0),
+            bar(10.0, 12.0, 10.0, 12.0),
+            bar(9.5, 11.0, 9.5, 11.0),
+        ];
+        let report = Backtester::new(1000.0)
+            .unwrap()
+            .with_order_type(OrderType::Limit(2.0))
+            .unwrap()
+            .run(&mut strategy(), &bars);
+
+        assert!(report.trades.is_empty());
+        assert_eq!(report.equity, vec![1000.0, 1000.0, 1000.0]);
+        assert_eq!(report.position, 0.0);
+    }
+
+    #[test]
+    fn test_run_open_position() {
+        let report = Backtester::new(1000.0)
+            .unwrap()
+            .run(&mut strategy(), &bars()[..2]);
+
+        assert!(report.trades.is_empty());
+        assert_eq!(round(report.position), 98.039);
+        assert_eq!(report.position_entry_index, Some(1));
+        assert_eq!(round(report.cash), 0.0);
+        assert_eq!(report.final_equity().map(round), Some(1078.431));
+    }
+
+    #[test]
+    fn test_run_closing() {
+        let report = Backtester::new(1000.0)
+            .unwrap()
+            .with_commission(1.0)
+            .unwrap()
+            .run_closing(&mut strategy(), &bars()[..2]);
+
+        assert_eq!(report.trades.len(), 1);
+        let trade = &report.trades[0];
+        assert_eq!((trade.entry_index, trade.exit_index), (1, 1));
+        assert_eq!(report.position, 0.0);
+        assert_eq!(report.position_entry_index, None);
+        assert_eq!(round(report.cash), round(report.final_equity().unwrap()));
+        assert_eq!(round(trade.profit), round(report.cash - 1000.0));
+    }
+
+    #[test]
+    fn test_run_empty() {
+        let report = Backtester::new(1000.0).unwrap().run(&mut strategy(), &[]);
+
+        assert!(report.trades.is_empty());
+        assert!(report.equity.is_empty());
+        assert_eq!(report.cash, 1000.0);
+        assert_eq!(report.final_equity(), None);
+    }
+}
@@ -0,0 +1,21 @@
+//! Backtesting of trading strategies over historical bars.
+//!
+//! A [Backtester](struct.Backtester.html) drives a [Strategy](../strategy/struct.Strategy.html),
+//! or any other type returning an [Action](../enum.Action.html) for every bar, over a series of
+//! bars, simulates the orders with commission and slippage, and produces a
+//! [report](struct.BacktestReport.html) with the list of trades and the equity curve.
+//!
+//! [WalkForward](struct.WalkForward.html) runs walk-forward analyses of parameterized
+//! strategies over rolling in-sample and out-of-sample windows.
+
+mod order_type;
+pub use self::order_type::OrderType;
+
+mod report;
+pub use self::report::{BacktestReport, Trade};
+
+mod backtester;
+pub use self::backtester::Backtester;
+
+mod walkforward;
+pub use self::walkforward::{WalkForward, WalkForwardReport, WalkForwardWindow};
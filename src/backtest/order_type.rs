@@ -0,0 +1,35 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Type of the orders placed by a [Backtester](struct.Backtester.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OrderType {
+    /// Filled at the open of the bar following the signal, adjusted by the slippage.
+    #[default]
+    Market,
+    /// Placed the given percentage below (buy) or above (sell) the close of the signal bar.
+    /// Filled at the limit price, or at the open when the bar opens beyond it, on the first
+    /// bar reaching the limit. Slippage does not apply.
+    Limit(f64),
+}
+
+impl OrderType {
+    pub(super) fn is_valid(&self) -> bool {
+        match self {
+            OrderType::Market => true,
+            OrderType::Limit(offset) => *offset >= 0.0 && *offset < 100.0,
+        }
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrderType::Market => write!(f, "MARKET"),
+            OrderType::Limit(offset) => write!(f, "LIMIT({})", offset),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Closed trade of a [backtest](struct.Backtester.html).
+///
+/// Indices are positions of the bars in the backtested series.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    pub entry_index: usize,
+    pub entry_price: f64,
+    pub exit_index: usize,
+    pub exit_price: f64,
+    pub quantity: f64,
+    /// Commission paid on entry and exit.
+    pub commission: f64,
+    /// Profit after commission.
+    pub profit: f64,
+}
+
+impl Trade {
+    /// Returns the profit after commission in percent of the entry value.
+    pub fn return_pct(&self) -> f64 {
+        self.profit / (self.entry_price * self.quantity) * 100.0
+    }
+}
+
+/// Result of a [backtest](struct.Backtester.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BacktestReport {
+    /// Closed trades in the order they were closed.
+    pub trades: Vec<Trade>,
+    /// Equity at the close of every bar: the cash plus the value of the open position.
+    pub equity: Vec<f64>,
+    /// Cash at the end of the backtest.
+    pub cash: f64,
+    /// Quantity of the position still open at the end of the backtest.
+    pub position: f64,
+    /// Index of the bar the position still open at the end of the backtest was entered on.
+    pub position_entry_index: Option<usize>,
+}
+
+impl BacktestReport {
+    /// Returns the equity at the end of the backtest.
+    pub fn final_equity(&self) -> Option<f64> {
+        self.equity.last().copied()
+    }
+}
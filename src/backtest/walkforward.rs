@@ -0,0 +1,378 @@
+use std::ops::Range;
+
+use crate::backtest::{BacktestReport, Backtester, Trade};
+use crate::errors::{Result, TaError};
+use crate::performance::PerformanceReport;
+use crate::{Action, Close, High, Low, Next, Open};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Walk-forward analysis.
+///
+/// Splits a series of bars into rolling windows made of an in-sample part followed by an
+/// out-of-sample part. The windows are moved forward by the size of the out-of-sample part,
+/// so the out-of-sample parts follow each other without overlapping. Bars after the last
+/// complete window are not used.
+///
+/// For every window, a strategy is created by the factory for every parameter set and
+/// backtested over the in-sample part. The parameter set with the highest score is then
+/// backtested over the out-of-sample part, with a new strategy. The strategies start without
+/// any state on every part, so the first bars of every part are used to warm up the indicators.
+///
+/// The out-of-sample backtests are chained: each one starts with the final equity of the
+/// previous one. A position open at the end of a part is closed at its last close, without
+/// commission, and recorded as a trade, so that the trades agree with the equity.
+///
+/// # Parameters
+///
+/// * _in_sample_ - number of bars of the in-sample part of every window (integer greater than 0)
+/// * _out_of_sample_ - number of bars of the out-of-sample part of every window (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use ta::backtest::{Backtester, WalkForward};
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::strategy::{Strategy, Threshold};
+/// use ta::DataItem;
+///
+/// let bars: Vec<DataItem> = [10.0, 10.0, 12.0, 12.0, 10.0, 10.0, 12.0, 12.0]
+///     .iter()
+///     .map(|&close| {
+///         DataItem::builder()
+///             .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+///     })
+///     .collect();
+///
+/// // Buys below the level and sells above 11
+/// let factory = |level: &f64| {
+///     Strategy::new(
+///         Threshold::below(Sma::new(1).unwrap(), *level),
+///         Threshold::above(Sma::new(1).unwrap(), 11.0),
+///     )
+/// };
+///
+/// let report = WalkForward::new(4, 2)
+///     .unwrap()
+///     .run(
+///         &Backtester::new(1000.0).unwrap(),
+///         &bars,
+///         &[9.0, 10.5],
+///         factory,
+///         |report| report.final_equity().unwrap(),
+///     )
+///     .unwrap();
+///
+/// assert_eq!(report.windows.len(), 2);
+/// assert_eq!(report.windows[0].params, 10.5);
+/// assert_eq!(report.windows[0].out_of_sample, 4..6);
+/// assert_eq!(report.out_of_sample.equity.len(), 4);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WalkForward {
+    in_sample: usize,
+    out_of_sample: usize,
+}
+
+/// Window of a [walk-forward analysis](struct.WalkForward.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalkForwardWindow<P> {
+    /// Indices of the in-sample bars.
+    pub in_sample: Range<usize>,
+    /// Indices of the out-of-sample bars.
+    pub out_of_sample: Range<usize>,
+    /// Parameter set with the highest in-sample score.
+    pub params: P,
+    /// In-sample score of the parameter set.
+    pub in_sample_score: f64,
+    /// Out-of-sample backtest of the parameter set. Indices are relative to the start of the
+    /// out-of-sample part.
+    pub report: BacktestReport,
+}
+
+/// Result of a [walk-forward analysis](struct.WalkForward.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalkForwardReport<P> {
+    pub windows: Vec<WalkForwardWindow<P>>,
+    /// Chained out-of-sample backtests. Indices are relative to the start of the first
+    /// out-of-sample part.
+    pub out_of_sample: BacktestReport,
+}
+
+impl<P> WalkForwardReport<P> {
+    /// Returns the performance of the chained out-of-sample backtests.
+    pub fn performance(&self) -> PerformanceReport {
+        PerformanceReport::from(&self.out_of_sample)
+    }
+}
+
+impl WalkForward {
+    pub fn new(in_sample: usize, out_of_sample: usize) -> Result<Self> {
+        if in_sample == 0 || out_of_sample == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            in_sample,
+            out_of_sample,
+        })
+    }
+
+    pub fn in_sample(&self) -> usize {
+        self.in_sample
+    }
+
+    pub fn out_of_sample(&self) -> usize {
+        self.out_of_sample
+    }
+
+    /// Returns the in-sample and out-of-sample indices of the windows of a series of _len_ bars.
+    pub fn windows(&self, len: usize) -> Vec<(Range<usize>, Range<usize>)> {
+        let size = self.in_sample + self.out_of_sample;
+
+        (0..)
+            .map(|window| window * self.out_of_sample)
+            .take_while(|start| start + size <= len)
+            .map(|start| {
+                let split = start + self.in_sample;
+                (start..split, split..start + size)
+            })
+            .collect()
+    }
+
+    /// Runs the walk-forward analysis of the strategies created by the _factory_ for every
+    /// parameter set of _params_, selecting the in-sample parameter sets with the _score_
+    /// of the backtests.
+    ///
+    /// Parameter sets with a NaN score are never selected. Returns an error when _params_ is
+    /// empty or when no parameter set has a score in some window.
+    pub fn run<P, F, S, M, T>(
+        &self,
+        backtester: &Backtester,
+        bars: &[T],
+        params: &[P],
+        factory: F,
+        score: M,
+    ) -> Result<WalkForwardReport<P>>
+    where
+        P: Clone,
+        F: Fn(&P) -> S,
+        S: for<'a> Next<&'a T, Output = Action>,
+        M: Fn(&BacktestReport) -> f64,
+        T: Open + High + Low + Close,
+    {
+        if params.is_empty() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let mut windows = vec![];
+        let mut out_of_sample = BacktestReport {
+            trades: vec![],
+            equity: vec![],
+            cash: backtester.initial_cash(),
+            position: 0.0,
+            position_entry_index: None,
+        };
+
+        for (in_sample, oos) in self.windows(bars.len()) {
+            let mut best: Option<(&P, f64)> = None;
+            for set in params {
+                let report = backtester.run(&mut factory(set), &bars[in_sample.clone()]);
+                let value = score(&report);
+                if !value.is_nan() && best.is_none_or(|(_, best_value)| value > best_value) {
+                    best = Some((set, value));
+                }
+            }
+            let (set, in_sample_score) = best.ok_or(TaError::InvalidParameter)?;
+
+            let initial_cash = out_of_sample
+                .final_equity()
+                .unwrap_or(backtester.initial_cash());
+            let report = backtester
+                .starting_with(initial_cash)
+                .run_closing(&mut factory(set), &bars[oos.clone()]);
+
+            let offset = oos.start - self.in_sample;
+            out_of_sample
+                .trades
+                .extend(report.trades.iter().map(|trade| Trade {
+                    entry_index: trade.entry_index + offset,
+                    exit_index: trade.exit_index + offset,
+                    ..trade.clone()
+                }));
+            out_of_sample.equity.extend_from_slice(&report.equity);
+            out_of_sample.cash = report.cash;
+
+            windows.push(WalkForwardWindow {
+                in_sample,
+                out_of_sample: oos,
+                params: set.clone(),
+                in_sample_score,
+                report,
+            });
+        }
+
+        Ok(WalkForwardReport {
+            windows,
+            out_of_sample,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::strategy::{Strategy, Threshold};
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    fn bars(closes: &[f64]) -> Vec<DataItem> {
+        closes.iter().map(|&close| item(close)).collect()
+    }
+
+    // Buys below the level and sells above 11
+    fn factory(level: &f64) -> impl for<'a> Next<&'a DataItem, Output = Action> {
+        Strategy::new(
+            Threshold::below(Sma::new(1).unwrap(), *level),
+            Threshold::above(Sma::new(1).unwrap(), 11.0),
+        )
+    }
+
+    fn final_equity(report: &BacktestReport) -> f64 {
+        report.final_equity().unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(WalkForward::new(0, 1).is_err());
+        assert!(WalkForward::new(1, 0).is_err());
+
+        let walk_forward = WalkForward::new(10, 5).unwrap();
+        assert_eq!(walk_forward.in_sample(), 10);
+        assert_eq!(walk_forward.out_of_sample(), 5);
+    }
+
+    #[test]
+    fn test_windows() {
+        let walk_forward = WalkForward::new(4, 2).unwrap();
+
+        assert_eq!(walk_forward.windows(5), vec![]);
+        assert_eq!(walk_forward.windows(6), vec![(0..4, 4..6)]);
+        assert_eq!(
+            walk_forward.windows(11),
+            vec![(0..4, 4..6), (2..6, 6..8), (4..8, 8..10)]
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let bars = bars(&[
+            10.0, 10.0, 12.0, 12.0, 10.0, 10.0, 12.0, 12.0, 10.0, 10.0, 12.0, 12.0, 12.0,
+        ]);
+        let backtester = Backtester::new(1000.0).unwrap();
+
+        let report = WalkForward::new(4, 4)
+            .unwrap()
+            .run(&backtester, &bars, &[9.0, 10.5], factory, final_equity)
+            .unwrap();
+
+        assert_eq!(report.windows.len(), 2);
+
+        let first = &report.windows[0];
+        assert_eq!(first.in_sample, 0..4);
+        assert_eq!(first.out_of_sample, 4..8);
+        assert_eq!(first.params, 10.5);
+        assert_eq!(first.in_sample_score, 1200.0);
+        assert_eq!(first.report.equity, vec![1000.0, 1000.0, 1200.0, 1200.0]);
+
+        // The second out-of-sample backtest starts with the final equity of the first one
+        let second = &report.windows[1];
+        assert_eq!(second.out_of_sample, 8..12);
+        assert_eq!(second.params, 10.5);
+        assert_eq!(second.report.equity, vec![1200.0, 1200.0, 1440.0, 1440.0]);
+        assert_eq!(second.report.trades[0].entry_index, 1);
+
+        let out_of_sample = &report.out_of_sample;
+        assert_eq!(
+            out_of_sample.equity,
+            vec![1000.0, 1000.0, 1200.0, 1200.0, 1200.0, 1200.0, 1440.0, 1440.0]
+        );
+        let indices: Vec<_> = out_of_sample
+            .trades
+            .iter()
+            .map(|trade| (trade.entry_index, trade.exit_index))
+            .collect();
+        assert_eq!(indices, vec![(1, 3), (5, 7)]);
+        assert_eq!(out_of_sample.cash, 1440.0);
+
+        let performance = report.performance();
+        assert_eq!(performance.total_trades, 2);
+        assert_eq!(performance.net_profit, 440.0);
+    }
+
+    #[test]
+    fn test_run_open_position() {
+        let bars = bars(&[
+            10.0, 10.0, 12.0, 12.0, 10.0, 10.0, 11.0, 11.0, 11.0, 11.0, 12.0, 12.0,
+        ]);
+        let backtester = Backtester::new(1000.0).unwrap();
+
+        let report = WalkForward::new(4, 4)
+            .unwrap()
+            .run(&backtester, &bars, &[9.0, 10.5], factory, final_equity)
+            .unwrap();
+
+        // The position open at the end of the first part is closed at its last close
+        let out_of_sample = &report.out_of_sample;
+        let indices: Vec<_> = out_of_sample
+            .trades
+            .iter()
+            .map(|trade| (trade.entry_index, trade.exit_index))
+            .collect();
+        assert_eq!(indices, vec![(1, 3)]);
+        assert_eq!(out_of_sample.position, 0.0);
+        assert_eq!(out_of_sample.position_entry_index, None);
+        assert_eq!(out_of_sample.cash, 1100.0);
+        assert_eq!(final_equity(out_of_sample), 1100.0);
+
+        let performance = report.performance();
+        assert_eq!(performance.total_trades, 1);
+        assert_eq!(performance.net_profit, 100.0);
+    }
+
+    #[test]
+    fn test_run_score() {
+        let bars = bars(&[10.0, 10.0, 12.0, 12.0, 10.0, 10.0, 12.0, 12.0]);
+        let backtester = Backtester::new(1000.0).unwrap();
+
+        let report = WalkForward::new(4, 4)
+            .unwrap()
+            .run(&backtester, &bars, &[9.0, 10.5], factory, |report| {
+                -final_equity(report)
+            })
+            .unwrap();
+
+        assert_eq!(report.windows[0].params, 9.0);
+        assert_eq!(report.windows[0].in_sample_score, -1000.0);
+        assert!(report.out_of_sample.trades.is_empty());
+    }
+
+    #[test]
+    fn test_run_invalid() {
+        let bars = bars(&[10.0, 12.0, 10.0]);
+        let backtester = Backtester::new(1000.0).unwrap();
+        let walk_forward = WalkForward::new(2, 1).unwrap();
+
+        let params: [f64; 0] = [];
+        assert!(walk_forward
+            .run(&backtester, &bars, &params, factory, final_equity)
+            .is_err());
+        assert!(walk_forward
+            .run(&backtester, &bars, &[10.5], factory, |_| f64::NAN)
+            .is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+
+use crate::bars::partial_bar::PartialBar;
+use crate::bars::Tick;
+use crate::errors::{Result, TaError};
+#[cfg(feature = "chrono")]
+use crate::{Close, Timestamp, Volume};
+use crate::{DataItem, Next, Reset};
+#[cfg(feature = "chrono")]
+use chrono::DateTime;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Time based bar aggregator.
+///
+/// Builds OHLCV bars of a fixed time interval from a stream of `(timestamp, price, volume)`
+/// ticks. Bars are aligned to multiples of the interval, and each bar is identified by the
+/// timestamp of its start. Timestamps can be in any unit (e.g. Unix milliseconds), as long
+/// as the interval is given in the same unit.
+///
+/// A bar is completed by the first tick of a later interval, so every call returns the
+/// completed bar, if any. Intervals without ticks produce no bars. Ticks older than the
+/// bar in progress are added to it.
+///
+/// With the `chrono` feature, inputs with a [Timestamp](../trait.Timestamp.html) and a close
+/// price and volume, e.g. trades or lower timeframe bars, can be given directly. The interval
+/// is then in milliseconds, and the resulting bars carry the timestamp of their start. Inputs
+/// without a timestamp are added to the bar in progress, or ignored when there is none.
+///
+/// # Parameters
+///
+/// * _interval_ - length of a bar (integer greater than 0). Default is 60000, i.e. one
+///   minute in milliseconds.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::BarAggregator;
+/// use ta::{Close, Next, Open};
+///
+/// let mut aggregator = BarAggregator::new(60).unwrap();
+///
+/// assert!(aggregator.next((0, 10.0, 1.0)).is_none());
+/// assert!(aggregator.next((30, 11.0, 1.0)).is_none());
+///
+/// let (timestamp, bar) = aggregator.next((60, 12.0, 1.0)).unwrap();
+/// assert_eq!(timestamp, 0);
+/// assert_eq!((bar.open(), bar.close()), (10.0, 11.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BarAggregator {
+    interval: i64,
+    start: Option<i64>,
+    bar: PartialBar,
+}
+
+impl BarAggregator {
+    pub fn new(interval: i64) -> Result<Self> {
+        if interval <= 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            interval,
+            start: None,
+            bar: PartialBar::new(),
+        })
+    }
+
+    pub fn interval(&self) -> i64 {
+        self.interval
+    }
+
+    /// Returns the start timestamp and the state of the bar in progress, if any.
+    pub fn current(&self) -> Option<(i64, DataItem)> {
+        self.start.zip(self.bar.current())
+    }
+
+    /// Completes the bar in progress, e.g. at the end of a session, and returns it.
+    pub fn flush(&mut self) -> Option<(i64, DataItem)> {
+        self.start.take().zip(self.bar.take())
+    }
+}
+
+impl Next<(i64, f64, f64)> for BarAggregator {
+    type Output = Option<(i64, DataItem)>;
+
+    fn next(&mut self, (timestamp, price, volume): (i64, f64, f64)) -> Self::Output {
+        // Saturates for timestamps close to i64::MIN
+        let start = timestamp.saturating_sub(timestamp.rem_euclid(self.interval));
+
+        let completed = match self.start {
+            Some(current) if start > current => self.flush(),
+            _ => None,
+        };
+        if self.start.is_none() {
+            self.start = Some(start);
+        }
+
+        self.bar.add(&Tick::new(price, volume));
+        completed
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: Close + Volume + Timestamp> Next<&T> for BarAggregator {
+    type Output = Option<(i64, DataItem)>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let timestamp = input
+            .timestamp()
+            .map(|timestamp| timestamp.timestamp_millis())
+            .or(self.start)?;
+        let completed = self.next((timestamp, input.close(), input.volume()));
+        self.bar
+            .set_timestamp(self.start.and_then(DateTime::from_timestamp_millis));
+        completed
+    }
+}
+
+impl Reset for BarAggregator {
+    fn reset(&mut self) {
+        self.start = None;
+        self.bar.take();
+    }
+}
+
+impl Default for BarAggregator {
+    fn default() -> Self {
+        Self::new(60_000).unwrap()
+    }
+}
+
+impl fmt::Display for BarAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BAR_AGGREGATOR({})", self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Close, High, Low, Open, Volume};
+
+    fn ohlcv(bar: Option<(i64, DataItem)>) -> Option<(i64, f64, f64, f64, f64, f64)> {
+        bar.map(|(timestamp, bar)| {
+            (
+                timestamp,
+                bar.open(),
+                bar.high(),
+                bar.low(),
+                bar.close(),
+                bar.volume(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(BarAggregator::new(0).is_err());
+        assert!(BarAggregator::new(-60).is_err());
+        assert!(BarAggregator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut aggregator = BarAggregator::new(60).unwrap();
+
+        assert_eq!(ohlcv(aggregator.next((65, 10.0, 1.0))), None);
+        assert_eq!(ohlcv(aggregator.next((80, 12.0, 2.0))), None);
+        assert_eq!(ohlcv(aggregator.next((100, 9.0, 1.0))), None);
+        assert_eq!(ohlcv(aggregator.next((119, 11.0, 3.0))), None);
+        assert_eq!(
+            ohlcv(aggregator.next((120, 11.5, 1.0))),
+            Some((60, 10.0, 12.0, 9.0, 11.0, 7.0))
+        );
+        // No ticks between 180 and 300
+        assert_eq!(
+            ohlcv(aggregator.next((310, 13.0, 1.0))),
+            Some((120, 11.5, 11.5, 11.5, 11.5, 1.0))
+        );
+        assert_eq!(
+            ohlcv(aggregator.current()),
+            Some((300, 13.0, 13.0, 13.0, 13.0, 1.0))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_with_timestamps() {
+        use crate::test_helper::timed_item;
+
+        let mut aggregator = BarAggregator::new(60_000).unwrap();
+
+        // Ignored without a bar in progress
+        assert_eq!(
+            ohlcv(aggregator.next(&timed_item(None, 12.0, 12.0, 1.0))),
+            None
+        );
+        assert_eq!(ohlcv(aggregator.current()), None);
+
+        aggregator.next(&timed_item(Some(65_000), 10.0, 10.0, 1.0));
+        aggregator.next(&timed_item(None, 12.0, 12.0, 2.0));
+        let completed = aggregator.next(&timed_item(Some(120_000), 11.0, 11.0, 1.0));
+
+        let (_, bar) = completed.clone().unwrap();
+        assert_eq!(
+            ohlcv(completed),
+            Some((60_000, 10.0, 12.0, 10.0, 12.0, 3.0))
+        );
+        assert_eq!(bar.timestamp(), DateTime::from_timestamp_millis(60_000));
+
+        let (_, bar) = aggregator.current().unwrap();
+        assert_eq!(bar.timestamp(), DateTime::from_timestamp_millis(120_000));
+    }
+
+    #[test]
+    fn test_next_late_tick() {
+        let mut aggregator = BarAggregator::new(60).unwrap();
+
+        aggregator.next((125, 10.0, 1.0));
+        assert_eq!(ohlcv(aggregator.next((110, 9.0, 1.0))), None);
+        assert_eq!(
+            ohlcv(aggregator.current()),
+            Some((120, 10.0, 10.0, 9.0, 9.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn test_next_negative_timestamps() {
+        let mut aggregator = BarAggregator::new(60).unwrap();
+
+        aggregator.next((-30, 10.0, 1.0));
+        assert_eq!(
+            ohlcv(aggregator.next((0, 11.0, 1.0))),
+            Some((-60, 10.0, 10.0, 10.0, 10.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_next_min_timestamp() {
+        let mut aggregator = BarAggregator::new(60).unwrap();
+
+        aggregator.next((i64::MIN, 10.0, 1.0));
+        assert_eq!(
+            ohlcv(aggregator.next((0, 11.0, 1.0))),
+            Some((i64::MIN, 10.0, 10.0, 10.0, 10.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_flush() {
+        let mut aggregator = BarAggregator::new(60).unwrap();
+
+        assert_eq!(ohlcv(aggregator.flush()), None);
+
+        aggregator.next((0, 10.0, 1.0));
+        aggregator.next((10, 11.0, 1.0));
+        assert_eq!(
+            ohlcv(aggregator.flush()),
+            Some((0, 10.0, 11.0, 10.0, 11.0, 2.0))
+        );
+        assert_eq!(ohlcv(aggregator.current()), None);
+        assert_eq!(ohlcv(aggregator.next((20, 12.0, 1.0))), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aggregator = BarAggregator::new(60).unwrap();
+
+        aggregator.next((0, 10.0, 1.0));
+
+        aggregator.reset();
+
+        assert_eq!(ohlcv(aggregator.current()), None);
+        assert_eq!(ohlcv(aggregator.next((70, 11.0, 1.0))), None);
+    }
+
+    #[test]
+    fn test_default() {
+        BarAggregator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let aggregator = BarAggregator::new(300).unwrap();
+        assert_eq!(format!("{}", aggregator), "BAR_AGGREGATOR(300)");
+    }
+}
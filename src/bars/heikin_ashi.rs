@@ -0,0 +1,210 @@
+use std::fmt;
+
+use crate::helpers::{max3, min3};
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Heikin-Ashi bars.
+///
+/// Transforms regular OHLC bars into Heikin-Ashi bars, which average the price movement
+/// and make trends easier to spot. The result is a [DataItem](../struct.DataItem.html),
+/// so indicators can be run on Heikin-Ashi bars directly.
+///
+/// # Formula
+///
+/// * HA Close = (Open + High + Low + Close) / 4
+/// * HA Open = (HA Open<sub>prev</sub> + HA Close<sub>prev</sub>) / 2
+/// * HA High = Max(High, HA Open, HA Close)
+/// * HA Low = Min(Low, HA Open, HA Close)
+///
+/// The open of the first bar is (Open + Close) / 2. Volume is passed through unchanged.
+///
+/// With the `chrono` feature, `(timestamp, bar)` pairs can also be given, e.g.
+/// `(bar.timestamp(), &bar)`, so the Heikin-Ashi bar carries the timestamp of the bar.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::HeikinAshi;
+/// use ta::{Close, DataItem, Next, Open};
+///
+/// let mut ha = HeikinAshi::new();
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(12.0).low(9.0).close(11.0).volume(100.0).build().unwrap();
+/// let first = ha.next(&bar);
+/// assert_eq!(first.open(), 10.5);
+/// assert_eq!(first.close(), 10.5);
+///
+/// let bar = DataItem::builder()
+///     .open(11.0).high(13.0).low(10.0).close(12.0).volume(100.0).build().unwrap();
+/// let second = ha.next(&bar);
+/// assert_eq!(second.open(), 10.5);
+/// assert_eq!(second.close(), 11.5);
+/// ```
+///
+/// # Links
+///
+/// * [Heikin-Ashi, Investopedia](https://www.investopedia.com/trading/heikin-ashi-better-candlestick/)
+///
+#[doc(alias = "HA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HeikinAshi {
+    prev: Option<(f64, f64)>,
+}
+
+impl HeikinAshi {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<&T> for HeikinAshi {
+    type Output = DataItem;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = (input.open() + input.high() + input.low() + input.close()) / 4.0;
+        let open = match self.prev {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / 2.0,
+            None => (input.open() + input.close()) / 2.0,
+        };
+        self.prev = Some((open, close));
+
+        DataItem::unchecked(
+            open,
+            max3(input.high(), open, close),
+            min3(input.low(), open, close),
+            close,
+            input.volume(),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: Open + High + Low + Close + Volume> Next<(Option<DateTime<Utc>>, &T)> for HeikinAshi {
+    type Output = DataItem;
+
+    fn next(&mut self, (timestamp, input): (Option<DateTime<Utc>>, &T)) -> Self::Output {
+        let mut bar = self.next(input);
+        bar.timestamp = timestamp;
+        bar
+    }
+}
+
+impl Reset for HeikinAshi {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for HeikinAshi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for HeikinAshi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HEIKIN_ASHI()")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(100.0)
+    }
+
+    fn ohlc(item: DataItem) -> (f64, f64, f64, f64) {
+        (item.open(), item.high(), item.low(), item.close())
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ha = HeikinAshi::new();
+
+        assert_eq!(
+            ohlc(ha.next(&bar(10.0, 12.0, 9.0, 11.0))),
+            (10.5, 12.0, 9.0, 10.5)
+        );
+        assert_eq!(
+            ohlc(ha.next(&bar(11.0, 13.0, 10.0, 12.0))),
+            (10.5, 13.0, 10.0, 11.5)
+        );
+        assert_eq!(
+            ohlc(ha.next(&bar(12.0, 12.5, 10.5, 11.0))),
+            (11.0, 12.5, 10.5, 11.5)
+        );
+    }
+
+    #[test]
+    fn test_next_high_low_from_body() {
+        let mut ha = HeikinAshi::new();
+
+        ha.next(&bar(20.0, 21.0, 19.0, 20.0));
+        // The HA open stays near 20, above the high of the gapped down bar
+        assert_eq!(
+            ohlc(ha.next(&bar(10.0, 11.0, 9.0, 10.0))),
+            (20.0, 20.0, 9.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_next_volume() {
+        let mut ha = HeikinAshi::new();
+
+        assert_eq!(ha.next(&bar(10.0, 12.0, 9.0, 11.0)).volume(), 100.0);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_with_timestamp() {
+        use crate::Timestamp;
+
+        let mut ha = HeikinAshi::new();
+        let timestamp = DateTime::from_timestamp_millis(1_624_788_000_000);
+
+        let out = ha.next((timestamp, &bar(10.0, 12.0, 9.0, 11.0)));
+        assert_eq!(ohlc(out.clone()), (10.5, 12.0, 9.0, 10.5));
+        assert_eq!(out.timestamp(), timestamp);
+        assert_eq!(ha.next(&bar(11.0, 13.0, 10.0, 12.0)).timestamp(), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ha = HeikinAshi::new();
+
+        ha.next(&bar(10.0, 12.0, 9.0, 11.0));
+        ha.next(&bar(11.0, 13.0, 10.0, 12.0));
+
+        ha.reset();
+
+        assert_eq!(
+            ohlc(ha.next(&bar(11.0, 13.0, 10.0, 12.0))),
+            (11.5, 13.0, 10.0, 11.5)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        HeikinAshi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ha = HeikinAshi::new();
+        assert_eq!(format!("{}", ha), "HEIKIN_ASHI()");
+    }
+}
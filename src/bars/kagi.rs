@@ -0,0 +1,470 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::TrendDirection;
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kagi chart builder.
+///
+/// Converts a stream of prices into the lines of a Kagi chart. A line is extended while
+/// the price keeps moving in its direction, and a new line in the opposite direction is
+/// started when the price reverses by at least the reversal amount.
+///
+/// A line turns thick (yang) when the price rises above the previous shoulder (the top of
+/// the previous up line), and thin (yin) when the price falls below the previous waist
+/// (the bottom of the previous down line). The first line is yang when it goes up and yin
+/// when it goes down.
+///
+/// Every call returns the events caused by the given price, in the order they happened:
+/// a [NewLine](enum.KagiEvent.html#variant.NewLine) or an
+/// [Extension](enum.KagiEvent.html#variant.Extension), possibly followed by a
+/// [ThicknessChange](enum.KagiEvent.html#variant.ThicknessChange).
+///
+/// # Parameters
+///
+/// * _reversal_ - minimal price move that starts a new line, either a fixed amount or a
+///   percentage of the end of the current line. Default is 4%.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::{KagiBuilder, KagiEvent, ReversalAmount};
+/// use ta::indicators::TrendDirection;
+/// use ta::Next;
+///
+/// let mut kagi = KagiBuilder::new(ReversalAmount::Fixed(2.0)).unwrap();
+///
+/// assert!(kagi.next(10.0).is_empty());
+/// assert_eq!(
+///     kagi.next(12.5),
+///     vec![KagiEvent::NewLine { direction: TrendDirection::Up, start: 10.0, end: 12.5 }]
+/// );
+/// assert_eq!(kagi.next(13.0), vec![KagiEvent::Extension { end: 13.0 }]);
+/// ```
+///
+/// # Links
+///
+/// * [Kagi chart, Wikipedia](https://en.wikipedia.org/wiki/Kagi_chart)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KagiBuilder {
+    reversal: ReversalAmount,
+    state: Option<KagiState>,
+}
+
+/// Amount of a price move needed for a reversal.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReversalAmount {
+    /// Fixed price amount.
+    Fixed(f64),
+    /// Percentage of the reference price.
+    Percentage(f64),
+}
+
+impl ReversalAmount {
+    fn validate(self) -> Result<Self> {
+        let amount = match self {
+            ReversalAmount::Fixed(amount) => amount,
+            ReversalAmount::Percentage(percent) => percent,
+        };
+        if amount.is_finite() && amount > 0.0 {
+            Ok(self)
+        } else {
+            Err(TaError::InvalidParameter)
+        }
+    }
+
+    /// Returns the reversal amount for the given reference price.
+    pub(super) fn amount(self, price: f64) -> f64 {
+        match self {
+            ReversalAmount::Fixed(amount) => amount,
+            ReversalAmount::Percentage(percent) => price.abs() * percent / 100.0,
+        }
+    }
+}
+
+impl fmt::Display for ReversalAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReversalAmount::Fixed(amount) => write!(f, "{}", amount),
+            ReversalAmount::Percentage(percent) => write!(f, "{}%", percent),
+        }
+    }
+}
+
+/// Thickness of a Kagi line.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KagiThickness {
+    /// Thick line, the price rose above the previous shoulder.
+    Yang,
+    /// Thin line, the price fell below the previous waist.
+    Yin,
+}
+
+/// Change of a Kagi chart caused by a price.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KagiEvent {
+    /// A new line was started, either the first one or after a reversal.
+    NewLine {
+        direction: TrendDirection,
+        start: f64,
+        end: f64,
+    },
+    /// The current line was extended to a new extreme.
+    Extension { end: f64 },
+    /// The thickness of the current line changed when the price crossed the given level.
+    ThicknessChange {
+        thickness: KagiThickness,
+        price: f64,
+    },
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum KagiState {
+    // No line was drawn yet
+    Starting {
+        start: f64,
+    },
+    Drawing {
+        direction: TrendDirection,
+        end: f64,
+        thickness: KagiThickness,
+        shoulder: Option<f64>,
+        waist: Option<f64>,
+    },
+}
+
+impl KagiBuilder {
+    pub fn new(reversal: ReversalAmount) -> Result<Self> {
+        Ok(Self {
+            reversal: reversal.validate()?,
+            state: None,
+        })
+    }
+
+    pub fn reversal(&self) -> ReversalAmount {
+        self.reversal
+    }
+
+    /// Returns the direction of the current line, if any line was started.
+    pub fn direction(&self) -> Option<TrendDirection> {
+        match self.state {
+            Some(KagiState::Drawing { direction, .. }) => Some(direction),
+            _ => None,
+        }
+    }
+
+    /// Returns the thickness of the current line, if any line was started.
+    pub fn thickness(&self) -> Option<KagiThickness> {
+        match self.state {
+            Some(KagiState::Drawing { thickness, .. }) => Some(thickness),
+            _ => None,
+        }
+    }
+}
+
+impl Next<f64> for KagiBuilder {
+    type Output = Vec<KagiEvent>;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut events = Vec::new();
+
+        match self.state {
+            None => {
+                self.state = Some(KagiState::Starting { start: input });
+            }
+            Some(KagiState::Starting { start }) => {
+                if (input - start).abs() >= self.reversal.amount(start) {
+                    let direction = if input > start {
+                        TrendDirection::Up
+                    } else {
+                        TrendDirection::Down
+                    };
+                    let thickness = match direction {
+                        TrendDirection::Up => KagiThickness::Yang,
+                        TrendDirection::Down => KagiThickness::Yin,
+                    };
+                    events.push(KagiEvent::NewLine {
+                        direction,
+                        start,
+                        end: input,
+                    });
+                    self.state = Some(KagiState::Drawing {
+                        direction,
+                        end: input,
+                        thickness,
+                        shoulder: None,
+                        waist: None,
+                    });
+                }
+            }
+            Some(KagiState::Drawing {
+                ref mut direction,
+                ref mut end,
+                ref mut thickness,
+                ref mut shoulder,
+                ref mut waist,
+            }) => {
+                let reversal = self.reversal.amount(*end);
+                let (extends, reverses) = match direction {
+                    TrendDirection::Up => (input > *end, input <= *end - reversal),
+                    TrendDirection::Down => (input < *end, input >= *end + reversal),
+                };
+
+                if extends {
+                    *end = input;
+                    events.push(KagiEvent::Extension { end: input });
+                } else if reverses {
+                    let start = *end;
+                    match direction {
+                        TrendDirection::Up => {
+                            *shoulder = Some(start);
+                            *direction = TrendDirection::Down;
+                        }
+                        TrendDirection::Down => {
+                            *waist = Some(start);
+                            *direction = TrendDirection::Up;
+                        }
+                    }
+                    *end = input;
+                    events.push(KagiEvent::NewLine {
+                        direction: *direction,
+                        start,
+                        end: input,
+                    });
+                } else {
+                    return events;
+                }
+
+                match (*direction, *thickness, *shoulder, *waist) {
+                    (TrendDirection::Up, KagiThickness::Yin, Some(level), _) if input > level => {
+                        *thickness = KagiThickness::Yang;
+                        events.push(KagiEvent::ThicknessChange {
+                            thickness: KagiThickness::Yang,
+                            price: level,
+                        });
+                    }
+                    (TrendDirection::Down, KagiThickness::Yang, _, Some(level))
+                        if input < level =>
+                    {
+                        *thickness = KagiThickness::Yin;
+                        events.push(KagiEvent::ThicknessChange {
+                            thickness: KagiThickness::Yin,
+                            price: level,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        events
+    }
+}
+
+impl<T: Close> Next<&T> for KagiBuilder {
+    type Output = Vec<KagiEvent>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for KagiBuilder {
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+impl Default for KagiBuilder {
+    fn default() -> Self {
+        Self::new(ReversalAmount::Percentage(4.0)).unwrap()
+    }
+}
+
+impl fmt::Display for KagiBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KAGI({})", self.reversal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    use KagiEvent::*;
+    use KagiThickness::*;
+    use TrendDirection::*;
+
+    #[test]
+    fn test_new() {
+        assert!(KagiBuilder::new(ReversalAmount::Fixed(0.0)).is_err());
+        assert!(KagiBuilder::new(ReversalAmount::Fixed(-1.0)).is_err());
+        assert!(KagiBuilder::new(ReversalAmount::Percentage(f64::NAN)).is_err());
+        assert!(KagiBuilder::new(ReversalAmount::Fixed(1.0)).is_ok());
+        assert!(KagiBuilder::new(ReversalAmount::Percentage(4.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next_fixed() {
+        let mut kagi = KagiBuilder::new(ReversalAmount::Fixed(2.0)).unwrap();
+
+        assert_eq!(kagi.next(10.0), vec![]);
+        assert_eq!(kagi.next(11.0), vec![]);
+        assert_eq!(
+            kagi.next(12.5),
+            vec![NewLine {
+                direction: Up,
+                start: 10.0,
+                end: 12.5
+            }]
+        );
+        assert_eq!(kagi.thickness(), Some(Yang));
+        assert_eq!(kagi.next(12.0), vec![]);
+        assert_eq!(kagi.next(11.0), vec![]);
+        assert_eq!(
+            kagi.next(10.5),
+            vec![NewLine {
+                direction: Down,
+                start: 12.5,
+                end: 10.5
+            }]
+        );
+        assert_eq!(kagi.next(9.0), vec![Extension { end: 9.0 }]);
+        assert_eq!(
+            kagi.next(12.0),
+            vec![NewLine {
+                direction: Up,
+                start: 9.0,
+                end: 12.0
+            }]
+        );
+        assert_eq!(kagi.next(13.0), vec![Extension { end: 13.0 }]);
+        assert_eq!(
+            kagi.next(8.0),
+            vec![
+                NewLine {
+                    direction: Down,
+                    start: 13.0,
+                    end: 8.0
+                },
+                ThicknessChange {
+                    thickness: Yin,
+                    price: 9.0
+                }
+            ]
+        );
+        assert_eq!(kagi.thickness(), Some(Yin));
+        assert_eq!(
+            kagi.next(10.0),
+            vec![NewLine {
+                direction: Up,
+                start: 8.0,
+                end: 10.0
+            }]
+        );
+        assert_eq!(
+            kagi.next(14.0),
+            vec![
+                Extension { end: 14.0 },
+                ThicknessChange {
+                    thickness: Yang,
+                    price: 13.0
+                }
+            ]
+        );
+        assert_eq!(kagi.direction(), Some(Up));
+        assert_eq!(kagi.thickness(), Some(Yang));
+    }
+
+    #[test]
+    fn test_next_percentage() {
+        let mut kagi = KagiBuilder::new(ReversalAmount::Percentage(10.0)).unwrap();
+
+        assert_eq!(kagi.next(100.0), vec![]);
+        assert_eq!(kagi.next(105.0), vec![]);
+        assert_eq!(
+            kagi.next(111.0),
+            vec![NewLine {
+                direction: Up,
+                start: 100.0,
+                end: 111.0
+            }]
+        );
+        // 10% of 111 is 11.1
+        assert_eq!(kagi.next(100.5), vec![]);
+        assert_eq!(
+            kagi.next(99.0),
+            vec![NewLine {
+                direction: Down,
+                start: 111.0,
+                end: 99.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_next_first_line_down() {
+        let mut kagi = KagiBuilder::new(ReversalAmount::Fixed(1.0)).unwrap();
+
+        kagi.next(10.0);
+        assert_eq!(
+            kagi.next(8.0),
+            vec![NewLine {
+                direction: Down,
+                start: 10.0,
+                end: 8.0
+            }]
+        );
+        assert_eq!(kagi.thickness(), Some(Yin));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut kagi = KagiBuilder::new(ReversalAmount::Fixed(2.0)).unwrap();
+
+        assert_eq!(kagi.next(&Bar::new().close(10.0)), vec![]);
+        assert_eq!(
+            kagi.next(&Bar::new().close(12.0)),
+            vec![NewLine {
+                direction: Up,
+                start: 10.0,
+                end: 12.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kagi = KagiBuilder::new(ReversalAmount::Fixed(2.0)).unwrap();
+
+        kagi.next(10.0);
+        kagi.next(12.5);
+
+        kagi.reset();
+
+        assert_eq!(kagi.direction(), None);
+        assert_eq!(kagi.next(12.5), vec![]);
+    }
+
+    #[test]
+    fn test_default() {
+        KagiBuilder::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let kagi = KagiBuilder::new(ReversalAmount::Fixed(1.5)).unwrap();
+        assert_eq!(format!("{}", kagi), "KAGI(1.5)");
+
+        let kagi = KagiBuilder::default();
+        assert_eq!(format!("{}", kagi), "KAGI(4%)");
+    }
+}
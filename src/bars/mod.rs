@@ -0,0 +1,42 @@
+//! Bar transformers, which turn a stream of bars into another kind of bars.
+//!
+//! Transformers implement [Next<T>](../trait.Next.html) and [Reset](../trait.Reset.html)
+//! like indicators, so their output can be fed directly into indicators.
+
+mod partial_bar;
+
+// Maximum number of bars completed by a single gap or volume, the last of them covering the
+// rest of it, so that extreme inputs cannot exhaust the memory
+const MAX_BARS_PER_INPUT: usize = 10_000;
+
+mod tick;
+pub use self::tick::Tick;
+
+mod heikin_ashi;
+pub use self::heikin_ashi::HeikinAshi;
+
+mod kagi;
+pub use self::kagi::{KagiBuilder, KagiEvent, KagiThickness, ReversalAmount};
+
+mod point_and_figure;
+pub use self::point_and_figure::{
+    ColumnKind, PointAndFigureBuilder, PointAndFigureColumn, PointAndFigureEvent,
+};
+
+mod range_bars;
+pub use self::range_bars::RangeBars;
+
+mod tick_bars;
+pub use self::tick_bars::TickBars;
+
+mod volume_bars;
+pub use self::volume_bars::VolumeBars;
+
+mod bar_aggregator;
+pub use self::bar_aggregator::BarAggregator;
+
+mod timeframe;
+pub use self::timeframe::Timeframe;
+
+mod resampler;
+pub use self::resampler::Resampler;
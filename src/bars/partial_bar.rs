@@ -0,0 +1,80 @@
+use crate::{Close, DataItem, High, Low, Open, Volume};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bar which is being aggregated from smaller bars or ticks.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(super) struct PartialBar {
+    bar: Option<DataItem>,
+    count: usize,
+}
+
+impl PartialBar {
+    pub(super) fn new() -> Self {
+        Self {
+            bar: None,
+            count: 0,
+        }
+    }
+
+    pub(super) fn add<T: Open + High + Low + Close + Volume>(&mut self, input: &T) {
+        self.add_with_volume(input, input.volume());
+    }
+
+    /// Adds the input with the given part of its volume.
+    pub(super) fn add_with_volume<T: Open + High + Low + Close>(&mut self, input: &T, volume: f64) {
+        self.count += 1;
+        match self.bar {
+            Some(ref mut bar) => {
+                bar.high = bar.high.max(input.high());
+                bar.low = bar.low.min(input.low());
+                bar.close = input.close();
+                bar.volume += volume;
+            }
+            None => {
+                self.bar = Some(DataItem::unchecked(
+                    input.open(),
+                    input.high(),
+                    input.low(),
+                    input.close(),
+                    volume,
+                ));
+            }
+        }
+    }
+
+    /// Sets the start time of the bar in progress, if any.
+    #[cfg(feature = "chrono")]
+    pub(super) fn set_timestamp(&mut self, timestamp: Option<DateTime<Utc>>) {
+        if let Some(ref mut bar) = self.bar {
+            bar.timestamp = timestamp;
+        }
+    }
+
+    /// Number of inputs aggregated into the bar.
+    pub(super) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the low and the high of the bar, if any input was added to it.
+    pub(super) fn low_high(&self) -> Option<(f64, f64)> {
+        self.bar.as_ref().map(|bar| (bar.low, bar.high))
+    }
+
+    pub(super) fn volume(&self) -> f64 {
+        self.bar.as_ref().map_or(0.0, |bar| bar.volume)
+    }
+
+    pub(super) fn current(&self) -> Option<DataItem> {
+        self.bar.clone()
+    }
+
+    /// Returns the aggregated bar and starts a new one.
+    pub(super) fn take(&mut self) -> Option<DataItem> {
+        self.count = 0;
+        self.bar.take()
+    }
+}
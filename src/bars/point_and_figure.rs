@@ -0,0 +1,341 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Point & Figure chart builder.
+///
+/// Converts a stream of prices into the columns of a Point & Figure chart, using the
+/// close price method. Prices are mapped to boxes at multiples of the box size:
+/// a column of Xs is extended whenever the price rises into a higher box, and a column
+/// of Os whenever it falls into a lower one. A new column is started when the price
+/// moves by _reversal_ boxes against the current column.
+///
+/// Box levels are multiples of the box size. A rising price fills the boxes up to the level
+/// at or below it, a falling price down to the level at or above it.
+///
+/// Every call returns the events caused by the given price: a
+/// [ColumnCompleted](enum.PointAndFigureEvent.html#variant.ColumnCompleted) event on a reversal,
+/// followed by a [ColumnUpdated](enum.PointAndFigureEvent.html#variant.ColumnUpdated) event
+/// with the current state of the new or extended column.
+///
+/// # Parameters
+///
+/// * _box_size_ - price range of a box (number greater than 0). Default is 1.
+/// * _reversal_ - number of boxes needed for a reversal (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::{ColumnKind, PointAndFigureBuilder, PointAndFigureEvent};
+/// use ta::Next;
+///
+/// let mut pnf = PointAndFigureBuilder::new(1.0, 3).unwrap();
+///
+/// assert!(pnf.next(10.0).is_empty());
+/// pnf.next(12.0);
+///
+/// let column = pnf.column().unwrap();
+/// assert_eq!(column.kind, ColumnKind::X);
+/// assert_eq!((column.low, column.high, column.boxes), (11.0, 12.0, 2));
+/// ```
+///
+/// # Links
+///
+/// * [Point and figure chart, Wikipedia](https://en.wikipedia.org/wiki/Point_and_figure_chart)
+///
+#[doc(alias = "PNF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PointAndFigureBuilder {
+    box_size: f64,
+    reversal: usize,
+    anchor: Option<f64>,
+    // Kind, lowest and highest box index of the current column
+    current: Option<(ColumnKind, i64, i64)>,
+}
+
+/// Kind of a Point & Figure column.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// Rising column.
+    X,
+    /// Falling column.
+    O,
+}
+
+/// Column of a Point & Figure chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointAndFigureColumn {
+    pub kind: ColumnKind,
+    /// Price of the lowest box.
+    pub low: f64,
+    /// Price of the highest box.
+    pub high: f64,
+    /// Number of boxes in the column.
+    pub boxes: usize,
+}
+
+/// Change of a Point & Figure chart caused by a price.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PointAndFigureEvent {
+    /// A column was started or extended.
+    ColumnUpdated(PointAndFigureColumn),
+    /// A column was completed by a reversal.
+    ColumnCompleted(PointAndFigureColumn),
+}
+
+impl PointAndFigureBuilder {
+    pub fn new(box_size: f64, reversal: usize) -> Result<Self> {
+        if !(box_size.is_finite() && box_size > 0.0) || reversal == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            box_size,
+            reversal,
+            anchor: None,
+            current: None,
+        })
+    }
+
+    pub fn box_size(&self) -> f64 {
+        self.box_size
+    }
+
+    pub fn reversal(&self) -> usize {
+        self.reversal
+    }
+
+    /// Returns the current column, if any column was started.
+    pub fn column(&self) -> Option<PointAndFigureColumn> {
+        self.current
+            .map(|(kind, low, high)| self.to_column(kind, low, high))
+    }
+
+    fn to_column(&self, kind: ColumnKind, low: i64, high: i64) -> PointAndFigureColumn {
+        PointAndFigureColumn {
+            kind,
+            low: low as f64 * self.box_size,
+            high: high as f64 * self.box_size,
+            boxes: (high - low + 1) as usize,
+        }
+    }
+
+    // Box reached by a rising price
+    fn box_up(&self, price: f64) -> i64 {
+        (price / self.box_size).floor() as i64
+    }
+
+    // Box reached by a falling price
+    fn box_down(&self, price: f64) -> i64 {
+        (price / self.box_size).ceil() as i64
+    }
+}
+
+impl Next<f64> for PointAndFigureBuilder {
+    type Output = Vec<PointAndFigureEvent>;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut events = Vec::new();
+        let up = self.box_up(input);
+        let down = self.box_down(input);
+        let reversal = self.reversal as i64;
+
+        let next = match self.current {
+            None => match self.anchor {
+                None => {
+                    self.anchor = Some(input);
+                    None
+                }
+                Some(anchor) => {
+                    if up > self.box_up(anchor) {
+                        Some((ColumnKind::X, self.box_up(anchor) + 1, up))
+                    } else if down < self.box_down(anchor) {
+                        Some((ColumnKind::O, down, self.box_down(anchor) - 1))
+                    } else {
+                        None
+                    }
+                }
+            },
+            Some((ColumnKind::X, low, high)) => {
+                if up > high {
+                    Some((ColumnKind::X, low, up))
+                } else if down <= high - reversal {
+                    events.push(PointAndFigureEvent::ColumnCompleted(self.to_column(
+                        ColumnKind::X,
+                        low,
+                        high,
+                    )));
+                    Some((ColumnKind::O, down, high - 1))
+                } else {
+                    None
+                }
+            }
+            Some((ColumnKind::O, low, high)) => {
+                if down < low {
+                    Some((ColumnKind::O, down, high))
+                } else if up >= low + reversal {
+                    events.push(PointAndFigureEvent::ColumnCompleted(self.to_column(
+                        ColumnKind::O,
+                        low,
+                        high,
+                    )));
+                    Some((ColumnKind::X, low + 1, up))
+                } else {
+                    None
+                }
+            }
+        };
+
+        if let Some((kind, low, high)) = next {
+            self.current = next;
+            events.push(PointAndFigureEvent::ColumnUpdated(
+                self.to_column(kind, low, high),
+            ));
+        }
+
+        events
+    }
+}
+
+impl<T: Close> Next<&T> for PointAndFigureBuilder {
+    type Output = Vec<PointAndFigureEvent>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for PointAndFigureBuilder {
+    fn reset(&mut self) {
+        self.anchor = None;
+        self.current = None;
+    }
+}
+
+impl Default for PointAndFigureBuilder {
+    fn default() -> Self {
+        Self::new(1.0, 3).unwrap()
+    }
+}
+
+impl fmt::Display for PointAndFigureBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PNF({}, {})", self.box_size, self.reversal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    use ColumnKind::{O, X};
+    use PointAndFigureEvent::*;
+
+    fn column(kind: ColumnKind, low: f64, high: f64, boxes: usize) -> PointAndFigureColumn {
+        PointAndFigureColumn {
+            kind,
+            low,
+            high,
+            boxes,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(PointAndFigureBuilder::new(0.0, 3).is_err());
+        assert!(PointAndFigureBuilder::new(-1.0, 3).is_err());
+        assert!(PointAndFigureBuilder::new(f64::INFINITY, 3).is_err());
+        assert!(PointAndFigureBuilder::new(1.0, 0).is_err());
+        assert!(PointAndFigureBuilder::new(0.5, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pnf = PointAndFigureBuilder::new(1.0, 3).unwrap();
+
+        assert_eq!(pnf.next(10.0), vec![]);
+        assert_eq!(pnf.next(10.5), vec![]);
+        assert_eq!(
+            pnf.next(11.0),
+            vec![ColumnUpdated(column(X, 11.0, 11.0, 1))]
+        );
+        assert_eq!(
+            pnf.next(12.4),
+            vec![ColumnUpdated(column(X, 11.0, 12.0, 2))]
+        );
+        assert_eq!(pnf.next(11.0), vec![]);
+        assert_eq!(pnf.next(9.5), vec![]);
+        assert_eq!(
+            pnf.next(9.0),
+            vec![
+                ColumnCompleted(column(X, 11.0, 12.0, 2)),
+                ColumnUpdated(column(O, 9.0, 11.0, 3))
+            ]
+        );
+        assert_eq!(pnf.next(8.2), vec![]);
+        assert_eq!(pnf.next(7.9), vec![ColumnUpdated(column(O, 8.0, 11.0, 4))]);
+        assert_eq!(pnf.next(10.9), vec![]);
+        assert_eq!(
+            pnf.next(11.0),
+            vec![
+                ColumnCompleted(column(O, 8.0, 11.0, 4)),
+                ColumnUpdated(column(X, 9.0, 11.0, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_first_column_down() {
+        let mut pnf = PointAndFigureBuilder::new(0.5, 2).unwrap();
+
+        assert_eq!(pnf.next(10.2), vec![]);
+        assert_eq!(pnf.next(9.9), vec![ColumnUpdated(column(O, 10.0, 10.0, 1))]);
+        assert_eq!(pnf.next(9.4), vec![ColumnUpdated(column(O, 9.5, 10.0, 2))]);
+        assert_eq!(pnf.column(), Some(column(O, 9.5, 10.0, 2)));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut pnf = PointAndFigureBuilder::new(1.0, 3).unwrap();
+
+        assert_eq!(pnf.next(&Bar::new().close(10.0)), vec![]);
+        assert_eq!(
+            pnf.next(&Bar::new().close(12.0)),
+            vec![ColumnUpdated(column(X, 11.0, 12.0, 2))]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pnf = PointAndFigureBuilder::new(1.0, 3).unwrap();
+
+        pnf.next(10.0);
+        pnf.next(12.0);
+
+        pnf.reset();
+
+        assert_eq!(pnf.column(), None);
+        assert_eq!(pnf.next(12.0), vec![]);
+        assert_eq!(
+            pnf.next(13.0),
+            vec![ColumnUpdated(column(X, 13.0, 13.0, 1))]
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        PointAndFigureBuilder::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pnf = PointAndFigureBuilder::new(0.5, 3).unwrap();
+        assert_eq!(format!("{}", pnf), "PNF(0.5, 3)");
+    }
+}
@@ -0,0 +1,364 @@
+use std::fmt;
+
+use crate::bars::partial_bar::PartialBar;
+use crate::bars::{Tick, MAX_BARS_PER_INPUT};
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Constant range bars.
+///
+/// Aggregates ticks or small bars into bars covering a constant price range.
+/// A bar is completed as soon as the difference between its high and low reaches _range_,
+/// and is capped at exactly _range_: a price beyond it completes the bar at its limit. The
+/// next bar opens at the close of the previous one, and a gap larger than _range_ completes
+/// several bars, the ones the price skipped having no volume. A single gap completes at most
+/// 10 000 bars, the last of them covering the rest of it. Non-finite prices are ignored.
+///
+/// Bars given as input are walked from the open to the nearer of the high and the low,
+/// then to the other one and to the close. Their volume goes to the bar of their close.
+///
+/// Every call returns the completed bars, if any. The bar in progress is available
+/// with [current](#method.current).
+///
+/// # Parameters
+///
+/// * _range_ - price range of a bar (number greater than 0). Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::{RangeBars, Tick};
+/// use ta::{Close, Next};
+///
+/// let mut bars = RangeBars::new(1.5).unwrap();
+///
+/// assert!(bars.next(&Tick::new(10.0, 1.0)).is_empty());
+/// assert!(bars.next(&Tick::new(11.0, 1.0)).is_empty());
+///
+/// let completed = bars.next(&Tick::new(9.5, 2.0));
+/// assert_eq!(completed[0].close(), 9.5);
+///
+/// // A gap completes several bars
+/// let completed = bars.next(&Tick::new(13.5, 1.0));
+/// assert_eq!(completed.len(), 2);
+/// assert_eq!(completed[1].close(), 12.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RangeBars {
+    range: f64,
+    bar: PartialBar,
+}
+
+impl RangeBars {
+    pub fn new(range: f64) -> Result<Self> {
+        if !(range.is_finite() && range > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            range,
+            bar: PartialBar::new(),
+        })
+    }
+
+    pub fn range(&self) -> f64 {
+        self.range
+    }
+
+    /// Returns the bar in progress, if any input was added to it.
+    pub fn current(&self) -> Option<DataItem> {
+        self.bar.current()
+    }
+
+    fn add_price(&mut self, price: f64, volume: f64, completed: &mut Vec<DataItem>) {
+        if !price.is_finite() {
+            return;
+        }
+
+        // Side of the bar the price moved away from, and the direction it moved in
+        let (from, step) = match self.bar.low_high() {
+            Some((low, _)) if price - low >= self.range => (low, self.range),
+            Some((_, high)) if high - price >= self.range => (high, -self.range),
+            _ => {
+                self.bar.add(&Tick::new(price, volume));
+                return;
+            }
+        };
+
+        let count = ((price - from) / step).floor();
+        let capped = count >= MAX_BARS_PER_INPUT as f64;
+        let count = count.min(MAX_BARS_PER_INPUT as f64) as usize;
+
+        let mut previous = from;
+        for k in 1..=count {
+            let limit = if k == count && capped {
+                price
+            } else if step > 0.0 {
+                (from + k as f64 * step).min(price)
+            } else {
+                (from + k as f64 * step).max(price)
+            };
+            // The range is below the precision of the price
+            if limit == previous {
+                continue;
+            }
+            previous = limit;
+
+            let reached = limit == price;
+            self.bar
+                .add(&Tick::new(limit, if reached { volume } else { 0.0 }));
+            completed.extend(self.bar.take());
+            self.bar.add(&Tick::new(limit, 0.0));
+            if reached {
+                return;
+            }
+        }
+
+        self.bar.add(&Tick::new(price, volume));
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<&T> for RangeBars {
+    type Output = Vec<DataItem>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let open = input.open();
+        let (first, second) = if input.high() - open < open - input.low() {
+            (input.high(), input.low())
+        } else {
+            (input.low(), input.high())
+        };
+
+        let mut path = vec![open, first, second, input.close()];
+        path.dedup();
+
+        let mut completed = Vec::new();
+        if let Some((&close, path)) = path.split_last() {
+            for &price in path {
+                self.add_price(price, 0.0, &mut completed);
+            }
+            self.add_price(close, input.volume(), &mut completed);
+        }
+        completed
+    }
+}
+
+impl Reset for RangeBars {
+    fn reset(&mut self) {
+        self.bar.take();
+    }
+}
+
+impl Default for RangeBars {
+    fn default() -> Self {
+        Self::new(1.0).unwrap()
+    }
+}
+
+impl fmt::Display for RangeBars {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RANGE_BARS({})", self.range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bars::{Tick, MAX_BARS_PER_INPUT};
+    use crate::test_helper::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn ohlcv(bar: &DataItem) -> Ohlcv {
+        (bar.open(), bar.high(), bar.low(), bar.close(), bar.volume())
+    }
+
+    fn completed(bars: Vec<DataItem>) -> Vec<Ohlcv> {
+        bars.iter().map(ohlcv).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RangeBars::new(0.0).is_err());
+        assert!(RangeBars::new(-1.0).is_err());
+        assert!(RangeBars::new(f64::NAN).is_err());
+        assert!(RangeBars::new(0.1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bars = RangeBars::new(1.5).unwrap();
+
+        assert_eq!(completed(bars.next(&Tick::new(10.0, 1.0))), vec![]);
+        assert_eq!(completed(bars.next(&Tick::new(11.0, 1.0))), vec![]);
+        assert_eq!(
+            completed(bars.next(&Tick::new(9.5, 2.0))),
+            vec![(10.0, 11.0, 9.5, 9.5, 4.0)]
+        );
+        assert_eq!(completed(bars.next(&Tick::new(9.6, 1.0))), vec![]);
+        assert_eq!(completed(bars.next(&Tick::new(10.4, 1.0))), vec![]);
+        assert_eq!(
+            completed(bars.next(&Tick::new(8.0, 1.0))),
+            vec![(9.5, 10.4, 8.9, 8.9, 2.0)]
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((8.9, 8.9, 8.0, 8.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_next_gap() {
+        let mut bars = RangeBars::new(1.0).unwrap();
+
+        assert_eq!(completed(bars.next(&Tick::new(10.0, 1.0))), vec![]);
+        assert_eq!(
+            completed(bars.next(&Tick::new(13.5, 2.0))),
+            vec![
+                (10.0, 11.0, 10.0, 11.0, 1.0),
+                (11.0, 12.0, 11.0, 12.0, 0.0),
+                (12.0, 13.0, 12.0, 13.0, 0.0),
+            ]
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((13.0, 13.5, 13.0, 13.5, 2.0))
+        );
+
+        assert_eq!(
+            completed(bars.next(&Tick::new(11.0, 1.0))),
+            vec![(13.0, 13.5, 12.5, 12.5, 2.0), (12.5, 12.5, 11.5, 11.5, 0.0)]
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((11.5, 11.5, 11.0, 11.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_next_large_gap() {
+        let mut bars = RangeBars::new(1.0).unwrap();
+
+        bars.next(&Tick::new(0.0, 1.0));
+        let completed = bars.next(&Tick::new(1e6, 2.0));
+        assert_eq!(completed.len(), MAX_BARS_PER_INPUT);
+        assert_eq!(ohlcv(&completed[0]), (0.0, 1.0, 0.0, 1.0, 1.0));
+        assert_eq!(
+            ohlcv(&completed[MAX_BARS_PER_INPUT - 1]),
+            (9999.0, 1e6, 9999.0, 1e6, 2.0)
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((1e6, 1e6, 1e6, 1e6, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_next_below_precision() {
+        let mut bars = RangeBars::new(1.0).unwrap();
+
+        // Steps of 1 are below the precision of these prices
+        bars.next(&Tick::new(1e17, 1.0));
+        let completed = bars.next(&Tick::new(1e17 + 1e3, 1.0));
+        assert!(!completed.is_empty() && completed.len() <= 1000);
+        assert!(completed
+            .windows(2)
+            .all(|pair| pair[1].close() > pair[0].close()));
+        assert_eq!(bars.current().map(|bar| bar.close()), Some(1e17 + 1e3));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut bars = RangeBars::new(1.5).unwrap();
+
+        let bar = Bar::new()
+            .open(10.0)
+            .high(11.0)
+            .low(9.8)
+            .close(10.5)
+            .volume(1.0);
+        assert_eq!(completed(bars.next(&bar)), vec![]);
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.0, 11.0, 9.8, 10.5, 1.0))
+        );
+
+        let mut bars = RangeBars::new(1.0).unwrap();
+
+        // Walked through 10, 9.75, 11.5 and 11
+        let bar = Bar::new()
+            .open(10.0)
+            .high(11.5)
+            .low(9.75)
+            .close(11.0)
+            .volume(2.0);
+        assert_eq!(
+            completed(bars.next(&bar)),
+            vec![(10.0, 10.75, 9.75, 10.75, 0.0)]
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.75, 11.5, 10.75, 11.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn test_next_not_finite() {
+        let mut bars = RangeBars::new(1.0).unwrap();
+
+        bars.next(&Tick::new(10.0, 1.0));
+        assert_eq!(completed(bars.next(&Tick::new(f64::INFINITY, 1.0))), vec![]);
+        assert_eq!(completed(bars.next(&Tick::new(f64::NAN, 1.0))), vec![]);
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.0, 10.0, 10.0, 10.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_current() {
+        let mut bars = RangeBars::new(1.5).unwrap();
+
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+        bars.next(&Tick::new(10.0, 1.0));
+        bars.next(&Tick::new(11.0, 1.0));
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.0, 11.0, 10.0, 11.0, 2.0))
+        );
+
+        // The next bar opens at the close of the completed one
+        bars.next(&Tick::new(9.5, 2.0));
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((9.5, 9.5, 9.5, 9.5, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bars = RangeBars::new(1.5).unwrap();
+
+        bars.next(&Tick::new(10.0, 1.0));
+        bars.next(&Tick::new(11.0, 1.0));
+
+        bars.reset();
+
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+        assert_eq!(completed(bars.next(&Tick::new(9.5, 2.0))), vec![]);
+    }
+
+    #[test]
+    fn test_default() {
+        RangeBars::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let bars = RangeBars::new(2.5).unwrap();
+        assert_eq!(format!("{}", bars), "RANGE_BARS(2.5)");
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+
+use crate::bars::partial_bar::PartialBar;
+use crate::bars::Timeframe;
+use crate::errors::{Result, TaError};
+#[cfg(feature = "chrono")]
+use crate::Timestamp;
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "chrono")]
+use chrono::DateTime;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Timeframe resampler.
+///
+/// Aggregates lower timeframe bars, given as `(timestamp, bar)` pairs, into bars of a higher
+/// [timeframe](enum.Timeframe.html), e.g. 1 minute bars into 5 minute or 1 hour bars.
+/// Timestamps are Unix milliseconds of the start of the bars, and the resulting bars are
+/// aligned to the UTC calendar and identified by the timestamp of their start.
+///
+/// A bar is completed by the first input of a later interval, so every call returns the
+/// completed bar, if any. Inputs older than the bar in progress are added to it.
+///
+/// With the `chrono` feature, the resulting bars carry the timestamp of their start, and
+/// bars with a [Timestamp](../trait.Timestamp.html) can be given directly. Bars without a
+/// timestamp are then added to the bar in progress, or ignored when there is none.
+///
+/// # Parameters
+///
+/// * _timeframe_ - timeframe of the resulting bars, which must be
+///   [valid](enum.Timeframe.html#method.is_valid). Default is 5 minutes.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::{Resampler, Timeframe};
+/// use ta::{Close, DataItem, High, Next};
+///
+/// let mut resampler = Resampler::new(Timeframe::Minutes(5)).unwrap();
+///
+/// for (minute, close) in [10.0, 11.0, 12.0, 11.0, 10.5, 10.0].iter().enumerate() {
+///     let bar = DataItem::builder()
+///         .open(*close).high(*close).low(*close).close(*close).volume(1.0).build().unwrap();
+///
+///     if let Some((timestamp, bar)) = resampler.next((minute as i64 * 60_000, &bar)) {
+///         assert_eq!(timestamp, 0);
+///         assert_eq!(bar.high(), 12.0);
+///         assert_eq!(bar.close(), 10.5);
+///     }
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    timeframe: Timeframe,
+    start: Option<i64>,
+    bar: PartialBar,
+}
+
+impl Resampler {
+    pub fn new(timeframe: Timeframe) -> Result<Self> {
+        if !timeframe.is_valid() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            timeframe,
+            start: None,
+            bar: PartialBar::new(),
+        })
+    }
+
+    pub fn timeframe(&self) -> Timeframe {
+        self.timeframe
+    }
+
+    /// Returns the start timestamp and the state of the bar in progress, if any.
+    pub fn current(&self) -> Option<(i64, DataItem)> {
+        self.start.zip(self.bar.current())
+    }
+
+    /// Completes the bar in progress, e.g. at the end of the data, and returns it.
+    pub fn flush(&mut self) -> Option<(i64, DataItem)> {
+        self.start.take().zip(self.bar.take())
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<(i64, &T)> for Resampler {
+    type Output = Option<(i64, DataItem)>;
+
+    fn next(&mut self, (timestamp, input): (i64, &T)) -> Self::Output {
+        let start = self.timeframe.saturating_start_of(timestamp);
+
+        let completed = match self.start {
+            Some(current) if start > current => self.flush(),
+            _ => None,
+        };
+        if self.start.is_none() {
+            self.start = Some(start);
+        }
+
+        self.bar.add(input);
+        #[cfg(feature = "chrono")]
+        self.bar
+            .set_timestamp(self.start.and_then(DateTime::from_timestamp_millis));
+        completed
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: Open + High + Low + Close + Volume + Timestamp> Next<&T> for Resampler {
+    type Output = Option<(i64, DataItem)>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let timestamp = input
+            .timestamp()
+            .map(|timestamp| timestamp.timestamp_millis())
+            .or(self.start)?;
+        self.next((timestamp, input))
+    }
+}
+
+impl Reset for Resampler {
+    fn reset(&mut self) {
+        self.start = None;
+        self.bar.take();
+    }
+}
+
+impl Default for Resampler {
+    fn default() -> Self {
+        Self::new(Timeframe::Minutes(5)).unwrap()
+    }
+}
+
+impl fmt::Display for Resampler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RESAMPLER({})", self.timeframe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    const MINUTE: i64 = 60_000;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(10.0)
+    }
+
+    fn ohlcv(bar: Option<(i64, DataItem)>) -> Option<(i64, f64, f64, f64, f64, f64)> {
+        bar.map(|(timestamp, bar)| {
+            (
+                timestamp,
+                bar.open(),
+                bar.high(),
+                bar.low(),
+                bar.close(),
+                bar.volume(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Resampler::new(Timeframe::Minutes(0)).is_err());
+        assert!(Resampler::new(Timeframe::Minutes(7)).is_err());
+        assert!(Resampler::new(Timeframe::Hours(1)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut resampler = Resampler::new(Timeframe::Minutes(5)).unwrap();
+
+        // Starts in the middle of the 13:05 interval
+        let start = 1_624_799_220_000;
+        let bars = [
+            bar(10.0, 11.0, 9.5, 10.5),
+            bar(10.5, 12.0, 10.0, 11.5),
+            bar(11.5, 11.8, 11.0, 11.2),
+            bar(11.2, 11.4, 10.8, 11.0),
+        ];
+
+        assert_eq!(ohlcv(resampler.next((start, &bars[0]))), None);
+        assert_eq!(ohlcv(resampler.next((start + MINUTE, &bars[1]))), None);
+        assert_eq!(ohlcv(resampler.next((start + 2 * MINUTE, &bars[2]))), None);
+        assert_eq!(
+            ohlcv(resampler.next((start + 3 * MINUTE, &bars[3]))),
+            Some((start - 2 * MINUTE, 10.0, 12.0, 9.5, 11.2, 30.0))
+        );
+        assert_eq!(
+            ohlcv(resampler.current()),
+            Some((start + 3 * MINUTE, 11.2, 11.4, 10.8, 11.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn test_next_months() {
+        let mut resampler = Resampler::new(Timeframe::Months(1)).unwrap();
+
+        // 2021-03-31 23:59 and 2021-04-01 00:00 UTC
+        let march = 1_617_235_140_000;
+        let april = 1_617_235_200_000;
+
+        assert_eq!(
+            ohlcv(resampler.next((march, &bar(1.0, 2.0, 1.0, 2.0)))),
+            None
+        );
+        assert_eq!(
+            ohlcv(resampler.next((april, &bar(2.0, 3.0, 2.0, 3.0)))),
+            Some((1_614_556_800_000, 1.0, 2.0, 1.0, 2.0, 10.0))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_with_timestamps() {
+        let mut resampler = Resampler::new(Timeframe::Minutes(5)).unwrap();
+
+        // Ignored without a bar in progress
+        assert_eq!(
+            ohlcv(resampler.next(&timed_item(None, 12.0, 12.0, 1.0))),
+            None
+        );
+        assert_eq!(ohlcv(resampler.current()), None);
+
+        resampler.next(&timed_item(Some(MINUTE), 11.0, 10.0, 1.0));
+        resampler.next(&timed_item(None, 13.0, 12.0, 1.0));
+        let completed = resampler.next(&timed_item(Some(5 * MINUTE), 14.0, 14.0, 1.0));
+
+        let (_, bar) = completed.clone().unwrap();
+        assert_eq!(ohlcv(completed), Some((0, 10.0, 13.0, 10.0, 13.0, 2.0)));
+        assert_eq!(bar.timestamp(), DateTime::from_timestamp_millis(0));
+
+        let (_, bar) = resampler.current().unwrap();
+        assert_eq!(bar.timestamp(), DateTime::from_timestamp_millis(5 * MINUTE));
+    }
+
+    #[test]
+    fn test_flush() {
+        let mut resampler = Resampler::new(Timeframe::Hours(1)).unwrap();
+
+        resampler.next((0, &bar(10.0, 11.0, 9.0, 10.5)));
+        assert_eq!(
+            ohlcv(resampler.flush()),
+            Some((0, 10.0, 11.0, 9.0, 10.5, 10.0))
+        );
+        assert_eq!(ohlcv(resampler.flush()), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut resampler = Resampler::new(Timeframe::Hours(1)).unwrap();
+
+        resampler.next((0, &bar(10.0, 11.0, 9.0, 10.5)));
+
+        resampler.reset();
+
+        assert_eq!(ohlcv(resampler.current()), None);
+        assert_eq!(
+            ohlcv(resampler.next((2 * 60 * MINUTE, &bar(10.0, 11.0, 9.0, 10.5)))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Resampler::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let resampler = Resampler::new(Timeframe::Hours(4)).unwrap();
+        assert_eq!(format!("{}", resampler), "RESAMPLER(4h)");
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{Close, High, Low, Open, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A single trade.
+///
+/// A tick behaves like a bar whose open, high, low and close are all equal to the price,
+/// so it can be fed into bar aggregators as well as into indicators.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::Tick;
+/// use ta::{High, Volume};
+///
+/// let tick = Tick::new(10.5, 200.0);
+/// assert_eq!(tick.high(), 10.5);
+/// assert_eq!(tick.volume(), 200.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+    pub price: f64,
+    pub volume: f64,
+}
+
+impl Tick {
+    pub fn new(price: f64, volume: f64) -> Self {
+        Self { price, volume }
+    }
+}
+
+impl Open for Tick {
+    fn open(&self) -> f64 {
+        self.price
+    }
+}
+
+impl High for Tick {
+    fn high(&self) -> f64 {
+        self.price
+    }
+}
+
+impl Low for Tick {
+    fn low(&self) -> f64 {
+        self.price
+    }
+}
+
+impl Close for Tick {
+    fn close(&self) -> f64 {
+        self.price
+    }
+}
+
+impl Volume for Tick {
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use crate::bars::partial_bar::PartialBar;
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Constant tick count bars.
+///
+/// Aggregates ticks or small bars into bars made of a constant number of inputs.
+/// A bar is completed as soon as _count_ inputs were added to it.
+///
+/// Every call returns the completed bar, if any. The bar in progress is available
+/// with [current](#method.current).
+///
+/// # Parameters
+///
+/// * _count_ - number of inputs in a bar (integer greater than 0). Default is 100.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::{TickBars, Tick};
+/// use ta::{Close, Next};
+///
+/// let mut bars = TickBars::new(3).unwrap();
+///
+/// assert!(bars.next(&Tick::new(10.0, 1.0)).is_none());
+/// assert!(bars.next(&Tick::new(11.0, 1.0)).is_none());
+///
+/// let bar = bars.next(&Tick::new(9.5, 2.0)).unwrap();
+/// assert_eq!(bar.close(), 9.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TickBars {
+    count: usize,
+    bar: PartialBar,
+}
+
+impl TickBars {
+    pub fn new(count: usize) -> Result<Self> {
+        if count == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            count,
+            bar: PartialBar::new(),
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the bar in progress, if any input was added to it.
+    pub fn current(&self) -> Option<DataItem> {
+        self.bar.current()
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<&T> for TickBars {
+    type Output = Option<DataItem>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bar.add(input);
+        if self.bar.count() >= self.count {
+            self.bar.take()
+        } else {
+            None
+        }
+    }
+}
+
+impl Reset for TickBars {
+    fn reset(&mut self) {
+        self.bar.take();
+    }
+}
+
+impl Default for TickBars {
+    fn default() -> Self {
+        Self::new(100).unwrap()
+    }
+}
+
+impl fmt::Display for TickBars {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TICK_BARS({})", self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bars::Tick;
+    use crate::test_helper::*;
+
+    fn ohlcv(bar: Option<DataItem>) -> Option<(f64, f64, f64, f64, f64)> {
+        bar.map(|bar| (bar.open(), bar.high(), bar.low(), bar.close(), bar.volume()))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(TickBars::new(0).is_err());
+        assert!(TickBars::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bars = TickBars::new(3).unwrap();
+
+        assert_eq!(ohlcv(bars.next(&Tick::new(10.0, 1.0))), None);
+        assert_eq!(ohlcv(bars.next(&Tick::new(11.0, 1.0))), None);
+        assert_eq!(
+            ohlcv(bars.next(&Tick::new(9.5, 2.0))),
+            Some((10.0, 11.0, 9.5, 9.5, 4.0))
+        );
+        assert_eq!(ohlcv(bars.next(&Tick::new(9.6, 1.0))), None);
+        assert_eq!(ohlcv(bars.next(&Tick::new(10.4, 1.0))), None);
+        assert_eq!(
+            ohlcv(bars.next(&Tick::new(8.0, 1.0))),
+            Some((9.6, 10.4, 8.0, 8.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut bars = TickBars::new(3).unwrap();
+
+        let bar = Bar::new()
+            .open(10.0)
+            .high(11.0)
+            .low(9.8)
+            .close(10.5)
+            .volume(1.0);
+        assert_eq!(ohlcv(bars.next(&bar)), None);
+        assert_eq!(ohlcv(bars.current()), Some((10.0, 11.0, 9.8, 10.5, 1.0)));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut bars = TickBars::new(3).unwrap();
+
+        assert_eq!(ohlcv(bars.current()), None);
+        bars.next(&Tick::new(10.0, 1.0));
+        bars.next(&Tick::new(11.0, 1.0));
+        assert_eq!(ohlcv(bars.current()), Some((10.0, 11.0, 10.0, 11.0, 2.0)));
+
+        bars.next(&Tick::new(9.5, 2.0));
+        assert_eq!(ohlcv(bars.current()), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bars = TickBars::new(3).unwrap();
+
+        bars.next(&Tick::new(10.0, 1.0));
+        bars.next(&Tick::new(11.0, 1.0));
+
+        bars.reset();
+
+        assert_eq!(ohlcv(bars.current()), None);
+        assert_eq!(ohlcv(bars.next(&Tick::new(9.5, 2.0))), None);
+    }
+
+    #[test]
+    fn test_default() {
+        TickBars::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let bars = TickBars::new(500).unwrap();
+        assert_eq!(format!("{}", bars), "TICK_BARS(500)");
+    }
+}
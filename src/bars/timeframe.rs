@@ -0,0 +1,252 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MS_PER_SECOND: i64 = 1000;
+const MS_PER_MINUTE: i64 = 60 * MS_PER_SECOND;
+const MS_PER_HOUR: i64 = 60 * MS_PER_MINUTE;
+const MS_PER_DAY: i64 = 24 * MS_PER_HOUR;
+
+/// Length of a bar, aligned to the UTC calendar.
+///
+/// Intervals are aligned to multiples of their length since the Unix epoch. Counts of seconds,
+/// minutes, hours and months must divide the enclosing minute, hour, day and year, so that the
+/// intervals are also aligned within it: e.g. 15 minute bars start at :00, :15, :30 and :45 and
+/// 3 month bars start in January, April, July and October. Weeks start on Monday, several days
+/// or weeks are counted from 1970-01-01 and from Monday 1969-12-29 respectively.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::Timeframe;
+///
+/// // 2021-06-27 13:07 UTC in Unix milliseconds
+/// let timestamp = 1_624_799_220_000;
+///
+/// // 2021-06-27 13:05 UTC
+/// assert_eq!(Timeframe::Minutes(5).start_of(timestamp), Some(1_624_799_100_000));
+/// // 2021-06-01 00:00 UTC
+/// assert_eq!(Timeframe::Months(1).start_of(timestamp), Some(1_622_505_600_000));
+/// // 7 minutes do not divide an hour
+/// assert_eq!(Timeframe::Minutes(7).start_of(timestamp), None);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timeframe {
+    Seconds(u32),
+    Minutes(u32),
+    Hours(u32),
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+}
+
+impl Timeframe {
+    pub(crate) fn count(self) -> u32 {
+        match self {
+            Timeframe::Seconds(n)
+            | Timeframe::Minutes(n)
+            | Timeframe::Hours(n)
+            | Timeframe::Days(n)
+            | Timeframe::Weeks(n)
+            | Timeframe::Months(n) => n,
+        }
+    }
+
+    /// Returns true if the count is greater than 0 and, for seconds, minutes, hours and months,
+    /// divides the enclosing minute, hour, day or year.
+    pub fn is_valid(self) -> bool {
+        match self {
+            Timeframe::Seconds(n) | Timeframe::Minutes(n) => n > 0 && 60 % n == 0,
+            Timeframe::Hours(n) => n > 0 && 24 % n == 0,
+            Timeframe::Days(n) | Timeframe::Weeks(n) => n > 0,
+            Timeframe::Months(n) => n > 0 && 12 % n == 0,
+        }
+    }
+
+    /// Returns the start of the interval containing the given timestamp.
+    /// Timestamps are Unix milliseconds. Returns `None` if the timeframe is not
+    /// [valid](#method.is_valid) or the start is out of range.
+    pub fn start_of(self, timestamp: i64) -> Option<i64> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let n = i64::from(self.count());
+        let floor = |value: i64, step: i64| value.checked_sub(value.rem_euclid(step));
+
+        match self {
+            Timeframe::Seconds(_) => floor(timestamp, n * MS_PER_SECOND),
+            Timeframe::Minutes(_) => floor(timestamp, n * MS_PER_MINUTE),
+            Timeframe::Hours(_) => floor(timestamp, n * MS_PER_HOUR),
+            Timeframe::Days(_) => floor(timestamp, n * MS_PER_DAY),
+            Timeframe::Weeks(_) => {
+                // 1970-01-01 was a Thursday, the week containing it started 3 days before
+                let days = timestamp.div_euclid(MS_PER_DAY) + 3;
+                (floor(days, 7 * n)? - 3).checked_mul(MS_PER_DAY)
+            }
+            Timeframe::Months(_) => {
+                let (year, month, _) = civil_from_days(timestamp.div_euclid(MS_PER_DAY));
+                let months = floor(year * 12 + month - 1, n)?;
+                days_from_civil(months.div_euclid(12), months.rem_euclid(12) + 1, 1)
+                    .checked_mul(MS_PER_DAY)
+            }
+        }
+    }
+
+    /// Returns the start of the interval containing the given timestamp, saturating to `i64::MIN`
+    /// if it is out of range. The timeframe must be valid.
+    pub(crate) fn saturating_start_of(self, timestamp: i64) -> i64 {
+        debug_assert!(self.is_valid());
+        self.start_of(timestamp).unwrap_or(i64::MIN)
+    }
+}
+
+impl fmt::Display for Timeframe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Timeframe::Seconds(n) => write!(f, "{}s", n),
+            Timeframe::Minutes(n) => write!(f, "{}m", n),
+            Timeframe::Hours(n) => write!(f, "{}h", n),
+            Timeframe::Days(n) => write!(f, "{}d", n),
+            Timeframe::Weeks(n) => write!(f, "{}w", n),
+            Timeframe::Months(n) => write!(f, "{}M", n),
+        }
+    }
+}
+
+// Algorithms from http://howardhinnant.github.io/date_algorithms.html
+
+/// Returns the number of days since 1970-01-01 of the given date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the (year, month, day) of the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2021-06-27 13:07 UTC, a Sunday
+    const TIMESTAMP: i64 = 1_624_799_220_000;
+
+    #[test]
+    fn test_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_start_of() {
+        assert_eq!(
+            Timeframe::Seconds(30).start_of(TIMESTAMP + 45_000),
+            Some(TIMESTAMP + 30_000)
+        );
+        assert_eq!(
+            Timeframe::Minutes(1).start_of(TIMESTAMP + 59_999),
+            Some(TIMESTAMP)
+        );
+        assert_eq!(
+            Timeframe::Minutes(5).start_of(TIMESTAMP),
+            Some(1_624_799_100_000)
+        );
+        assert_eq!(
+            Timeframe::Hours(1).start_of(TIMESTAMP),
+            Some(1_624_798_800_000)
+        );
+        assert_eq!(
+            Timeframe::Days(1).start_of(TIMESTAMP),
+            Some(1_624_752_000_000)
+        );
+        // Monday, 2021-06-21
+        assert_eq!(
+            Timeframe::Weeks(1).start_of(TIMESTAMP),
+            Some(1_624_233_600_000)
+        );
+        // 2021-06-01
+        assert_eq!(
+            Timeframe::Months(1).start_of(TIMESTAMP),
+            Some(1_622_505_600_000)
+        );
+        // 2021-04-01
+        assert_eq!(
+            Timeframe::Months(3).start_of(TIMESTAMP),
+            Some(1_617_235_200_000)
+        );
+        // 2021-01-01
+        assert_eq!(
+            Timeframe::Months(12).start_of(TIMESTAMP),
+            Some(1_609_459_200_000)
+        );
+    }
+
+    #[test]
+    fn test_start_of_before_epoch() {
+        // 1969-12-31 23:59 UTC
+        assert_eq!(Timeframe::Days(1).start_of(-60_000), Some(-MS_PER_DAY));
+        // Monday, 1969-12-29
+        assert_eq!(Timeframe::Weeks(1).start_of(-60_000), Some(-3 * MS_PER_DAY));
+        // 1969-12-01
+        assert_eq!(
+            Timeframe::Months(1).start_of(-60_000),
+            Some(-31 * MS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Timeframe::Seconds(15).is_valid());
+        assert!(Timeframe::Minutes(30).is_valid());
+        assert!(Timeframe::Hours(4).is_valid());
+        assert!(Timeframe::Days(3).is_valid());
+        assert!(Timeframe::Weeks(2).is_valid());
+        assert!(Timeframe::Months(6).is_valid());
+
+        assert!(!Timeframe::Minutes(0).is_valid());
+        assert!(!Timeframe::Days(0).is_valid());
+        assert!(!Timeframe::Seconds(7).is_valid());
+        assert!(!Timeframe::Minutes(45).is_valid());
+        assert!(!Timeframe::Hours(5).is_valid());
+        assert!(!Timeframe::Months(5).is_valid());
+    }
+
+    #[test]
+    fn test_start_of_invalid() {
+        assert_eq!(Timeframe::Minutes(0).start_of(TIMESTAMP), None);
+        assert_eq!(Timeframe::Weeks(0).start_of(TIMESTAMP), None);
+        assert_eq!(Timeframe::Hours(7).start_of(TIMESTAMP), None);
+        assert_eq!(Timeframe::Seconds(1).start_of(i64::MIN), None);
+        assert_eq!(Timeframe::Weeks(1).start_of(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Timeframe::Minutes(5)), "5m");
+        assert_eq!(format!("{}", Timeframe::Months(1)), "1M");
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt;
+
+use crate::bars::partial_bar::PartialBar;
+use crate::bars::MAX_BARS_PER_INPUT;
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Constant volume bars.
+///
+/// Aggregates ticks or small bars into bars of a constant traded volume.
+/// A bar is completed as soon as its volume reaches _volume_. The volume of an input
+/// is split between bars, so that every completed bar has exactly _volume_: an input
+/// larger than the remaining volume completes the bar and the rest of it goes to the next
+/// bars, each of them getting the prices of the input. A single input completes at most
+/// 10 000 bars, the last of them getting the rest of its volume.
+///
+/// Every call returns the completed bars, if any. The bar in progress is available
+/// with [current](#method.current).
+///
+/// # Parameters
+///
+/// * _volume_ - volume of a bar (number greater than 0). Default is 1000.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::{VolumeBars, Tick};
+/// use ta::{Close, Next, Volume};
+///
+/// let mut bars = VolumeBars::new(3.5).unwrap();
+///
+/// assert!(bars.next(&Tick::new(10.0, 1.0)).is_empty());
+/// assert!(bars.next(&Tick::new(11.0, 1.0)).is_empty());
+///
+/// let completed = bars.next(&Tick::new(9.5, 2.0));
+/// assert_eq!(completed[0].close(), 9.5);
+/// assert_eq!(completed[0].volume(), 3.5);
+///
+/// // The rest of the volume went to the next bar
+/// assert_eq!(bars.current().unwrap().volume(), 0.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeBars {
+    volume: f64,
+    bar: PartialBar,
+}
+
+impl VolumeBars {
+    pub fn new(volume: f64) -> Result<Self> {
+        if !(volume.is_finite() && volume > 0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            volume,
+            bar: PartialBar::new(),
+        })
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    /// Returns the bar in progress, if any input was added to it.
+    pub fn current(&self) -> Option<DataItem> {
+        self.bar.current()
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<&T> for VolumeBars {
+    type Output = Vec<DataItem>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let volume = input.volume();
+        let room = self.volume - self.bar.volume();
+
+        // Volumes which are not finite are added as they are
+        if !(volume.is_finite() && volume >= room) {
+            self.bar.add_with_volume(input, volume);
+            return Vec::new();
+        }
+
+        // The bar in progress is filled up, followed by as many full bars as the rest allows
+        let rest = volume - room;
+        let full = (rest / self.volume).floor();
+        let capped = full >= (MAX_BARS_PER_INPUT - 1) as f64;
+        let full = full.min((MAX_BARS_PER_INPUT - 1) as f64) as usize;
+        let remainder = rest - full as f64 * self.volume;
+
+        let mut completed = Vec::with_capacity(full + 1);
+        self.bar.add_with_volume(input, room);
+        completed.extend(self.bar.take());
+        for _ in 0..full {
+            self.bar.add_with_volume(input, self.volume);
+            completed.extend(self.bar.take());
+        }
+
+        if capped {
+            if let Some(last) = completed.last_mut() {
+                last.volume += remainder;
+            }
+        } else if remainder > 0.0 {
+            self.bar.add_with_volume(input, remainder);
+        }
+        completed
+    }
+}
+
+impl Reset for VolumeBars {
+    fn reset(&mut self) {
+        self.bar.take();
+    }
+}
+
+impl Default for VolumeBars {
+    fn default() -> Self {
+        Self::new(1000.0).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeBars {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VOLUME_BARS({})", self.volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bars::Tick;
+    use crate::test_helper::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn ohlcv(bar: &DataItem) -> Ohlcv {
+        (bar.open(), bar.high(), bar.low(), bar.close(), bar.volume())
+    }
+
+    fn completed(bars: Vec<DataItem>) -> Vec<Ohlcv> {
+        bars.iter().map(ohlcv).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeBars::new(0.0).is_err());
+        assert!(VolumeBars::new(-1.0).is_err());
+        assert!(VolumeBars::new(f64::INFINITY).is_err());
+        assert!(VolumeBars::new(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bars = VolumeBars::new(3.5).unwrap();
+
+        assert_eq!(completed(bars.next(&Tick::new(10.0, 1.0))), vec![]);
+        assert_eq!(completed(bars.next(&Tick::new(11.0, 1.0))), vec![]);
+        assert_eq!(
+            completed(bars.next(&Tick::new(9.5, 2.0))),
+            vec![(10.0, 11.0, 9.5, 9.5, 3.5)]
+        );
+        assert_eq!(completed(bars.next(&Tick::new(9.6, 1.0))), vec![]);
+        assert_eq!(completed(bars.next(&Tick::new(10.4, 1.0))), vec![]);
+        assert_eq!(
+            completed(bars.next(&Tick::new(8.0, 2.0))),
+            vec![(9.5, 10.4, 8.0, 8.0, 3.5)]
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((8.0, 8.0, 8.0, 8.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_next_split() {
+        let mut bars = VolumeBars::new(2.0).unwrap();
+
+        assert_eq!(completed(bars.next(&Tick::new(11.0, 1.0))), vec![]);
+        assert_eq!(
+            completed(bars.next(&Tick::new(10.0, 4.0))),
+            vec![(11.0, 11.0, 10.0, 10.0, 2.0), (10.0, 10.0, 10.0, 10.0, 2.0)]
+        );
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.0, 10.0, 10.0, 10.0, 1.0))
+        );
+
+        // An input filling the bar exactly leaves no bar in progress
+        assert_eq!(
+            completed(bars.next(&Tick::new(9.0, 1.0))),
+            vec![(10.0, 10.0, 9.0, 9.0, 2.0)]
+        );
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+
+        assert_eq!(completed(bars.next(&Tick::new(9.0, f64::INFINITY))), vec![]);
+    }
+
+    #[test]
+    fn test_next_large_volume() {
+        let mut bars = VolumeBars::new(1.0).unwrap();
+
+        let completed = bars.next(&Tick::new(10.0, 1e16));
+        assert_eq!(completed.len(), MAX_BARS_PER_INPUT);
+        assert_eq!(ohlcv(&completed[0]), (10.0, 10.0, 10.0, 10.0, 1.0));
+        assert_eq!(completed[MAX_BARS_PER_INPUT - 1].volume(), 1e16 - 9999.0);
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut bars = VolumeBars::new(3.5).unwrap();
+
+        let bar = Bar::new()
+            .open(10.0)
+            .high(11.0)
+            .low(9.8)
+            .close(10.5)
+            .volume(1.0);
+        assert_eq!(completed(bars.next(&bar)), vec![]);
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.0, 11.0, 9.8, 10.5, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_current() {
+        let mut bars = VolumeBars::new(3.5).unwrap();
+
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+        bars.next(&Tick::new(10.0, 1.0));
+        bars.next(&Tick::new(11.0, 1.0));
+        assert_eq!(
+            bars.current().map(|bar| ohlcv(&bar)),
+            Some((10.0, 11.0, 10.0, 11.0, 2.0))
+        );
+
+        bars.next(&Tick::new(9.5, 1.5));
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bars = VolumeBars::new(3.5).unwrap();
+
+        bars.next(&Tick::new(10.0, 1.0));
+        bars.next(&Tick::new(11.0, 1.0));
+
+        bars.reset();
+
+        assert_eq!(bars.current().map(|bar| ohlcv(&bar)), None);
+        assert_eq!(completed(bars.next(&Tick::new(9.5, 2.0))), vec![]);
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeBars::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let bars = VolumeBars::new(2500.0).unwrap();
+        assert_eq!(format!("{}", bars), "VOLUME_BARS(2500)");
+    }
+}
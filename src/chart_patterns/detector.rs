@@ -0,0 +1,665 @@
+use std::fmt;
+
+use crate::chart_patterns::{PatternDirection, PatternEvent, PatternKind};
+use crate::errors::{Result, TaError};
+use crate::indicators::{Pivot, PivotDetector};
+use crate::{Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Number of the most recent swing points the patterns are searched in
+const MAX_SWINGS: usize = 5;
+
+/// Chart pattern detector.
+///
+/// Builds an alternating sequence of swing highs and lows from a
+/// [PivotDetector](../indicators/struct.PivotDetector.html): when two swings of the same kind
+/// follow each other, only the more extreme one is kept. Whenever a swing is confirmed, the most
+/// recent swings are matched against the [patterns](enum.PatternKind.html):
+///
+/// * head and shoulders - broken below the neckline
+/// * inverse head and shoulders - broken above the neckline
+/// * double top - broken below the swing low between the tops
+/// * double bottom - broken above the swing high between the bottoms
+/// * ascending, descending and symmetrical triangles - broken in either direction
+/// * bull flag - broken above the line through the highs of the flag
+/// * bear flag - broken below the line through the lows of the flag
+///
+/// Prices are considered equal when they differ by at most _tolerance_ percent of their average.
+///
+/// A formed pattern is completed when a bar closes beyond its breakout line, which emits a
+/// [PatternEvent](struct.PatternEvent.html). The measured-move target is the height of the
+/// pattern projected from the breakout price: the distance from the head to the neckline,
+/// between the tops (bottoms) and the swing in between, between the triangle lines at the
+/// first swing, or the length of the flag pole. A pattern is dropped when the price closes
+/// beyond its opposite side (the head, the tops or bottoms, or half of the flag pole), when
+/// the lines of a triangle cross, or when a newer pattern of the same kind is formed.
+///
+/// # Parameters
+///
+/// * _left_ - number of bars before a swing point (integer greater than 0). Default is 5.
+/// * _right_ - number of bars after a swing point (integer greater than 0). Default is 5.
+/// * _tolerance_ - maximum difference of equal prices in percent (number greater than or equal to 0). Default is 3.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::chart_patterns::{ChartPatterns, PatternDirection, PatternKind};
+/// use ta::Next;
+///
+/// let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+///
+/// let mut events = vec![];
+/// for &close in &[10.0, 20.0, 15.0, 20.5, 16.0, 14.0] {
+///     events.extend(patterns.next(close));
+/// }
+///
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].kind, PatternKind::DoubleTop);
+/// assert_eq!(events[0].direction, PatternDirection::Bearish);
+/// assert_eq!(events[0].completion_index, 5);
+/// assert_eq!(events[0].breakout_price, 15.0);
+/// assert_eq!(events[0].target, 9.5);
+/// ```
+///
+/// # Links
+///
+/// * [Chart patterns, Investopedia](https://www.investopedia.com/articles/technical/112601.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChartPatterns {
+    tolerance: f64,
+    pivots: PivotDetector,
+    count: usize,
+    swings: Vec<Swing>,
+    candidates: Vec<Candidate>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct Swing {
+    pivot: Pivot,
+    is_high: bool,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct Line {
+    index: usize,
+    price: f64,
+    slope: f64,
+}
+
+impl Line {
+    fn horizontal(price: f64) -> Self {
+        Self {
+            index: 0,
+            price,
+            slope: 0.0,
+        }
+    }
+
+    fn through(start: Pivot, end: Pivot) -> Self {
+        Self {
+            index: start.index,
+            price: start.price,
+            slope: (end.price - start.price) / (end.index - start.index) as f64,
+        }
+    }
+
+    fn at(&self, index: usize) -> f64 {
+        self.price + self.slope * (index as f64 - self.index as f64)
+    }
+}
+
+// Formed pattern waiting for the breakout
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct Candidate {
+    kind: PatternKind,
+    points: Vec<Pivot>,
+    upper: Line,
+    lower: Line,
+    height: f64,
+    bullish: bool,
+    bearish: bool,
+}
+
+impl ChartPatterns {
+    pub fn new(left: usize, right: usize, tolerance: f64) -> Result<Self> {
+        if !(tolerance >= 0.0 && tolerance.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            tolerance,
+            pivots: PivotDetector::new(left, right)?,
+            count: 0,
+            swings: Vec::with_capacity(MAX_SWINGS + 1),
+            candidates: vec![],
+        })
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    fn equal(&self, a: f64, b: f64) -> bool {
+        (a - b).abs() <= self.tolerance / 100.0 * (a.abs() + b.abs()) / 2.0
+    }
+
+    fn add_swing(&mut self, pivot: Pivot, is_high: bool) {
+        match self.swings.last_mut() {
+            Some(last) if last.is_high == is_high => {
+                let is_more_extreme = if is_high {
+                    pivot.price > last.pivot.price
+                } else {
+                    pivot.price < last.pivot.price
+                };
+                if !is_more_extreme {
+                    return;
+                }
+                last.pivot = pivot;
+            }
+            _ => {
+                self.swings.push(Swing { pivot, is_high });
+                if self.swings.len() > MAX_SWINGS {
+                    self.swings.remove(0);
+                }
+            }
+        }
+
+        self.find_patterns(is_high);
+    }
+
+    fn find_patterns(&mut self, last_is_high: bool) {
+        let points: Vec<Pivot> = self.swings.iter().map(|swing| swing.pivot).collect();
+        let n = points.len();
+
+        if n >= 3 {
+            self.find_double(&points[n - 3..], last_is_high);
+        }
+        if n >= 4 {
+            self.find_triangle(&points[n - 4..], last_is_high);
+        }
+        if n >= 5 {
+            self.find_head_and_shoulders(&points[n - 5..], last_is_high);
+            self.find_flag(&points[n - 5..], last_is_high);
+        }
+    }
+
+    fn find_double(&mut self, p: &[Pivot], last_is_high: bool) {
+        if !self.equal(p[0].price, p[2].price) {
+            return;
+        }
+
+        let candidate = if last_is_high {
+            let top = p[0].price.max(p[2].price);
+            Candidate {
+                kind: PatternKind::DoubleTop,
+                points: p.to_vec(),
+                upper: Line::horizontal(top),
+                lower: Line::horizontal(p[1].price),
+                height: top - p[1].price,
+                bullish: false,
+                bearish: true,
+            }
+        } else {
+            let bottom = p[0].price.min(p[2].price);
+            Candidate {
+                kind: PatternKind::DoubleBottom,
+                points: p.to_vec(),
+                upper: Line::horizontal(p[1].price),
+                lower: Line::horizontal(bottom),
+                height: p[1].price - bottom,
+                bullish: true,
+                bearish: false,
+            }
+        };
+        self.add_candidate(candidate);
+    }
+
+    fn find_triangle(&mut self, p: &[Pivot], last_is_high: bool) {
+        // Swings alternate, so the highs are either at odd or at even positions
+        let (highs, lows) = if last_is_high {
+            ((p[1], p[3]), (p[0], p[2]))
+        } else {
+            ((p[0], p[2]), (p[1], p[3]))
+        };
+
+        let flat_highs = self.equal(highs.0.price, highs.1.price);
+        let flat_lows = self.equal(lows.0.price, lows.1.price);
+        let falling_highs = !flat_highs && highs.1.price < highs.0.price;
+        let rising_lows = !flat_lows && lows.1.price > lows.0.price;
+
+        let kind = if flat_highs && rising_lows {
+            PatternKind::AscendingTriangle
+        } else if falling_highs && flat_lows {
+            PatternKind::DescendingTriangle
+        } else if falling_highs && rising_lows {
+            PatternKind::SymmetricalTriangle
+        } else {
+            return;
+        };
+
+        let upper = Line::through(highs.0, highs.1);
+        let lower = Line::through(lows.0, lows.1);
+        self.add_candidate(Candidate {
+            kind,
+            points: p.to_vec(),
+            upper,
+            lower,
+            height: upper.at(p[0].index) - lower.at(p[0].index),
+            bullish: true,
+            bearish: true,
+        });
+    }
+
+    fn find_head_and_shoulders(&mut self, p: &[Pivot], last_is_high: bool) {
+        let (left, head, right) = (p[0].price, p[2].price, p[4].price);
+        if !self.equal(left, right) || self.equal(head, left) || self.equal(head, right) {
+            return;
+        }
+
+        let neckline = Line::through(p[1], p[3]);
+        let neckline_at_head = neckline.at(p[2].index);
+
+        let candidate = if last_is_high && head > left && head > right {
+            Candidate {
+                kind: PatternKind::HeadAndShoulders,
+                points: p.to_vec(),
+                upper: Line::horizontal(head),
+                lower: neckline,
+                height: head - neckline_at_head,
+                bullish: false,
+                bearish: true,
+            }
+        } else if !last_is_high && head < left && head < right {
+            Candidate {
+                kind: PatternKind::InverseHeadAndShoulders,
+                points: p.to_vec(),
+                upper: neckline,
+                lower: Line::horizontal(head),
+                height: neckline_at_head - head,
+                bullish: true,
+                bearish: false,
+            }
+        } else {
+            return;
+        };
+        self.add_candidate(candidate);
+    }
+
+    fn find_flag(&mut self, p: &[Pivot], last_is_high: bool) {
+        // The pole runs from the first swing to the second one, the flag is made of the rest
+        let pole = (p[1].price - p[0].price).abs();
+        if pole == 0.0 {
+            return;
+        }
+
+        let candidate = if !last_is_high {
+            let (high1, low1, high2, low2) = (p[1].price, p[2].price, p[3].price, p[4].price);
+            if high2 > high1 || low2 > low1 || high1 - low1.min(low2) > pole / 2.0 {
+                return;
+            }
+            Candidate {
+                kind: PatternKind::BullFlag,
+                points: p.to_vec(),
+                upper: Line::through(p[1], p[3]),
+                lower: Line::horizontal(high1 - pole / 2.0),
+                height: pole,
+                bullish: true,
+                bearish: false,
+            }
+        } else {
+            let (low1, high1, low2, high2) = (p[1].price, p[2].price, p[3].price, p[4].price);
+            if low2 < low1 || high2 < high1 || high1.max(high2) - low1 > pole / 2.0 {
+                return;
+            }
+            Candidate {
+                kind: PatternKind::BearFlag,
+                points: p.to_vec(),
+                upper: Line::horizontal(low1 + pole / 2.0),
+                lower: Line::through(p[1], p[3]),
+                height: pole,
+                bullish: false,
+                bearish: true,
+            }
+        };
+        self.add_candidate(candidate);
+    }
+
+    fn add_candidate(&mut self, candidate: Candidate) {
+        self.candidates.retain(|other| other.kind != candidate.kind);
+        self.candidates.push(candidate);
+    }
+
+    fn check_breakouts(&mut self, close: f64, index: usize) -> Vec<PatternEvent> {
+        let mut events = vec![];
+
+        self.candidates.retain(|candidate| {
+            let upper = candidate.upper.at(index);
+            let lower = candidate.lower.at(index);
+            if upper < lower {
+                return false;
+            }
+
+            let (direction, breakout_price, target, is_allowed) = if close > upper {
+                (
+                    PatternDirection::Bullish,
+                    upper,
+                    upper + candidate.height,
+                    candidate.bullish,
+                )
+            } else if close < lower {
+                (
+                    PatternDirection::Bearish,
+                    lower,
+                    lower - candidate.height,
+                    candidate.bearish,
+                )
+            } else {
+                return true;
+            };
+
+            if is_allowed {
+                events.push(PatternEvent {
+                    kind: candidate.kind,
+                    direction,
+                    points: candidate.points.clone(),
+                    completion_index: index,
+                    breakout_price,
+                    target,
+                });
+            }
+            false
+        });
+
+        events
+    }
+}
+
+impl Next<(f64, f64, f64)> for ChartPatterns {
+    type Output = Vec<PatternEvent>;
+
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        let pivots = self.pivots.next((high, low));
+        let index = self.count;
+        self.count += 1;
+
+        // A bar can be both a swing high and a swing low, keep the sequence alternating
+        let last_is_high = self.swings.last().is_some_and(|swing| swing.is_high);
+        match (pivots.high, pivots.low) {
+            (Some(high), Some(low)) if last_is_high => {
+                self.add_swing(low, false);
+                self.add_swing(high, true);
+            }
+            (Some(high), Some(low)) => {
+                self.add_swing(high, true);
+                self.add_swing(low, false);
+            }
+            (Some(high), None) => self.add_swing(high, true),
+            (None, Some(low)) => self.add_swing(low, false),
+            (None, None) => {}
+        }
+
+        self.check_breakouts(close, index)
+    }
+}
+
+impl Next<f64> for ChartPatterns {
+    type Output = Vec<PatternEvent>;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.next((input, input, input))
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for ChartPatterns {
+    type Output = Vec<PatternEvent>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high(), input.low(), input.close()))
+    }
+}
+
+impl Reset for ChartPatterns {
+    fn reset(&mut self) {
+        self.pivots.reset();
+        self.count = 0;
+        self.swings.clear();
+        self.candidates.clear();
+    }
+}
+
+impl Default for ChartPatterns {
+    fn default() -> Self {
+        Self::new(5, 5, 3.0).unwrap()
+    }
+}
+
+impl fmt::Display for ChartPatterns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PATTERNS({}, {}, {})",
+            self.pivots.left(),
+            self.pivots.right(),
+            self.tolerance
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ChartPatterns);
+
+    // (kind, direction, completion index, breakout price, target) of every event
+    fn run(
+        patterns: &mut ChartPatterns,
+        closes: &[f64],
+    ) -> Vec<(PatternKind, PatternDirection, usize, f64, f64)> {
+        closes
+            .iter()
+            .flat_map(|&close| patterns.next(close))
+            .map(|event| {
+                (
+                    event.kind,
+                    event.direction,
+                    event.completion_index,
+                    round(event.breakout_price),
+                    round(event.target),
+                )
+            })
+            .collect()
+    }
+
+    fn pivot(index: usize, price: f64) -> Pivot {
+        Pivot { index, price }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ChartPatterns::new(0, 1, 3.0).is_err());
+        assert!(ChartPatterns::new(1, 0, 3.0).is_err());
+        assert!(ChartPatterns::new(1, 1, -1.0).is_err());
+        assert!(ChartPatterns::new(1, 1, f64::NAN).is_err());
+        assert!(ChartPatterns::new(1, 1, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_next_double_top() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        let events = run(&mut patterns, &[10.0, 20.0, 15.0, 20.5, 16.0, 14.0]);
+        assert_eq!(
+            events,
+            vec![(
+                PatternKind::DoubleTop,
+                PatternDirection::Bearish,
+                5,
+                15.0,
+                9.5
+            )]
+        );
+    }
+
+    #[test]
+    fn test_next_head_and_shoulders() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        let closes = [10.0, 20.0, 16.0, 25.0, 15.0, 20.3];
+        assert!(run(&mut patterns, &closes).is_empty());
+
+        let events = patterns.next(12.0);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, PatternKind::HeadAndShoulders);
+        assert_eq!(events[0].direction, PatternDirection::Bearish);
+        assert_eq!(
+            events[0].points,
+            vec![
+                pivot(1, 20.0),
+                pivot(2, 16.0),
+                pivot(3, 25.0),
+                pivot(4, 15.0),
+                pivot(5, 20.3)
+            ]
+        );
+        assert_eq!(events[0].completion_index, 6);
+        assert_eq!(events[0].breakout_price, 14.0);
+        assert_eq!(events[0].target, 4.5);
+    }
+
+    #[test]
+    fn test_next_inverse_head_and_shoulders() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        let events = run(&mut patterns, &[30.0, 20.0, 24.0, 15.0, 25.0, 19.7, 28.0]);
+        assert_eq!(
+            events,
+            vec![(
+                PatternKind::InverseHeadAndShoulders,
+                PatternDirection::Bullish,
+                6,
+                26.0,
+                35.5
+            )]
+        );
+    }
+
+    #[test]
+    fn test_next_triangle() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        // The double bottom formed on the way is dropped by the breakout below it
+        let events = run(&mut patterns, &[30.0, 20.0, 28.0, 20.2, 26.0, 15.0]);
+        assert_eq!(
+            events,
+            vec![(
+                PatternKind::DescendingTriangle,
+                PatternDirection::Bearish,
+                5,
+                20.4,
+                11.4
+            )]
+        );
+    }
+
+    #[test]
+    fn test_next_invalidated() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        // The double top is dropped above the tops, completing an ascending triangle instead
+        let events = run(&mut patterns, &[10.0, 20.0, 15.0, 20.2, 16.0, 21.0, 14.0]);
+        assert_eq!(
+            events,
+            vec![(
+                PatternKind::AscendingTriangle,
+                PatternDirection::Bullish,
+                5,
+                20.4,
+                25.9
+            )]
+        );
+    }
+
+    #[test]
+    fn test_next_flags() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        let events = run(&mut patterns, &[12.0, 10.0, 20.0, 17.0, 19.0, 16.0, 21.0]);
+        assert_eq!(
+            events,
+            vec![(
+                PatternKind::BullFlag,
+                PatternDirection::Bullish,
+                6,
+                18.0,
+                28.0
+            )]
+        );
+
+        patterns.reset();
+
+        let events = run(&mut patterns, &[28.0, 30.0, 20.0, 23.0, 21.0, 24.0, 19.0]);
+        assert_eq!(
+            events,
+            vec![(
+                PatternKind::BearFlag,
+                PatternDirection::Bearish,
+                6,
+                22.0,
+                12.0
+            )]
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64, close: f64) -> Bar {
+            Bar::new().high(high).low(low).close(close)
+        }
+
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        assert!(patterns.next(&bar(11.0, 9.0, 10.0)).is_empty());
+        assert!(patterns.next(&bar(21.0, 18.0, 20.0)).is_empty());
+        assert!(patterns.next(&bar(17.0, 15.0, 16.0)).is_empty());
+        assert!(patterns.next(&bar(20.5, 17.0, 19.0)).is_empty());
+        assert!(patterns.next(&bar(18.0, 16.0, 17.0)).is_empty());
+
+        let events = patterns.next(&bar(16.0, 13.0, 14.0));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, PatternKind::DoubleTop);
+        assert_eq!(events[0].breakout_price, 15.0);
+        assert_eq!(events[0].target, 9.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut patterns = ChartPatterns::new(1, 1, 3.0).unwrap();
+
+        run(&mut patterns, &[10.0, 20.0, 15.0, 20.5, 16.0]);
+
+        patterns.reset();
+
+        let events = run(&mut patterns, &[10.0, 20.0, 15.0, 20.5, 16.0, 14.0]);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].2, 5);
+    }
+
+    #[test]
+    fn test_default() {
+        ChartPatterns::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ChartPatterns::new(5, 3, 2.5).unwrap();
+        assert_eq!(format!("{}", indicator), "PATTERNS(5, 3, 2.5)");
+    }
+}
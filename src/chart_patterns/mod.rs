@@ -0,0 +1,15 @@
+//! Classical chart pattern recognition.
+//!
+//! Patterns are built from swing highs and lows found by a
+//! [PivotDetector](../indicators/struct.PivotDetector.html). A pattern is reported once it is
+//! completed, i.e. when the price closes beyond its breakout line, together with the
+//! measured-move target.
+//!
+//! The detector implements [Next<T>](../trait.Next.html) and [Reset](../trait.Reset.html)
+//! like indicators.
+
+mod pattern;
+pub use self::pattern::{PatternDirection, PatternEvent, PatternKind};
+
+mod detector;
+pub use self::detector::ChartPatterns;
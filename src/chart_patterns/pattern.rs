@@ -0,0 +1,55 @@
+use crate::indicators::Pivot;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of a chart pattern.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    /// Three swing highs, the middle one (head) above the other two (shoulders), which are
+    /// roughly equal. The neckline goes through the two swing lows between them.
+    HeadAndShoulders,
+    /// Head and shoulders turned upside down.
+    InverseHeadAndShoulders,
+    /// Two roughly equal swing highs separated by a swing low.
+    DoubleTop,
+    /// Two roughly equal swing lows separated by a swing high.
+    DoubleBottom,
+    /// Roughly equal swing highs and rising swing lows.
+    AscendingTriangle,
+    /// Falling swing highs and roughly equal swing lows.
+    DescendingTriangle,
+    /// Falling swing highs and rising swing lows.
+    SymmetricalTriangle,
+    /// Sharp rise (pole) followed by a consolidation with falling swing highs and lows,
+    /// retracing at most half of the pole.
+    BullFlag,
+    /// Sharp fall (pole) followed by a consolidation with rising swing highs and lows,
+    /// retracing at most half of the pole.
+    BearFlag,
+}
+
+/// Direction of the breakout of a chart pattern.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternDirection {
+    Bullish,
+    Bearish,
+}
+
+/// Completed chart pattern found by [ChartPatterns](struct.ChartPatterns.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternEvent {
+    pub kind: PatternKind,
+    pub direction: PatternDirection,
+    /// Swing points forming the pattern, from the oldest one.
+    pub points: Vec<Pivot>,
+    /// Index of the bar closing beyond the breakout line, counted from 0 since the detector
+    /// was created or reset.
+    pub completion_index: usize,
+    /// Value of the breakout line at the completion bar.
+    pub breakout_price: f64,
+    /// Measured-move target: the height of the pattern projected from the breakout price.
+    pub target: f64,
+}
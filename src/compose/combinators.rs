@@ -0,0 +1,384 @@
+use std::fmt;
+
+use crate::{Current, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Indicator feeding the outputs of an indicator into another one.
+///
+/// Returned by [Compose::then](trait.Compose.html#method.then).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Then<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Then<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T, A, B> Next<T> for Then<A, B>
+where
+    A: Next<T>,
+    B: Next<A::Output>,
+{
+    type Output = B::Output;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.second.next(self.first.next(input))
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Then<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<A, B: Current> Current for Then<A, B> {
+    type Output = B::Output;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.second.current()
+    }
+}
+
+impl<A: Period, B: Period> Period for Then<A, B> {
+    fn period(&self) -> usize {
+        self.first.period().max(self.second.period())
+    }
+}
+
+impl<A: Lookback, B: Lookback> Lookback for Then<A, B> {
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback()
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Then<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.first, self.second)
+    }
+}
+
+/// Indicator applying a function to the outputs of an indicator.
+///
+/// Returned by [Compose::map](trait.Compose.html#method.map).
+#[derive(Clone)]
+pub struct Map<A, F> {
+    indicator: A,
+    f: F,
+}
+
+impl<A, F> Map<A, F> {
+    pub fn new(indicator: A, f: F) -> Self {
+        Self { indicator, f }
+    }
+}
+
+impl<T, O, A, F> Next<T> for Map<A, F>
+where
+    A: Next<T>,
+    F: FnMut(A::Output) -> O,
+{
+    type Output = O;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        (self.f)(self.indicator.next(input))
+    }
+}
+
+impl<A: Reset, F> Reset for Map<A, F> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<A: Period, F> Period for Map<A, F> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<A: Lookback, F> Lookback for Map<A, F> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<A: fmt::Display, F> fmt::Display for Map<A, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAP({})", self.indicator)
+    }
+}
+
+impl<A: fmt::Debug, F> fmt::Debug for Map<A, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Map")
+            .field("indicator", &self.indicator)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Indicator giving every input to two indicators and pairing their outputs.
+///
+/// Returned by [Compose::zip](trait.Compose.html#method.zip).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Zip<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> Zip<A, B> {
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<T, A, B> Next<T> for Zip<A, B>
+where
+    T: Clone,
+    A: Next<T>,
+    B: Next<T>,
+{
+    type Output = (A::Output, B::Output);
+
+    fn next(&mut self, input: T) -> Self::Output {
+        (self.left.next(input.clone()), self.right.next(input))
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Zip<A, B> {
+    fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
+impl<A: Current, B: Current> Current for Zip<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn current(&self) -> Option<Self::Output> {
+        self.left.current().zip(self.right.current())
+    }
+}
+
+impl<A: Period, B: Period> Period for Zip<A, B> {
+    fn period(&self) -> usize {
+        self.left.period().max(self.right.period())
+    }
+}
+
+impl<A: Lookback, B: Lookback> Lookback for Zip<A, B> {
+    fn lookback(&self) -> usize {
+        self.left.lookback().max(self.right.lookback())
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Zip<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.left, self.right)
+    }
+}
+
+/// Indicator feeding the outputs of an indicator into another one and pairing the outputs
+/// of both.
+///
+/// Returned by [Compose::tee](trait.Compose.html#method.tee).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Tee<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T, A, B> Next<T> for Tee<A, B>
+where
+    A: Next<T>,
+    A::Output: Clone,
+    B: Next<A::Output>,
+{
+    type Output = (A::Output, B::Output);
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let first = self.first.next(input);
+        let second = self.second.next(first.clone());
+        (first, second)
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Tee<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<A: Current, B: Current> Current for Tee<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn current(&self) -> Option<Self::Output> {
+        self.first.current().zip(self.second.current())
+    }
+}
+
+impl<A: Period, B: Period> Period for Tee<A, B> {
+    fn period(&self) -> usize {
+        self.first.period().max(self.second.period())
+    }
+}
+
+impl<A: Lookback, B: Lookback> Lookback for Tee<A, B> {
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback()
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Tee<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {} -> {})", self.first, self.first, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compose::Compose;
+    use crate::indicators::{
+        ExponentialMovingAverage as Ema, Maximum, RelativeStrengthIndex as Rsi,
+        SimpleMovingAverage as Sma, TrueRange,
+    };
+    use crate::test_helper::*;
+    use crate::{Current, Lookback, Next, Period, Reset};
+
+    #[test]
+    fn test_then() {
+        let mut indicator = Sma::new(2).unwrap().then(Maximum::new(2).unwrap());
+        assert_eq!(indicator.next(4.0), 4.0);
+        assert_eq!(indicator.next(2.0), 4.0);
+        assert_eq!(indicator.next(2.0), 3.0);
+
+        // Bars into numbers
+        let mut atr = TrueRange::new().then(Sma::new(2).unwrap());
+        let bar = Bar::new().high(4).low(2).close(3);
+        assert_eq!(atr.next(&bar), 2.0);
+    }
+
+    #[test]
+    fn test_map() {
+        let mut indicator = Sma::new(2).unwrap().map(|value| value * 10.0);
+        assert_eq!(indicator.next(1.0), 10.0);
+        assert_eq!(indicator.next(2.0), 15.0);
+
+        let mut state = 0.0;
+        let mut total = Sma::new(1).unwrap().map(move |value| {
+            state += value;
+            state
+        });
+        total.next(1.0);
+        assert_eq!(total.next(2.0), 3.0);
+    }
+
+    #[test]
+    fn test_zip() {
+        let mut indicator = Sma::new(1).unwrap().zip(Sma::new(2).unwrap());
+        assert_eq!(indicator.next(2.0), (2.0, 2.0));
+        assert_eq!(indicator.next(4.0), (4.0, 3.0));
+
+        let mut bars = TrueRange::new().zip(Sma::new(2).unwrap());
+        assert_eq!(bars.next(&Bar::new().high(4).low(2).close(3)), (2.0, 3.0));
+    }
+
+    #[test]
+    fn test_tee() {
+        let mut indicator = Sma::new(1).unwrap().tee(Sma::new(2).unwrap());
+        assert_eq!(indicator.next(2.0), (2.0, 2.0));
+        assert_eq!(indicator.next(4.0), (4.0, 3.0));
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut indicator = Ema::new(3)
+            .unwrap()
+            .then(Rsi::new(3).unwrap())
+            .zip(Sma::new(2).unwrap())
+            .map(|(rsi, sma)| rsi + sma);
+
+        assert_eq!(indicator.next(2.0), 52.0);
+        assert_eq!(round(indicator.next(4.0)), 94.667);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut indicator = Sma::new(2)
+            .unwrap()
+            .then(Sma::new(2).unwrap())
+            .tee(Sma::new(2).unwrap())
+            .zip(Sma::new(2).unwrap().map(|value| value * 2.0));
+        indicator.next(2.0);
+        indicator.next(6.0);
+
+        indicator.reset();
+        assert_eq!(indicator.next(8.0), ((8.0, 8.0), 16.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut indicator = Sma::new(1)
+            .unwrap()
+            .then(Sma::new(2).unwrap())
+            .tee(Sma::new(2).unwrap())
+            .zip(Sma::new(1).unwrap());
+        assert_eq!(indicator.current(), None);
+
+        indicator.next(2.0);
+        indicator.next(4.0);
+        assert_eq!(indicator.current(), Some(((3.0, 2.5), 4.0)));
+        assert_eq!(indicator.current(), Some(((3.0, 2.5), 4.0)));
+    }
+
+    #[test]
+    fn test_period() {
+        let indicator = Sma::new(2)
+            .unwrap()
+            .then(Sma::new(5).unwrap())
+            .tee(Sma::new(3).unwrap())
+            .zip(Sma::new(4).unwrap().map(|value: f64| value));
+        assert_eq!(indicator.period(), 5);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let indicator = Sma::new(2)
+            .unwrap()
+            .then(Sma::new(5).unwrap())
+            .tee(Sma::new(3).unwrap())
+            .zip(Sma::new(9).unwrap().map(|value: f64| value));
+        assert_eq!(indicator.lookback(), 8);
+    }
+
+    #[test]
+    fn test_display() {
+        let then = Sma::new(2).unwrap().then(Rsi::new(3).unwrap());
+        assert_eq!(format!("{}", then), "SMA(2) -> RSI(3)");
+
+        let map = Sma::new(2).unwrap().map(|value: f64| value);
+        assert_eq!(format!("{}", map), "MAP(SMA(2))");
+
+        let zip = Sma::new(2).unwrap().zip(Sma::new(3).unwrap());
+        assert_eq!(format!("{}", zip), "(SMA(2), SMA(3))");
+
+        let tee = Sma::new(2).unwrap().tee(Sma::new(3).unwrap());
+        assert_eq!(format!("{}", tee), "(SMA(2), SMA(2) -> SMA(3))");
+    }
+}
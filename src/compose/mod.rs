@@ -0,0 +1,96 @@
+//! Composition of indicators into new indicators.
+//!
+//! The [Compose](trait.Compose.html) trait, implemented for every indicator, chains
+//! indicators with [then](trait.Compose.html#method.then), transforms outputs with
+//! [map](trait.Compose.html#method.map) and fans inputs out with
+//! [zip](trait.Compose.html#method.zip) and [tee](trait.Compose.html#method.tee), and
+//! keeps previous outputs with [with_history](trait.Compose.html#method.with_history) or
+//! previous states with [undoable](trait.Compose.html#method.undoable). The
+//! combinators are indicators themselves, so they can be composed further, and chains can
+//! be declared with the [pipeline!](../macro.pipeline.html) macro.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::compose::Compose;
+//! use ta::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+//! use ta::{Next, Period};
+//!
+//! // Smoothed EMA with its distance to the input
+//! let mut smoothed = Ema::new(3).unwrap().then(Sma::new(2).unwrap());
+//! let mut distance = Sma::new(1).unwrap().zip(Sma::new(2).unwrap()).map(|(a, b): (f64, f64)| a - b);
+//!
+//! assert_eq!(smoothed.next(2.0), 2.0);
+//! assert_eq!(smoothed.next(4.0), 2.5);
+//! assert_eq!(distance.next(2.0), 0.0);
+//! assert_eq!(distance.next(4.0), 1.0);
+//! assert_eq!(smoothed.period(), 3);
+//! ```
+
+mod combinators;
+pub use self::combinators::{Map, Tee, Then, Zip};
+
+mod pipeline;
+
+mod undoable;
+pub use self::undoable::Undoable;
+
+mod validated;
+pub use self::validated::{InvalidInputPolicy, Validate, Validated};
+
+mod warmup;
+pub use self::warmup::Warmup;
+
+mod with_history;
+pub use self::with_history::WithHistory;
+
+use crate::errors::Result;
+use crate::Reset;
+
+/// Combinators of indicators, implemented for every type implementing
+/// [Reset](../trait.Reset.html).
+pub trait Compose: Reset + Sized {
+    /// Returns an indicator feeding the outputs of this indicator into `next`.
+    fn then<B>(self, next: B) -> Then<Self, B> {
+        Then::new(self, next)
+    }
+
+    /// Returns an indicator applying `f` to the outputs of this indicator.
+    fn map<F>(self, f: F) -> Map<Self, F> {
+        Map::new(self, f)
+    }
+
+    /// Returns an indicator giving every input to this indicator and `other` and pairing
+    /// their outputs.
+    fn zip<B>(self, other: B) -> Zip<Self, B> {
+        Zip::new(self, other)
+    }
+
+    /// Returns an indicator feeding the outputs of this indicator into `next` and pairing
+    /// them with the outputs of `next`.
+    fn tee<B>(self, next: B) -> Tee<Self, B> {
+        Tee::new(self, next)
+    }
+
+    /// Returns an indicator able to undo its latest input.
+    fn undoable(self) -> Undoable<Self> {
+        Undoable::new(self)
+    }
+
+    /// Returns an indicator handling NaN or infinite inputs according to `policy`.
+    fn validated(self, policy: InvalidInputPolicy) -> Validated<Self> {
+        Validated::new(self, policy)
+    }
+
+    /// Returns an indicator telling when its output is stable.
+    fn warmup(self) -> Warmup<Self> {
+        Warmup::new(self)
+    }
+
+    /// Returns an indicator keeping the last `K` outputs of this indicator.
+    fn with_history<const K: usize, O>(self) -> Result<WithHistory<Self, K, O>> {
+        WithHistory::new(self)
+    }
+}
+
+impl<I: Reset> Compose for I {}
@@ -0,0 +1,83 @@
+/// Declares a chain of indicators, each one consuming the outputs of the previous one.
+///
+/// `pipeline!(a => b => c)` expands to `a.then(b).then(c)`, while
+/// `pipeline!(type A => B => C)` expands to the type of such a chain, i.e.
+/// `Then<Then<A, B>, C>`, so derived indicators can be named and reused.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{
+///     ExponentialMovingAverage as Ema, Maximum, RelativeStrengthIndex as Rsi,
+///     SimpleMovingAverage as Sma,
+/// };
+/// use ta::{pipeline, Next};
+///
+/// type SmoothedRsi = pipeline!(type Ema => Rsi => Sma);
+///
+/// fn smoothed_rsi(period: usize) -> ta::errors::Result<SmoothedRsi> {
+///     Ok(pipeline!(Ema::new(3)? => Rsi::new(period)? => Sma::new(3)?))
+/// }
+///
+/// let mut rsi = smoothed_rsi(14).unwrap();
+/// assert_eq!(rsi.next(10.0), 50.0);
+///
+/// let mut highest_average = pipeline!(Sma::new(2).unwrap() => Maximum::new(3).unwrap());
+/// assert_eq!(highest_average.next(4.0), 4.0);
+/// assert_eq!(highest_average.next(2.0), 4.0);
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    (@type $acc:ty;) => {
+        $acc
+    };
+    (@type $acc:ty; $next:ty $(, $rest:ty)*) => {
+        $crate::pipeline!(@type $crate::compose::Then<$acc, $next>; $($rest),*)
+    };
+    (type $first:ty $(=> $rest:ty)+) => {
+        $crate::pipeline!(@type $first; $($rest),+)
+    };
+    ($first:expr $(=> $rest:expr)+) => {{
+        use $crate::compose::Compose as _;
+        $first$(.then($rest))+
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compose::Then;
+    use crate::indicators::{Maximum, SimpleMovingAverage as Sma, TrueRange};
+    use crate::test_helper::*;
+    use crate::{Next, Reset};
+
+    #[test]
+    fn test_pipeline() {
+        let mut indicator = pipeline!(Sma::new(1).unwrap() => Sma::new(2).unwrap());
+        assert_eq!(indicator.next(2.0), 2.0);
+        assert_eq!(indicator.next(4.0), 3.0);
+
+        let mut indicator = pipeline!(
+            TrueRange::new() => Sma::new(2).unwrap() => Maximum::new(2).unwrap()
+        );
+        assert_eq!(indicator.next(&Bar::new().high(4).low(2).close(3)), 2.0);
+        assert_eq!(indicator.next(&Bar::new().high(3).low(3).close(3)), 2.0);
+        assert_eq!(indicator.next(&Bar::new().high(3).low(3).close(3)), 1.0);
+
+        indicator.reset();
+        assert_eq!(indicator.next(&Bar::new().high(6).low(2).close(3)), 4.0);
+    }
+
+    #[test]
+    fn test_pipeline_type() {
+        type Chain = pipeline!(type TrueRange => Sma => Maximum);
+
+        let indicator: Chain = Then::new(
+            Then::new(TrueRange::new(), Sma::new(3).unwrap()),
+            Maximum::new(2).unwrap(),
+        );
+        let same: Chain =
+            pipeline!(TrueRange::new() => Sma::new(3).unwrap() => Maximum::new(2).unwrap());
+
+        assert_eq!(format!("{}", indicator), format!("{}", same));
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::{Current, Lookback, Next, Period, Reset, Rollback, UpdateLast};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Indicator able to undo its latest input.
+///
+/// Keeps a copy of the wrapped indicator taken before the latest call of `next`, so
+/// [undo](../trait.Rollback.html#tymethod.undo) restores the previous state, e.g. to handle
+/// late corrections, and [update_last](../trait.UpdateLast.html) works for every
+/// indicator. Copying the indicator on every input costs time and memory proportional to
+/// its state.
+///
+/// # Example
+///
+/// ```
+/// use ta::compose::Undoable;
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::{Next, Rollback};
+///
+/// let mut sma = Undoable::new(Sma::new(2).unwrap());
+/// assert_eq!(sma.next(2.0), 2.0);
+/// assert_eq!(sma.next(10.0), 6.0);
+///
+/// sma.undo();
+/// assert_eq!(sma.next(4.0), 3.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Undoable<I> {
+    indicator: I,
+    previous: Option<I>,
+}
+
+impl<I> Undoable<I> {
+    pub fn new(indicator: I) -> Self {
+        Self {
+            indicator,
+            previous: None,
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Returns `true` when there is an input to undo.
+    pub fn can_undo(&self) -> bool {
+        self.previous.is_some()
+    }
+}
+
+impl<T, I: Next<T> + Clone> Next<T> for Undoable<I> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.previous = Some(self.indicator.clone());
+        self.indicator.next(input)
+    }
+}
+
+impl<I> Rollback for Undoable<I> {
+    fn undo(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            self.indicator = previous;
+        }
+    }
+}
+
+impl<T, I: Next<T> + Clone> UpdateLast<T> for Undoable<I> {
+    fn update_last(&mut self, input: T) -> Self::Output {
+        self.undo();
+        self.next(input)
+    }
+}
+
+impl<I: Reset> Reset for Undoable<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.previous = None;
+    }
+}
+
+impl<I: Current> Current for Undoable<I> {
+    type Output = I::Output;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.indicator.current()
+    }
+}
+
+impl<I: Period> Period for Undoable<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback> Lookback for Undoable<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Undoable<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compose::Compose;
+    use crate::indicators::{KeltnerChannel, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_undo() {
+        let mut sma = Undoable::new(Sma::new(2).unwrap());
+        assert!(!sma.can_undo());
+
+        sma.next(2.0);
+        sma.next(4.0);
+        assert!(sma.can_undo());
+
+        sma.undo();
+        assert!(!sma.can_undo());
+        assert_eq!(sma.current(), Some(2.0));
+
+        // Only the latest input can be undone
+        sma.undo();
+        assert_eq!(sma.current(), Some(2.0));
+        assert_eq!(sma.next(8.0), 5.0);
+    }
+
+    #[test]
+    fn test_update_last() {
+        let bar = |close: f64| Bar::new().high(close + 1.0).low(close - 1.0).close(close);
+        let mut kc = KeltnerChannel::new(3, 2.0).unwrap().undoable();
+        let mut expected = KeltnerChannel::new(3, 2.0).unwrap();
+
+        assert_eq!(kc.update_last(&bar(4.0)), expected.next(&bar(4.0)));
+        kc.next(&bar(9.0));
+        kc.update_last(&bar(7.0));
+        assert_eq!(kc.update_last(&bar(5.0)), expected.next(&bar(5.0)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = Undoable::new(Sma::new(2).unwrap());
+        sma.next(2.0);
+
+        sma.reset();
+        assert!(!sma.can_undo());
+        assert_eq!(sma.current(), None);
+    }
+
+    #[test]
+    fn test_period_and_display() {
+        let sma = Undoable::new(Sma::new(3).unwrap());
+        assert_eq!(sma.period(), 3);
+        assert_eq!(format!("{}", sma), "SMA(3)");
+        assert_eq!(format!("{}", sma.indicator()), "SMA(3)");
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Current, DataItem, High, Lookback, Low, Next, Open, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Input which can be checked for NaN or infinite values.
+pub trait Validate {
+    /// Returns `true` when every value of the input is finite.
+    fn is_valid(&self) -> bool;
+}
+
+impl Validate for f64 {
+    fn is_valid(&self) -> bool {
+        self.is_finite()
+    }
+}
+
+impl Validate for (f64, f64) {
+    fn is_valid(&self) -> bool {
+        self.0.is_finite() && self.1.is_finite()
+    }
+}
+
+impl Validate for DataItem {
+    fn is_valid(&self) -> bool {
+        [
+            self.open(),
+            self.high(),
+            self.low(),
+            self.close(),
+            self.volume(),
+        ]
+        .iter()
+        .all(|value| value.is_finite())
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for &T {
+    fn is_valid(&self) -> bool {
+        (**self).is_valid()
+    }
+}
+
+/// Handling of NaN or infinite inputs by a [Validated](struct.Validated.html) indicator.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvalidInputPolicy {
+    /// Gives the input to the indicator anyway, as if it was not validated.
+    #[default]
+    Propagate,
+    /// Ignores the input, the indicator keeps its state and nothing is returned.
+    Skip,
+    /// Ignores the input and returns an `InvalidInput` error.
+    Error,
+}
+
+/// Indicator checking its inputs for NaN or infinite values.
+///
+/// A single NaN fed into most indicators, e.g. the SMA or the EMA, poisons their state for
+/// good. The [policy](enum.InvalidInputPolicy.html) tells whether such inputs are given to
+/// the indicator anyway, skipped or reported as errors. `next` returns the output of the
+/// indicator, `None` for skipped inputs.
+///
+/// # Example
+///
+/// ```
+/// use ta::compose::{InvalidInputPolicy, Validated};
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::Next;
+///
+/// let mut sma = Validated::new(Sma::new(2).unwrap(), InvalidInputPolicy::Skip);
+///
+/// assert_eq!(sma.next(2.0).unwrap(), Some(2.0));
+/// assert_eq!(sma.next(f64::NAN).unwrap(), None);
+/// assert_eq!(sma.next(4.0).unwrap(), Some(3.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Validated<I> {
+    indicator: I,
+    policy: InvalidInputPolicy,
+}
+
+impl<I> Validated<I> {
+    pub fn new(indicator: I, policy: InvalidInputPolicy) -> Self {
+        Self { indicator, policy }
+    }
+
+    pub fn policy(&self) -> InvalidInputPolicy {
+        self.policy
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<T: Validate, I: Next<T>> Next<T> for Validated<I> {
+    type Output = Result<Option<I::Output>>;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        if input.is_valid() {
+            return Ok(Some(self.indicator.next(input)));
+        }
+
+        match self.policy {
+            InvalidInputPolicy::Propagate => Ok(Some(self.indicator.next(input))),
+            InvalidInputPolicy::Skip => Ok(None),
+            InvalidInputPolicy::Error => Err(TaError::InvalidInput),
+        }
+    }
+}
+
+impl<I: Reset> Reset for Validated<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: Current> Current for Validated<I> {
+    type Output = I::Output;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.indicator.current()
+    }
+}
+
+impl<I: Lookback> Lookback for Validated<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<I: Period> Period for Validated<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Validated<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compose::Compose;
+    use crate::indicators::{
+        Cross, CrossEvent, ExponentialMovingAverage as Ema, OnBalanceVolume as Obv,
+        SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(1.0.is_valid());
+        assert!(!f64::NAN.is_valid());
+        assert!(!f64::INFINITY.is_valid());
+        assert!(!f64::NEG_INFINITY.is_valid());
+        assert!((1.0, 2.0).is_valid());
+        assert!(!(1.0, f64::NAN).is_valid());
+        assert!(item(1.0).is_valid());
+        assert!(Validate::is_valid(&&item(1.0)));
+
+        let bar = DataItem {
+            high: f64::INFINITY,
+            ..item(1.0)
+        };
+        assert!(!bar.is_valid());
+    }
+
+    #[test]
+    fn test_propagate() {
+        let mut ema = Ema::new(3)
+            .unwrap()
+            .validated(InvalidInputPolicy::default());
+        assert_eq!(ema.next(2.0).unwrap(), Some(2.0));
+        assert!(ema.next(f64::NAN).unwrap().unwrap().is_nan());
+        assert!(ema.next(2.0).unwrap().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_skip() {
+        let mut ema = Ema::new(3).unwrap().validated(InvalidInputPolicy::Skip);
+        assert_eq!(ema.next(2.0).unwrap(), Some(2.0));
+        assert_eq!(ema.next(f64::NAN).unwrap(), None);
+        assert_eq!(ema.next(f64::INFINITY).unwrap(), None);
+        assert_eq!(ema.next(4.0).unwrap(), Some(3.0));
+
+        let mut cross = Validated::new(Cross::new(), InvalidInputPolicy::Skip);
+        cross.next((1.0, 2.0)).unwrap();
+        assert_eq!(cross.next((f64::NAN, 2.0)).unwrap(), None);
+        assert_eq!(cross.next((3.0, 2.0)).unwrap(), Some(CrossEvent::Over));
+    }
+
+    #[test]
+    fn test_error() {
+        let mut sma = Sma::new(2).unwrap().validated(InvalidInputPolicy::Error);
+        assert_eq!(sma.next(2.0).unwrap(), Some(2.0));
+        assert!(matches!(
+            sma.next(f64::NEG_INFINITY),
+            Err(TaError::InvalidInput)
+        ));
+        assert_eq!(sma.next(4.0).unwrap(), Some(3.0));
+
+        let mut obv = Obv::new().validated(InvalidInputPolicy::Error);
+        assert_eq!(obv.next(&item(1.0)).unwrap(), Some(0.0));
+    }
+
+    #[test]
+    fn test_reset_and_display() {
+        let mut sma = Validated::new(Sma::new(2).unwrap(), InvalidInputPolicy::Error);
+        sma.next(2.0).unwrap();
+
+        sma.reset();
+        assert_eq!(sma.current(), None);
+        assert_eq!(sma.policy(), InvalidInputPolicy::Error);
+        assert_eq!(sma.period(), 2);
+        assert_eq!(format!("{}", sma), "SMA(2)");
+        assert_eq!(format!("{}", sma.indicator()), "SMA(2)");
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use crate::{Current, IsReady, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Indicator counting its inputs to tell when its output is stable.
+///
+/// [is_ready](../trait.IsReady.html) returns `true` once the wrapped indicator has consumed
+/// more inputs than its [lookback](../trait.Lookback.html), so trading logic can be gated
+/// without tracking bar counts.
+///
+/// # Example
+///
+/// ```
+/// use ta::compose::Warmup;
+/// use ta::indicators::ExponentialMovingAverage as Ema;
+/// use ta::{IsReady, Next};
+///
+/// let mut ema = Warmup::new(Ema::new(3).unwrap());
+///
+/// ema.next(1.0);
+/// ema.next(2.0);
+/// assert!(!ema.is_ready());
+///
+/// ema.next(3.0);
+/// assert!(ema.is_ready());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Warmup<I> {
+    indicator: I,
+    count: usize,
+}
+
+impl<I> Warmup<I> {
+    pub fn new(indicator: I) -> Self {
+        Self {
+            indicator,
+            count: 0,
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Returns the number of inputs consumed since the creation or the latest reset.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T, I: Next<T>> Next<T> for Warmup<I> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.count = self.count.saturating_add(1);
+        self.indicator.next(input)
+    }
+}
+
+impl<I: Lookback> IsReady for Warmup<I> {
+    fn is_ready(&self) -> bool {
+        self.count > self.indicator.lookback()
+    }
+}
+
+impl<I: Reset> Reset for Warmup<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.count = 0;
+    }
+}
+
+impl<I: Current> Current for Warmup<I> {
+    type Output = I::Output;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.indicator.current()
+    }
+}
+
+impl<I: Lookback> Lookback for Warmup<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<I: Period> Period for Warmup<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Warmup<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compose::Compose;
+    use crate::indicators::{
+        MovingAverageConvergenceDivergence as Macd, SimpleMovingAverage as Sma,
+    };
+
+    #[test]
+    fn test_is_ready() {
+        let mut macd = Macd::new(2, 3, 2).unwrap().warmup();
+        for _ in 0..4 {
+            assert!(!macd.is_ready());
+            macd.next(1.0);
+        }
+        assert!(macd.is_ready());
+        assert_eq!(macd.count(), 4);
+
+        let mut chain = Sma::new(2).unwrap().then(Sma::new(3).unwrap()).warmup();
+        for _ in 0..3 {
+            chain.next(1.0);
+        }
+        assert!(!chain.is_ready());
+        chain.next(1.0);
+        assert!(chain.is_ready());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = Warmup::new(Sma::new(1).unwrap());
+        sma.next(1.0);
+        assert!(sma.is_ready());
+
+        sma.reset();
+        assert!(!sma.is_ready());
+        assert_eq!(sma.count(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = Warmup::new(Sma::new(3).unwrap());
+        assert_eq!(format!("{}", sma), "SMA(3)");
+        assert_eq!(format!("{}", sma.indicator()), "SMA(3)");
+        assert_eq!(sma.period(), 3);
+        assert_eq!(sma.lookback(), 2);
+    }
+}
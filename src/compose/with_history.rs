@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::errors::{Result, TaError};
+use crate::{Current, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Indicator keeping its last `K` outputs.
+///
+/// Outputs are accessed back from the latest one, like `series[n]` in Pine Script:
+/// `value(0)` is the latest output, `value(1)` the one before and so on, up to
+/// `value(K - 1)`. `O` is the output of the indicator, a number by default.
+///
+/// # Example
+///
+/// ```
+/// use ta::compose::WithHistory;
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::Next;
+///
+/// let mut sma = WithHistory::<_, 3>::new(Sma::new(2).unwrap()).unwrap();
+///
+/// for &close in &[2.0, 4.0, 6.0, 8.0] {
+///     sma.next(close);
+/// }
+///
+/// assert_eq!(sma.value(0), Some(7.0));
+/// assert_eq!(sma.value(1), Some(5.0));
+/// assert_eq!(sma.value(2), Some(3.0));
+/// assert_eq!(sma.value(3), None);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WithHistory<I, const K: usize, O = f64> {
+    indicator: I,
+    // Latest output first
+    history: VecDeque<O>,
+    output: PhantomData<O>,
+}
+
+impl<I, const K: usize, O> WithHistory<I, K, O> {
+    pub fn new(indicator: I) -> Result<Self> {
+        if K == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            indicator,
+            history: VecDeque::with_capacity(K),
+            output: PhantomData,
+        })
+    }
+
+    /// Returns the output `n` steps back from the latest one, or `None` when there are not
+    /// enough outputs yet or `n` is not less than `K`.
+    pub fn value(&self, n: usize) -> Option<O>
+    where
+        O: Clone,
+    {
+        self.history.get(n).cloned()
+    }
+
+    /// Returns the number of kept outputs, at most `K`.
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<T, I, const K: usize, O> Next<T> for WithHistory<I, K, O>
+where
+    I: Next<T, Output = O>,
+    O: Clone,
+{
+    type Output = O;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let output = self.indicator.next(input);
+        if self.history.len() == K {
+            self.history.pop_back();
+        }
+        self.history.push_front(output.clone());
+        output
+    }
+}
+
+impl<I: Reset, const K: usize, O> Reset for WithHistory<I, K, O> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.history.clear();
+    }
+}
+
+impl<I, const K: usize, O: Clone> Current for WithHistory<I, K, O> {
+    type Output = O;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.value(0)
+    }
+}
+
+impl<I: Period, const K: usize, O> Period for WithHistory<I, K, O> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback, const K: usize, O> Lookback for WithHistory<I, K, O> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<I: fmt::Display, const K: usize, O> fmt::Display for WithHistory<I, K, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compose::Compose;
+    use crate::indicators::{
+        MovingAverageConvergenceDivergence as Macd, SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(WithHistory::<_, 0>::new(Sma::new(2).unwrap()).is_err());
+        assert!(WithHistory::<_, 1>::new(Sma::new(2).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = WithHistory::<_, 2>::new(Sma::new(1).unwrap()).unwrap();
+        assert!(sma.is_empty());
+        assert_eq!(sma.value(0), None);
+
+        assert_eq!(sma.next(1.0), 1.0);
+        assert_eq!(sma.len(), 1);
+        assert_eq!(sma.value(0), Some(1.0));
+        assert_eq!(sma.value(1), None);
+
+        sma.next(2.0);
+        sma.next(3.0);
+        assert_eq!(sma.current(), Some(3.0));
+        assert_eq!(sma.len(), 2);
+        assert_eq!(sma.value(0), Some(3.0));
+        assert_eq!(sma.value(1), Some(2.0));
+        assert_eq!(sma.value(2), None);
+    }
+
+    #[test]
+    fn test_next_bars() {
+        let mut sma = WithHistory::<_, 2>::new(Sma::new(2).unwrap()).unwrap();
+        sma.next(&Bar::new().close(2));
+        sma.next(&Bar::new().close(4));
+        assert_eq!(sma.value(1), Some(2.0));
+    }
+
+    #[test]
+    fn test_next_output() {
+        let mut macd: WithHistory<_, 2, _> = Macd::new(3, 6, 4).unwrap().with_history().unwrap();
+        macd.next(2.0);
+        macd.next(3.0);
+        assert_eq!(macd.value(1).unwrap().macd, 0.0);
+        assert_eq!(round(macd.value(0).unwrap().macd), 0.214);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = WithHistory::<_, 2>::new(Sma::new(2).unwrap()).unwrap();
+        sma.next(2.0);
+        sma.next(4.0);
+
+        sma.reset();
+        assert!(sma.is_empty());
+        assert_eq!(sma.next(8.0), 8.0);
+        assert_eq!(sma.value(1), None);
+    }
+
+    #[test]
+    fn test_period_and_display() {
+        let sma = WithHistory::<_, 5>::new(Sma::new(3).unwrap()).unwrap();
+        assert_eq!(sma.period(), 3);
+        assert_eq!(format!("{}", sma), "SMA(3)");
+        assert_eq!(format!("{}", sma.indicator()), "SMA(3)");
+    }
+}
@@ -1,5 +1,9 @@
 use crate::errors::*;
-use crate::traits::{Close, High, Low, Open, Volume};
+#[cfg(feature = "chrono")]
+use crate::traits::Timestamp;
+use crate::traits::{BidAsk, Close, High, Low, Open, OpenInterest, TradeCount, Volume};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -28,20 +32,105 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(item.volume(), 7500.0);
 /// ```
 ///
+/// The builder also takes the optional open interest, best bid and ask quotes and trade count,
+/// read with the [OpenInterest](trait.OpenInterest.html), [BidAsk](trait.BidAsk.html) and
+/// [TradeCount](trait.TradeCount.html) traits.
+///
+/// With the `chrono` feature, the builder also takes an optional timestamp of the start of the
+/// bar, read with the [Timestamp](trait.Timestamp.html) trait.
+///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct DataItem {
-    open: f64,
-    high: f64,
-    low: f64,
-    close: f64,
-    volume: f64,
+    pub(crate) open: f64,
+    pub(crate) high: f64,
+    pub(crate) low: f64,
+    pub(crate) close: f64,
+    pub(crate) volume: f64,
+    pub(crate) open_interest: Option<f64>,
+    pub(crate) bid: Option<f64>,
+    pub(crate) ask: Option<f64>,
+    pub(crate) trade_count: Option<u64>,
+    #[cfg(feature = "chrono")]
+    pub(crate) timestamp: Option<DateTime<Utc>>,
 }
 
 impl DataItem {
     pub fn builder() -> DataItemBuilder {
         DataItemBuilder::new()
     }
+
+    /// Parses a data item from comma separated open, high, low, close and volume, e.g. a row
+    /// of a CSV file. Whitespace around the values is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::{Close, DataItem};
+    ///
+    /// let item = DataItem::from_ohlcv_str("20.0, 25.0, 15.0, 21.0, 7500").unwrap();
+    /// assert_eq!(item.close(), 21.0);
+    /// ```
+    pub fn from_ohlcv_str(s: &str) -> Result<Self> {
+        const FIELDS: [&str; 5] = ["open", "high", "low", "close", "volume"];
+
+        let mut values = [0.0; 5];
+        let mut parts = s.split(',');
+        for (value, field) in values.iter_mut().zip(FIELDS.iter()) {
+            let part = parts.next().ok_or(TaError::DataItemIncomplete)?;
+            *value = part
+                .trim()
+                .parse()
+                .map_err(|_| TaError::DataItemInvalid(DataItemError::NotANumber(field)))?;
+        }
+        if parts.next().is_some() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Self::try_from(values)
+    }
+
+    // Data item with the given prices and volume only, which are not validated
+    #[cfg(feature = "std")]
+    pub(crate) fn unchecked(open: f64, high: f64, low: f64, close: f64, volume: f64) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+            open_interest: None,
+            bid: None,
+            ask: None,
+            trade_count: None,
+            #[cfg(feature = "chrono")]
+            timestamp: None,
+        }
+    }
+}
+
+/// Builds a data item from `(open, high, low, close, volume)`.
+impl TryFrom<(f64, f64, f64, f64, f64)> for DataItem {
+    type Error = TaError;
+
+    fn try_from((open, high, low, close, volume): (f64, f64, f64, f64, f64)) -> Result<Self> {
+        Self::builder()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(volume)
+            .build()
+    }
+}
+
+/// Builds a data item from `[open, high, low, close, volume]`.
+impl TryFrom<[f64; 5]> for DataItem {
+    type Error = TaError;
+
+    fn try_from([open, high, low, close, volume]: [f64; 5]) -> Result<Self> {
+        Self::try_from((open, high, low, close, volume))
+    }
 }
 
 impl Open for DataItem {
@@ -74,12 +163,47 @@ impl Volume for DataItem {
     }
 }
 
+impl OpenInterest for DataItem {
+    fn open_interest(&self) -> Option<f64> {
+        self.open_interest
+    }
+}
+
+impl BidAsk for DataItem {
+    fn bid(&self) -> Option<f64> {
+        self.bid
+    }
+
+    fn ask(&self) -> Option<f64> {
+        self.ask
+    }
+}
+
+impl TradeCount for DataItem {
+    fn trade_count(&self) -> Option<u64> {
+        self.trade_count
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for DataItem {
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+    }
+}
+
 pub struct DataItemBuilder {
     open: Option<f64>,
     high: Option<f64>,
     low: Option<f64>,
     close: Option<f64>,
     volume: Option<f64>,
+    open_interest: Option<f64>,
+    bid: Option<f64>,
+    ask: Option<f64>,
+    trade_count: Option<u64>,
+    #[cfg(feature = "chrono")]
+    timestamp: Option<DateTime<Utc>>,
 }
 
 impl DataItemBuilder {
@@ -90,6 +214,12 @@ impl DataItemBuilder {
             low: None,
             close: None,
             volume: None,
+            open_interest: None,
+            bid: None,
+            ask: None,
+            trade_count: None,
+            #[cfg(feature = "chrono")]
+            timestamp: None,
         }
     }
 
@@ -118,34 +248,150 @@ impl DataItemBuilder {
         self
     }
 
+    pub fn open_interest(mut self, val: f64) -> Self {
+        self.open_interest = Some(val);
+        self
+    }
+
+    pub fn bid(mut self, val: f64) -> Self {
+        self.bid = Some(val);
+        self
+    }
+
+    pub fn ask(mut self, val: f64) -> Self {
+        self.ask = Some(val);
+        self
+    }
+
+    pub fn trade_count(mut self, val: u64) -> Self {
+        self.trade_count = Some(val);
+        self
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn timestamp(mut self, val: DateTime<Utc>) -> Self {
+        self.timestamp = Some(val);
+        self
+    }
+
+    /// Builds the data item, returning a `DataItemInvalid` error with the failed invariant
+    /// when the prices are inconsistent.
     pub fn build(self) -> Result<DataItem> {
         if let (Some(open), Some(high), Some(low), Some(close), Some(volume)) =
             (self.open, self.high, self.low, self.close, self.volume)
         {
-            // validate
-            if low <= open
-                && low <= close
-                && low <= high
-                && high >= open
-                && high >= close
-                && volume >= 0.0
-                && low >= 0.0
-            {
-                let item = DataItem {
-                    open,
-                    high,
-                    low,
-                    close,
-                    volume,
-                };
-                Ok(item)
-            } else {
-                Err(TaError::DataItemInvalid)
-            }
+            validate(open, high, low, close, volume)
+                .and_then(|_| validate_extended(self.open_interest, self.bid, self.ask))
+                .map_err(TaError::DataItemInvalid)?;
+            Ok(DataItem {
+                open,
+                high,
+                low,
+                close,
+                volume,
+                open_interest: self.open_interest,
+                bid: self.bid,
+                ask: self.ask,
+                trade_count: self.trade_count,
+                #[cfg(feature = "chrono")]
+                timestamp: self.timestamp,
+            })
         } else {
             Err(TaError::DataItemIncomplete)
         }
     }
+
+    /// Builds the data item, widening the high and the low to cover the open and the close
+    /// instead of rejecting the bar, e.g. for noisy feeds reporting a high slightly below the
+    /// close. The bar is only widened by up to _tolerance_ times its highest price, e.g. 0.001
+    /// for 0.1%: a larger inconsistency is rejected as by [build](#method.build). NaN or
+    /// infinite fields, negative prices and negative volumes are still rejected.
+    pub fn build_lenient(self, tolerance: f64) -> Result<DataItem> {
+        if let (Some(open), Some(high), Some(low), Some(close)) =
+            (self.open, self.high, self.low, self.close)
+        {
+            let prices = [open, high, low, close];
+            if prices.iter().any(|price| !price.is_finite()) {
+                return self.build();
+            }
+            let widened_high = prices.iter().copied().fold(high, f64::max);
+            let widened_low = prices.iter().copied().fold(low, f64::min);
+            let excess = (widened_high - high).max(low - widened_low);
+            if tolerance.is_nan() || excess > tolerance * widened_high {
+                return self.build();
+            }
+            Self {
+                high: Some(widened_high),
+                low: Some(widened_low),
+                ..self
+            }
+            .build()
+        } else {
+            self.build()
+        }
+    }
+}
+
+fn validate(
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+) -> core::result::Result<(), DataItemError> {
+    let fields = [
+        ("open", open),
+        ("high", high),
+        ("low", low),
+        ("close", close),
+        ("volume", volume),
+    ];
+    if let Some((field, _)) = fields.iter().find(|(_, value)| !value.is_finite()) {
+        return Err(DataItemError::NotFinite(field));
+    }
+    if let Some((field, _)) = fields[..4].iter().find(|(_, value)| *value < 0.0) {
+        return Err(DataItemError::NegativePrice(field));
+    }
+
+    if volume < 0.0 {
+        Err(DataItemError::NegativeVolume)
+    } else if high < low {
+        Err(DataItemError::HighBelowLow)
+    } else if open < low || open > high {
+        Err(DataItemError::OpenOutOfRange)
+    } else if close < low || close > high {
+        Err(DataItemError::CloseOutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_extended(
+    open_interest: Option<f64>,
+    bid: Option<f64>,
+    ask: Option<f64>,
+) -> core::result::Result<(), DataItemError> {
+    let fields = [("open_interest", open_interest), ("bid", bid), ("ask", ask)];
+    if let Some((field, _)) = fields
+        .iter()
+        .find(|(_, value)| value.is_some_and(|value| !value.is_finite()))
+    {
+        return Err(DataItemError::NotFinite(field));
+    }
+    if let Some((field, _)) = fields[1..]
+        .iter()
+        .find(|(_, value)| value.is_some_and(|value| value < 0.0))
+    {
+        return Err(DataItemError::NegativePrice(field));
+    }
+
+    match (open_interest, bid, ask) {
+        (Some(open_interest), _, _) if open_interest < 0.0 => {
+            Err(DataItemError::NegativeOpenInterest)
+        }
+        (_, Some(bid), Some(ask)) if bid > ask => Err(DataItemError::BidAboveAsk),
+        _ => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -203,4 +449,201 @@ mod tests {
             assert_invalid(record)
         }
     }
+
+    fn builder((open, high, low, close, volume): (f64, f64, f64, f64, f64)) -> DataItemBuilder {
+        DataItem::builder()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(volume)
+    }
+
+    #[test]
+    fn test_build_error() {
+        fn error(record: (f64, f64, f64, f64, f64)) -> DataItemError {
+            match builder(record).build() {
+                Err(TaError::DataItemInvalid(err)) => err,
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        let not_finite = DataItemError::NotFinite;
+        assert_eq!(error((f64::NAN, 25.0, 15.0, 21.0, 1.0)), not_finite("open"));
+        assert_eq!(
+            error((20.0, 25.0, 15.0, 21.0, f64::INFINITY)),
+            not_finite("volume")
+        );
+        assert_eq!(
+            error((20.0, 25.0, -1.0, 21.0, 1.0)),
+            DataItemError::NegativePrice("low")
+        );
+        assert_eq!(
+            error((20.0, 25.0, 15.0, 21.0, -1.0)),
+            DataItemError::NegativeVolume
+        );
+        assert_eq!(
+            error((20.0, 15.0, 25.0, 21.0, 1.0)),
+            DataItemError::HighBelowLow
+        );
+        assert_eq!(
+            error((14.9, 25.0, 15.0, 21.0, 1.0)),
+            DataItemError::OpenOutOfRange
+        );
+        assert_eq!(
+            error((20.0, 25.0, 15.0, 25.1, 1.0)),
+            DataItemError::CloseOutOfRange
+        );
+
+        let err = builder((20.0, 25.0, 15.0, 25.1, 1.0)).build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "data item is invalid: close is outside of the high-low range"
+        );
+        assert!(DataItem::builder().build_lenient(0.0).is_err());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let item = DataItem::try_from((20.0, 25.0, 15.0, 21.0, 7500.0)).unwrap();
+        assert_eq!((item.open(), item.high(), item.low()), (20.0, 25.0, 15.0));
+        assert_eq!((item.close(), item.volume()), (21.0, 7500.0));
+
+        let item = DataItem::try_from([20.0, 25.0, 15.0, 21.0, 7500.0]).unwrap();
+        assert_eq!(item.close(), 21.0);
+
+        assert!(DataItem::try_from((20.0, 15.0, 25.0, 21.0, 7500.0)).is_err());
+        assert!(DataItem::try_from([20.0, 25.0, 15.0, 21.0, -1.0]).is_err());
+    }
+
+    #[test]
+    fn test_from_ohlcv_str() {
+        let item = DataItem::from_ohlcv_str("20,25.5, 15 ,21.0,7500").unwrap();
+        assert_eq!((item.open(), item.high(), item.low()), (20.0, 25.5, 15.0));
+        assert_eq!((item.close(), item.volume()), (21.0, 7500.0));
+
+        assert!(matches!(
+            DataItem::from_ohlcv_str("20,25,15,21"),
+            Err(TaError::DataItemIncomplete)
+        ));
+        assert!(matches!(
+            DataItem::from_ohlcv_str("20,25,15,21,7500,1"),
+            Err(TaError::InvalidParameter)
+        ));
+        assert!(matches!(
+            DataItem::from_ohlcv_str("20,25,x,21,7500"),
+            Err(TaError::DataItemInvalid(DataItemError::NotANumber("low")))
+        ));
+        assert!(matches!(
+            DataItem::from_ohlcv_str("20,25,15,NaN,7500"),
+            Err(TaError::DataItemInvalid(DataItemError::NotFinite("close")))
+        ));
+        assert!(DataItem::from_ohlcv_str("").is_err());
+    }
+
+    #[test]
+    fn test_extended_fields() {
+        let item = builder((20.0, 25.0, 15.0, 21.0, 1.0)).build().unwrap();
+        assert_eq!(item.open_interest(), None);
+        assert_eq!(item.spread(), None);
+        assert_eq!(item.trade_count(), None);
+
+        let item = builder((20.0, 25.0, 15.0, 21.0, 1.0))
+            .open_interest(1500.0)
+            .bid(20.5)
+            .ask(21.0)
+            .trade_count(42)
+            .build()
+            .unwrap();
+        assert_eq!(item.open_interest(), Some(1500.0));
+        assert_eq!((item.bid(), item.ask()), (Some(20.5), Some(21.0)));
+        assert_eq!(item.spread(), Some(0.5));
+        assert_eq!(item.trade_count(), Some(42));
+
+        let error = |builder: DataItemBuilder| match builder.build() {
+            Err(TaError::DataItemInvalid(err)) => err,
+            other => panic!("unexpected result: {:?}", other),
+        };
+        let bar = || builder((20.0, 25.0, 15.0, 21.0, 1.0));
+        assert_eq!(
+            error(bar().open_interest(f64::NAN)),
+            DataItemError::NotFinite("open_interest")
+        );
+        assert_eq!(
+            error(bar().open_interest(-1.0)),
+            DataItemError::NegativeOpenInterest
+        );
+        assert_eq!(error(bar().bid(-1.0)), DataItemError::NegativePrice("bid"));
+        assert_eq!(error(bar().bid(21.0).ask(20.5)), DataItemError::BidAboveAsk);
+        assert!(bar().ask(20.5).build().is_ok());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamp() {
+        use chrono::TimeZone;
+
+        let item = builder((20.0, 25.0, 15.0, 21.0, 1.0)).build().unwrap();
+        assert_eq!(item.timestamp(), None);
+
+        let time = Utc.with_ymd_and_hms(2021, 6, 27, 13, 5, 0).unwrap();
+        let item = builder((20.0, 25.0, 15.0, 21.0, 1.0))
+            .timestamp(time)
+            .build_lenient(0.0)
+            .unwrap();
+        assert_eq!(item.timestamp(), Some(time));
+    }
+
+    #[test]
+    fn test_build_lenient() {
+        let item = builder((20.0, 25.0, 15.0, 25.1, 1.0))
+            .build_lenient(0.01)
+            .unwrap();
+        assert_eq!((item.high(), item.low(), item.close()), (25.1, 15.0, 25.1));
+
+        let item = builder((14.9, 25.0, 15.0, 21.0, 1.0))
+            .build_lenient(0.01)
+            .unwrap();
+        assert_eq!((item.high(), item.low()), (25.0, 14.9));
+
+        assert!(builder((20.0, f64::NAN, 15.0, 21.0, 1.0))
+            .build_lenient(0.01)
+            .is_err());
+        assert!(builder((20.0, 25.0, -1.0, 21.0, 1.0))
+            .build_lenient(0.01)
+            .is_err());
+        assert!(builder((20.0, 25.0, 15.0, 21.0, -1.0))
+            .build_lenient(0.01)
+            .is_err());
+    }
+
+    #[test]
+    fn test_build_lenient_tolerance() {
+        let err = builder((20.0, 25.0, 15.0, 26.0, 1.0))
+            .build_lenient(0.01)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TaError::DataItemInvalid(DataItemError::CloseOutOfRange)
+        ));
+        assert!(builder((20.0, 25.0, 15.0, 26.0, 1.0))
+            .build_lenient(0.05)
+            .is_ok());
+
+        // Swapped high and low are not widened
+        let err = builder((20.0, 15.0, 25.0, 21.0, 1.0))
+            .build_lenient(0.01)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TaError::DataItemInvalid(DataItemError::HighBelowLow)
+        ));
+
+        assert!(builder((20.0, 25.0, 15.0, 25.1, 1.0))
+            .build_lenient(f64::NAN)
+            .is_err());
+        assert!(builder((20.0, 25.0, 15.0, 25.0, 1.0))
+            .build_lenient(0.0)
+            .is_ok());
+    }
 }
@@ -0,0 +1,128 @@
+use crate::{Next, Reset};
+
+/// Object safe counterpart of [Next](trait.Next.html) and [Reset](trait.Reset.html).
+///
+/// Implemented for every indicator implementing both traits, so indicators of different
+/// types can be stored together as [boxed indicators](type.BoxedIndicator.html), which
+/// implement `Next` and `Reset` again. The methods have their own names so they do not
+/// clash with `next` and `reset` when both traits are in scope.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+/// use ta::{BoxedIndicator, Next};
+///
+/// let mut indicators: Vec<BoxedIndicator<f64>> = vec![
+///     Box::new(Sma::new(2).unwrap()),
+///     Box::new(Ema::new(3).unwrap()),
+/// ];
+///
+/// let values: Vec<f64> = indicators.iter_mut().map(|ind| ind.next(4.0)).collect();
+/// assert_eq!(values, vec![4.0, 4.0]);
+/// ```
+pub trait DynNext<T> {
+    type Output;
+
+    /// Consumes a data item, like [Next::next](trait.Next.html#tymethod.next).
+    fn dyn_next(&mut self, input: T) -> Self::Output;
+
+    /// Resets to the initial state, like [Reset::reset](trait.Reset.html#tymethod.reset).
+    fn dyn_reset(&mut self);
+}
+
+impl<T, I> DynNext<T> for I
+where
+    I: Next<T> + Reset,
+{
+    type Output = I::Output;
+
+    fn dyn_next(&mut self, input: T) -> Self::Output {
+        self.next(input)
+    }
+
+    fn dyn_reset(&mut self) {
+        self.reset();
+    }
+}
+
+/// Boxed indicator consuming `T` and returning `O`, with the type of the indicator erased.
+pub type BoxedIndicator<'a, T, O = f64> = Box<dyn DynNext<T, Output = O> + 'a>;
+
+impl<'a, T, O> Next<T> for Box<dyn DynNext<T, Output = O> + 'a> {
+    type Output = O;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        (**self).dyn_next(input)
+    }
+}
+
+impl<'a, T, O> Reset for Box<dyn DynNext<T, Output = O> + 'a> {
+    fn reset(&mut self) {
+        (**self).dyn_reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        MovingAverageConvergenceDivergence as Macd, OnBalanceVolume as Obv,
+        SimpleMovingAverage as Sma, TrueRange,
+    };
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    #[test]
+    fn test_next() {
+        let mut indicators: Vec<BoxedIndicator<f64>> = vec![
+            Box::new(Sma::new(2).unwrap()),
+            Box::new(Sma::new(3).unwrap()),
+        ];
+
+        for &input in &[2.0, 4.0] {
+            for indicator in indicators.iter_mut() {
+                indicator.next(input);
+            }
+        }
+
+        let values: Vec<f64> = indicators.iter_mut().map(|ind| ind.next(6.0)).collect();
+        assert_eq!(values, vec![5.0, 4.0]);
+    }
+
+    #[test]
+    fn test_next_data_item() {
+        let bar = item(5.0);
+        let mut indicators: Vec<BoxedIndicator<&DataItem>> =
+            vec![Box::new(TrueRange::new()), Box::new(Obv::new())];
+
+        let values: Vec<f64> = indicators.iter_mut().map(|ind| ind.next(&bar)).collect();
+        assert_eq!(values, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_output() {
+        let mut macd: BoxedIndicator<f64, _> = Box::new(Macd::new(3, 6, 4).unwrap());
+        assert_eq!(macd.next(2.0).macd, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut indicator: BoxedIndicator<f64> = Box::new(Sma::new(2).unwrap());
+        indicator.next(2.0);
+        assert_eq!(indicator.next(4.0), 3.0);
+
+        indicator.reset();
+        assert_eq!(indicator.next(8.0), 8.0);
+    }
+
+    #[test]
+    fn test_generic() {
+        fn last<I: Next<f64, Output = f64>>(mut indicator: I, inputs: &[f64]) -> f64 {
+            inputs.iter().fold(0.0, |_, &input| indicator.next(input))
+        }
+
+        let indicator: BoxedIndicator<f64> = Box::new(Sma::new(2).unwrap());
+        assert_eq!(last(indicator, &[2.0, 4.0, 8.0]), 6.0);
+    }
+}
@@ -1,21 +1,25 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 
-pub type Result<T> = std::result::Result<T, TaError>;
+pub type Result<T> = core::result::Result<T, TaError>;
 
 #[derive(Debug)]
 pub enum TaError {
     InvalidParameter,
     DataItemIncomplete,
-    DataItemInvalid,
+    DataItemInvalid(DataItemError),
+    InvalidInput,
+    DivisionByZero,
 }
 
 impl Display for TaError {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match *self {
             TaError::InvalidParameter => write!(f, "invalid parameter"),
             TaError::DataItemIncomplete => write!(f, "data item is incomplete"),
-            TaError::DataItemInvalid => write!(f, "data item is invalid"),
+            TaError::DataItemInvalid(ref err) => write!(f, "data item is invalid: {}", err),
+            TaError::InvalidInput => write!(f, "input is NaN or infinite"),
+            TaError::DivisionByZero => write!(f, "division by zero"),
         }
     }
 }
@@ -25,7 +29,44 @@ impl Error for TaError {
         match *self {
             TaError::InvalidParameter => None,
             TaError::DataItemIncomplete => None,
-            TaError::DataItemInvalid => None,
+            TaError::DataItemInvalid(ref err) => Some(err),
+            TaError::InvalidInput => None,
+            TaError::DivisionByZero => None,
         }
     }
 }
+
+/// Invariant of a [DataItem](crate::DataItem) which failed to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataItemError {
+    /// The named field is NaN or infinite.
+    NotFinite(&'static str),
+    /// The named field is not a number.
+    NotANumber(&'static str),
+    /// The named price is negative.
+    NegativePrice(&'static str),
+    NegativeVolume,
+    NegativeOpenInterest,
+    HighBelowLow,
+    OpenOutOfRange,
+    CloseOutOfRange,
+    BidAboveAsk,
+}
+
+impl Display for DataItemError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match *self {
+            DataItemError::NotFinite(field) => write!(f, "{} is NaN or infinite", field),
+            DataItemError::NotANumber(field) => write!(f, "{} is not a number", field),
+            DataItemError::NegativePrice(field) => write!(f, "{} is negative", field),
+            DataItemError::NegativeVolume => write!(f, "volume is negative"),
+            DataItemError::NegativeOpenInterest => write!(f, "open interest is negative"),
+            DataItemError::HighBelowLow => write!(f, "high is below low"),
+            DataItemError::OpenOutOfRange => write!(f, "open is outside of the high-low range"),
+            DataItemError::CloseOutOfRange => write!(f, "close is outside of the high-low range"),
+            DataItemError::BidAboveAsk => write!(f, "bid is above ask"),
+        }
+    }
+}
+
+impl Error for DataItemError {}
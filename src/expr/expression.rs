@@ -0,0 +1,274 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::expr::node::{Kind, Node, Value};
+use crate::expr::parser::parse;
+use crate::registry::IndicatorRegistry;
+use crate::{DataItem, Next, Reset};
+
+/// Expression over prices and indicators, compiled from text.
+///
+/// Indicators are created with an [IndicatorRegistry](../registry/struct.IndicatorRegistry.html)
+/// and every call in the source gets its own instance, so `ema(9) - ema(9)` holds two
+/// moving averages.
+///
+/// # Grammar
+///
+/// * Numbers, e.g. `30` or `2.5`.
+/// * Prices of the bar: `open`, `high`, `low`, `close` and `volume`.
+/// * Indicators of the registry with constant parameters, e.g. `rsi(14)`, `obv` or
+///   `bb(20, 2).upper`. Without a field, the first output of the indicator is used.
+/// * `abs(x)`, `cross(a, b)` (an alias of `cross_over(a, b)`) and `cross_under(a, b)`.
+/// * Arithmetic `+ - * /`, comparisons `< <= > >= == !=`, logic `&& || !` and parentheses.
+///
+/// Operands of arithmetic and comparisons are numbers, operands of logic are booleans.
+/// Both operands of `&&` and `||` are always evaluated, so every indicator sees every bar.
+///
+/// # Example
+///
+/// ```
+/// use ta::expr::{Expression, Value};
+/// use ta::{DataItem, Next};
+///
+/// let mut range = Expression::parse("(high - low) / close * 100").unwrap();
+///
+/// let bar = DataItem::builder()
+///     .open(9.0).high(11.0).low(9.0).close(10.0).volume(0.0).build().unwrap();
+///
+/// assert_eq!(range.next(&bar), Value::Number(20.0));
+/// ```
+#[derive(Debug)]
+pub struct Expression {
+    source: String,
+    root: Node,
+}
+
+impl Expression {
+    /// Parses an expression, creating its indicators with the built-in registry.
+    pub fn parse(source: &str) -> Result<Self> {
+        Self::parse_with(source, &IndicatorRegistry::new())
+    }
+
+    /// Parses an expression, creating its indicators with the given registry.
+    pub fn parse_with(source: &str, registry: &IndicatorRegistry) -> Result<Self> {
+        Ok(Self {
+            source: source.trim().to_string(),
+            root: parse(source, registry)?,
+        })
+    }
+
+    /// Returns `true` when the expression evaluates to booleans.
+    pub fn is_condition(&self) -> bool {
+        self.root.kind() == Kind::Bool
+    }
+}
+
+impl Next<&DataItem> for Expression {
+    type Output = Value;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        self.root.next(input)
+    }
+}
+
+impl Reset for Expression {
+    fn reset(&mut self) {
+        self.root.reset();
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+/// Boolean [Expression](struct.Expression.html), usable as a
+/// [Condition](../strategy/trait.Condition.html) of a strategy.
+///
+/// # Example
+///
+/// ```
+/// use ta::expr::ExpressionCondition;
+/// use ta::strategy::Strategy;
+///
+/// let buy = ExpressionCondition::parse("cross(ema(9), ema(21)) && rsi(14) < 70").unwrap();
+/// let sell = ExpressionCondition::parse("cross_under(ema(9), ema(21))").unwrap();
+/// let strategy = Strategy::new(buy, sell);
+///
+/// assert!(ExpressionCondition::parse("rsi(14)").is_err());
+/// ```
+#[derive(Debug)]
+pub struct ExpressionCondition {
+    expression: Expression,
+}
+
+impl ExpressionCondition {
+    /// Parses a boolean expression, creating its indicators with the built-in registry.
+    pub fn parse(source: &str) -> Result<Self> {
+        Self::parse_with(source, &IndicatorRegistry::new())
+    }
+
+    /// Parses a boolean expression, creating its indicators with the given registry.
+    pub fn parse_with(source: &str, registry: &IndicatorRegistry) -> Result<Self> {
+        let expression = Expression::parse_with(source, registry)?;
+        if !expression.is_condition() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self { expression })
+    }
+}
+
+impl Next<&DataItem> for ExpressionCondition {
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        self.expression.next(input) == Value::Bool(true)
+    }
+}
+
+impl Reset for ExpressionCondition {
+    fn reset(&mut self) {
+        self.expression.reset();
+    }
+}
+
+impl fmt::Display for ExpressionCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::Strategy;
+    use crate::test_helper::*;
+    use crate::Action;
+
+    fn numbers(expression: &mut Expression, closes: &[f64]) -> Vec<f64> {
+        closes
+            .iter()
+            .map(|&close| round(expression.next(&item(close)).as_f64().unwrap()))
+            .collect()
+    }
+
+    fn conditions(condition: &mut ExpressionCondition, closes: &[f64]) -> Vec<bool> {
+        closes
+            .iter()
+            .map(|&close| condition.next(&item(close)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(Expression::parse("close * 2").is_ok());
+        assert!(Expression::parse("close *").is_err());
+
+        assert!(!Expression::parse("rsi(14)").unwrap().is_condition());
+        assert!(Expression::parse("rsi(14) < 30").unwrap().is_condition());
+
+        assert!(ExpressionCondition::parse("rsi(14) < 30").is_ok());
+        assert!(ExpressionCondition::parse("rsi(14)").is_err());
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let registry = IndicatorRegistry::empty();
+        assert!(Expression::parse_with("close > 1", &registry).is_ok());
+        assert!(Expression::parse_with("sma(3) > 1", &registry).is_err());
+    }
+
+    #[test]
+    fn test_next_number() {
+        let mut expression =
+            Expression::parse("-(close - sma(2)) * 2 + abs(1 - close) / 4").unwrap();
+        assert_eq!(numbers(&mut expression, &[3.0, 5.0]), vec![0.5, -1.0]);
+
+        let mut width = Expression::parse("bb(2, 1).upper - BB(2, 1).Lower").unwrap();
+        assert_eq!(numbers(&mut width, &[2.0, 4.0, 4.0]), vec![0.0, 2.0, 0.0]);
+
+        let mut signal = Expression::parse("macd(3, 6, 4).signal").unwrap();
+        assert_eq!(numbers(&mut signal, &[2.0, 3.0]), vec![0.0, 0.086]);
+    }
+
+    #[test]
+    fn test_next_condition() {
+        let mut condition = ExpressionCondition::parse("close > 10 && !(close >= 12)").unwrap();
+        assert_eq!(
+            conditions(&mut condition, &[9.0, 11.0, 12.0]),
+            vec![false, true, false]
+        );
+
+        let mut condition = ExpressionCondition::parse("close < 5 || close == 10").unwrap();
+        assert_eq!(
+            conditions(&mut condition, &[4.0, 10.0, 7.0]),
+            vec![true, true, false]
+        );
+
+        let mut over = ExpressionCondition::parse("cross(sma(1), sma(2))").unwrap();
+        let mut under = ExpressionCondition::parse("cross_under(sma(1), sma(2))").unwrap();
+        let closes = [10.0, 9.0, 11.0, 8.0];
+        assert_eq!(
+            conditions(&mut over, &closes),
+            vec![false, false, true, false]
+        );
+        assert_eq!(
+            conditions(&mut under, &closes),
+            vec![false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_next_evaluates_all_operands() {
+        // The moving average sees the first bar even though the left operand is false
+        let mut condition = ExpressionCondition::parse("close < 10 && sma(2) >= 10.5").unwrap();
+        assert_eq!(conditions(&mut condition, &[12.0, 9.0]), vec![false, true]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut expression = Expression::parse("sma(2)").unwrap();
+        assert_eq!(numbers(&mut expression, &[2.0, 4.0]), vec![2.0, 3.0]);
+
+        expression.reset();
+        assert_eq!(numbers(&mut expression, &[8.0]), vec![8.0]);
+
+        let mut condition = ExpressionCondition::parse("cross(sma(1), sma(2))").unwrap();
+        conditions(&mut condition, &[10.0, 9.0]);
+        condition.reset();
+        assert_eq!(conditions(&mut condition, &[11.0]), vec![false]);
+    }
+
+    #[test]
+    fn test_strategy() {
+        let buy = ExpressionCondition::parse("close < sma(3) - 1").unwrap();
+        let sell = ExpressionCondition::parse("close > sma(3) + 1").unwrap();
+        let mut strategy = Strategy::new(buy, sell);
+
+        let actions: Vec<Action> = [10.0, 10.0, 7.0, 10.0, 13.0]
+            .iter()
+            .map(|&close| strategy.next(&item(close)))
+            .collect();
+
+        assert_eq!(
+            actions,
+            vec![
+                Action::Neutral,
+                Action::Neutral,
+                Action::Buy,
+                Action::Neutral,
+                Action::Sell
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let expression = Expression::parse("  rsi(14) < 30 ").unwrap();
+        assert_eq!(format!("{}", expression), "rsi(14) < 30");
+
+        let condition = ExpressionCondition::parse("cross(ema(9), ema(21))").unwrap();
+        assert_eq!(format!("{}", condition), "cross(ema(9), ema(21))");
+    }
+}
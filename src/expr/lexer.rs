@@ -0,0 +1,157 @@
+use crate::errors::{Result, TaError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Token {
+    Number(f64),
+    Ident(String),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    And,
+    Or,
+    Not,
+}
+
+// Splits the source of an expression into tokens
+pub(super) fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() || (c == '.' && next.is_some_and(|n| n.is_ascii_digit())) {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let number = text.parse().map_err(|_| TaError::InvalidParameter)?;
+            tokens.push(Token::Number(number));
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        let (token, len) = match (c, next) {
+            ('<', Some('=')) => (Token::Le, 2),
+            ('>', Some('=')) => (Token::Ge, 2),
+            ('=', Some('=')) => (Token::Eq, 2),
+            ('!', Some('=')) => (Token::Ne, 2),
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('<', _) => (Token::Lt, 1),
+            ('>', _) => (Token::Gt, 1),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::LParen, 1),
+            (')', _) => (Token::RParen, 1),
+            (',', _) => (Token::Comma, 1),
+            ('.', _) => (Token::Dot, 1),
+            ('+', _) => (Token::Plus, 1),
+            ('-', _) => (Token::Minus, 1),
+            ('*', _) => (Token::Star, 1),
+            ('/', _) => (Token::Slash, 1),
+            _ => return Err(TaError::InvalidParameter),
+        };
+        tokens.push(token);
+        i += len;
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("cross(ema(9), ema(21)) && rsi(14) <= 30.5").unwrap(),
+            vec![
+                Token::Ident("cross".to_string()),
+                Token::LParen,
+                Token::Ident("ema".to_string()),
+                Token::LParen,
+                Token::Number(9.0),
+                Token::RParen,
+                Token::Comma,
+                Token::Ident("ema".to_string()),
+                Token::LParen,
+                Token::Number(21.0),
+                Token::RParen,
+                Token::RParen,
+                Token::And,
+                Token::Ident("rsi".to_string()),
+                Token::LParen,
+                Token::Number(14.0),
+                Token::RParen,
+                Token::Le,
+                Token::Number(30.5),
+            ]
+        );
+        assert_eq!(
+            tokenize("!(a != .5) || -b.upper * 2 / 3 + 1 >= c == d > e < f").unwrap(),
+            vec![
+                Token::Not,
+                Token::LParen,
+                Token::Ident("a".to_string()),
+                Token::Ne,
+                Token::Number(0.5),
+                Token::RParen,
+                Token::Or,
+                Token::Minus,
+                Token::Ident("b".to_string()),
+                Token::Dot,
+                Token::Ident("upper".to_string()),
+                Token::Star,
+                Token::Number(2.0),
+                Token::Slash,
+                Token::Number(3.0),
+                Token::Plus,
+                Token::Number(1.0),
+                Token::Ge,
+                Token::Ident("c".to_string()),
+                Token::Eq,
+                Token::Ident("d".to_string()),
+                Token::Gt,
+                Token::Ident("e".to_string()),
+                Token::Lt,
+                Token::Ident("f".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_invalid() {
+        assert!(tokenize("a & b").is_err());
+        assert!(tokenize("a = b").is_err());
+        assert!(tokenize("1.2.3").is_err());
+        assert!(tokenize("a # b").is_err());
+    }
+}
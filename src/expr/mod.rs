@@ -0,0 +1,41 @@
+//! Expressions over prices and indicators, compiled from text.
+//!
+//! An [Expression](struct.Expression.html) like `cross(ema(9), ema(21)) && rsi(14) < 30`
+//! is compiled into a graph of indicator instances created by an
+//! [IndicatorRegistry](../registry/struct.IndicatorRegistry.html), then evaluated for every
+//! bar. Boolean expressions can be used as strategy conditions through
+//! [ExpressionCondition](struct.ExpressionCondition.html).
+//!
+//! Expressions nested more than 128 levels deep, counting parentheses, calls, unary and binary
+//! operators, are rejected with `InvalidParameter`.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::expr::ExpressionCondition;
+//! use ta::{DataItem, Next};
+//!
+//! let mut condition = ExpressionCondition::parse("cross(sma(1), sma(2)) && close > 10").unwrap();
+//!
+//! let bar = |close| {
+//!     DataItem::builder()
+//!         .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+//! };
+//!
+//! let signals: Vec<bool> = [10.0, 9.0, 11.0, 12.0]
+//!     .iter()
+//!     .map(|&close| condition.next(&bar(close)))
+//!     .collect();
+//!
+//! assert_eq!(signals, vec![false, false, true, false]);
+//! ```
+
+mod lexer;
+
+mod node;
+pub use self::node::Value;
+
+mod parser;
+
+mod expression;
+pub use self::expression::{Expression, ExpressionCondition};
@@ -0,0 +1,196 @@
+use crate::indicators::{Cross, CrossEvent};
+use crate::registry::DynIndicator;
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Value of an [expression](struct.Expression.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Bool(bool),
+}
+
+impl Value {
+    /// Returns the number, or `None` for a boolean.
+    pub fn as_f64(self) -> Option<f64> {
+        match self {
+            Value::Number(number) => Some(number),
+            Value::Bool(_) => None,
+        }
+    }
+
+    /// Returns the boolean, or `None` for a number.
+    pub fn as_bool(self) -> Option<bool> {
+        match self {
+            Value::Bool(boolean) => Some(boolean),
+            Value::Number(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Kind {
+    Number,
+    Bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Price {
+    Open,
+    High,
+    Low,
+    Close,
+    Volume,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Arithmetic {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Logical {
+    And,
+    Or,
+}
+
+// Node of a compiled expression. Every node is evaluated for every bar, so the indicators
+// see all the bars.
+#[derive(Debug)]
+pub(super) enum Node {
+    Constant(f64),
+    Price(Price),
+    Indicator {
+        indicator: DynIndicator,
+        field: usize,
+    },
+    Cross {
+        a: Box<Node>,
+        b: Box<Node>,
+        cross: Cross,
+        event: CrossEvent,
+    },
+    Abs(Box<Node>),
+    Neg(Box<Node>),
+    Not(Box<Node>),
+    Arithmetic(Arithmetic, Box<Node>, Box<Node>),
+    Comparison(Comparison, Box<Node>, Box<Node>),
+    Logical(Logical, Box<Node>, Box<Node>),
+}
+
+impl Node {
+    pub(super) fn kind(&self) -> Kind {
+        match self {
+            Node::Constant(_)
+            | Node::Price(_)
+            | Node::Indicator { .. }
+            | Node::Abs(_)
+            | Node::Neg(_)
+            | Node::Arithmetic(..) => Kind::Number,
+            Node::Cross { .. } | Node::Not(_) | Node::Comparison(..) | Node::Logical(..) => {
+                Kind::Bool
+            }
+        }
+    }
+
+    // Returns the value of a number node
+    fn number(&mut self, input: &DataItem) -> f64 {
+        match self.next(input) {
+            Value::Number(number) => number,
+            Value::Bool(_) => unreachable!("the kinds of the nodes are checked when parsing"),
+        }
+    }
+
+    // Returns the value of a boolean node
+    fn boolean(&mut self, input: &DataItem) -> bool {
+        match self.next(input) {
+            Value::Bool(boolean) => boolean,
+            Value::Number(_) => unreachable!("the kinds of the nodes are checked when parsing"),
+        }
+    }
+
+    pub(super) fn next(&mut self, input: &DataItem) -> Value {
+        match self {
+            Node::Constant(value) => Value::Number(*value),
+            Node::Price(price) => Value::Number(match price {
+                Price::Open => input.open(),
+                Price::High => input.high(),
+                Price::Low => input.low(),
+                Price::Close => input.close(),
+                Price::Volume => input.volume(),
+            }),
+            Node::Indicator { indicator, field } => Value::Number(indicator.next(input)[*field]),
+            Node::Cross { a, b, cross, event } => {
+                let a = a.number(input);
+                let b = b.number(input);
+                Value::Bool(cross.next((a, b)) == *event)
+            }
+            Node::Abs(node) => Value::Number(node.number(input).abs()),
+            Node::Neg(node) => Value::Number(-node.number(input)),
+            Node::Not(node) => Value::Bool(!node.boolean(input)),
+            Node::Arithmetic(op, a, b) => {
+                let a = a.number(input);
+                let b = b.number(input);
+                Value::Number(match op {
+                    Arithmetic::Add => a + b,
+                    Arithmetic::Sub => a - b,
+                    Arithmetic::Mul => a * b,
+                    Arithmetic::Div => a / b,
+                })
+            }
+            Node::Comparison(op, a, b) => {
+                let a = a.number(input);
+                let b = b.number(input);
+                Value::Bool(match op {
+                    Comparison::Lt => a < b,
+                    Comparison::Le => a <= b,
+                    Comparison::Gt => a > b,
+                    Comparison::Ge => a >= b,
+                    Comparison::Eq => a == b,
+                    Comparison::Ne => a != b,
+                })
+            }
+            Node::Logical(op, a, b) => {
+                // Both operands are evaluated, so the indicators of both see every bar
+                let a = a.boolean(input);
+                let b = b.boolean(input);
+                Value::Bool(match op {
+                    Logical::And => a && b,
+                    Logical::Or => a || b,
+                })
+            }
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        match self {
+            Node::Constant(_) | Node::Price(_) => {}
+            Node::Indicator { indicator, .. } => indicator.reset(),
+            Node::Cross { a, b, cross, .. } => {
+                a.reset();
+                b.reset();
+                cross.reset();
+            }
+            Node::Abs(node) | Node::Neg(node) | Node::Not(node) => node.reset(),
+            Node::Arithmetic(_, a, b) | Node::Comparison(_, a, b) | Node::Logical(_, a, b) => {
+                a.reset();
+                b.reset();
+            }
+        }
+    }
+}
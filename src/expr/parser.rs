@@ -0,0 +1,387 @@
+use crate::errors::{Result, TaError};
+use crate::expr::lexer::{tokenize, Token};
+use crate::expr::node::{Arithmetic, Comparison, Kind, Logical, Node, Price};
+use crate::indicators::{Cross, CrossEvent};
+use crate::registry::{IndicatorRegistry, IndicatorSpec};
+
+// Maximum depth of the nodes of an expression, so that deeply nested expressions are rejected
+// instead of overflowing the stack when parsed, evaluated or dropped
+const MAX_DEPTH: usize = 128;
+
+// Recursive descent parser compiling the tokens of an expression into nodes
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    // Depth of the node being parsed
+    depth: usize,
+    registry: &'a IndicatorRegistry,
+}
+
+pub(super) fn parse(source: &str, registry: &IndicatorRegistry) -> Result<Node> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        position: 0,
+        depth: 0,
+        registry,
+    };
+    let node = parser.or()?;
+    if parser.position < parser.tokens.len() {
+        return Err(TaError::InvalidParameter);
+    }
+    Ok(node)
+}
+
+fn expect_kind(node: &Node, kind: Kind) -> Result<()> {
+    if node.kind() != kind {
+        return Err(TaError::InvalidParameter);
+    }
+    Ok(())
+}
+
+// Returns the value of a constant number, possibly negated
+fn constant(node: &Node) -> Option<f64> {
+    match node {
+        Node::Constant(value) => Some(*value),
+        Node::Neg(node) => constant(node).map(|value| -value),
+        _ => None,
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    // Consumes the next token when it is the given one
+    fn accept(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<()> {
+        if self.accept(token) {
+            Ok(())
+        } else {
+            Err(TaError::InvalidParameter)
+        }
+    }
+
+    // Enters a nested node, failing past the maximum depth. Parsing stops at the first error,
+    // so only successful parses leave the nodes they entered.
+    fn enter(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(())
+    }
+
+    fn leave(&mut self, nodes: usize) {
+        self.depth -= nodes;
+    }
+
+    fn or(&mut self) -> Result<Node> {
+        let mut node = self.and()?;
+        let mut nodes = 0;
+        while self.accept(&Token::Or) {
+            self.enter()?;
+            nodes += 1;
+            node = logical(Logical::Or, node, self.and()?)?;
+        }
+        self.leave(nodes);
+        Ok(node)
+    }
+
+    fn and(&mut self) -> Result<Node> {
+        let mut node = self.comparison()?;
+        let mut nodes = 0;
+        while self.accept(&Token::And) {
+            self.enter()?;
+            nodes += 1;
+            node = logical(Logical::And, node, self.comparison()?)?;
+        }
+        self.leave(nodes);
+        Ok(node)
+    }
+
+    fn comparison(&mut self) -> Result<Node> {
+        let node = self.additive()?;
+        let op = match self.peek() {
+            Some(Token::Lt) => Comparison::Lt,
+            Some(Token::Le) => Comparison::Le,
+            Some(Token::Gt) => Comparison::Gt,
+            Some(Token::Ge) => Comparison::Ge,
+            Some(Token::Eq) => Comparison::Eq,
+            Some(Token::Ne) => Comparison::Ne,
+            _ => return Ok(node),
+        };
+        self.position += 1;
+
+        self.enter()?;
+        let other = self.additive()?;
+        self.leave(1);
+        expect_kind(&node, Kind::Number)?;
+        expect_kind(&other, Kind::Number)?;
+        Ok(Node::Comparison(op, Box::new(node), Box::new(other)))
+    }
+
+    fn additive(&mut self) -> Result<Node> {
+        let mut node = self.multiplicative()?;
+        let mut nodes = 0;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => Arithmetic::Add,
+                Some(Token::Minus) => Arithmetic::Sub,
+                _ => {
+                    self.leave(nodes);
+                    return Ok(node);
+                }
+            };
+            self.position += 1;
+            self.enter()?;
+            nodes += 1;
+            node = arithmetic(op, node, self.multiplicative()?)?;
+        }
+    }
+
+    fn multiplicative(&mut self) -> Result<Node> {
+        let mut node = self.unary()?;
+        let mut nodes = 0;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => Arithmetic::Mul,
+                Some(Token::Slash) => Arithmetic::Div,
+                _ => {
+                    self.leave(nodes);
+                    return Ok(node);
+                }
+            };
+            self.position += 1;
+            self.enter()?;
+            nodes += 1;
+            node = arithmetic(op, node, self.unary()?)?;
+        }
+    }
+
+    fn unary(&mut self) -> Result<Node> {
+        if self.accept(&Token::Minus) {
+            self.enter()?;
+            let node = self.unary()?;
+            self.leave(1);
+            expect_kind(&node, Kind::Number)?;
+            return Ok(Node::Neg(Box::new(node)));
+        }
+        if self.accept(&Token::Not) {
+            self.enter()?;
+            let node = self.unary()?;
+            self.leave(1);
+            expect_kind(&node, Kind::Bool)?;
+            return Ok(Node::Not(Box::new(node)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Node> {
+        match self.tokens.get(self.position).cloned() {
+            Some(Token::Number(value)) => {
+                self.position += 1;
+                Ok(Node::Constant(value))
+            }
+            Some(Token::LParen) => {
+                self.position += 1;
+                self.enter()?;
+                let node = self.or()?;
+                self.leave(1);
+                self.expect(&Token::RParen)?;
+                Ok(node)
+            }
+            Some(Token::Ident(name)) => {
+                self.position += 1;
+                self.identifier(&name.to_ascii_lowercase())
+            }
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+
+    // Arguments of a call, the opening parenthesis being already consumed
+    fn arguments(&mut self) -> Result<Vec<Node>> {
+        let mut args = vec![];
+        if self.accept(&Token::RParen) {
+            return Ok(args);
+        }
+        self.enter()?;
+        loop {
+            args.push(self.or()?);
+            if self.accept(&Token::RParen) {
+                self.leave(1);
+                return Ok(args);
+            }
+            self.expect(&Token::Comma)?;
+        }
+    }
+
+    fn identifier(&mut self, name: &str) -> Result<Node> {
+        let args = if self.accept(&Token::LParen) {
+            Some(self.arguments()?)
+        } else {
+            None
+        };
+
+        let price = match name {
+            "open" => Some(Price::Open),
+            "high" => Some(Price::High),
+            "low" => Some(Price::Low),
+            "close" => Some(Price::Close),
+            "volume" => Some(Price::Volume),
+            _ => None,
+        };
+
+        match (name, price, args) {
+            (_, Some(price), None) => Ok(Node::Price(price)),
+            ("cross" | "cross_over" | "cross_under", _, Some(args)) => {
+                let event = if name == "cross_under" {
+                    CrossEvent::Under
+                } else {
+                    CrossEvent::Over
+                };
+                let [a, b]: [Node; 2] = args.try_into().map_err(|_| TaError::InvalidParameter)?;
+                expect_kind(&a, Kind::Number)?;
+                expect_kind(&b, Kind::Number)?;
+                Ok(Node::Cross {
+                    a: Box::new(a),
+                    b: Box::new(b),
+                    cross: Cross::new(),
+                    event,
+                })
+            }
+            ("abs", _, Some(args)) => {
+                let [node]: [Node; 1] = args.try_into().map_err(|_| TaError::InvalidParameter)?;
+                expect_kind(&node, Kind::Number)?;
+                Ok(Node::Abs(Box::new(node)))
+            }
+            (_, _, args) => self.indicator(name, args.unwrap_or_default()),
+        }
+    }
+
+    // Indicator of the registry, with constant arguments and an optional field
+    fn indicator(&mut self, name: &str, args: Vec<Node>) -> Result<Node> {
+        let params = args
+            .iter()
+            .map(constant)
+            .collect::<Option<Vec<f64>>>()
+            .ok_or(TaError::InvalidParameter)?;
+        let indicator = self
+            .registry
+            .create_from(&IndicatorSpec::new(name, &params)?)?;
+
+        let field = if self.accept(&Token::Dot) {
+            match self.tokens.get(self.position) {
+                Some(Token::Ident(field)) => {
+                    self.position += 1;
+                    indicator
+                        .fields()
+                        .iter()
+                        .position(|other| other.eq_ignore_ascii_case(field))
+                        .ok_or(TaError::InvalidParameter)?
+                }
+                _ => return Err(TaError::InvalidParameter),
+            }
+        } else {
+            0
+        };
+
+        Ok(Node::Indicator { indicator, field })
+    }
+}
+
+fn arithmetic(op: Arithmetic, a: Node, b: Node) -> Result<Node> {
+    expect_kind(&a, Kind::Number)?;
+    expect_kind(&b, Kind::Number)?;
+    Ok(Node::Arithmetic(op, Box::new(a), Box::new(b)))
+}
+
+fn logical(op: Logical, a: Node, b: Node) -> Result<Node> {
+    expect_kind(&a, Kind::Bool)?;
+    expect_kind(&b, Kind::Bool)?;
+    Ok(Node::Logical(op, Box::new(a), Box::new(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(source: &str) -> Result<Kind> {
+        parse(source, &IndicatorRegistry::new()).map(|node| node.kind())
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(kind("close").unwrap(), Kind::Number);
+        assert_eq!(kind("-(high - low) / 2 * volume").unwrap(), Kind::Number);
+        assert_eq!(kind("macd(12, 26, 9).signal").unwrap(), Kind::Number);
+        assert_eq!(kind("kc(10, -1)").unwrap(), Kind::Number);
+        assert_eq!(kind("OBV").unwrap(), Kind::Number);
+        assert_eq!(kind("abs(mom(3))").unwrap(), Kind::Number);
+        assert_eq!(kind("rsi(14) < 30").unwrap(), Kind::Bool);
+        assert_eq!(
+            kind("cross(ema(9), ema(21)) && !(rsi < 30 || close >= bb.upper)").unwrap(),
+            Kind::Bool
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // Syntax
+        assert!(kind("").is_err());
+        assert!(kind("rsi(14) <").is_err());
+        assert!(kind("(close").is_err());
+        assert!(kind("close)").is_err());
+        assert!(kind("close close").is_err());
+        assert!(kind("ema(9,)").is_err());
+        assert!(kind("1 < 2 < 3").is_err());
+
+        // Indicators
+        assert!(kind("foo(3)").is_err());
+        assert!(kind("ema(0)").is_err());
+        assert!(kind("ema(close)").is_err());
+        assert!(kind("macd.foo").is_err());
+        assert!(kind("macd.").is_err());
+        assert!(kind("close(3)").is_err());
+
+        // Kinds
+        assert!(kind("rsi && close").is_err());
+        assert!(kind("!close").is_err());
+        assert!(kind("-(close > 1)").is_err());
+        assert!(kind("(close > 1) + 1").is_err());
+        assert!(kind("(close > 1) < 2").is_err());
+        assert!(kind("cross(close > 1, close)").is_err());
+        assert!(kind("cross(close)").is_err());
+        assert!(kind("abs(close, 1)").is_err());
+    }
+
+    #[test]
+    fn test_parse_depth() {
+        let nested = |depth: usize, unit: &str, open: &str, close: &str| {
+            format!("{}{}{}", open.repeat(depth), unit, close.repeat(depth))
+        };
+
+        // The parentheses and the comparison are two levels
+        assert!(kind(&nested(MAX_DEPTH - 2, "(close > 1)", "!", "")).is_ok());
+        assert!(kind(&nested(MAX_DEPTH - 1, "(close > 1)", "!", "")).is_err());
+        assert!(kind(&nested(200_000, "(close > 1)", "!", "")).is_err());
+        assert!(kind(&nested(200_000, "close", "-", "")).is_err());
+        assert!(kind(&nested(MAX_DEPTH, "close", "(", ")")).is_ok());
+        assert!(kind(&nested(200_000, "close", "(", ")")).is_err());
+        assert!(kind(&nested(200_000, "close", "abs(", ")")).is_err());
+
+        let chain = |length: usize, op: &str| vec!["close"; length].join(op);
+        assert!(kind(&chain(MAX_DEPTH, " + ")).is_ok());
+        assert!(kind(&chain(200_000, " + ")).is_err());
+        assert!(kind(&chain(200_000, " * ")).is_err());
+        assert!(kind(&format!("({}) > 1", chain(MAX_DEPTH - 1, " / "))).is_ok());
+    }
+}
@@ -3,6 +3,104 @@ pub fn max3(a: f64, b: f64, c: f64) -> f64 {
     a.max(b).max(c)
 }
 
+/// Returns the smallest of 3 given numbers.
+pub fn min3(a: f64, b: f64, c: f64) -> f64 {
+    a.min(b).min(c)
+}
+
+// The batch helpers below are written as plain loops over zipped slices, free of bounds
+// checks and, where possible, of dependencies between iterations. They use no explicit SIMD.
+
+/// Writes `f(input, lagged)` for every input into `out`, `lagged` being the input `period`
+/// inputs before, with `period` the length of the ring buffer of a windowed indicator. The
+/// values before the inputs are read from the ring buffer, whose oldest value is at `index`.
+pub fn map_lagged<F: Fn(f64, f64) -> f64>(
+    deque: &[f64],
+    index: usize,
+    inputs: &[f64],
+    out: &mut [f64],
+    f: F,
+) {
+    let period = deque.len();
+    let head = period.min(inputs.len());
+    for (i, (out, &input)) in out[..head].iter_mut().zip(&inputs[..head]).enumerate() {
+        *out = f(input, deque[(index + i) % period]);
+    }
+
+    if inputs.len() > period {
+        let lagged = &inputs[..inputs.len() - period];
+        for ((out, &input), &lagged) in out[period..].iter_mut().zip(&inputs[period..]).zip(lagged)
+        {
+            *out = f(input, lagged);
+        }
+    }
+}
+
+/// Pushes the inputs into the ring buffer of a windowed indicator, whose oldest value is at
+/// `index`, and returns the new index of the oldest value.
+pub fn push_ring(deque: &mut [f64], index: usize, inputs: &[f64]) -> usize {
+    let period = deque.len();
+    let skip = inputs.len().saturating_sub(period);
+    let mut index = (index + skip) % period;
+    for &input in &inputs[skip..] {
+        deque[index] = input;
+        index = if index + 1 < period { index + 1 } else { 0 };
+    }
+    index
+}
+
+/// Writes the extreme, e.g. with `f64::max`, of every window of `period` values of `values`
+/// into `out`, which has `period - 1` values less than `values`.
+///
+/// Uses the van Herk/Gil-Werman algorithm: the values are split into blocks of `period`
+/// values, and every window is made of the end of a block and the start of the next one, so
+/// its extreme combines the suffix extreme of the first and the prefix extreme of the next.
+/// The suffix extremes are written into `out` first, so nothing is allocated.
+pub fn sliding_extreme<F: Fn(f64, f64) -> f64>(
+    values: &[f64],
+    period: usize,
+    out: &mut [f64],
+    f: F,
+) {
+    if out.is_empty() {
+        return;
+    }
+
+    for start in (0..out.len()).step_by(period) {
+        let end = (start + period).min(values.len());
+        let mut suffix = f64::NAN;
+        for i in (start..end).rev() {
+            suffix = if i + 1 == end {
+                values[i]
+            } else {
+                f(values[i], suffix)
+            };
+            if i < out.len() {
+                out[i] = suffix;
+            }
+        }
+    }
+
+    // The window starting at i ends at i + period - 1, whose prefix extreme starts over at
+    // every block
+    let mut prefix = values[..period]
+        .iter()
+        .copied()
+        .reduce(&f)
+        .unwrap_or(f64::NAN);
+    for (i, out) in out.iter_mut().enumerate() {
+        let end = i + period - 1;
+        if i > 0 {
+            prefix = if end.is_multiple_of(period) {
+                values[end]
+            } else {
+                f(prefix, values[end])
+            };
+        }
+        *out = f(*out, prefix);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -13,4 +111,51 @@ mod tests {
         assert_eq!(max3(2.0, 3.0, 1.0), 3.0);
         assert_eq!(max3(2.0, 1.0, 3.0), 3.0);
     }
+
+    #[test]
+    fn test_map_lagged() {
+        let mut out = [0.0; 4];
+        map_lagged(
+            &[3.0, 1.0, 2.0],
+            1,
+            &[4.0, 5.0, 6.0, 7.0],
+            &mut out,
+            |a, b| a - b,
+        );
+        assert_eq!(out, [3.0, 3.0, 3.0, 3.0]);
+
+        let mut out = [0.0; 1];
+        map_lagged(&[3.0, 1.0, 2.0], 1, &[4.0], &mut out, |a, b| a - b);
+        assert_eq!(out, [3.0]);
+    }
+
+    #[test]
+    fn test_push_ring() {
+        let mut deque = [3.0, 1.0, 2.0];
+        assert_eq!(push_ring(&mut deque, 1, &[4.0]), 2);
+        assert_eq!(deque, [3.0, 4.0, 2.0]);
+        assert_eq!(push_ring(&mut deque, 2, &[5.0, 6.0, 7.0, 8.0]), 0);
+        assert_eq!(deque, [6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn test_sliding_extreme() {
+        let values = [1.0, 3.0, 2.0, 5.0, 4.0, 0.0, 1.0];
+        let mut out = [0.0; 5];
+        sliding_extreme(&values, 3, &mut out, f64::max);
+        assert_eq!(out, [3.0, 5.0, 5.0, 5.0, 4.0]);
+        sliding_extreme(&values, 3, &mut out, f64::min);
+        assert_eq!(out, [1.0, 2.0, 2.0, 0.0, 0.0]);
+
+        let mut out = [0.0; 7];
+        sliding_extreme(&values, 1, &mut out, f64::max);
+        assert_eq!(out, values);
+    }
+
+    #[test]
+    fn test_min3() {
+        assert_eq!(min3(3.0, 2.0, 1.0), 1.0);
+        assert_eq!(min3(2.0, 1.0, 3.0), 1.0);
+        assert_eq!(min3(1.0, 3.0, 2.0), 1.0);
+    }
 }
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use crate::errors::{Result, TaError};
+use crate::{DataItem, Next, Reset};
+
+// Indicator with the mapping of its output to values
+pub(crate) trait Entry {
+    fn next(&mut self, input: &DataItem, values: &mut Vec<f64>);
+    fn reset(&mut self);
+}
+
+pub(crate) struct Mapped<I, F> {
+    pub(crate) indicator: I,
+    pub(crate) map: F,
+}
+
+impl<I, F, const N: usize> Entry for Mapped<I, F>
+where
+    I: for<'a> Next<&'a DataItem> + Reset,
+    F: for<'a> Fn(<I as Next<&'a DataItem>>::Output) -> [f64; N],
+{
+    fn next(&mut self, input: &DataItem, values: &mut Vec<f64>) {
+        values.extend_from_slice(&(self.map)(self.indicator.next(input)));
+    }
+
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+/// Set of indicators advanced together.
+///
+/// Holds indicators of different types, gives every bar to all of them with a single `next`
+/// and returns their outputs as named [values](struct.IndicatorValues.html), e.g. to extract
+/// the features of a machine learning model. Indicators returning a number are added with
+/// `add`, while `add_with` maps the output of any indicator to one or more numbers, e.g. the
+/// lines of the [MACD](indicators/struct.MovingAverageConvergenceDivergence.html).
+///
+/// The values are in the order the indicators were added.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{
+///     MovingAverageConvergenceDivergence as Macd, RelativeStrengthIndex as Rsi,
+///     SimpleMovingAverage as Sma,
+/// };
+/// use ta::{DataItem, IndicatorSet, Next};
+///
+/// let mut set = IndicatorSet::new()
+///     .add("sma", Sma::new(3).unwrap()).unwrap()
+///     .add("rsi", Rsi::new(14).unwrap()).unwrap()
+///     .add_with(["macd", "macd_signal"], Macd::default(), |out| [out.macd, out.signal])
+///     .unwrap();
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(10.0).low(10.0).close(10.0).volume(0.0).build().unwrap();
+/// let values = set.next(&bar);
+///
+/// assert_eq!(values.names(), ["sma", "rsi", "macd", "macd_signal"]);
+/// assert_eq!(values.get("sma"), Some(10.0));
+/// assert_eq!(values.get("macd"), Some(0.0));
+/// assert_eq!(values.get("ema"), None);
+/// ```
+#[derive(Default)]
+pub struct IndicatorSet {
+    names: Vec<String>,
+    entries: Vec<Box<dyn Entry>>,
+    // Shared with the values returned by next, cleared when a name is added
+    shared_names: Option<Arc<[String]>>,
+}
+
+/// Named values returned by an [IndicatorSet](struct.IndicatorSet.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorValues {
+    names: Arc<[String]>,
+    values: Vec<f64>,
+}
+
+impl IndicatorSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an indicator returning a number, named _name_. Returns an error when the name is
+    /// already used.
+    pub fn add<I>(self, name: &str, indicator: I) -> Result<Self>
+    where
+        I: for<'a> Next<&'a DataItem, Output = f64> + Reset + 'static,
+    {
+        self.add_with([name], indicator, |value| [value])
+    }
+
+    /// Adds an indicator whose output is mapped to values by _map_, named _names_. Returns
+    /// an error when a name is already used.
+    pub fn add_with<I, F, const N: usize>(
+        mut self,
+        names: [&str; N],
+        indicator: I,
+        map: F,
+    ) -> Result<Self>
+    where
+        I: for<'a> Next<&'a DataItem> + Reset + 'static,
+        F: for<'a> Fn(<I as Next<&'a DataItem>>::Output) -> [f64; N] + 'static,
+    {
+        for (index, name) in names.iter().enumerate() {
+            if names[..index].contains(name) || self.names.iter().any(|other| other == name) {
+                return Err(TaError::InvalidParameter);
+            }
+        }
+
+        self.names.extend(names.iter().map(|name| name.to_string()));
+        self.entries.push(Box::new(Mapped { indicator, map }));
+        self.shared_names = None;
+        Ok(self)
+    }
+
+    /// Returns the names of the values.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl Next<&DataItem> for IndicatorSet {
+    type Output = IndicatorValues;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let mut values = Vec::with_capacity(self.names.len());
+        for entry in self.entries.iter_mut() {
+            entry.next(input, &mut values);
+        }
+
+        let names = self
+            .shared_names
+            .get_or_insert_with(|| self.names.clone().into())
+            .clone();
+        IndicatorValues { names, values }
+    }
+}
+
+impl Reset for IndicatorSet {
+    fn reset(&mut self) {
+        for entry in self.entries.iter_mut() {
+            entry.reset();
+        }
+    }
+}
+
+impl IndicatorValues {
+    /// Returns the value named _name_.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.names
+            .iter()
+            .position(|other| other == name)
+            .map(|index| self.values[index])
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the values in the order of the names.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns an iterator over the names and the values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.names
+            .iter()
+            .map(|name| name.as_str())
+            .zip(self.values.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{BollingerBands, Maximum, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    fn set() -> IndicatorSet {
+        IndicatorSet::new()
+            .add("sma", Sma::new(2).unwrap())
+            .unwrap()
+            .add_with(
+                ["bb_upper", "bb_lower"],
+                BollingerBands::new(2, 1.0).unwrap(),
+                |out| [out.upper, out.lower],
+            )
+            .unwrap()
+            .add("max", Maximum::new(3).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_add() {
+        assert!(set().add("sma", Sma::new(3).unwrap()).is_err());
+        assert!(IndicatorSet::new()
+            .add_with(["a", "a"], Sma::new(3).unwrap(), |v| [v, v])
+            .is_err());
+
+        let set = set();
+        assert_eq!(set.len(), 4);
+        assert!(!set.is_empty());
+        assert_eq!(set.names(), ["sma", "bb_upper", "bb_lower", "max"]);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut set = set();
+
+        let values = set.next(&item(10.0));
+        assert_eq!(values.values(), [10.0, 10.0, 10.0, 10.0]);
+
+        let values = set.next(&item(12.0));
+        assert_eq!(values.get("sma"), Some(11.0));
+        assert_eq!(values.get("bb_upper"), Some(12.0));
+        assert_eq!(values.get("bb_lower"), Some(10.0));
+        assert_eq!(values.get("max"), Some(12.0));
+        assert_eq!(values.get("min"), None);
+
+        let pairs: Vec<_> = values.iter().collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("sma", 11.0),
+                ("bb_upper", 12.0),
+                ("bb_lower", 10.0),
+                ("max", 12.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_empty() {
+        let mut set = IndicatorSet::new();
+        assert!(set.is_empty());
+        assert_eq!(set.next(&item(10.0)).values(), &[] as &[f64]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut set = set();
+        set.next(&item(20.0));
+        set.next(&item(30.0));
+
+        set.reset();
+
+        assert_eq!(set.next(&item(10.0)).values(), [10.0, 10.0, 10.0, 10.0]);
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Acceleration Bands (ABANDS).
+///
+/// Developed by Price Headley, Acceleration Bands are envelopes around a
+/// [simple moving average](struct.SimpleMovingAverage.html) of the close price, widened
+/// by the relative range of each bar. Prices breaking out of the bands indicate an
+/// acceleration of the trend.
+///
+/// # Formula
+///
+/// * _Middle band_ = SMA(Close, _period_)
+/// * _Upper band_ = SMA(High * (1 + _factor_ * (High - Low) / (High + Low)), _period_)
+/// * _Lower band_ = SMA(Low * (1 - _factor_ * (High - Low) / (High + Low)), _period_)
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _factor_ - width factor of the bands. Default is 4.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AccelerationBands;
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(1.0).build().unwrap();
+///
+/// let mut abands = AccelerationBands::new(20, 4.0).unwrap();
+/// let out = abands.next(&bar);
+///
+/// assert_eq!(out.average, 10.0);
+/// assert_eq!((out.upper * 1000.0).round() / 1000.0, 15.4);
+/// assert_eq!((out.lower * 1000.0).round() / 1000.0, 5.4);
+/// ```
+///
+/// # Links
+///
+/// * [Acceleration Bands, TA-Lib](https://ta-lib.org/functions/accbands/)
+///
+#[doc(alias = "ABANDS")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccelerationBands {
+    factor: f64,
+    average: Sma,
+    upper: Sma,
+    lower: Sma,
+}
+
+impl AccelerationBands {
+    pub fn new(period: usize, factor: f64) -> Result<Self> {
+        Ok(Self {
+            factor,
+            average: Sma::new(period)?,
+            upper: Sma::new(period)?,
+            lower: Sma::new(period)?,
+        })
+    }
+
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccelerationBandsOutput {
+    pub average: f64,
+    pub upper: f64,
+    pub lower: f64,
+}
+
+impl From<AccelerationBandsOutput> for (f64, f64, f64) {
+    fn from(ab: AccelerationBandsOutput) -> Self {
+        (ab.average, ab.upper, ab.lower)
+    }
+}
+
+impl Period for AccelerationBands {
+    fn period(&self) -> usize {
+        self.average.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AccelerationBands {
+    type Output = AccelerationBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        let sum = high + low;
+        let width = if sum == 0.0 {
+            0.0
+        } else {
+            self.factor * (high - low) / sum
+        };
+
+        AccelerationBandsOutput {
+            average: self.average.next(input.close()),
+            upper: self.upper.next(high * (1.0 + width)),
+            lower: self.lower.next(low * (1.0 - width)),
+        }
+    }
+}
+
+impl Reset for AccelerationBands {
+    fn reset(&mut self) {
+        self.average.reset();
+        self.upper.reset();
+        self.lower.reset();
+    }
+}
+
+impl Default for AccelerationBands {
+    fn default() -> Self {
+        Self::new(20, 4.0).unwrap()
+    }
+}
+
+impl fmt::Display for AccelerationBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ABANDS({}, {})", self.period(), self.factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        (n0, n1, n2)
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(AccelerationBands::new(0, 4.0).is_err());
+        assert!(AccelerationBands::new(1, 4.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut abands = AccelerationBands::new(2, 4.0).unwrap();
+
+        assert_eq!(
+            round(abands.next(&bar(11.0, 9.0, 10.0)).into()),
+            (10.0, 15.4, 5.4)
+        );
+        assert_eq!(
+            round(abands.next(&bar(12.0, 10.0, 11.0)).into()),
+            (10.5, 15.882, 5.882)
+        );
+        assert_eq!(
+            round(abands.next(&bar(13.0, 11.0, 12.0)).into()),
+            (11.5, 16.848, 6.848)
+        );
+    }
+
+    #[test]
+    fn test_next_zero_prices() {
+        let mut abands = AccelerationBands::new(2, 4.0).unwrap();
+
+        assert_eq!(
+            round(abands.next(&bar(0.0, 0.0, 0.0)).into()),
+            (0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut abands = AccelerationBands::new(2, 4.0).unwrap();
+
+        abands.next(&bar(11.0, 9.0, 10.0));
+        abands.next(&bar(12.0, 10.0, 11.0));
+
+        abands.reset();
+
+        assert_eq!(
+            round(abands.next(&bar(11.0, 9.0, 10.0)).into()),
+            (10.0, 15.4, 5.4)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        AccelerationBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AccelerationBands::new(20, 4.0).unwrap();
+        assert_eq!(format!("{}", indicator), "ABANDS(20, 4)");
+    }
+}
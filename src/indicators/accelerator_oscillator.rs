@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AwesomeOscillator, SimpleMovingAverage as Sma};
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accelerator Oscillator (AC).
+///
+/// Developed by Bill Williams, the Accelerator Oscillator measures the acceleration
+/// of the market momentum. It is the difference between the
+/// [Awesome Oscillator](struct.AwesomeOscillator.html) and its simple moving average.
+///
+/// # Formula
+///
+/// AC = AO - SMA(_signal_period_) of AO
+///
+/// Where:
+///
+/// * _AO_ - [Awesome Oscillator](struct.AwesomeOscillator.html) with _fast_period_ and _slow_period_
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast SMA of AO (integer greater than 0). Default is 5.
+/// * _slow_period_ - period for the slow SMA of AO (integer greater than 0). Default is 34.
+/// * _signal_period_ - period for the SMA of AO (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AcceleratorOscillator;
+/// use ta::Next;
+///
+/// let mut ac = AcceleratorOscillator::new(2, 3, 2).unwrap();
+/// assert_eq!(ac.next(10.0), 0.0);
+/// assert_eq!(ac.next(11.0), 0.0);
+/// assert_eq!(ac.next(12.0), 0.25);
+/// ```
+///
+/// # Links
+///
+/// * [Accelerator Oscillator, TradingView](https://www.tradingview.com/support/solutions/43000501837-accelerator-oscillator-ac/)
+///
+#[doc(alias = "AC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AcceleratorOscillator {
+    ao: AwesomeOscillator,
+    signal_sma: Sma,
+}
+
+impl AcceleratorOscillator {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            ao: AwesomeOscillator::new(fast_period, slow_period)?,
+            signal_sma: Sma::new(signal_period)?,
+        })
+    }
+}
+
+impl Period for AcceleratorOscillator {
+    fn period(&self) -> usize {
+        self.ao.period()
+    }
+}
+
+impl Lookback for AcceleratorOscillator {
+    fn lookback(&self) -> usize {
+        self.ao.lookback() + self.signal_sma.lookback()
+    }
+}
+
+impl Next<f64> for AcceleratorOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ao = self.ao.next(input);
+        ao - self.signal_sma.next(ao)
+    }
+}
+
+impl<T: High + Low> Next<&T> for AcceleratorOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ao = self.ao.next(input);
+        ao - self.signal_sma.next(ao)
+    }
+}
+
+impl Reset for AcceleratorOscillator {
+    fn reset(&mut self) {
+        self.ao.reset();
+        self.signal_sma.reset();
+    }
+}
+
+impl Default for AcceleratorOscillator {
+    fn default() -> Self {
+        Self::new(5, 34, 5).unwrap()
+    }
+}
+
+impl fmt::Display for AcceleratorOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AC({}, {}, {})",
+            self.ao.fast_period(),
+            self.ao.period(),
+            self.signal_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(AcceleratorOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(AcceleratorOscillator::new(0, 1, 1).is_err());
+        assert!(AcceleratorOscillator::new(1, 0, 1).is_err());
+        assert!(AcceleratorOscillator::new(1, 1, 0).is_err());
+        assert!(AcceleratorOscillator::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ac = AcceleratorOscillator::new(2, 3, 2).unwrap();
+
+        assert_eq!(round(ac.next(10.0)), 0.0);
+        assert_eq!(round(ac.next(11.0)), 0.0);
+        assert_eq!(round(ac.next(12.0)), 0.25);
+        assert_eq!(round(ac.next(11.0)), -0.167);
+        assert_eq!(round(ac.next(13.0)), -0.083);
+        assert_eq!(round(ac.next(14.0)), 0.417);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut ac = AcceleratorOscillator::new(2, 3, 2).unwrap();
+
+        assert_eq!(round(ac.next(&bar(11.0, 9.0))), 0.0);
+        assert_eq!(round(ac.next(&bar(12.0, 10.0))), 0.0);
+        assert_eq!(round(ac.next(&bar(13.0, 11.0))), 0.25);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ac = AcceleratorOscillator::new(2, 3, 2).unwrap();
+
+        ac.next(10.0);
+        ac.next(11.0);
+        assert_eq!(round(ac.next(12.0)), 0.25);
+
+        ac.reset();
+
+        assert_eq!(round(ac.next(12.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        AcceleratorOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AcceleratorOscillator::new(5, 34, 5).unwrap();
+        assert_eq!(format!("{}", indicator), "AC(5, 34, 5)");
+    }
+}
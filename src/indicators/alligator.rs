@@ -0,0 +1,279 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SmoothedMovingAverage as Smma;
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Alligator.
+///
+/// Developed by Bill Williams, the Alligator consists of three
+/// [smoothed moving averages](struct.SmoothedMovingAverage.html) of the median price,
+/// each shifted forward by a number of bars: the Jaw (blue line), the Teeth (red line)
+/// and the Lips (green line).
+///
+/// The outputs are already displaced, so the value returned for the current bar is the
+/// value plotted at this bar on a conventional chart, i.e. the moving average computed
+/// _shift_ bars ago. Until enough bars have been given, the first computed value is returned.
+///
+/// # Formula
+///
+/// Median price = (High + Low) / 2
+///
+/// * Jaw<sub>t</sub> = SMMA(_jaw_period_) of median price at _t_ - _jaw_shift_
+/// * Teeth<sub>t</sub> = SMMA(_teeth_period_) of median price at _t_ - _teeth_shift_
+/// * Lips<sub>t</sub> = SMMA(_lips_period_) of median price at _t_ - _lips_shift_
+///
+/// # Parameters
+///
+/// * _jaw_period_ - period of the Jaw SMMA (integer greater than 0). Default is 13.
+/// * _jaw_shift_ - displacement of the Jaw in bars. Default is 8.
+/// * _teeth_period_ - period of the Teeth SMMA (integer greater than 0). Default is 8.
+/// * _teeth_shift_ - displacement of the Teeth in bars. Default is 5.
+/// * _lips_period_ - period of the Lips SMMA (integer greater than 0). Default is 5.
+/// * _lips_shift_ - displacement of the Lips in bars. Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Alligator;
+/// use ta::Next;
+///
+/// let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+///
+/// alligator.next(10.0);
+/// alligator.next(11.0);
+/// let out = alligator.next(12.0);
+///
+/// assert_eq!(out.jaw, 10.0);
+/// assert_eq!(out.teeth, 10.5);
+/// assert_eq!(out.lips, 12.0);
+/// ```
+///
+/// # Links
+///
+/// * [Williams Alligator, Investopedia](https://www.investopedia.com/articles/trading/072115/exploring-williams-alligator-indicator.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Alligator {
+    jaw: Smma,
+    teeth: Smma,
+    lips: Smma,
+    jaw_shift: Displacement,
+    teeth_shift: Displacement,
+    lips_shift: Displacement,
+}
+
+impl Alligator {
+    pub fn new(
+        jaw_period: usize,
+        jaw_shift: usize,
+        teeth_period: usize,
+        teeth_shift: usize,
+        lips_period: usize,
+        lips_shift: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            jaw: Smma::new(jaw_period)?,
+            teeth: Smma::new(teeth_period)?,
+            lips: Smma::new(lips_period)?,
+            jaw_shift: Displacement::new(jaw_shift),
+            teeth_shift: Displacement::new(teeth_shift),
+            lips_shift: Displacement::new(lips_shift),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlligatorOutput {
+    pub jaw: f64,
+    pub teeth: f64,
+    pub lips: f64,
+}
+
+impl From<AlligatorOutput> for (f64, f64, f64) {
+    fn from(ao: AlligatorOutput) -> Self {
+        (ao.jaw, ao.teeth, ao.lips)
+    }
+}
+
+impl Next<f64> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        AlligatorOutput {
+            jaw: self.jaw_shift.next(self.jaw.next(input)),
+            teeth: self.teeth_shift.next(self.teeth.next(input)),
+            lips: self.lips_shift.next(self.lips.next(input)),
+        }
+    }
+}
+
+impl<T: High + Low> Next<&T> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for Alligator {
+    fn reset(&mut self) {
+        self.jaw.reset();
+        self.teeth.reset();
+        self.lips.reset();
+        self.jaw_shift.reset();
+        self.teeth_shift.reset();
+        self.lips_shift.reset();
+    }
+}
+
+impl Default for Alligator {
+    fn default() -> Self {
+        Self::new(13, 8, 8, 5, 5, 3).unwrap()
+    }
+}
+
+impl fmt::Display for Alligator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALLIGATOR({}, {}, {}, {}, {}, {})",
+            self.jaw.period(),
+            self.jaw_shift.shift(),
+            self.teeth.period(),
+            self.teeth_shift.shift(),
+            self.lips.period(),
+            self.lips_shift.shift()
+        )
+    }
+}
+
+/// Returns the value given _shift_ calls ago.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct Displacement {
+    index: usize,
+    is_new: bool,
+    deque: Box<[f64]>,
+}
+
+impl Displacement {
+    fn new(shift: usize) -> Self {
+        Self {
+            index: 0,
+            is_new: true,
+            deque: vec![0.0; shift].into_boxed_slice(),
+        }
+    }
+
+    fn shift(&self) -> usize {
+        self.deque.len()
+    }
+
+    fn next(&mut self, input: f64) -> f64 {
+        if self.deque.is_empty() {
+            return input;
+        }
+        if self.is_new {
+            self.is_new = false;
+            for val in self.deque.iter_mut() {
+                *val = input;
+            }
+        }
+
+        let output = self.deque[self.index];
+        self.deque[self.index] = input;
+        self.index = (self.index + 1) % self.deque.len();
+        output
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.is_new = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Alligator);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        (n0, n1, n2)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Alligator::new(0, 8, 8, 5, 5, 3).is_err());
+        assert!(Alligator::new(13, 8, 0, 5, 5, 3).is_err());
+        assert!(Alligator::new(13, 8, 8, 5, 0, 3).is_err());
+        assert!(Alligator::new(1, 0, 1, 0, 1, 0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        assert_eq!(round(alligator.next(10.0).into()), (10.0, 10.0, 10.0));
+        assert_eq!(round(alligator.next(11.0).into()), (10.0, 10.0, 11.0));
+        assert_eq!(round(alligator.next(12.0).into()), (10.0, 10.5, 12.0));
+        assert_eq!(round(alligator.next(11.0).into()), (10.5, 11.25, 11.0));
+        assert_eq!(round(alligator.next(13.0).into()), (11.0, 11.125, 13.0));
+        assert_eq!(round(alligator.next(14.0).into()), (11.0, 12.063, 14.0));
+        assert_eq!(round(alligator.next(12.0).into()), (11.667, 13.031, 12.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        assert_eq!(
+            round(alligator.next(&bar(11.0, 9.0)).into()),
+            (10.0, 10.0, 10.0)
+        );
+        assert_eq!(
+            round(alligator.next(&bar(12.0, 10.0)).into()),
+            (10.0, 10.0, 11.0)
+        );
+        assert_eq!(
+            round(alligator.next(&bar(13.0, 11.0)).into()),
+            (10.0, 10.5, 12.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        alligator.next(10.0);
+        alligator.next(11.0);
+        assert_eq!(round(alligator.next(12.0).into()), (10.0, 10.5, 12.0));
+
+        alligator.reset();
+
+        assert_eq!(round(alligator.next(12.0).into()), (12.0, 12.0, 12.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Alligator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Alligator::default();
+        assert_eq!(format!("{}", indicator), "ALLIGATOR(13, 8, 8, 5, 5, 3)");
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon indicator.
+///
+/// Developed by Tushar Chande, the Aroon indicator measures the number of periods passed
+/// since the highest high and the lowest low within a given time frame. It is used to
+/// identify trend changes and the strength of a trend.
+///
+/// The indicator returns three values: Aroon Up, Aroon Down and Aroon Oscillator.
+/// Aroon Up and Aroon Down are in the range of 0..100, the oscillator is in the range of -100..100.
+///
+/// # Formula
+///
+/// Aroon Up = (_period_ - periods since the highest high) / _period_ * 100
+///
+/// Aroon Down = (_period_ - periods since the lowest low) / _period_ * 100
+///
+/// Aroon Oscillator = Aroon Up - Aroon Down
+///
+/// The highest high and the lowest low are taken over the last _period_ + 1 periods,
+/// so the number of periods passed is in the range of 0.._period_.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Aroon;
+/// use ta::Next;
+///
+/// let mut aroon = Aroon::new(4).unwrap();
+///
+/// aroon.next(10.0);
+/// aroon.next(12.0);
+/// let out = aroon.next(11.0);
+///
+/// assert_eq!(out.up, 75.0);
+/// assert_eq!(out.down, 50.0);
+/// assert_eq!(out.oscillator, 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Aroon, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+/// * [Aroon Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon_oscillator)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    period: usize,
+    max: Maximum,
+    min: Minimum,
+}
+
+impl Aroon {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                max: Maximum::new(period + 1)?,
+                min: Minimum::new(period + 1)?,
+            }),
+        }
+    }
+
+    fn output(&self) -> AroonOutput {
+        let period = self.period as f64;
+        let up = (period - self.max.bars_since_max() as f64) / period * 100.0;
+        let down = (period - self.min.bars_since_min() as f64) / period * 100.0;
+
+        AroonOutput {
+            up,
+            down,
+            oscillator: up - down,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput {
+    pub up: f64,
+    pub down: f64,
+    pub oscillator: f64,
+}
+
+impl From<AroonOutput> for (f64, f64, f64) {
+    fn from(ao: AroonOutput) -> Self {
+        (ao.up, ao.down, ao.oscillator)
+    }
+}
+
+impl Period for Aroon {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.max.next(input);
+        self.min.next(input);
+        self.output()
+    }
+}
+
+impl<T: High + Low> Next<&T> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.max.next(input.high());
+        self.min.next(input.low());
+        self.output()
+    }
+}
+
+impl Reset for Aroon {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+    }
+}
+
+impl Default for Aroon {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl fmt::Display for Aroon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AROON({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Aroon);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 100.0).round() / 100.0;
+        let n1 = (nums.1 * 100.0).round() / 100.0;
+        let n2 = (nums.2 * 100.0).round() / 100.0;
+        (n0, n1, n2)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Aroon::new(0).is_err());
+        assert!(Aroon::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        assert_eq!(round(aroon.next(10.0).into()), (100.0, 100.0, 0.0));
+        assert_eq!(round(aroon.next(12.0).into()), (100.0, 75.0, 25.0));
+        assert_eq!(round(aroon.next(11.0).into()), (75.0, 50.0, 25.0));
+        assert_eq!(round(aroon.next(9.0).into()), (50.0, 100.0, -50.0));
+        assert_eq!(round(aroon.next(9.5).into()), (25.0, 75.0, -50.0));
+        assert_eq!(round(aroon.next(10.0).into()), (0.0, 50.0, -50.0));
+        assert_eq!(round(aroon.next(10.5).into()), (0.0, 25.0, -25.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut aroon = Aroon::new(2).unwrap();
+
+        let bar1 = Bar::new().high(5).low(3);
+        let bar2 = Bar::new().high(6).low(4);
+        let bar3 = Bar::new().high(4).low(2);
+        let bar4 = Bar::new().high(5).low(4);
+
+        assert_eq!(round(aroon.next(&bar1).into()), (100.0, 100.0, 0.0));
+        assert_eq!(round(aroon.next(&bar2).into()), (100.0, 50.0, 50.0));
+        assert_eq!(round(aroon.next(&bar3).into()), (50.0, 100.0, -50.0));
+        assert_eq!(round(aroon.next(&bar4).into()), (0.0, 50.0, -50.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        assert_eq!(round(aroon.next(10.0).into()), (100.0, 100.0, 0.0));
+        assert_eq!(round(aroon.next(12.0).into()), (100.0, 75.0, 25.0));
+
+        aroon.reset();
+
+        assert_eq!(round(aroon.next(10.0).into()), (100.0, 100.0, 0.0));
+        assert_eq!(round(aroon.next(12.0).into()), (100.0, 75.0, 25.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Aroon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Aroon::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "AROON(14)");
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, TrueRange};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, Current, High, Lookback, Low, Next, Period, Reset, UpdateLast};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -79,6 +79,12 @@ impl Period for AverageTrueRange {
     }
 }
 
+impl Lookback for AverageTrueRange {
+    fn lookback(&self) -> usize {
+        self.true_range.lookback() + self.ema.lookback()
+    }
+}
+
 impl Next<f64> for AverageTrueRange {
     type Output = f64;
 
@@ -95,6 +101,26 @@ impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
     }
 }
 
+impl UpdateLast<f64> for AverageTrueRange {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.ema.current().is_none() {
+            return self.next(input);
+        }
+
+        self.ema.update_last(self.true_range.update_last(input))
+    }
+}
+
+impl<T: High + Low + Close> UpdateLast<&T> for AverageTrueRange {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.ema.current().is_none() {
+            return self.next(input);
+        }
+
+        self.ema.update_last(self.true_range.update_last(input))
+    }
+}
+
 impl Reset for AverageTrueRange {
     fn reset(&mut self) {
         self.true_range.reset();
@@ -102,6 +128,14 @@ impl Reset for AverageTrueRange {
     }
 }
 
+impl Current for AverageTrueRange {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.ema.current()
+    }
+}
+
 impl Default for AverageTrueRange {
     fn default() -> Self {
         Self::new(14).unwrap()
@@ -139,6 +173,45 @@ mod tests {
         assert_eq!(atr.next(&bar3), 3.375);
     }
 
+    #[test]
+    fn test_current() {
+        let mut atr = AverageTrueRange::new(3).unwrap();
+        assert_eq!(atr.current(), None);
+
+        for &input in &[4.0, 5.0, 3.0] {
+            let output = atr.next(&Bar::new().high(input + 1.0).low(input - 1.0).close(input));
+            assert_eq!(atr.current(), Some(output));
+        }
+
+        atr.reset();
+        assert_eq!(atr.current(), None);
+    }
+
+    #[test]
+    fn test_update_last() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().high(close + 1.0).low(close - 2.0).close(close)
+        }
+
+        let mut atr = AverageTrueRange::new(3).unwrap();
+        let mut expected = AverageTrueRange::new(3).unwrap();
+        assert_eq!(atr.update_last(&bar(4.0)), expected.next(&bar(4.0)));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            atr.next(&bar(close + 3.0));
+            atr.update_last(&bar(close - 1.0));
+            assert_eq!(
+                round(atr.update_last(&bar(close))),
+                round(expected.next(&bar(close)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(AverageTrueRange::new(14).unwrap().lookback(), 14);
+    }
+
     #[test]
     fn test_reset() {
         let mut atr = AverageTrueRange::new(9).unwrap();
@@ -0,0 +1,188 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Awesome Oscillator (AO).
+///
+/// Developed by Bill Williams, the Awesome Oscillator measures market momentum
+/// as the difference between a fast and a slow simple moving average of the median price.
+///
+/// # Formula
+///
+/// Median price = (High + Low) / 2
+///
+/// AO = SMA(_fast_period_) of median price - SMA(_slow_period_) of median price
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast SMA (integer greater than 0). Default is 5.
+/// * _slow_period_ - period for the slow SMA (integer greater than 0). Default is 34.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AwesomeOscillator;
+/// use ta::{Next, DataItem};
+///
+/// let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+///
+/// let di1 = DataItem::builder()
+///             .high(11.0).low(9.0).close(10.0).open(10.0).volume(100.0)
+///             .build().unwrap();
+/// let di2 = DataItem::builder()
+///             .high(12.0).low(10.0).close(11.0).open(11.0).volume(100.0)
+///             .build().unwrap();
+/// let di3 = DataItem::builder()
+///             .high(13.0).low(11.0).close(12.0).open(12.0).volume(100.0)
+///             .build().unwrap();
+///
+/// assert_eq!(ao.next(&di1), 0.0);
+/// assert_eq!(ao.next(&di2), 0.0);
+/// assert_eq!(ao.next(&di3), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Awesome Oscillator, TradingView](https://www.tradingview.com/support/solutions/43000501826-awesome-oscillator-ao/)
+///
+#[doc(alias = "AO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AwesomeOscillator {
+    fast_sma: Sma,
+    slow_sma: Sma,
+}
+
+impl AwesomeOscillator {
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_sma: Sma::new(fast_period)?,
+            slow_sma: Sma::new(slow_period)?,
+        })
+    }
+
+    pub(super) fn fast_period(&self) -> usize {
+        self.fast_sma.period()
+    }
+}
+
+impl Period for AwesomeOscillator {
+    fn period(&self) -> usize {
+        self.slow_sma.period()
+    }
+}
+
+impl Lookback for AwesomeOscillator {
+    fn lookback(&self) -> usize {
+        self.fast_sma.lookback().max(self.slow_sma.lookback())
+    }
+}
+
+impl Next<f64> for AwesomeOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.fast_sma.next(input) - self.slow_sma.next(input)
+    }
+}
+
+impl<T: High + Low> Next<&T> for AwesomeOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for AwesomeOscillator {
+    fn reset(&mut self) {
+        self.fast_sma.reset();
+        self.slow_sma.reset();
+    }
+}
+
+impl Default for AwesomeOscillator {
+    fn default() -> Self {
+        Self::new(5, 34).unwrap()
+    }
+}
+
+impl fmt::Display for AwesomeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AO({}, {})",
+            self.fast_sma.period(),
+            self.slow_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(AwesomeOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(AwesomeOscillator::new(0, 1).is_err());
+        assert!(AwesomeOscillator::new(1, 0).is_err());
+        assert!(AwesomeOscillator::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+
+        assert_eq!(round(ao.next(10.0)), 0.0);
+        assert_eq!(round(ao.next(11.0)), 0.0);
+        assert_eq!(round(ao.next(12.0)), 0.5);
+        assert_eq!(round(ao.next(11.0)), 0.167);
+        assert_eq!(round(ao.next(13.0)), 0.0);
+        assert_eq!(round(ao.next(14.0)), 0.833);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+
+        assert_eq!(round(ao.next(&bar(11.0, 9.0))), 0.0);
+        assert_eq!(round(ao.next(&bar(12.0, 10.0))), 0.0);
+        assert_eq!(round(ao.next(&bar(13.0, 11.0))), 0.5);
+        assert_eq!(round(ao.next(&bar(12.0, 10.0))), 0.167);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+
+        ao.next(10.0);
+        ao.next(11.0);
+        assert_eq!(round(ao.next(12.0)), 0.5);
+
+        ao.reset();
+
+        assert_eq!(round(ao.next(12.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        AwesomeOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AwesomeOscillator::new(5, 34).unwrap();
+        assert_eq!(format!("{}", indicator), "AO(5, 34)");
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::StandardDeviation as Sd;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, IsReady, Lookback, Next, Period, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -81,6 +81,18 @@ impl Period for BollingerBands {
     }
 }
 
+impl Lookback for BollingerBands {
+    fn lookback(&self) -> usize {
+        self.sd.lookback()
+    }
+}
+
+impl IsReady for BollingerBands {
+    fn is_ready(&self) -> bool {
+        self.sd.is_ready()
+    }
+}
+
 impl Next<f64> for BollingerBands {
     type Output = BollingerBandsOutput;
 
@@ -104,6 +116,25 @@ impl<T: Close> Next<&T> for BollingerBands {
     }
 }
 
+impl UpdateLast<f64> for BollingerBands {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        let sd = self.sd.update_last(input);
+        let mean = self.sd.mean();
+
+        Self::Output {
+            average: mean,
+            upper: mean + sd * self.multiplier,
+            lower: mean - sd * self.multiplier,
+        }
+    }
+}
+
+impl<T: Close> UpdateLast<&T> for BollingerBands {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.close())
+    }
+}
+
 impl Reset for BollingerBands {
     fn reset(&mut self) {
         self.sd.reset();
@@ -161,6 +192,22 @@ mod tests {
         assert_eq!(round(d.lower), -0.395);
     }
 
+    #[test]
+    fn test_update_last() {
+        let mut bb = BollingerBands::new(3, 2.0).unwrap();
+        let mut expected = BollingerBands::new(3, 2.0).unwrap();
+        assert_eq!(bb.update_last(4.0).upper, expected.next(4.0).upper);
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            bb.next(close + 3.0);
+            bb.update_last(close - 1.0);
+            assert_eq!(
+                round(bb.update_last(close).upper),
+                round(expected.next(close).upper)
+            );
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut bb = BollingerBands::new(5, 2.0_f64).unwrap();
@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chande Kroll Stop (CKS).
+///
+/// Developed by Tushar Chande and Stanley Kroll, it is a trend following indicator which
+/// computes preliminary stops from the [average true range](struct.AverageTrueRange.html)
+/// and smooths them with the highest and lowest values over a second time frame.
+///
+/// # Formula
+///
+/// * First high stop = Max(High, _p_) - ATR(_p_) * _multiplier_
+/// * First low stop = Min(Low, _p_) + ATR(_p_) * _multiplier_
+/// * Stop short = Max(First high stop, _q_)
+/// * Stop long = Min(First low stop, _q_)
+///
+/// # Parameters
+///
+/// * _p_ - period of ATR and of the preliminary stops (integer greater than 0). Default is 10.
+/// * _q_ - period of the final stops (integer greater than 0). Default is 20.
+/// * _multiplier_ - ATR factor. Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChandeKrollStop;
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(1.5).high(2.0).low(1.0).close(1.5).volume(1.0).build().unwrap();
+///
+/// let mut cks = ChandeKrollStop::new(3, 2, 1.0).unwrap();
+/// let out = cks.next(&bar);
+///
+/// assert_eq!(out.stop_long, 2.0);
+/// assert_eq!(out.stop_short, 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Chande Kroll Stop, TradingView](https://www.tradingview.com/support/solutions/43000589105-chande-kroll-stop/)
+///
+#[doc(alias = "CKS")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChandeKrollStop {
+    multiplier: f64,
+    atr: AverageTrueRange,
+    max_high: Maximum,
+    min_low: Minimum,
+    max_stop: Maximum,
+    min_stop: Minimum,
+}
+
+impl ChandeKrollStop {
+    pub fn new(p: usize, q: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            multiplier,
+            atr: AverageTrueRange::new(p)?,
+            max_high: Maximum::new(p)?,
+            min_low: Minimum::new(p)?,
+            max_stop: Maximum::new(q)?,
+            min_stop: Minimum::new(q)?,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChandeKrollStopOutput {
+    pub stop_long: f64,
+    pub stop_short: f64,
+}
+
+impl From<ChandeKrollStopOutput> for (f64, f64) {
+    fn from(cks: ChandeKrollStopOutput) -> Self {
+        (cks.stop_long, cks.stop_short)
+    }
+}
+
+impl Period for ChandeKrollStop {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for ChandeKrollStop {
+    type Output = ChandeKrollStopOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input) * self.multiplier;
+        let first_high_stop = self.max_high.next(input) - atr;
+        let first_low_stop = self.min_low.next(input) + atr;
+
+        ChandeKrollStopOutput {
+            stop_long: self.min_stop.next(first_low_stop),
+            stop_short: self.max_stop.next(first_high_stop),
+        }
+    }
+}
+
+impl Reset for ChandeKrollStop {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.max_high.reset();
+        self.min_low.reset();
+        self.max_stop.reset();
+        self.min_stop.reset();
+    }
+}
+
+impl Default for ChandeKrollStop {
+    fn default() -> Self {
+        Self::new(10, 20, 1.0).unwrap()
+    }
+}
+
+impl fmt::Display for ChandeKrollStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CKS({}, {}, {})",
+            self.atr.period(),
+            self.max_stop.period(),
+            self.multiplier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ChandeKrollStop::new(0, 20, 1.0).is_err());
+        assert!(ChandeKrollStop::new(10, 0, 1.0).is_err());
+        assert!(ChandeKrollStop::new(1, 1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cks = ChandeKrollStop::new(3, 2, 1.0).unwrap();
+
+        assert_eq!(round(cks.next(&bar(2.0, 1.0, 1.5)).into()), (2.0, 1.0));
+        assert_eq!(round(cks.next(&bar(5.0, 3.0, 4.0)).into()), (2.0, 2.75));
+        assert_eq!(round(cks.next(&bar(9.0, 7.0, 8.0)).into()), (3.25, 5.375));
+        assert_eq!(round(cks.next(&bar(5.0, 3.0, 4.0)).into()), (4.625, 5.375));
+        assert_eq!(round(cks.next(&bar(5.0, 3.0, 4.0)).into()), (6.156, 5.844));
+        assert_eq!(round(cks.next(&bar(2.0, 1.0, 1.5)).into()), (4.078, 5.844));
+        assert_eq!(round(cks.next(&bar(4.0, 2.0, 3.0)).into()), (3.789, 2.211));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cks = ChandeKrollStop::new(3, 2, 1.0).unwrap();
+
+        assert_eq!(round(cks.next(&bar(2.0, 1.0, 1.5)).into()), (2.0, 1.0));
+        assert_eq!(round(cks.next(&bar(5.0, 3.0, 4.0)).into()), (2.0, 2.75));
+
+        cks.reset();
+
+        assert_eq!(round(cks.next(&bar(2.0, 1.0, 1.5)).into()), (2.0, 1.0));
+        assert_eq!(round(cks.next(&bar(5.0, 3.0, 4.0)).into()), (2.0, 2.75));
+    }
+
+    #[test]
+    fn test_default() {
+        ChandeKrollStop::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ChandeKrollStop::new(10, 9, 1.5).unwrap();
+        assert_eq!(format!("{}", indicator), "CKS(10, 9, 1.5)");
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
 use crate::indicators::{AverageTrueRange, Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
 
 /// Chandelier Exit (CE).
 ///
@@ -93,6 +93,15 @@ impl Period for ChandelierExit {
     }
 }
 
+impl Lookback for ChandelierExit {
+    fn lookback(&self) -> usize {
+        self.atr
+            .lookback()
+            .max(self.min.lookback())
+            .max(self.max.lookback())
+    }
+}
+
 impl<T: Low + High + Close> Next<&T> for ChandelierExit {
     type Output = ChandelierExitOutput;
 
@@ -172,6 +181,11 @@ mod tests {
         assert_eq!(round(ce.next(&bar6).into()), (2.92, 7.08));
     }
 
+    #[test]
+    fn test_lookback() {
+        assert_eq!(ChandelierExit::new(5, 2.0).unwrap().lookback(), 5);
+    }
+
     #[test]
     fn test_reset() {
         let mut ce = Ce::new(5, 2.0).unwrap();
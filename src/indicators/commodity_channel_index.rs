@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
 use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::signal::Thresholds;
+use crate::{Action, Close, Current, High, Lookback, Low, Next, Period, Reset, Signal};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -19,6 +20,9 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 /// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) * 0.015)
 ///
+/// The CCI implements [Signal](../trait.Signal.html), with the oversold and overbought
+/// thresholds of -100 and 100 by default.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default is 20.
@@ -33,6 +37,8 @@ use crate::{Close, High, Low, Next, Period, Reset};
 pub struct CommodityChannelIndex {
     sma: SimpleMovingAverage,
     mad: MeanAbsoluteDeviation,
+    current: f64,
+    thresholds: Thresholds,
 }
 
 impl CommodityChannelIndex {
@@ -40,8 +46,16 @@ impl CommodityChannelIndex {
         Ok(Self {
             sma: SimpleMovingAverage::new(period)?,
             mad: MeanAbsoluteDeviation::new(period)?,
+            current: f64::NAN,
+            thresholds: Thresholds::new(-100.0, 100.0)?,
         })
     }
+
+    /// Sets the oversold and overbought thresholds of the [signal](../trait.Signal.html).
+    pub fn with_thresholds(mut self, oversold: f64, overbought: f64) -> Result<Self> {
+        self.thresholds = Thresholds::new(oversold, overbought)?;
+        Ok(self)
+    }
 }
 
 impl Period for CommodityChannelIndex {
@@ -50,6 +64,12 @@ impl Period for CommodityChannelIndex {
     }
 }
 
+impl Lookback for CommodityChannelIndex {
+    fn lookback(&self) -> usize {
+        self.sma.lookback().max(self.mad.lookback())
+    }
+}
+
 impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
     type Output = f64;
 
@@ -58,11 +78,18 @@ impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
         let sma = self.sma.next(tp);
         let mad = self.mad.next(input);
 
-        if mad == 0.0 {
-            return 0.0;
-        }
+        self.current = if mad == 0.0 {
+            0.0
+        } else {
+            (tp - sma) / (mad * 0.015)
+        };
+        self.current
+    }
+}
 
-        (tp - sma) / (mad * 0.015)
+impl Signal for CommodityChannelIndex {
+    fn signal(&self) -> Action {
+        self.thresholds.action(self.current)
     }
 }
 
@@ -70,6 +97,15 @@ impl Reset for CommodityChannelIndex {
     fn reset(&mut self) {
         self.sma.reset();
         self.mad.reset();
+        self.current = f64::NAN;
+    }
+}
+
+impl Current for CommodityChannelIndex {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(self.current).filter(|current| !current.is_nan())
     }
 }
 
@@ -119,6 +155,30 @@ mod tests {
         assert_eq!(round(cci.next(&bar6)), -126.126);
     }
 
+    #[test]
+    fn test_signal() {
+        let mut cci = CommodityChannelIndex::new(5).unwrap();
+        assert_eq!(cci.signal(), Action::Neutral);
+
+        cci.next(&Bar::new().high(2).low(1).close(1.5));
+        cci.next(&Bar::new().high(5).low(3).close(4));
+        assert_eq!(cci.signal(), Action::Neutral);
+
+        cci.next(&Bar::new().high(9).low(7).close(8));
+        cci.next(&Bar::new().high(5).low(3).close(4));
+        cci.next(&Bar::new().high(5).low(3).close(4));
+        cci.next(&Bar::new().high(2).low(1).close(1.5));
+        assert_eq!(cci.signal(), Action::Buy);
+
+        let mut cci = CommodityChannelIndex::new(5)
+            .unwrap()
+            .with_thresholds(-200.0, 50.0)
+            .unwrap();
+        cci.next(&Bar::new().high(2).low(1).close(1.5));
+        cci.next(&Bar::new().high(5).low(3).close(4));
+        assert_eq!(cci.signal(), Action::Sell);
+    }
+
     #[test]
     fn test_reset() {
         let mut cci = CommodityChannelIndex::new(5).unwrap();
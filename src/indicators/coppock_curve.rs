@@ -0,0 +1,177 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, WeightedMovingAverage as Wma};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Coppock Curve.
+///
+/// Developed by Edwin Coppock, the Coppock Curve is a long-term momentum indicator.
+/// It is a weighted moving average of the sum of two rates of change.
+///
+/// # Formula
+///
+/// Coppock Curve = WMA(_wma_period_) of (ROC(_long_roc_period_) + ROC(_short_roc_period_))
+///
+/// Where:
+///
+/// * _WMA_ - [weighted moving average](struct.WeightedMovingAverage.html)
+/// * _ROC_ - [rate of change](struct.RateOfChange.html)
+///
+/// # Parameters
+///
+/// * _long_roc_period_ - period of the longer ROC (integer greater than 0). Default is 14.
+/// * _short_roc_period_ - period of the shorter ROC (integer greater than 0). Default is 11.
+/// * _wma_period_ - period of the WMA (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CoppockCurve;
+/// use ta::Next;
+///
+/// let mut cc = CoppockCurve::new(3, 2, 2).unwrap();
+/// assert_eq!(cc.next(10.0), 0.0);
+/// assert_eq!(cc.next(11.0).round(), 13.0);
+/// assert_eq!(cc.next(12.0).round(), 33.0);
+/// ```
+///
+/// # Links
+///
+/// * [Coppock curve, Wikipedia](https://en.wikipedia.org/wiki/Coppock_curve)
+/// * [Coppock Curve, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:coppock_curve)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CoppockCurve {
+    long_roc: RateOfChange,
+    short_roc: RateOfChange,
+    wma: Wma,
+}
+
+impl CoppockCurve {
+    pub fn new(long_roc_period: usize, short_roc_period: usize, wma_period: usize) -> Result<Self> {
+        Ok(Self {
+            long_roc: RateOfChange::new(long_roc_period)?,
+            short_roc: RateOfChange::new(short_roc_period)?,
+            wma: Wma::new(wma_period)?,
+        })
+    }
+}
+
+impl Period for CoppockCurve {
+    fn period(&self) -> usize {
+        self.wma.period()
+    }
+}
+
+impl Next<f64> for CoppockCurve {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let roc_sum = self.long_roc.next(input) + self.short_roc.next(input);
+        self.wma.next(roc_sum)
+    }
+}
+
+impl<T: Close> Next<&T> for CoppockCurve {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for CoppockCurve {
+    fn reset(&mut self) {
+        self.long_roc.reset();
+        self.short_roc.reset();
+        self.wma.reset();
+    }
+}
+
+impl Default for CoppockCurve {
+    fn default() -> Self {
+        Self::new(14, 11, 10).unwrap()
+    }
+}
+
+impl fmt::Display for CoppockCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "COPPOCK({}, {}, {})",
+            self.long_roc.period(),
+            self.short_roc.period(),
+            self.wma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(CoppockCurve);
+
+    #[test]
+    fn test_new() {
+        assert!(CoppockCurve::new(0, 1, 1).is_err());
+        assert!(CoppockCurve::new(1, 0, 1).is_err());
+        assert!(CoppockCurve::new(1, 1, 0).is_err());
+        assert!(CoppockCurve::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cc = CoppockCurve::new(3, 2, 2).unwrap();
+
+        assert_eq!(round(cc.next(10.0)), 0.0);
+        assert_eq!(round(cc.next(11.0)), 13.333);
+        assert_eq!(round(cc.next(12.0)), 33.333);
+        assert_eq!(round(cc.next(11.0)), 20.0);
+        assert_eq!(round(cc.next(13.0)), 21.01);
+        assert_eq!(round(cc.next(14.0)), 38.131);
+        assert_eq!(round(cc.next(12.0)), 15.579);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut cc = CoppockCurve::new(3, 2, 2).unwrap();
+
+        assert_eq!(round(cc.next(&bar(10.0))), 0.0);
+        assert_eq!(round(cc.next(&bar(11.0))), 13.333);
+        assert_eq!(round(cc.next(&bar(12.0))), 33.333);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cc = CoppockCurve::new(3, 2, 2).unwrap();
+
+        assert_eq!(round(cc.next(10.0)), 0.0);
+        assert_eq!(round(cc.next(11.0)), 13.333);
+
+        cc.reset();
+
+        assert_eq!(round(cc.next(10.0)), 0.0);
+        assert_eq!(round(cc.next(11.0)), 13.333);
+    }
+
+    #[test]
+    fn test_default() {
+        CoppockCurve::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = CoppockCurve::new(14, 11, 10).unwrap();
+        assert_eq!(format!("{}", indicator), "COPPOCK(14, 11, 10)");
+    }
+}
@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::{Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Event reported by [Cross](struct.Cross.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossEvent {
+    /// The first series crossed above the second one.
+    Over,
+    /// The first series crossed below the second one.
+    Under,
+    /// No cross.
+    None,
+}
+
+/// Crossover and crossunder detector.
+///
+/// Takes `(a, b)` pairs of values of two series, e.g. a fast and a slow moving average,
+/// and reports when _a_ crosses above or below _b_.
+///
+/// Only strict relationships count: while the series are equal, the previous relationship is
+/// kept, so touching the other series and turning back is not a cross, while moving through it
+/// over several periods is reported once the series separate.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Cross, CrossEvent};
+/// use ta::Next;
+///
+/// let mut cross = Cross::new();
+/// assert_eq!(cross.next((9.0, 10.0)), CrossEvent::None);
+/// assert_eq!(cross.next((11.0, 10.0)), CrossEvent::Over);
+/// assert_eq!(cross.next((12.0, 10.0)), CrossEvent::None);
+/// assert_eq!(cross.next((9.0, 10.0)), CrossEvent::Under);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Cross {
+    // Last strict relationship of a to b
+    above: Option<bool>,
+}
+
+impl Cross {
+    pub fn new() -> Self {
+        Self { above: None }
+    }
+}
+
+impl Next<(f64, f64)> for Cross {
+    type Output = CrossEvent;
+
+    fn next(&mut self, (a, b): (f64, f64)) -> Self::Output {
+        let above = match a.partial_cmp(&b) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Less) => false,
+            _ => return CrossEvent::None,
+        };
+
+        match self.above.replace(above) {
+            Some(false) if above => CrossEvent::Over,
+            Some(true) if !above => CrossEvent::Under,
+            _ => CrossEvent::None,
+        }
+    }
+}
+
+impl Reset for Cross {
+    fn reset(&mut self) {
+        self.above = None;
+    }
+}
+
+impl Default for Cross {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Cross {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CROSS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let mut cross = Cross::new();
+
+        assert_eq!(cross.next((10.0, 11.0)), CrossEvent::None);
+        assert_eq!(cross.next((10.5, 11.0)), CrossEvent::None);
+        assert_eq!(cross.next((11.5, 11.0)), CrossEvent::Over);
+        assert_eq!(cross.next((12.0, 11.5)), CrossEvent::None);
+        assert_eq!(cross.next((11.0, 11.5)), CrossEvent::Under);
+        assert_eq!(cross.next((11.0, 11.5)), CrossEvent::None);
+    }
+
+    #[test]
+    fn test_next_equal() {
+        let mut cross = Cross::new();
+
+        assert_eq!(cross.next((10.0, 10.0)), CrossEvent::None);
+        assert_eq!(cross.next((9.0, 10.0)), CrossEvent::None);
+
+        // Touch and turn back
+        assert_eq!(cross.next((10.0, 10.0)), CrossEvent::None);
+        assert_eq!(cross.next((9.0, 10.0)), CrossEvent::None);
+
+        // Move through over several periods
+        assert_eq!(cross.next((10.0, 10.0)), CrossEvent::None);
+        assert_eq!(cross.next((10.0, 10.0)), CrossEvent::None);
+        assert_eq!(cross.next((11.0, 10.0)), CrossEvent::Over);
+    }
+
+    #[test]
+    fn test_next_nan() {
+        let mut cross = Cross::new();
+
+        assert_eq!(cross.next((9.0, 10.0)), CrossEvent::None);
+        assert_eq!(cross.next((f64::NAN, 10.0)), CrossEvent::None);
+        assert_eq!(cross.next((11.0, 10.0)), CrossEvent::Over);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cross = Cross::new();
+
+        cross.next((9.0, 10.0));
+
+        cross.reset();
+
+        assert_eq!(cross.next((11.0, 10.0)), CrossEvent::None);
+    }
+
+    #[test]
+    fn test_default() {
+        Cross::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cross = Cross::new();
+        assert_eq!(format!("{}", cross), "CROSS");
+    }
+}
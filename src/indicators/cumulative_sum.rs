@@ -0,0 +1,172 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Cumulative sum (CUMSUM).
+///
+/// Running total of the input values, optionally with a decay which makes older values
+/// count less. It is a building block of accumulation indicators like
+/// [On Balance Volume](struct.OnBalanceVolume.html) and of custom cumulative studies.
+///
+/// # Formula
+///
+/// S<sub>t</sub> = _decay_ * S<sub>t-1</sub> + p<sub>t</sub>
+///
+/// Where:
+///
+/// * _S<sub>t</sub>_ - cumulative sum at a point of time _t_
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// # Parameters
+///
+/// * _decay_ - factor applied to the previous sum (number in range (0, 1]). Default is 1.0, i.e. no decay.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CumulativeSum;
+/// use ta::Next;
+///
+/// let mut cumsum = CumulativeSum::new(1.0).unwrap();
+/// assert_eq!(cumsum.next(1.0), 1.0);
+/// assert_eq!(cumsum.next(2.0), 3.0);
+///
+/// let mut decayed = CumulativeSum::new(0.5).unwrap();
+/// assert_eq!(decayed.next(1.0), 1.0);
+/// assert_eq!(decayed.next(2.0), 2.5);
+/// ```
+///
+/// # Links
+///
+/// * [Running total, Wikipedia](https://en.wikipedia.org/wiki/Running_total)
+///
+#[doc(alias = "CUMSUM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CumulativeSum {
+    decay: f64,
+    sum: f64,
+}
+
+impl CumulativeSum {
+    pub fn new(decay: f64) -> Result<Self> {
+        if decay <= 0.0 || decay > 1.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self { decay, sum: 0.0 })
+    }
+
+    pub fn decay(&self) -> f64 {
+        self.decay
+    }
+}
+
+impl Next<f64> for CumulativeSum {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.sum = self.decay * self.sum + input;
+        self.sum
+    }
+}
+
+impl<T: Close> Next<&T> for CumulativeSum {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for CumulativeSum {
+    fn reset(&mut self) {
+        self.sum = 0.0;
+    }
+}
+
+impl Default for CumulativeSum {
+    fn default() -> Self {
+        Self::new(1.0).unwrap()
+    }
+}
+
+impl fmt::Display for CumulativeSum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CUMSUM({})", self.decay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(CumulativeSum);
+
+    #[test]
+    fn test_new() {
+        assert!(CumulativeSum::new(0.0).is_err());
+        assert!(CumulativeSum::new(1.1).is_err());
+        assert!(CumulativeSum::new(0.9).is_ok());
+        assert!(CumulativeSum::new(1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cumsum = CumulativeSum::new(1.0).unwrap();
+
+        assert_eq!(cumsum.next(1.0), 1.0);
+        assert_eq!(cumsum.next(2.0), 3.0);
+        assert_eq!(cumsum.next(-4.0), -1.0);
+        assert_eq!(cumsum.next(0.5), -0.5);
+    }
+
+    #[test]
+    fn test_next_with_decay() {
+        let mut cumsum = CumulativeSum::new(0.9).unwrap();
+
+        assert_eq!(round(cumsum.next(10.0)), 10.0);
+        assert_eq!(round(cumsum.next(10.0)), 19.0);
+        assert_eq!(round(cumsum.next(10.0)), 27.1);
+        assert_eq!(round(cumsum.next(0.0)), 24.39);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut cumsum = CumulativeSum::new(1.0).unwrap();
+
+        assert_eq!(cumsum.next(&bar(1.0)), 1.0);
+        assert_eq!(cumsum.next(&bar(2.0)), 3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cumsum = CumulativeSum::new(1.0).unwrap();
+
+        cumsum.next(1.0);
+        cumsum.next(2.0);
+
+        cumsum.reset();
+
+        assert_eq!(cumsum.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_default() {
+        CumulativeSum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = CumulativeSum::new(0.9).unwrap();
+        assert_eq!(format!("{}", indicator), "CUMSUM(0.9)");
+    }
+}
@@ -0,0 +1,520 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Pivot, PivotDetector};
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of a [divergence](struct.Divergence.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivergenceKind {
+    /// Price makes a lower low, the oscillator a higher low.
+    RegularBullish,
+    /// Price makes a higher low, the oscillator a lower low.
+    HiddenBullish,
+    /// Price makes a higher high, the oscillator a lower high.
+    RegularBearish,
+    /// Price makes a lower high, the oscillator a higher high.
+    HiddenBearish,
+}
+
+/// Swing point of a [divergence](struct.Divergence.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DivergencePoint {
+    /// Index of the bar of the price swing, counted from 0 since the detector was created or
+    /// reset.
+    pub index: usize,
+    pub price: f64,
+    /// Index of the bar of the oscillator swing matched with the price swing.
+    pub oscillator_index: usize,
+    pub oscillator: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DivergenceEvent {
+    pub kind: DivergenceKind,
+    /// The earlier swing point.
+    pub start: DivergencePoint,
+    /// The later swing point.
+    pub end: DivergencePoint,
+}
+
+/// Divergence detector.
+///
+/// Compares swings of the price with swings of an oscillator, e.g.
+/// [RSI](struct.RelativeStrengthIndex.html) or
+/// [MACD](struct.MovingAverageConvergenceDivergence.html). Swing highs and lows of both are
+/// found by [PivotDetector](struct.PivotDetector.html)s. Every price swing is matched with the
+/// nearest oscillator swing of the same kind at most _tolerance_ bars away, the earlier one on
+/// ties, and compared with the previous matched price swing of the same kind. Price swings
+/// without an oscillator swing nearby are ignored.
+///
+/// The inputs are `(price, oscillator)` pairs, or `(bar, oscillator)` pairs, in which case the
+/// highs of the bars are used for swing highs and the lows for swing lows. Divergences are
+/// reported _right_ + _tolerance_ bars after the later price swing occurred, once the
+/// oscillator swings which can match it are confirmed.
+///
+/// # Parameters
+///
+/// * _left_ - number of bars before a swing point (integer greater than 0). Default is 5.
+/// * _right_ - number of bars after a swing point (integer greater than 0). Default is 5.
+/// * _max_distance_ - maximum number of bars between the two swing points (integer greater than 0). Default is 60.
+/// * _tolerance_ - maximum number of bars between a price swing and its oscillator swing (integer up to _max_distance_). Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Divergence, DivergenceKind};
+/// use ta::Next;
+///
+/// let mut divergence = Divergence::new(1, 1, 60, 1).unwrap();
+///
+/// // The first oscillator low is a bar after the first price low
+/// let prices = [10.0, 8.0, 9.0, 9.5, 7.0, 9.0, 10.0];
+/// let oscillator = [50.0, 40.0, 30.0, 40.0, 35.0, 45.0, 50.0];
+///
+/// let mut events = vec![];
+/// for (&price, &osc) in prices.iter().zip(oscillator.iter()) {
+///     events.extend(divergence.next((price, osc)));
+/// }
+///
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].kind, DivergenceKind::RegularBullish);
+/// assert_eq!(events[0].start.index, 1);
+/// assert_eq!(events[0].start.oscillator_index, 2);
+/// assert_eq!(events[0].end.index, 4);
+/// ```
+///
+/// # Links
+///
+/// * [Divergence, Investopedia](https://www.investopedia.com/terms/d/divergence.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Divergence {
+    max_distance: usize,
+    tolerance: usize,
+    pivots: PivotDetector,
+    oscillator_pivots: PivotDetector,
+    // Number of bars given since the detector was created or reset
+    count: usize,
+    // Confirmed price swings waiting for the oscillator swings which can match them
+    pending_highs: VecDeque<Pivot>,
+    pending_lows: VecDeque<Pivot>,
+    // Confirmed oscillator swings which can still match price swings
+    oscillator_highs: VecDeque<Pivot>,
+    oscillator_lows: VecDeque<Pivot>,
+    last_high: Option<DivergencePoint>,
+    last_low: Option<DivergencePoint>,
+}
+
+impl Divergence {
+    pub fn new(left: usize, right: usize, max_distance: usize, tolerance: usize) -> Result<Self> {
+        if max_distance == 0 || tolerance > max_distance {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            max_distance,
+            tolerance,
+            pivots: PivotDetector::new(left, right)?,
+            oscillator_pivots: PivotDetector::new(left, right)?,
+            count: 0,
+            pending_highs: VecDeque::new(),
+            pending_lows: VecDeque::new(),
+            oscillator_highs: VecDeque::new(),
+            oscillator_lows: VecDeque::new(),
+            last_high: None,
+            last_low: None,
+        })
+    }
+
+    pub fn tolerance(&self) -> usize {
+        self.tolerance
+    }
+
+    // Returns the point of the nearest oscillator swing within the tolerance of the price swing
+    fn matched(
+        &self,
+        pivot: Pivot,
+        oscillator_pivots: &VecDeque<Pivot>,
+    ) -> Option<DivergencePoint> {
+        oscillator_pivots
+            .iter()
+            .filter(|other| other.index.abs_diff(pivot.index) <= self.tolerance)
+            .min_by_key(|other| other.index.abs_diff(pivot.index))
+            .map(|other| DivergencePoint {
+                index: pivot.index,
+                price: pivot.price,
+                oscillator_index: other.index,
+                oscillator: other.price,
+            })
+    }
+
+    // Compares the pending price swings whose oscillator swings are all confirmed
+    fn process(&mut self, is_high: bool, events: &mut Vec<DivergenceEvent>) {
+        // Oscillator swings up to this index are confirmed
+        let confirmed = (self.count - 1).saturating_sub(self.pivots.right());
+
+        loop {
+            let pending = if is_high {
+                &mut self.pending_highs
+            } else {
+                &mut self.pending_lows
+            };
+            let pivot = match pending.front() {
+                Some(pivot) if pivot.index.saturating_add(self.tolerance) <= confirmed => *pivot,
+                _ => break,
+            };
+            pending.pop_front();
+
+            let (oscillator_pivots, last) = if is_high {
+                (&self.oscillator_highs, self.last_high)
+            } else {
+                (&self.oscillator_lows, self.last_low)
+            };
+            if let Some(point) = self.matched(pivot, oscillator_pivots) {
+                events.extend(self.compare(last, point, is_high));
+                if is_high {
+                    self.last_high = Some(point);
+                } else {
+                    self.last_low = Some(point);
+                }
+            }
+        }
+
+        // Later price swings are at least at the index of the next pending one
+        let oldest = if is_high {
+            self.pending_highs.front()
+        } else {
+            self.pending_lows.front()
+        }
+        .map_or(confirmed + 1, |pivot| pivot.index);
+        let oscillator_pivots = if is_high {
+            &mut self.oscillator_highs
+        } else {
+            &mut self.oscillator_lows
+        };
+        while oscillator_pivots
+            .front()
+            .is_some_and(|pivot| pivot.index.saturating_add(self.tolerance) < oldest)
+        {
+            oscillator_pivots.pop_front();
+        }
+    }
+
+    fn compare(
+        &self,
+        start: Option<DivergencePoint>,
+        end: DivergencePoint,
+        is_high: bool,
+    ) -> Option<DivergenceEvent> {
+        let start = start.filter(|start| end.index - start.index <= self.max_distance)?;
+
+        let price_higher = end.price > start.price;
+        let price_lower = end.price < start.price;
+        let oscillator_higher = end.oscillator > start.oscillator;
+        let oscillator_lower = end.oscillator < start.oscillator;
+
+        let kind = match (is_high, price_higher, price_lower) {
+            (false, _, true) if oscillator_higher => DivergenceKind::RegularBullish,
+            (false, true, _) if oscillator_lower => DivergenceKind::HiddenBullish,
+            (true, true, _) if oscillator_lower => DivergenceKind::RegularBearish,
+            (true, _, true) if oscillator_higher => DivergenceKind::HiddenBearish,
+            _ => return None,
+        };
+
+        Some(DivergenceEvent { kind, start, end })
+    }
+}
+
+impl Next<((f64, f64), f64)> for Divergence {
+    type Output = Vec<DivergenceEvent>;
+
+    fn next(&mut self, ((high, low), oscillator): ((f64, f64), f64)) -> Self::Output {
+        self.count += 1;
+
+        let pivots = self.pivots.next((high, low));
+        self.pending_highs.extend(pivots.high);
+        self.pending_lows.extend(pivots.low);
+
+        let oscillator_pivots = self.oscillator_pivots.next(oscillator);
+        self.oscillator_highs.extend(oscillator_pivots.high);
+        self.oscillator_lows.extend(oscillator_pivots.low);
+
+        let mut events = Vec::new();
+        self.process(true, &mut events);
+        self.process(false, &mut events);
+        events
+    }
+}
+
+impl Next<(f64, f64)> for Divergence {
+    type Output = Vec<DivergenceEvent>;
+
+    fn next(&mut self, (price, oscillator): (f64, f64)) -> Self::Output {
+        self.next(((price, price), oscillator))
+    }
+}
+
+impl<T: High + Low> Next<(&T, f64)> for Divergence {
+    type Output = Vec<DivergenceEvent>;
+
+    fn next(&mut self, (input, oscillator): (&T, f64)) -> Self::Output {
+        self.next(((input.high(), input.low()), oscillator))
+    }
+}
+
+impl Reset for Divergence {
+    fn reset(&mut self) {
+        self.pivots.reset();
+        self.oscillator_pivots.reset();
+        self.count = 0;
+        self.pending_highs.clear();
+        self.pending_lows.clear();
+        self.oscillator_highs.clear();
+        self.oscillator_lows.clear();
+        self.last_high = None;
+        self.last_low = None;
+    }
+}
+
+impl Default for Divergence {
+    fn default() -> Self {
+        Self::new(5, 5, 60, 2).unwrap()
+    }
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DIVERGENCE({}, {}, {}, {})",
+            self.pivots.left(),
+            self.pivots.right(),
+            self.max_distance,
+            self.tolerance
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn run(
+        divergence: &mut Divergence,
+        prices: &[f64],
+        oscillator: &[f64],
+    ) -> Vec<DivergenceEvent> {
+        prices
+            .iter()
+            .zip(oscillator.iter())
+            .flat_map(|(&price, &osc)| divergence.next((price, osc)))
+            .collect()
+    }
+
+    fn point(index: usize, price: f64, oscillator: f64) -> DivergencePoint {
+        DivergencePoint {
+            index,
+            price,
+            oscillator_index: index,
+            oscillator,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Divergence::new(0, 1, 60, 0).is_err());
+        assert!(Divergence::new(1, 0, 60, 0).is_err());
+        assert!(Divergence::new(1, 1, 0, 0).is_err());
+        assert!(Divergence::new(1, 1, 1, 2).is_err());
+        assert!(Divergence::new(1, 1, 1, 0).is_ok());
+        assert!(Divergence::new(1, 1, usize::MAX, usize::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_next_bullish() {
+        let mut divergence = Divergence::new(1, 1, 60, 0).unwrap();
+
+        let events = run(
+            &mut divergence,
+            &[10.0, 8.0, 9.0, 7.0, 9.0, 8.0, 10.0],
+            &[50.0, 30.0, 40.0, 35.0, 45.0, 32.0, 50.0],
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                DivergenceEvent {
+                    kind: DivergenceKind::RegularBullish,
+                    start: point(1, 8.0, 30.0),
+                    end: point(3, 7.0, 35.0),
+                },
+                DivergenceEvent {
+                    kind: DivergenceKind::HiddenBullish,
+                    start: point(3, 7.0, 35.0),
+                    end: point(5, 8.0, 32.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_bearish() {
+        let mut divergence = Divergence::new(1, 1, 60, 0).unwrap();
+
+        let events = run(
+            &mut divergence,
+            &[10.0, 12.0, 11.0, 11.5, 10.0, 13.0, 12.0],
+            &[50.0, 70.0, 60.0, 75.0, 55.0, 72.0, 60.0],
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                DivergenceEvent {
+                    kind: DivergenceKind::HiddenBearish,
+                    start: point(1, 12.0, 70.0),
+                    end: point(3, 11.5, 75.0),
+                },
+                DivergenceEvent {
+                    kind: DivergenceKind::RegularBearish,
+                    start: point(3, 11.5, 75.0),
+                    end: point(5, 13.0, 72.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_no_divergence() {
+        let mut divergence = Divergence::new(1, 1, 60, 0).unwrap();
+
+        // Oscillator confirms the price
+        let events = run(
+            &mut divergence,
+            &[10.0, 8.0, 9.0, 7.0, 9.0],
+            &[50.0, 30.0, 40.0, 25.0, 45.0],
+        );
+
+        assert_eq!(events, vec![]);
+    }
+
+    #[test]
+    fn test_next_tolerance() {
+        let prices = [10.0, 8.0, 9.0, 9.5, 7.0, 9.0, 10.0, 11.0];
+        // The first oscillator low is a bar after the first price low, the oscillator at the
+        // bar of the price low being above the second oscillator low
+        let oscillator = [50.0, 40.0, 30.0, 40.0, 35.0, 45.0, 50.0, 55.0];
+
+        let mut divergence = Divergence::new(1, 1, 60, 1).unwrap();
+        let events = run(&mut divergence, &prices, &oscillator);
+        assert_eq!(
+            events,
+            vec![DivergenceEvent {
+                kind: DivergenceKind::RegularBullish,
+                start: DivergencePoint {
+                    index: 1,
+                    price: 8.0,
+                    oscillator_index: 2,
+                    oscillator: 30.0,
+                },
+                end: point(4, 7.0, 35.0),
+            }]
+        );
+
+        // Without tolerance, the first price low has no oscillator low
+        let mut divergence = Divergence::new(1, 1, 60, 0).unwrap();
+        assert_eq!(run(&mut divergence, &prices, &oscillator), vec![]);
+
+        // The price swings wait for their oscillator swings without overflowing
+        let mut divergence = Divergence::new(1, 1, usize::MAX, usize::MAX).unwrap();
+        assert_eq!(run(&mut divergence, &prices, &oscillator), vec![]);
+    }
+
+    #[test]
+    fn test_next_without_oscillator_swing() {
+        let mut divergence = Divergence::new(1, 1, 60, 1).unwrap();
+
+        // The oscillator keeps falling, so the price lows have no oscillator low to compare
+        let events = run(
+            &mut divergence,
+            &[10.0, 8.0, 9.0, 7.0, 9.0, 10.0],
+            &[50.0, 45.0, 40.0, 35.0, 30.0, 25.0],
+        );
+
+        assert_eq!(events, vec![]);
+    }
+
+    #[test]
+    fn test_next_max_distance() {
+        let mut divergence = Divergence::new(1, 1, 1, 0).unwrap();
+
+        let events = run(
+            &mut divergence,
+            &[10.0, 8.0, 9.0, 7.0, 9.0],
+            &[50.0, 30.0, 40.0, 35.0, 45.0],
+        );
+
+        assert_eq!(events, vec![]);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut divergence = Divergence::new(1, 1, 60, 0).unwrap();
+
+        let bars = [
+            bar(11.0, 10.0),
+            bar(9.0, 8.0),
+            bar(10.0, 9.0),
+            bar(8.0, 7.0),
+            bar(10.0, 9.0),
+        ];
+        let oscillator = [50.0, 30.0, 40.0, 35.0, 45.0];
+
+        let events: Vec<_> = bars
+            .iter()
+            .zip(oscillator.iter())
+            .flat_map(|(bar, &osc)| divergence.next((bar, osc)))
+            .collect();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, DivergenceKind::RegularBullish);
+        assert_eq!(events[0].start, point(1, 8.0, 30.0));
+        assert_eq!(events[0].end, point(3, 7.0, 35.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut divergence = Divergence::new(1, 1, 60, 0).unwrap();
+
+        run(&mut divergence, &[10.0, 8.0, 9.0], &[50.0, 30.0, 40.0]);
+
+        divergence.reset();
+
+        let events = run(&mut divergence, &[10.0, 7.0, 9.0], &[50.0, 35.0, 45.0]);
+        assert_eq!(events, vec![]);
+    }
+
+    #[test]
+    fn test_default() {
+        Divergence::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let divergence = Divergence::default();
+        assert_eq!(format!("{}", divergence), "DIVERGENCE(5, 5, 60, 2)");
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Drawdown (DD).
+///
+/// Tracks the running peak of the input and returns how far below the peak the current value
+/// is, together with the largest such decline observed so far. It is used to analyse equity
+/// curves, and on prices as a gauge of stress in the market.
+///
+/// # Formula
+///
+/// DD = (peak - p) / peak * 100
+///
+/// MDD = max(DD)
+///
+/// Where:
+///
+/// * _peak_ - highest input value so far
+/// * _p_ - current input value
+///
+/// Both are percentages, 0 at a new peak. While the peak is not positive, DD is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Drawdown;
+/// use ta::Next;
+///
+/// let mut dd = Drawdown::new();
+/// dd.next(100.0);
+/// dd.next(80.0);
+/// let out = dd.next(90.0);
+///
+/// assert_eq!(out.drawdown, 10.0);
+/// assert_eq!(out.max_drawdown, 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Drawdown, Wikipedia](https://en.wikipedia.org/wiki/Drawdown_(economics))
+///
+#[doc(alias = "DD")]
+#[doc(alias = "MDD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Drawdown {
+    peak: f64,
+    max_drawdown: f64,
+}
+
+impl Drawdown {
+    pub fn new() -> Self {
+        Self {
+            peak: f64::NEG_INFINITY,
+            max_drawdown: 0.0,
+        }
+    }
+
+    /// Returns the highest input value so far.
+    pub fn peak(&self) -> f64 {
+        self.peak
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrawdownOutput {
+    pub drawdown: f64,
+    pub max_drawdown: f64,
+}
+
+impl From<DrawdownOutput> for (f64, f64) {
+    fn from(dd: DrawdownOutput) -> Self {
+        (dd.drawdown, dd.max_drawdown)
+    }
+}
+
+impl Next<f64> for Drawdown {
+    type Output = DrawdownOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.peak = self.peak.max(input);
+
+        let drawdown = if self.peak > 0.0 {
+            (self.peak - input) / self.peak * 100.0
+        } else {
+            0.0
+        };
+        self.max_drawdown = self.max_drawdown.max(drawdown);
+
+        DrawdownOutput {
+            drawdown,
+            max_drawdown: self.max_drawdown,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Drawdown {
+    type Output = DrawdownOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Drawdown {
+    fn reset(&mut self) {
+        self.peak = f64::NEG_INFINITY;
+        self.max_drawdown = 0.0;
+    }
+}
+
+impl Default for Drawdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Drawdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DD")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Drawdown);
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dd = Drawdown::new();
+
+        assert_eq!(round(dd.next(100.0).into()), (0.0, 0.0));
+        assert_eq!(round(dd.next(110.0).into()), (0.0, 0.0));
+        assert_eq!(round(dd.next(99.0).into()), (10.0, 10.0));
+        assert_eq!(round(dd.next(88.0).into()), (20.0, 20.0));
+        assert_eq!(round(dd.next(105.0).into()), (4.545, 20.0));
+        assert_eq!(round(dd.next(120.0).into()), (0.0, 20.0));
+        assert_eq!(round(dd.next(108.0).into()), (10.0, 20.0));
+        assert_eq!(dd.peak(), 120.0);
+    }
+
+    #[test]
+    fn test_next_not_positive() {
+        let mut dd = Drawdown::new();
+
+        assert_eq!(round(dd.next(-10.0).into()), (0.0, 0.0));
+        assert_eq!(round(dd.next(0.0).into()), (0.0, 0.0));
+        assert_eq!(round(dd.next(-5.0).into()), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut dd = Drawdown::new();
+
+        assert_eq!(round(dd.next(&bar(100.0)).into()), (0.0, 0.0));
+        assert_eq!(round(dd.next(&bar(75.0)).into()), (25.0, 25.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dd = Drawdown::new();
+
+        dd.next(100.0);
+        dd.next(50.0);
+
+        dd.reset();
+
+        assert_eq!(round(dd.next(50.0).into()), (0.0, 0.0));
+        assert_eq!(round(dd.next(40.0).into()), (20.0, 20.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Drawdown::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Drawdown::new();
+        assert_eq!(format!("{}", indicator), "DD");
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt;
+
+use crate::indicators::Drawdown;
+use crate::{Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// Equity curve statistics.
+///
+/// Consumes points of an equity curve, as `(timestamp, equity)` pairs where timestamps are
+/// Unix milliseconds, and maintains statistics of the whole curve so far. It can be fed by
+/// any backtester or by a live account.
+///
+/// # Formula
+///
+/// CAGR = ((E<sub>last</sub> / E<sub>first</sub>)<sup>1 / years</sup> - 1) * 100
+///
+/// Calmar ratio = CAGR / MDD
+///
+/// Recovery factor = (E<sub>last</sub> - E<sub>first</sub>) / max(peak - E)
+///
+/// Where:
+///
+/// * _E_ - equity
+/// * _years_ - time between the first and the last point, in years of 365.25 days
+/// * _MDD_ - maximum [drawdown](struct.Drawdown.html) in percent
+/// * _max(peak - E)_ - maximum drawdown in units of the equity
+///
+/// The maximum drawdown duration is the longest time in milliseconds between a peak and
+/// the recovery to it, or the last point when the equity has not recovered yet.
+///
+/// Statistics which are undefined, e.g. CAGR of the first point or ratios without drawdowns,
+/// are 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::EquityStats;
+/// use ta::Next;
+///
+/// let year = 31_557_600_000;
+/// let mut stats = EquityStats::new();
+///
+/// stats.next((0, 100.0));
+/// stats.next((year / 2, 120.0));
+/// stats.next((year / 4 * 3, 90.0));
+/// let out = stats.next((year, 121.0));
+///
+/// assert_eq!(out.max_drawdown, 25.0);
+/// assert_eq!(out.max_drawdown_duration, year / 2);
+/// assert_eq!((out.cagr * 1000.0).round() / 1000.0, 21.0);
+/// assert_eq!((out.recovery_factor * 1000.0).round() / 1000.0, 0.7);
+/// ```
+///
+/// # Links
+///
+/// * [Compound annual growth rate, Wikipedia](https://en.wikipedia.org/wiki/Compound_annual_growth_rate)
+/// * [Calmar ratio, Wikipedia](https://en.wikipedia.org/wiki/Calmar_ratio)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EquityStats {
+    first: Option<(i64, f64)>,
+    drawdown: Drawdown,
+    peak_timestamp: i64,
+    last_equity: f64,
+    max_drawdown_amount: f64,
+    max_drawdown_duration: i64,
+}
+
+impl EquityStats {
+    pub fn new() -> Self {
+        Self {
+            first: None,
+            drawdown: Drawdown::new(),
+            peak_timestamp: 0,
+            last_equity: f64::NEG_INFINITY,
+            max_drawdown_amount: 0.0,
+            max_drawdown_duration: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityStatsOutput {
+    pub cagr: f64,
+    pub max_drawdown: f64,
+    pub calmar_ratio: f64,
+    pub recovery_factor: f64,
+    pub max_drawdown_duration: i64,
+}
+
+impl Next<(i64, f64)> for EquityStats {
+    type Output = EquityStatsOutput;
+
+    fn next(&mut self, (timestamp, equity): (i64, f64)) -> Self::Output {
+        let (first_timestamp, first_equity) = *self.first.get_or_insert((timestamp, equity));
+
+        // Time since the peak counts while under water and at the recovery
+        let prev_peak = self.drawdown.peak();
+        if self.last_equity < prev_peak || equity < prev_peak {
+            self.max_drawdown_duration = self
+                .max_drawdown_duration
+                .max(timestamp - self.peak_timestamp);
+        }
+        if equity >= prev_peak {
+            self.peak_timestamp = timestamp;
+        }
+        self.last_equity = equity;
+
+        let max_drawdown = self.drawdown.next(equity).max_drawdown;
+        self.max_drawdown_amount = self.max_drawdown_amount.max(self.drawdown.peak() - equity);
+
+        let years = (timestamp - first_timestamp) as f64 / MS_PER_YEAR;
+        let cagr = if years > 0.0 && first_equity > 0.0 && equity >= 0.0 {
+            ((equity / first_equity).powf(1.0 / years) - 1.0) * 100.0
+        } else {
+            0.0
+        };
+
+        let calmar_ratio = if max_drawdown > 0.0 {
+            cagr / max_drawdown
+        } else {
+            0.0
+        };
+        let recovery_factor = if self.max_drawdown_amount > 0.0 {
+            (equity - first_equity) / self.max_drawdown_amount
+        } else {
+            0.0
+        };
+
+        EquityStatsOutput {
+            cagr,
+            max_drawdown,
+            calmar_ratio,
+            recovery_factor,
+            max_drawdown_duration: self.max_drawdown_duration,
+        }
+    }
+}
+
+impl Reset for EquityStats {
+    fn reset(&mut self) {
+        self.first = None;
+        self.drawdown.reset();
+        self.peak_timestamp = 0;
+        self.last_equity = f64::NEG_INFINITY;
+        self.max_drawdown_amount = 0.0;
+        self.max_drawdown_duration = 0;
+    }
+}
+
+impl Default for EquityStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for EquityStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EQUITY_STATS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: i64 = 31_557_600_000;
+
+    fn round(num: f64) -> f64 {
+        (num * 1000.0).round() / 1000.0
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stats = EquityStats::new();
+
+        let out = stats.next((0, 100.0));
+        assert_eq!(
+            out,
+            EquityStatsOutput {
+                cagr: 0.0,
+                max_drawdown: 0.0,
+                calmar_ratio: 0.0,
+                recovery_factor: 0.0,
+                max_drawdown_duration: 0,
+            }
+        );
+
+        let out = stats.next((YEAR / 2, 120.0));
+        assert_eq!(round(out.cagr), 44.0);
+        assert_eq!(out.calmar_ratio, 0.0);
+        assert_eq!(out.recovery_factor, 0.0);
+
+        let out = stats.next((YEAR / 4 * 3, 90.0));
+        assert_eq!(round(out.cagr), -13.106);
+        assert_eq!(out.max_drawdown, 25.0);
+        assert_eq!(round(out.calmar_ratio), -0.524);
+        assert_eq!(round(out.recovery_factor), -0.333);
+        assert_eq!(out.max_drawdown_duration, YEAR / 4);
+
+        let out = stats.next((YEAR, 121.0));
+        assert_eq!(round(out.cagr), 21.0);
+        assert_eq!(out.max_drawdown, 25.0);
+        assert_eq!(round(out.calmar_ratio), 0.84);
+        assert_eq!(round(out.recovery_factor), 0.7);
+        assert_eq!(out.max_drawdown_duration, YEAR / 2);
+
+        // The drawdown duration keeps the longest one
+        let out = stats.next((YEAR + 1000, 120.0));
+        assert_eq!(out.max_drawdown_duration, YEAR / 2);
+    }
+
+    #[test]
+    fn test_next_not_recovered() {
+        let mut stats = EquityStats::new();
+
+        stats.next((0, 100.0));
+        stats.next((1000, 90.0));
+        let out = stats.next((5000, 95.0));
+
+        assert_eq!(out.max_drawdown, 10.0);
+        assert_eq!(out.max_drawdown_duration, 5000);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stats = EquityStats::new();
+
+        stats.next((0, 100.0));
+        stats.next((1000, 90.0));
+
+        stats.reset();
+
+        let out = stats.next((2000, 50.0));
+        assert_eq!(out.max_drawdown, 0.0);
+        assert_eq!(out.max_drawdown_duration, 0);
+    }
+
+    #[test]
+    fn test_default() {
+        EquityStats::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = EquityStats::new();
+        assert_eq!(format!("{}", stats), "EQUITY_STATS");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Lookback, Next, NextSlice, Period, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -51,7 +51,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
 #[doc(alias = "EMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -59,6 +58,8 @@ pub struct ExponentialMovingAverage {
     period: usize,
     k: f64,
     current: f64,
+    // Value before the latest input, None when it was the first one
+    previous: Option<f64>,
     is_new: bool,
 }
 
@@ -70,6 +71,7 @@ impl ExponentialMovingAverage {
                 period,
                 k: 2.0 / (period + 1) as f64,
                 current: 0.0,
+                previous: None,
                 is_new: true,
             }),
         }
@@ -82,14 +84,22 @@ impl Period for ExponentialMovingAverage {
     }
 }
 
+impl Lookback for ExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for ExponentialMovingAverage {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
         if self.is_new {
             self.is_new = false;
+            self.previous = None;
             self.current = input;
         } else {
+            self.previous = Some(self.current);
             self.current = self.k * input + (1.0 - self.k) * self.current;
         }
         self.current
@@ -104,13 +114,57 @@ impl<T: Close> Next<&T> for ExponentialMovingAverage {
     }
 }
 
+impl NextSlice for ExponentialMovingAverage {
+    /// Every output depends on the previous one, so the batch is processed sequentially.
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]) {
+        assert_eq!(inputs.len(), out.len());
+
+        for (out, &input) in out.iter_mut().zip(inputs) {
+            *out = self.next(input);
+        }
+    }
+}
+
+impl UpdateLast<f64> for ExponentialMovingAverage {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
+        }
+
+        self.current = match self.previous {
+            Some(previous) => self.k * input + (1.0 - self.k) * previous,
+            None => input,
+        };
+        self.current
+    }
+}
+
+impl<T: Close> UpdateLast<&T> for ExponentialMovingAverage {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.close())
+    }
+}
+
 impl Reset for ExponentialMovingAverage {
     fn reset(&mut self) {
         self.current = 0.0;
+        self.previous = None;
         self.is_new = true;
     }
 }
 
+impl Current for ExponentialMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.is_new {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
+}
+
 impl Default for ExponentialMovingAverage {
     fn default() -> Self {
         Self::new(9).unwrap()
@@ -152,6 +206,38 @@ mod tests {
         assert_eq!(ema.next(&bar2), 3.5);
     }
 
+    #[test]
+    fn test_current() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.current(), None);
+
+        for &input in &[4.0, 5.0, 9.0] {
+            let output = ema.next(input);
+            assert_eq!(ema.current(), Some(output));
+        }
+
+        ema.reset();
+        assert_eq!(ema.current(), None);
+    }
+
+    #[test]
+    fn test_update_last() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        let mut expected = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.update_last(4.0), expected.next(4.0));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            ema.next(close + 3.0);
+            ema.update_last(close - 1.0);
+            assert_eq!(round(ema.update_last(close)), round(expected.next(close)));
+        }
+    }
+
+    #[test]
+    fn test_next_slice() {
+        assert_next_slice(ExponentialMovingAverage::new(9).unwrap());
+    }
+
     #[test]
     fn test_reset() {
         let mut ema = ExponentialMovingAverage::new(5).unwrap();
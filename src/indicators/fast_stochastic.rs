@@ -1,8 +1,11 @@
 use std::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::{Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::signal::Thresholds;
+use crate::{
+    Action, Close, Current, High, Lookback, Low, Next, Period, Reset, Signal, TryNext, UpdateLast,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +25,8 @@ use serde::{Deserialize, Serialize};
 /// * L<sub>n</sub> - lowest price for the last _n_ periods
 /// * H<sub>n</sub> - highest price for the last _n_ periods
 ///
+/// The oscillator implements [Signal](../trait.Signal.html), with the oversold and overbought
+/// thresholds of 20 and 80 by default.
 ///
 /// # Parameters
 ///
@@ -46,6 +51,8 @@ pub struct FastStochastic {
     period: usize,
     minimum: Minimum,
     maximum: Maximum,
+    current: f64,
+    thresholds: Thresholds,
 }
 
 impl FastStochastic {
@@ -54,8 +61,16 @@ impl FastStochastic {
             period,
             minimum: Minimum::new(period)?,
             maximum: Maximum::new(period)?,
+            current: f64::NAN,
+            thresholds: Thresholds::new(20.0, 80.0)?,
         })
     }
+
+    /// Sets the oversold and overbought thresholds of the [signal](../trait.Signal.html).
+    pub fn with_thresholds(mut self, oversold: f64, overbought: f64) -> Result<Self> {
+        self.thresholds = Thresholds::new(oversold, overbought)?;
+        Ok(self)
+    }
 }
 
 impl Period for FastStochastic {
@@ -64,6 +79,12 @@ impl Period for FastStochastic {
     }
 }
 
+impl Lookback for FastStochastic {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for FastStochastic {
     type Output = f64;
 
@@ -71,13 +92,14 @@ impl Next<f64> for FastStochastic {
         let min = self.minimum.next(input);
         let max = self.maximum.next(input);
 
-        if min == max {
+        self.current = if min == max {
             // When only 1 input was given, than min and max are the same,
             // therefore it makes sense to return 50
             50.0
         } else {
             (input - min) / (max - min) * 100.0
-        }
+        };
+        self.current
     }
 }
 
@@ -89,12 +111,91 @@ impl<T: High + Low + Close> Next<&T> for FastStochastic {
         let lowest = self.minimum.next(input.low());
         let close = input.close();
 
-        if highest == lowest {
+        self.current = if highest == lowest {
             // To avoid division by zero, return 50.0
             50.0
         } else {
             (close - lowest) / (highest - lowest) * 100.0
+        };
+        self.current
+    }
+}
+
+impl TryNext<f64> for FastStochastic {
+    type Output = f64;
+
+    /// Returns a `DivisionByZero` error when the minimum and the maximum are equal, e.g.
+    /// after the first input, where `next` returns 50.
+    fn try_next(&mut self, input: f64) -> Result<Self::Output> {
+        if !input.is_finite() {
+            return Err(TaError::InvalidInput);
+        }
+
+        let min = self.minimum.next(input);
+        let max = self.maximum.next(input);
+        if min == max {
+            self.current = f64::NAN;
+            return Err(TaError::DivisionByZero);
         }
+
+        self.current = (input - min) / (max - min) * 100.0;
+        Ok(self.current)
+    }
+}
+
+impl<T: High + Low + Close> TryNext<&T> for FastStochastic {
+    type Output = f64;
+
+    fn try_next(&mut self, input: &T) -> Result<Self::Output> {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        if !(high.is_finite() && low.is_finite() && close.is_finite()) {
+            return Err(TaError::InvalidInput);
+        }
+
+        let highest = self.maximum.next(high);
+        let lowest = self.minimum.next(low);
+        if highest == lowest {
+            self.current = f64::NAN;
+            return Err(TaError::DivisionByZero);
+        }
+
+        self.current = (close - lowest) / (highest - lowest) * 100.0;
+        Ok(self.current)
+    }
+}
+
+impl UpdateLast<f64> for FastStochastic {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        let min = self.minimum.update_last(input);
+        let max = self.maximum.update_last(input);
+
+        self.current = if min == max {
+            50.0
+        } else {
+            (input - min) / (max - min) * 100.0
+        };
+        self.current
+    }
+}
+
+impl<T: High + Low + Close> UpdateLast<&T> for FastStochastic {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        let highest = self.maximum.update_last(input.high());
+        let lowest = self.minimum.update_last(input.low());
+        let close = input.close();
+
+        self.current = if highest == lowest {
+            50.0
+        } else {
+            (close - lowest) / (highest - lowest) * 100.0
+        };
+        self.current
+    }
+}
+
+impl Signal for FastStochastic {
+    fn signal(&self) -> Action {
+        self.thresholds.action(self.current)
     }
 }
 
@@ -102,6 +203,15 @@ impl Reset for FastStochastic {
     fn reset(&mut self) {
         self.minimum.reset();
         self.maximum.reset();
+        self.current = f64::NAN;
+    }
+}
+
+impl Current for FastStochastic {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(self.current).filter(|current| !current.is_nan())
     }
 }
 
@@ -160,6 +270,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_signal() {
+        let mut stoch = FastStochastic::new(5).unwrap();
+        assert_eq!(stoch.signal(), Action::Neutral);
+
+        stoch.next(20.0);
+        assert_eq!(stoch.signal(), Action::Neutral);
+        stoch.next(30.0);
+        assert_eq!(stoch.signal(), Action::Sell);
+        stoch.next(35.0);
+        assert_eq!(stoch.signal(), Action::Sell);
+        stoch.next(31.0);
+        assert_eq!(stoch.signal(), Action::Neutral);
+        stoch.next(15.0);
+        assert_eq!(stoch.signal(), Action::Buy);
+
+        stoch.reset();
+        assert_eq!(stoch.signal(), Action::Neutral);
+    }
+
+    #[test]
+    fn test_update_last() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().high(close + 1.0).low(close - 2.0).close(close)
+        }
+
+        let mut stoch = FastStochastic::new(3).unwrap();
+        let mut expected = FastStochastic::new(3).unwrap();
+        assert_eq!(stoch.update_last(&bar(4.0)), expected.next(&bar(4.0)));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            stoch.next(&bar(close + 3.0));
+            stoch.update_last(&bar(close - 1.0));
+            assert_eq!(
+                round(stoch.update_last(&bar(close))),
+                round(expected.next(&bar(close)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_next() {
+        let mut stoch = FastStochastic::new(3).unwrap();
+        assert!(matches!(
+            stoch.try_next(f64::NAN),
+            Err(TaError::InvalidInput)
+        ));
+        assert!(matches!(stoch.try_next(20.0), Err(TaError::DivisionByZero)));
+        assert_eq!(stoch.current(), None);
+        assert_eq!(stoch.try_next(30.0).unwrap(), 100.0);
+
+        let mut stoch = FastStochastic::new(3).unwrap();
+        let bar = |high: f64, low: f64, close: f64| Bar::new().high(high).low(low).close(close);
+        assert!(matches!(
+            stoch.try_next(&bar(f64::INFINITY, 1.0, 1.0)),
+            Err(TaError::InvalidInput)
+        ));
+        assert!(matches!(
+            stoch.try_next(&bar(2.0, 2.0, 2.0)),
+            Err(TaError::DivisionByZero)
+        ));
+        assert_eq!(stoch.try_next(&bar(4.0, 2.0, 3.0)).unwrap(), 50.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut indicator = FastStochastic::new(10).unwrap();
@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fractal Dimension Index (FDI).
+///
+/// Estimates the fractal dimension of the price path over the last _period_ values using
+/// Sevcik's method. Values close to 1 indicate a smooth, trending market, while values close
+/// to 2 indicate a rough, ranging market. It is commonly used as a regime filter.
+///
+/// # Formula
+///
+/// FDI = 1 + (ln(L) + ln(2)) / ln(2 * (n - 1))
+///
+/// Where:
+///
+/// * _n_ - number of periods
+/// * _L_ - length of the path of the values normalized into the unit square:
+///   Σ sqrt((y<sub>i</sub> - y<sub>i-1</sub>)<sup>2</sup> + 1 / (n - 1)<sup>2</sup>)
+/// * _y<sub>i</sub>_ = (p<sub>i</sub> - min) / (max - min), or 0 when all values are equal
+///
+/// Until _period_ values were given, 1.5 (the dimension of a random walk) is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::FractalDimensionIndex;
+/// use ta::Next;
+///
+/// let mut fdi = FractalDimensionIndex::new(4).unwrap();
+/// assert_eq!(fdi.next(10.0), 1.5);
+/// assert_eq!(fdi.next(11.0), 1.5);
+/// assert_eq!(fdi.next(12.0), 1.5);
+/// assert_eq!((fdi.next(11.0) * 1000.0).round() / 1000.0, 1.716);
+/// ```
+///
+/// # Links
+///
+/// * [Fractal dimension, Wikipedia](https://en.wikipedia.org/wiki/Fractal_dimension)
+/// * [Sevcik, C. A procedure to Estimate the Fractal Dimension of Waveforms](https://arxiv.org/abs/1003.5266)
+///
+#[doc(alias = "FDI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FractalDimensionIndex {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl FractalDimensionIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 | 1 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for FractalDimensionIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for FractalDimensionIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < self.period {
+            return 1.5;
+        }
+
+        let max = self.deque.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        let min = self.deque.iter().fold(f64::INFINITY, |a, &b| a.min(b));
+        let range = max - min;
+        let normalize = |value: f64| {
+            if range > 0.0 {
+                (value - min) / range
+            } else {
+                0.0
+            }
+        };
+
+        let intervals = (self.period - 1) as f64;
+        let dx2 = 1.0 / (intervals * intervals);
+
+        // The oldest value is at the current index
+        let mut length = 0.0;
+        let mut prev = normalize(self.deque[self.index]);
+        for i in 1..self.period {
+            let y = normalize(self.deque[(self.index + i) % self.period]);
+            length += ((y - prev) * (y - prev) + dx2).sqrt();
+            prev = y;
+        }
+
+        1.0 + (length.ln() + 2f64.ln()) / (2.0 * intervals).ln()
+    }
+}
+
+impl<T: Close> Next<&T> for FractalDimensionIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for FractalDimensionIndex {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for FractalDimensionIndex {
+    fn default() -> Self {
+        Self::new(30).unwrap()
+    }
+}
+
+impl fmt::Display for FractalDimensionIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FDI({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(FractalDimensionIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(FractalDimensionIndex::new(0).is_err());
+        assert!(FractalDimensionIndex::new(1).is_err());
+        assert!(FractalDimensionIndex::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fdi = FractalDimensionIndex::new(4).unwrap();
+
+        assert_eq!(fdi.next(10.0), 1.5);
+        assert_eq!(fdi.next(11.0), 1.5);
+        assert_eq!(fdi.next(12.0), 1.5);
+        assert_eq!(round(fdi.next(11.0)), 1.716);
+        assert_eq!(round(fdi.next(13.0)), 1.841);
+        assert_eq!(round(fdi.next(14.0)), 1.679);
+        assert_eq!(round(fdi.next(12.0)), 1.763);
+        assert_eq!(round(fdi.next(12.0)), 1.77);
+    }
+
+    #[test]
+    fn test_next_trend() {
+        let mut fdi = FractalDimensionIndex::new(4).unwrap();
+
+        fdi.next(10.0);
+        fdi.next(11.0);
+        fdi.next(12.0);
+        assert_eq!(round(fdi.next(13.0)), 1.58);
+        assert_eq!(round(fdi.next(14.0)), 1.58);
+    }
+
+    #[test]
+    fn test_next_same_values() {
+        let mut fdi = FractalDimensionIndex::new(4).unwrap();
+
+        for _ in 0..3 {
+            fdi.next(5.0);
+        }
+        assert_eq!(round(fdi.next(5.0)), 1.387);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut fdi = FractalDimensionIndex::new(4).unwrap();
+
+        fdi.next(&bar(10.0));
+        fdi.next(&bar(11.0));
+        fdi.next(&bar(12.0));
+        assert_eq!(round(fdi.next(&bar(11.0))), 1.716);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fdi = FractalDimensionIndex::new(4).unwrap();
+
+        for &value in &[10.0, 11.0, 12.0, 11.0] {
+            fdi.next(value);
+        }
+
+        fdi.reset();
+
+        assert_eq!(fdi.next(11.0), 1.5);
+    }
+
+    #[test]
+    fn test_default() {
+        FractalDimensionIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = FractalDimensionIndex::new(30).unwrap();
+        assert_eq!(format!("{}", indicator), "FDI(30)");
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Garman-Klass volatility.
+///
+/// An OHLC volatility estimator developed by Mark Garman and Michael Klass. It extends the
+/// [Parkinson](struct.ParkinsonVolatility.html) estimator with the open and close prices,
+/// assuming no drift and no opening jumps.
+///
+/// # Formula
+///
+/// σ = sqrt(Σ (0.5 * ln(High / Low)<sup>2</sup> - (2 * ln(2) - 1) * ln(Close / Open)<sup>2</sup>) / n)
+///
+/// Where:
+///
+/// * _n_ - number of bars in the time frame
+///
+/// The result is the volatility per bar, not annualized. Prices are expected to be positive.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::GarmanKlassVolatility;
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.5).volume(1.0).build().unwrap();
+///
+/// let mut gk = GarmanKlassVolatility::new(3).unwrap();
+/// assert_eq!((gk.next(&bar) * 10000.0).round() / 10000.0, 0.1386);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility estimators, Portfolio Optimizer](https://portfoliooptimizer.io/blog/range-based-volatility-estimators-overview-and-examples-of-usage/)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GarmanKlassVolatility {
+    sma: Sma,
+}
+
+impl GarmanKlassVolatility {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for GarmanKlassVolatility {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for GarmanKlassVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let log_hl = (input.high() / input.low()).ln();
+        let log_co = (input.close() / input.open()).ln();
+        let term = 0.5 * log_hl * log_hl - (2.0 * std::f64::consts::LN_2 - 1.0) * log_co * log_co;
+
+        self.sma.next(term).max(0.0).sqrt()
+    }
+}
+
+impl Reset for GarmanKlassVolatility {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for GarmanKlassVolatility {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for GarmanKlassVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GK({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(num: f64) -> f64 {
+        (num * 10000.0).round() / 10000.0
+    }
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(GarmanKlassVolatility::new(0).is_err());
+        assert!(GarmanKlassVolatility::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut gk = GarmanKlassVolatility::new(3).unwrap();
+
+        assert_eq!(round(gk.next(&bar(10.0, 11.0, 9.0, 10.5))), 0.1386);
+        assert_eq!(round(gk.next(&bar(10.6, 11.5, 10.2, 11.0))), 0.1138);
+        assert_eq!(round(gk.next(&bar(11.2, 12.0, 10.8, 11.5))), 0.1019);
+        assert_eq!(round(gk.next(&bar(11.3, 11.6, 10.5, 10.8))), 0.0733);
+        assert_eq!(round(gk.next(&bar(10.7, 11.0, 10.0, 10.2))), 0.0661);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gk = GarmanKlassVolatility::new(3).unwrap();
+
+        assert_eq!(round(gk.next(&bar(10.0, 11.0, 9.0, 10.5))), 0.1386);
+        gk.next(&bar(10.6, 11.5, 10.2, 11.0));
+
+        gk.reset();
+
+        assert_eq!(round(gk.next(&bar(10.0, 11.0, 9.0, 10.5))), 0.1386);
+    }
+
+    #[test]
+    fn test_default() {
+        GarmanKlassVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = GarmanKlassVolatility::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "GK(20)");
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt;
+
+use crate::indicators::*;
+use crate::{DataItem, Next, OutputInfo, OutputValues, Pane, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Generates IndicatorKind with a variant per indicator, its conversions and the forwarding of
+// the traits. Each indicator names the variant of IndicatorKindOutput holding its output and
+// whether it has a period.
+macro_rules! indicator_kind {
+    ($($name:ident => $output:ident, $period:ident;)*) => {
+        /// Any indicator of the crate consuming bars, with the type of the indicator held
+        /// by an enum variant.
+        ///
+        /// Unlike a [boxed indicator](../type.BoxedIndicator.html), indicators of different
+        /// types are stored without allocation, e.g. in a `Vec<IndicatorKind>`, and the enum
+        /// can be serialized with the `serde` feature. The output is an
+        /// [IndicatorKindOutput](enum.IndicatorKindOutput.html).
+        ///
+        /// Indicators without a single period, like OBV or MACD, report a period of 1.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use ta::indicators::{
+        ///     IndicatorKind, IndicatorKindOutput, OnBalanceVolume as Obv, SimpleMovingAverage as Sma,
+        /// };
+        /// use ta::{DataItem, Next};
+        ///
+        /// let mut indicators: Vec<IndicatorKind> =
+        ///     vec![Sma::new(3).unwrap().into(), Obv::new().into()];
+        ///
+        /// let bar = DataItem::builder()
+        ///     .open(10.0).high(10.0).low(10.0).close(10.0).volume(100.0).build().unwrap();
+        /// let outputs: Vec<IndicatorKindOutput> =
+        ///     indicators.iter_mut().map(|ind| ind.next(&bar)).collect();
+        ///
+        /// assert_eq!(outputs[0].value(), Some(10.0));
+        /// assert_eq!(outputs[1].value(), Some(100.0));
+        /// ```
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone)]
+        pub enum IndicatorKind {
+            $($name($name),)*
+        }
+
+        $(
+            impl From<$name> for IndicatorKind {
+                fn from(indicator: $name) -> Self {
+                    IndicatorKind::$name(indicator)
+                }
+            }
+        )*
+
+        impl Next<&DataItem> for IndicatorKind {
+            type Output = IndicatorKindOutput;
+
+            fn next(&mut self, input: &DataItem) -> Self::Output {
+                match self {
+                    $(IndicatorKind::$name(indicator) => {
+                        IndicatorKindOutput::$output(indicator.next(input))
+                    })*
+                }
+            }
+        }
+
+        impl Reset for IndicatorKind {
+            fn reset(&mut self) {
+                match self {
+                    $(IndicatorKind::$name(indicator) => indicator.reset(),)*
+                }
+            }
+        }
+
+        impl Period for IndicatorKind {
+            fn period(&self) -> usize {
+                match self {
+                    $(IndicatorKind::$name(indicator) => indicator_kind!(@period indicator, $period),)*
+                }
+            }
+        }
+
+        impl OutputInfo for IndicatorKind {
+            fn output_fields(&self) -> &[&'static str] {
+                match self {
+                    $(IndicatorKind::$name(indicator) => indicator.output_fields(),)*
+                }
+            }
+
+            fn output_range(&self) -> Option<(f64, f64)> {
+                match self {
+                    $(IndicatorKind::$name(indicator) => indicator.output_range(),)*
+                }
+            }
+
+            fn pane(&self) -> Pane {
+                match self {
+                    $(IndicatorKind::$name(indicator) => indicator.pane(),)*
+                }
+            }
+        }
+
+        impl fmt::Display for IndicatorKind {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self {
+                    $(IndicatorKind::$name(indicator) => write!(f, "{}", indicator),)*
+                }
+            }
+        }
+    };
+    (@period $indicator:ident, period) => {
+        $indicator.period()
+    };
+    (@period $indicator:ident, none) => {{
+        let _ = $indicator;
+        1
+    }};
+}
+
+indicator_kind! {
+    AccelerationBands => AccelerationBands, period;
+    AcceleratorOscillator => Value, period;
+    AccumulationDistribution => Value, none;
+    Alligator => Alligator, none;
+    Aroon => Aroon, period;
+    AverageTrueRange => Value, period;
+    AwesomeOscillator => Value, period;
+    BollingerBands => BollingerBands, period;
+    ChandeKrollStop => ChandeKrollStop, period;
+    ChandelierExit => ChandelierExit, period;
+    CommodityChannelIndex => Value, period;
+    CoppockCurve => Value, period;
+    CumulativeSum => Value, none;
+    Drawdown => Drawdown, none;
+    EfficiencyRatio => Value, period;
+    ExponentialMovingAverage => Value, period;
+    FastStochastic => Value, period;
+    FractalDimensionIndex => Value, period;
+    GarmanKlassVolatility => Value, period;
+    KalmanFilter => Value, none;
+    KaufmanAdaptiveMovingAverage => Value, period;
+    KeltnerChannel => KeltnerChannel, period;
+    Lag => Lag, period;
+    LinearRegression => LinearRegression, period;
+    LinearRegressionChannel => LinearRegressionChannel, period;
+    LogReturns => Value, none;
+    Maximum => Value, period;
+    McGinleyDynamic => Value, period;
+    MeanAbsoluteDeviation => Value, period;
+    MedianPrice => Value, none;
+    MidPrice => Value, period;
+    Minimum => Value, period;
+    Momentum => Value, period;
+    MoneyFlowIndex => Value, period;
+    MovingAverageConvergenceDivergence => MovingAverageConvergenceDivergence, none;
+    NormalizedAverageTrueRange => Value, period;
+    OnBalanceVolume => Value, none;
+    ParkinsonVolatility => Value, period;
+    PercentRank => Value, period;
+    PercentagePriceOscillator => PercentagePriceOscillator, none;
+    PivotDetector => PivotDetector, none;
+    RateOfChange => Value, period;
+    RelativeStrengthIndex => Value, period;
+    RollingKurtosis => Value, period;
+    RollingMedian => Value, period;
+    RollingPercentile => Value, period;
+    RollingRSquared => Value, period;
+    RollingSkewness => Value, period;
+    RollingSortino => Value, period;
+    SampleEntropy => Value, period;
+    SavitzkyGolay => SavitzkyGolay, period;
+    SimpleMovingAverage => Value, period;
+    SimpleReturns => Value, none;
+    SlowStochastic => Value, none;
+    SmoothedMovingAverage => Value, period;
+    StandardDeviation => Value, period;
+    StochasticMomentumIndex => StochasticMomentumIndex, period;
+    StochasticRsi => StochasticRsi, none;
+    T3MovingAverage => Value, period;
+    TrendLines => TrendLines, none;
+    TrueRange => Value, none;
+    UltimateOscillator => Value, period;
+    VolatilityStop => VolatilityStop, period;
+    WeightedMovingAverage => Value, period;
+    YangZhangVolatility => Value, period;
+    ZScore => Value, period;
+}
+
+/// Output of an [IndicatorKind](enum.IndicatorKind.html).
+///
+/// Indicators returning a single number return a `Value`, the others return the variant
+/// named after them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndicatorKindOutput {
+    Value(f64),
+    AccelerationBands(AccelerationBandsOutput),
+    Alligator(AlligatorOutput),
+    Aroon(AroonOutput),
+    BollingerBands(BollingerBandsOutput),
+    ChandeKrollStop(ChandeKrollStopOutput),
+    ChandelierExit(ChandelierExitOutput),
+    Drawdown(DrawdownOutput),
+    KeltnerChannel(KeltnerChannelOutput),
+    Lag(Option<f64>),
+    LinearRegression(LinearRegressionOutput),
+    LinearRegressionChannel(LinearRegressionChannelOutput),
+    MovingAverageConvergenceDivergence(MovingAverageConvergenceDivergenceOutput),
+    PercentagePriceOscillator(PercentagePriceOscillatorOutput),
+    PivotDetector(PivotDetectorOutput),
+    SavitzkyGolay(SavitzkyGolayOutput),
+    StochasticMomentumIndex(StochasticMomentumIndexOutput),
+    StochasticRsi(StochasticRsiOutput),
+    TrendLines(TrendLinesOutput),
+    VolatilityStop(VolatilityStopOutput),
+}
+
+impl IndicatorKindOutput {
+    /// Returns the number of a `Value`, or `None` for the other outputs.
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            IndicatorKindOutput::Value(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl OutputValues for IndicatorKindOutput {
+    fn output_values(&self) -> Vec<f64> {
+        match self {
+            IndicatorKindOutput::Value(value) => value.output_values(),
+            IndicatorKindOutput::AccelerationBands(output) => output.output_values(),
+            IndicatorKindOutput::Alligator(output) => output.output_values(),
+            IndicatorKindOutput::Aroon(output) => output.output_values(),
+            IndicatorKindOutput::BollingerBands(output) => output.output_values(),
+            IndicatorKindOutput::ChandeKrollStop(output) => output.output_values(),
+            IndicatorKindOutput::ChandelierExit(output) => output.output_values(),
+            IndicatorKindOutput::Drawdown(output) => output.output_values(),
+            IndicatorKindOutput::KeltnerChannel(output) => output.output_values(),
+            IndicatorKindOutput::Lag(output) => output.output_values(),
+            IndicatorKindOutput::LinearRegression(output) => output.output_values(),
+            IndicatorKindOutput::LinearRegressionChannel(output) => output.output_values(),
+            IndicatorKindOutput::MovingAverageConvergenceDivergence(output) => {
+                output.output_values()
+            }
+            IndicatorKindOutput::PercentagePriceOscillator(output) => output.output_values(),
+            IndicatorKindOutput::PivotDetector(output) => output.output_values(),
+            IndicatorKindOutput::SavitzkyGolay(output) => output.output_values(),
+            IndicatorKindOutput::StochasticMomentumIndex(output) => output.output_values(),
+            IndicatorKindOutput::StochasticRsi(output) => output.output_values(),
+            IndicatorKindOutput::TrendLines(output) => output.output_values(),
+            IndicatorKindOutput::VolatilityStop(output) => output.output_values(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut indicators: Vec<IndicatorKind> = vec![
+            SimpleMovingAverage::new(2).unwrap().into(),
+            Lag::new(1).unwrap().into(),
+            MovingAverageConvergenceDivergence::new(3, 6, 4)
+                .unwrap()
+                .into(),
+        ];
+
+        for &close in &[2.0, 4.0] {
+            for indicator in indicators.iter_mut() {
+                indicator.next(&item(close));
+            }
+        }
+
+        let outputs: Vec<IndicatorKindOutput> = indicators
+            .iter_mut()
+            .map(|indicator| indicator.next(&item(6.0)))
+            .collect();
+
+        assert_eq!(outputs[0], IndicatorKindOutput::Value(5.0));
+        assert_eq!(outputs[1], IndicatorKindOutput::Lag(Some(4.0)));
+        match &outputs[2] {
+            IndicatorKindOutput::MovingAverageConvergenceDivergence(macd) => {
+                assert_eq!(round(macd.macd), 0.949)
+            }
+            output => panic!("unexpected output {:?}", output),
+        }
+        assert_eq!(outputs[2].value(), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut indicator = IndicatorKind::from(SimpleMovingAverage::new(2).unwrap());
+        indicator.next(&item(2.0));
+        assert_eq!(indicator.next(&item(4.0)).value(), Some(3.0));
+
+        indicator.reset();
+        assert_eq!(indicator.next(&item(8.0)).value(), Some(8.0));
+    }
+
+    #[test]
+    fn test_period() {
+        let rsi = IndicatorKind::from(RelativeStrengthIndex::new(9).unwrap());
+        assert_eq!(rsi.period(), 9);
+
+        let obv = IndicatorKind::from(OnBalanceVolume::new());
+        assert_eq!(obv.period(), 1);
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = IndicatorKind::from(SimpleMovingAverage::new(7).unwrap());
+        assert_eq!(format!("{}", sma), "SMA(7)");
+    }
+
+    #[test]
+    fn test_output_info() {
+        let mut macd =
+            IndicatorKind::from(MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap());
+        assert_eq!(macd.output_fields(), ["macd", "signal", "histogram"]);
+        assert_eq!(macd.pane(), Pane::Separate);
+        assert_eq!(macd.next(&item(2.0)).output_values(), [0.0, 0.0, 0.0]);
+
+        let rsi = IndicatorKind::from(RelativeStrengthIndex::new(9).unwrap());
+        assert_eq!(rsi.output_fields(), ["value"]);
+        assert_eq!(rsi.output_range(), Some((0.0, 100.0)));
+    }
+}
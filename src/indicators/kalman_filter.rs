@@ -0,0 +1,246 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Current, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// One-dimensional Kalman filter (KALMAN).
+///
+/// Smooths the input with a Kalman filter that models the price as a random walk observed
+/// with noise. Unlike moving averages it has no fixed window: the gain adapts to the ratio of
+/// the process noise to the measurement noise, so the filter follows the price more closely
+/// the larger the process noise is.
+///
+/// # Formula
+///
+/// Prediction:
+///
+/// P<sub>t|t-1</sub> = P<sub>t-1</sub> + Q
+///
+/// Update:
+///
+/// K<sub>t</sub> = P<sub>t|t-1</sub> / (P<sub>t|t-1</sub> + R)
+///
+/// x<sub>t</sub> = x<sub>t-1</sub> + K<sub>t</sub> * (p<sub>t</sub> - x<sub>t-1</sub>)
+///
+/// P<sub>t</sub> = (1 - K<sub>t</sub>) * P<sub>t|t-1</sub>
+///
+/// Where:
+///
+/// * _x<sub>t</sub>_ - estimated value at a point of time _t_
+/// * _P<sub>t</sub>_ - variance of the estimate
+/// * _K<sub>t</sub>_ - Kalman gain
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+/// * _Q_ - process noise
+/// * _R_ - measurement noise
+///
+/// The first input value is used as the initial estimate, with the variance _R_.
+///
+/// # Parameters
+///
+/// * _process_noise_ - variance _Q_ of the changes of the true value (number greater than or equal to 0). Default is 0.01.
+/// * _measurement_noise_ - variance _R_ of the noise of the inputs (number greater than 0). Default is 1.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KalmanFilter;
+/// use ta::Next;
+///
+/// let mut kalman = KalmanFilter::new(1.0, 1.0).unwrap();
+/// assert_eq!(kalman.next(10.0), 10.0);
+/// assert_eq!((kalman.next(11.0) * 1000.0).round() / 1000.0, 10.667);
+/// assert_eq!((kalman.next(12.0) * 1000.0).round() / 1000.0, 11.5);
+/// ```
+///
+/// # Links
+///
+/// * [Kalman filter, Wikipedia](https://en.wikipedia.org/wiki/Kalman_filter)
+///
+#[doc(alias = "KALMAN")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KalmanFilter {
+    process_noise: f64,
+    measurement_noise: f64,
+    estimate: f64,
+    variance: f64,
+    gain: f64,
+    is_new: bool,
+}
+
+impl KalmanFilter {
+    pub fn new(process_noise: f64, measurement_noise: f64) -> Result<Self> {
+        let is_valid = process_noise >= 0.0
+            && process_noise.is_finite()
+            && measurement_noise > 0.0
+            && measurement_noise.is_finite();
+        if !is_valid {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            process_noise,
+            measurement_noise,
+            estimate: 0.0,
+            variance: 0.0,
+            gain: 0.0,
+            is_new: true,
+        })
+    }
+
+    pub fn process_noise(&self) -> f64 {
+        self.process_noise
+    }
+
+    pub fn measurement_noise(&self) -> f64 {
+        self.measurement_noise
+    }
+
+    /// Returns the Kalman gain of the last update.
+    pub fn gain(&self) -> f64 {
+        self.gain
+    }
+}
+
+impl Next<f64> for KalmanFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            self.is_new = false;
+            self.estimate = input;
+            self.variance = self.measurement_noise;
+        } else {
+            let predicted_variance = self.variance + self.process_noise;
+            self.gain = predicted_variance / (predicted_variance + self.measurement_noise);
+            self.estimate += self.gain * (input - self.estimate);
+            self.variance = (1.0 - self.gain) * predicted_variance;
+        }
+        self.estimate
+    }
+}
+
+impl<T: Close> Next<&T> for KalmanFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for KalmanFilter {
+    fn reset(&mut self) {
+        self.estimate = 0.0;
+        self.variance = 0.0;
+        self.gain = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Current for KalmanFilter {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.is_new {
+            None
+        } else {
+            Some(self.estimate)
+        }
+    }
+}
+
+impl Default for KalmanFilter {
+    fn default() -> Self {
+        Self::new(0.01, 1.0).unwrap()
+    }
+}
+
+impl fmt::Display for KalmanFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KALMAN({}, {})",
+            self.process_noise, self.measurement_noise
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(KalmanFilter);
+
+    #[test]
+    fn test_new() {
+        assert!(KalmanFilter::new(-0.1, 1.0).is_err());
+        assert!(KalmanFilter::new(0.1, 0.0).is_err());
+        assert!(KalmanFilter::new(f64::NAN, 1.0).is_err());
+        assert!(KalmanFilter::new(f64::INFINITY, 1.0).is_err());
+        assert!(KalmanFilter::new(0.1, f64::NAN).is_err());
+        assert!(KalmanFilter::new(0.1, f64::INFINITY).is_err());
+        assert!(KalmanFilter::new(0.0, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kalman = KalmanFilter::new(0.1, 1.0).unwrap();
+
+        assert_eq!(kalman.next(10.0), 10.0);
+        assert_eq!(round(kalman.next(11.0)), 10.524);
+        assert_eq!(round(kalman.next(12.0)), 11.091);
+        assert_eq!(round(kalman.next(11.0)), 11.061);
+        assert_eq!(round(kalman.next(13.0)), 11.641);
+        assert_eq!(round(kalman.next(14.0)), 12.313);
+    }
+
+    #[test]
+    fn test_next_without_process_noise() {
+        // With Q = 0, the estimate is the cumulative average
+        let mut kalman = KalmanFilter::new(0.0, 1.0).unwrap();
+
+        assert_eq!(kalman.next(10.0), 10.0);
+        assert_eq!(round(kalman.next(12.0)), 11.0);
+        assert_eq!(round(kalman.next(14.0)), 12.0);
+        assert_eq!(round(kalman.gain()), 0.333);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut kalman = KalmanFilter::new(0.1, 1.0).unwrap();
+
+        assert_eq!(kalman.next(&bar(10.0)), 10.0);
+        assert_eq!(round(kalman.next(&bar(11.0))), 10.524);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kalman = KalmanFilter::new(0.1, 1.0).unwrap();
+
+        kalman.next(10.0);
+        kalman.next(11.0);
+
+        kalman.reset();
+
+        assert_eq!(kalman.next(20.0), 20.0);
+        assert_eq!(kalman.gain(), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        KalmanFilter::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = KalmanFilter::new(0.01, 1.0).unwrap();
+        assert_eq!(format!("{}", indicator), "KALMAN(0.01, 1)");
+    }
+}
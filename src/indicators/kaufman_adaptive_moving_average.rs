@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::EfficiencyRatio;
+use crate::{Close, Current, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's Adaptive Moving Average (KAMA).
+///
+/// Developed by Perry Kaufman, KAMA is a moving average designed to account for market noise
+/// or volatility. It closely follows prices when the price swings are relatively small
+/// and the noise is low, and adjusts when the price swings widen.
+///
+/// The smoothing constant is modulated by the [Efficiency Ratio](struct.EfficiencyRatio.html):
+/// the more efficient (trending) the market is, the closer the smoothing gets to the fast EMA.
+///
+/// # Formula
+///
+/// KAMA<sub>t</sub> = KAMA<sub>t-1</sub> + SC * (p<sub>t</sub> - KAMA<sub>t-1</sub>)
+///
+/// SC = (ER * (fast SC - slow SC) + slow SC)<sup>2</sup>
+///
+/// Where:
+///
+/// * _ER_ - [Efficiency Ratio](struct.EfficiencyRatio.html) for the last _er_period_ periods
+/// * _fast SC_ = 2 / (_fast_period_ + 1)
+/// * _slow SC_ = 2 / (_slow_period_ + 1)
+/// * _p<sub>t</sub>_ - input value at a time period _t_
+///
+/// The first input value is used as the initial value of KAMA.
+///
+/// # Parameters
+///
+/// * _er_period_ - number of periods for the efficiency ratio (integer greater than 0). Default is 10.
+/// * _fast_period_ - period of the fastest EMA (integer greater than 0). Default is 2.
+/// * _slow_period_ - period of the slowest EMA (integer greater than 0). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KaufmanAdaptiveMovingAverage;
+/// use ta::Next;
+///
+/// let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 30).unwrap();
+/// assert_eq!(kama.next(10.0), 10.0);
+/// assert_eq!((kama.next(11.0) * 1000.0).round() / 1000.0, 10.444);
+/// ```
+///
+/// # Links
+///
+/// * [Kaufman's Adaptive Moving Average, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average)
+///
+#[doc(alias = "KAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KaufmanAdaptiveMovingAverage {
+    fast_period: usize,
+    slow_period: usize,
+    fast_sc: f64,
+    slow_sc: f64,
+    er: EfficiencyRatio,
+    current: f64,
+    is_new: bool,
+}
+
+impl KaufmanAdaptiveMovingAverage {
+    pub fn new(er_period: usize, fast_period: usize, slow_period: usize) -> Result<Self> {
+        if fast_period == 0 || slow_period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            fast_period,
+            slow_period,
+            fast_sc: 2.0 / (fast_period + 1) as f64,
+            slow_sc: 2.0 / (slow_period + 1) as f64,
+            er: EfficiencyRatio::new(er_period)?,
+            current: 0.0,
+            is_new: true,
+        })
+    }
+}
+
+impl Period for KaufmanAdaptiveMovingAverage {
+    fn period(&self) -> usize {
+        self.er.period()
+    }
+}
+
+impl Next<f64> for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let er = self.er.next(input);
+
+        if self.is_new {
+            self.is_new = false;
+            self.current = input;
+        } else {
+            // When the price does not move at all, the efficiency ratio is 0 / 0
+            let er = if er.is_nan() { 0.0 } else { er };
+            let sc = (er * (self.fast_sc - self.slow_sc) + self.slow_sc).powi(2);
+            self.current += sc * (input - self.current);
+        }
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for KaufmanAdaptiveMovingAverage {
+    fn reset(&mut self) {
+        self.er.reset();
+        self.current = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Current for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.is_new {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
+}
+
+impl Default for KaufmanAdaptiveMovingAverage {
+    fn default() -> Self {
+        Self::new(10, 2, 30).unwrap()
+    }
+}
+
+impl fmt::Display for KaufmanAdaptiveMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KAMA({}, {}, {})",
+            self.er.period(),
+            self.fast_period,
+            self.slow_period
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Kama = KaufmanAdaptiveMovingAverage;
+
+    test_indicator!(Kama);
+
+    #[test]
+    fn test_new() {
+        assert!(Kama::new(0, 2, 30).is_err());
+        assert!(Kama::new(10, 0, 30).is_err());
+        assert!(Kama::new(10, 2, 0).is_err());
+        assert!(Kama::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kama = Kama::new(3, 2, 30).unwrap();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(round(kama.next(11.0)), 10.444);
+        assert_eq!(round(kama.next(11.5)), 10.914);
+        assert_eq!(round(kama.next(10.5)), 10.899);
+        assert_eq!(round(kama.next(12.0)), 10.977);
+        assert_eq!(round(kama.next(13.0)), 11.187);
+        assert_eq!(round(kama.next(12.5)), 11.472);
+    }
+
+    #[test]
+    fn test_next_same_values() {
+        let mut kama = Kama::new(3, 2, 30).unwrap();
+
+        assert_eq!(kama.next(5.0), 5.0);
+        assert_eq!(kama.next(5.0), 5.0);
+        assert_eq!(kama.next(5.0), 5.0);
+        assert_eq!(kama.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut kama = Kama::new(3, 2, 30).unwrap();
+
+        assert_eq!(kama.next(&bar(10.0)), 10.0);
+        assert_eq!(round(kama.next(&bar(11.0))), 10.444);
+        assert_eq!(round(kama.next(&bar(11.5))), 10.914);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kama = Kama::new(3, 2, 30).unwrap();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(round(kama.next(11.0)), 10.444);
+
+        kama.reset();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(round(kama.next(11.0)), 10.444);
+    }
+
+    #[test]
+    fn test_default() {
+        Kama::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Kama::new(10, 2, 30).unwrap();
+        assert_eq!(format!("{}", indicator), "KAMA(10, 2, 30)");
+    }
+}
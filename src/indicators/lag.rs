@@ -0,0 +1,193 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Lag.
+///
+/// Returns the input value given _period_ calls ago, or `None` until that many values were
+/// given. It is useful for displaced indicators and for comparisons like the close against
+/// the close 5 bars ago.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods to shift by (integer greater than 0). Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Lag;
+/// use ta::Next;
+///
+/// let mut lag = Lag::new(2).unwrap();
+/// assert_eq!(lag.next(10.0), None);
+/// assert_eq!(lag.next(11.0), None);
+/// assert_eq!(lag.next(12.0), Some(10.0));
+/// assert_eq!(lag.next(13.0), Some(11.0));
+/// ```
+///
+/// # Links
+///
+/// * [Lag operator, Wikipedia](https://en.wikipedia.org/wiki/Lag_operator)
+///
+#[doc(alias = "SHIFT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Lag {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl Lag {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for Lag {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for Lag {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for Lag {
+    type Output = Option<f64>;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+            None
+        } else {
+            Some(old_val)
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Lag {
+    type Output = Option<f64>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Lag {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for Lag {
+    fn default() -> Self {
+        Self::new(1).unwrap()
+    }
+}
+
+impl fmt::Display for Lag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LAG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Lag);
+
+    #[test]
+    fn test_new() {
+        assert!(Lag::new(0).is_err());
+        assert!(Lag::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut lag = Lag::new(3).unwrap();
+
+        assert_eq!(lag.next(10.0), None);
+        assert_eq!(lag.next(11.0), None);
+        assert_eq!(lag.next(12.0), None);
+        assert_eq!(lag.next(13.0), Some(10.0));
+        assert_eq!(lag.next(14.0), Some(11.0));
+        assert_eq!(lag.next(15.0), Some(12.0));
+        assert_eq!(lag.next(16.0), Some(13.0));
+    }
+
+    #[test]
+    fn test_next_one() {
+        let mut lag = Lag::new(1).unwrap();
+
+        assert_eq!(lag.next(10.0), None);
+        assert_eq!(lag.next(11.0), Some(10.0));
+        assert_eq!(lag.next(12.0), Some(11.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut lag = Lag::new(1).unwrap();
+
+        assert_eq!(lag.next(&bar(10.0)), None);
+        assert_eq!(lag.next(&bar(11.0)), Some(10.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut lag = Lag::new(1).unwrap();
+
+        lag.next(10.0);
+        lag.next(11.0);
+
+        lag.reset();
+
+        assert_eq!(lag.next(12.0), None);
+        assert_eq!(lag.next(13.0), Some(12.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Lag::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Lag::new(5).unwrap();
+        assert_eq!(format!("{}", indicator), "LAG(5)");
+    }
+}
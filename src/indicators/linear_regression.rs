@@ -0,0 +1,339 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Linear regression (LINREG).
+///
+/// Fits a straight line through the last _period_ values using the least squares method,
+/// where _x_ is the position of a value in the time frame (0 for the oldest value)
+/// and _y_ is the value itself.
+///
+/// The sums required for the fit are maintained incrementally, so every update takes constant time.
+///
+/// # Formula
+///
+/// slope = (n * Σxy - Σx * Σy) / (n * Σx<sup>2</sup> - (Σx)<sup>2</sup>)
+///
+/// intercept = (Σy - slope * Σx) / n
+///
+/// forecast = intercept + slope * n
+///
+/// r<sup>2</sup> = (n * Σxy - Σx * Σy)<sup>2</sup> / ((n * Σx<sup>2</sup> - (Σx)<sup>2</sup>) * (n * Σy<sup>2</sup> - (Σy)<sup>2</sup>))
+///
+/// Where:
+///
+/// * _n_ - number of values in the time frame
+/// * _intercept_ - value of the regression line at the oldest value of the time frame
+/// * _forecast_ - value of the regression line projected one period ahead
+/// * _r<sup>2</sup>_ - coefficient of determination, in the range of 0..1. It is 0 when all values are equal.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LinearRegression;
+/// use ta::Next;
+///
+/// let mut linreg = LinearRegression::new(3).unwrap();
+/// linreg.next(1.0);
+/// linreg.next(3.0);
+/// let out = linreg.next(5.0);
+///
+/// assert_eq!(out.slope, 2.0);
+/// assert_eq!(out.intercept, 1.0);
+/// assert_eq!(out.forecast, 7.0);
+/// assert_eq!(out.r_squared, 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Linear regression, Wikipedia](https://en.wikipedia.org/wiki/Simple_linear_regression)
+/// * [Linear Regression Indicator, Investopedia](https://www.investopedia.com/terms/l/linearregression.asp)
+///
+#[doc(alias = "LINREG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegression {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_y: f64,
+    sum_xy: f64,
+    sum_y2: f64,
+    deque: Box<[f64]>,
+}
+
+impl LinearRegression {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_y: 0.0,
+                sum_xy: 0.0,
+                sum_y2: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    fn sum_x(&self) -> f64 {
+        let n = self.count as f64;
+        n * (n - 1.0) / 2.0
+    }
+
+    fn sum_x2(&self) -> f64 {
+        let n = self.count as f64;
+        (n - 1.0) * n * (2.0 * n - 1.0) / 6.0
+    }
+
+    // Returns (slope, intercept) of the current time frame.
+    fn fit(&self) -> (f64, f64) {
+        let n = self.count as f64;
+        let sum_x = self.sum_x();
+        let denominator = n * self.sum_x2() - sum_x * sum_x;
+
+        let slope = if denominator == 0.0 {
+            0.0
+        } else {
+            (n * self.sum_xy - sum_x * self.sum_y) / denominator
+        };
+        let intercept = (self.sum_y - slope * sum_x) / n;
+
+        (slope, intercept)
+    }
+
+    pub(super) fn r_squared(&self) -> f64 {
+        let n = self.count as f64;
+        let sum_x = self.sum_x();
+        let x_variance = n * self.sum_x2() - sum_x * sum_x;
+        let y_variance = n * self.sum_y2 - self.sum_y * self.sum_y;
+
+        if x_variance <= 0.0 || y_variance <= 0.0 {
+            0.0
+        } else {
+            let covariance = n * self.sum_xy - sum_x * self.sum_y;
+            (covariance * covariance / (x_variance * y_variance)).min(1.0)
+        }
+    }
+
+    /// Returns the value of the regression line at the most recent value of the time frame.
+    pub(super) fn endpoint(&self) -> f64 {
+        let (slope, intercept) = self.fit();
+        intercept + slope * (self.count as f64 - 1.0)
+    }
+
+    /// Returns the standard error of the estimate for the current time frame.
+    pub(super) fn standard_error(&self) -> f64 {
+        if self.count <= 2 {
+            return 0.0;
+        }
+
+        let (slope, intercept) = self.fit();
+        let sse = self.sum_y2 - intercept * self.sum_y - slope * self.sum_xy;
+        (sse.max(0.0) / (self.count as f64 - 2.0)).sqrt()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionOutput {
+    pub slope: f64,
+    pub intercept: f64,
+    pub forecast: f64,
+    pub r_squared: f64,
+}
+
+impl From<LinearRegressionOutput> for (f64, f64, f64, f64) {
+    fn from(lo: LinearRegressionOutput) -> Self {
+        (lo.slope, lo.intercept, lo.forecast, lo.r_squared)
+    }
+}
+
+impl Period for LinearRegression {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.sum_xy += self.count as f64 * input;
+            self.count += 1;
+        } else {
+            // The oldest value (x = 0) drops out and every other value moves one position back
+            self.sum_xy += (self.period - 1) as f64 * input - (self.sum_y - old_val);
+            self.sum_y -= old_val;
+            self.sum_y2 -= old_val * old_val;
+        }
+        self.sum_y += input;
+        self.sum_y2 += input * input;
+
+        let (slope, intercept) = self.fit();
+
+        LinearRegressionOutput {
+            slope,
+            intercept,
+            forecast: intercept + slope * self.count as f64,
+            r_squared: self.r_squared(),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LinearRegression {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_y = 0.0;
+        self.sum_xy = 0.0;
+        self.sum_y2 = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINREG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(LinearRegression);
+
+    fn round(nums: (f64, f64, f64, f64)) -> (f64, f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        let n3 = (nums.3 * 1000.0).round() / 1000.0;
+        (n0, n1, n2, n3)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegression::new(0).is_err());
+        assert!(LinearRegression::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut linreg = LinearRegression::new(4).unwrap();
+
+        assert_eq!(round(linreg.next(10.0).into()), (0.0, 10.0, 10.0, 0.0));
+        assert_eq!(round(linreg.next(12.0).into()), (2.0, 10.0, 14.0, 1.0));
+        assert_eq!(round(linreg.next(11.0).into()), (0.5, 10.5, 12.0, 0.25));
+        assert_eq!(round(linreg.next(14.0).into()), (1.1, 10.1, 14.5, 0.691));
+        assert_eq!(round(linreg.next(13.0).into()), (0.6, 11.6, 14.0, 0.36));
+        assert_eq!(round(linreg.next(15.0).into()), (1.1, 11.6, 16.0, 0.691));
+        assert_eq!(round(linreg.next(15.0).into()), (0.5, 13.5, 15.5, 0.455));
+    }
+
+    #[test]
+    fn test_next_same_values() {
+        let mut linreg = LinearRegression::new(3).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(round(linreg.next(7.0).into()), (0.0, 7.0, 7.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_standard_error() {
+        let mut linreg = LinearRegression::new(4).unwrap();
+
+        linreg.next(10.0);
+        linreg.next(12.0);
+        assert_eq!(linreg.standard_error(), 0.0);
+
+        linreg.next(11.0);
+        assert_eq!(round4(linreg.standard_error()), 1.2247);
+        assert_eq!(round4(linreg.endpoint()), 11.5);
+
+        linreg.next(14.0);
+        assert_eq!(round4(linreg.standard_error()), 1.1619);
+    }
+
+    fn round4(num: f64) -> f64 {
+        (num * 10000.0).round() / 10000.0
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut linreg = LinearRegression::new(4).unwrap();
+
+        assert_eq!(
+            round(linreg.next(&bar(10.0)).into()),
+            (0.0, 10.0, 10.0, 0.0)
+        );
+        assert_eq!(
+            round(linreg.next(&bar(12.0)).into()),
+            (2.0, 10.0, 14.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut linreg = LinearRegression::new(4).unwrap();
+
+        linreg.next(10.0);
+        linreg.next(12.0);
+
+        linreg.reset();
+
+        assert_eq!(round(linreg.next(12.0).into()), (0.0, 12.0, 12.0, 0.0));
+        assert_eq!(round(linreg.next(10.0).into()), (-2.0, 12.0, 8.0, 1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegression::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = LinearRegression::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "LINREG(20)");
+    }
+}
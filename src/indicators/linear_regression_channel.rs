@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::LinearRegression;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Linear regression channel (LRC).
+///
+/// The middle line is the value of the [linear regression](struct.LinearRegression.html)
+/// line at the current bar. The upper and lower lines are shifted from it by a multiple
+/// of the standard error of the estimate.
+///
+/// # Formula
+///
+/// * _Middle_ = intercept + slope * (n - 1)
+/// * _Upper_ = Middle + SE * multiplier
+/// * _Lower_ = Middle - SE * multiplier
+///
+/// Where:
+///
+/// * _n_ - number of values in the time frame
+/// * _SE_ = sqrt(Σ(y - ŷ)<sup>2</sup> / (n - 2)), standard error of the estimate. It is 0 while less than 3 values were given.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+/// * _multiplier_ - number of standard errors between the middle and outer lines. Default is 2.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LinearRegressionChannel;
+/// use ta::Next;
+///
+/// let mut lrc = LinearRegressionChannel::new(3, 2.0).unwrap();
+/// lrc.next(1.0);
+/// lrc.next(3.0);
+/// let out = lrc.next(5.0);
+///
+/// assert_eq!(out.middle, 5.0);
+/// assert_eq!(out.upper, 5.0);
+/// assert_eq!(out.lower, 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Linear Regression Channel, StockCharts](https://chartschool.stockcharts.com/table-of-contents/chart-analysis/chart-annotation-tools/raff-regression-channel)
+///
+#[doc(alias = "LRC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegressionChannel {
+    multiplier: f64,
+    linreg: LinearRegression,
+}
+
+impl LinearRegressionChannel {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            multiplier,
+            linreg: LinearRegression::new(period)?,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionChannelOutput {
+    pub middle: f64,
+    pub upper: f64,
+    pub lower: f64,
+}
+
+impl From<LinearRegressionChannelOutput> for (f64, f64, f64) {
+    fn from(lo: LinearRegressionChannelOutput) -> Self {
+        (lo.middle, lo.upper, lo.lower)
+    }
+}
+
+impl Period for LinearRegressionChannel {
+    fn period(&self) -> usize {
+        self.linreg.period()
+    }
+}
+
+impl Next<f64> for LinearRegressionChannel {
+    type Output = LinearRegressionChannelOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.linreg.next(input);
+
+        let middle = self.linreg.endpoint();
+        let width = self.linreg.standard_error() * self.multiplier;
+
+        LinearRegressionChannelOutput {
+            middle,
+            upper: middle + width,
+            lower: middle - width,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegressionChannel {
+    type Output = LinearRegressionChannelOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LinearRegressionChannel {
+    fn reset(&mut self) {
+        self.linreg.reset();
+    }
+}
+
+impl Default for LinearRegressionChannel {
+    fn default() -> Self {
+        Self::new(14, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegressionChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LRC({}, {})", self.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(LinearRegressionChannel);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        (n0, n1, n2)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegressionChannel::new(0, 2.0).is_err());
+        assert!(LinearRegressionChannel::new(1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut lrc = LinearRegressionChannel::new(4, 2.0).unwrap();
+
+        assert_eq!(round(lrc.next(10.0).into()), (10.0, 10.0, 10.0));
+        assert_eq!(round(lrc.next(12.0).into()), (12.0, 12.0, 12.0));
+        assert_eq!(round(lrc.next(11.0).into()), (11.5, 13.949, 9.051));
+        assert_eq!(round(lrc.next(14.0).into()), (13.4, 15.724, 11.076));
+        assert_eq!(round(lrc.next(13.0).into()), (13.4, 15.93, 10.87));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut lrc = LinearRegressionChannel::new(4, 2.0).unwrap();
+
+        assert_eq!(round(lrc.next(&bar(10.0)).into()), (10.0, 10.0, 10.0));
+        assert_eq!(round(lrc.next(&bar(12.0)).into()), (12.0, 12.0, 12.0));
+        assert_eq!(round(lrc.next(&bar(11.0)).into()), (11.5, 13.949, 9.051));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut lrc = LinearRegressionChannel::new(4, 2.0).unwrap();
+
+        lrc.next(10.0);
+        lrc.next(12.0);
+        lrc.next(11.0);
+
+        lrc.reset();
+
+        assert_eq!(round(lrc.next(5.0).into()), (5.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegressionChannel::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = LinearRegressionChannel::new(20, 2.5).unwrap();
+        assert_eq!(format!("{}", indicator), "LRC(20, 2.5)");
+    }
+}
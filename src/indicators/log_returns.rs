@@ -0,0 +1,146 @@
+use std::fmt;
+
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Logarithmic returns.
+///
+/// Natural logarithm of the ratio of the input to the previous value. Unlike
+/// [simple returns](struct.SimpleReturns.html), log returns add up over time, which makes
+/// them the common input of volatility estimators.
+///
+/// # Formula
+///
+/// r<sub>t</sub> = ln(p<sub>t</sub> / p<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// The first return, and returns involving values which are not positive, are 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LogReturns;
+/// use ta::Next;
+///
+/// let mut returns = LogReturns::new();
+/// assert_eq!(returns.next(10.0), 0.0);
+/// assert_eq!(returns.next(10.0 * std::f64::consts::E), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Logarithmic return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return#Logarithmic_or_continuously_compounded_return)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LogReturns {
+    prev: Option<f64>,
+}
+
+impl LogReturns {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl Next<f64> for LogReturns {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        match self.prev.replace(input) {
+            Some(prev) if prev > 0.0 && input > 0.0 => (input / prev).ln(),
+            _ => 0.0,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LogReturns {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LogReturns {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for LogReturns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for LogReturns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LOG_RETURNS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(LogReturns);
+
+    #[test]
+    fn test_next() {
+        let mut returns = LogReturns::new();
+
+        assert_eq!(returns.next(10.0), 0.0);
+        assert_eq!(round(returns.next(12.0)), 0.182);
+        assert_eq!(round(returns.next(9.0)), -0.288);
+        assert_eq!(returns.next(9.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_not_positive() {
+        let mut returns = LogReturns::new();
+
+        assert_eq!(returns.next(0.0), 0.0);
+        assert_eq!(returns.next(5.0), 0.0);
+        assert_eq!(returns.next(-5.0), 0.0);
+        assert_eq!(returns.next(5.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut returns = LogReturns::new();
+
+        assert_eq!(returns.next(&bar(10.0)), 0.0);
+        assert_eq!(round(returns.next(&bar(12.0))), 0.182);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut returns = LogReturns::new();
+
+        returns.next(10.0);
+        returns.reset();
+
+        assert_eq!(returns.next(12.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        LogReturns::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = LogReturns::new();
+        assert_eq!(format!("{}", indicator), "LOG_RETURNS");
+    }
+}
@@ -1,8 +1,8 @@
-use std::f64::INFINITY;
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{High, Next, Period, Reset};
+use crate::helpers::{push_ring, sliding_extreme};
+use crate::{High, Lookback, Next, NextSlice, Period, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,7 @@ pub struct Maximum {
     max_index: usize,
     cur_index: usize,
     deque: Box<[f64]>,
+    is_new: bool,
 }
 
 impl Maximum {
@@ -42,17 +43,31 @@ impl Maximum {
                 period,
                 max_index: 0,
                 cur_index: 0,
-                deque: vec![-INFINITY; period].into_boxed_slice(),
+                deque: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
+                is_new: true,
             }),
         }
     }
 
+    /// Returns the number of periods passed since the highest value in the time frame.
+    ///
+    /// Returns 0 when the highest value is the most recent input. If the highest value
+    /// occurs several times, the most recent occurrence is taken.
+    pub fn bars_since_max(&self) -> usize {
+        let last_index = (self.cur_index + self.period - 1) % self.period;
+        (last_index + self.period - self.max_index) % self.period
+    }
+
+    // Scans the values from the oldest to the newest one, so that the most recent
+    // occurrence wins when there are several equal extremes.
     fn find_max_index(&self) -> usize {
-        let mut max = -INFINITY;
+        let mut max = f64::NEG_INFINITY;
         let mut index: usize = 0;
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val > max {
+        for offset in 1..=self.period {
+            let i = (self.cur_index + offset) % self.period;
+            let val = self.deque[i];
+            if val >= max {
                 max = val;
                 index = i;
             }
@@ -68,16 +83,23 @@ impl Period for Maximum {
     }
 }
 
+impl Lookback for Maximum {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for Maximum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.is_new = false;
         self.deque[self.cur_index] = input;
 
-        if input > self.deque[self.max_index] {
-            self.max_index = self.cur_index;
-        } else if self.max_index == self.cur_index {
+        if self.max_index == self.cur_index {
             self.max_index = self.find_max_index();
+        } else if input >= self.deque[self.max_index] {
+            self.max_index = self.cur_index;
         }
 
         self.cur_index = if self.cur_index + 1 < self.period {
@@ -98,10 +120,59 @@ impl<T: High> Next<&T> for Maximum {
     }
 }
 
+impl NextSlice for Maximum {
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]) {
+        assert_eq!(inputs.len(), out.len());
+        if inputs.is_empty() {
+            return;
+        }
+
+        // The windows of the batch start with the latest values of the ring buffer, where
+        // the unused slots hold negative infinity, which never wins
+        let mut values = Vec::with_capacity(self.period - 1 + inputs.len());
+        for offset in 1..self.period {
+            values.push(self.deque[(self.cur_index + offset) % self.period]);
+        }
+        values.extend_from_slice(inputs);
+        sliding_extreme(&values, self.period, out, f64::max);
+
+        self.is_new = false;
+        self.cur_index = push_ring(&mut self.deque, self.cur_index, inputs);
+        self.max_index = self.find_max_index();
+    }
+}
+
+impl UpdateLast<f64> for Maximum {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
+        }
+
+        // Scans from the oldest value as next does, with the latest slot as current
+        let last = (self.cur_index + self.period - 1) % self.period;
+        let cur_index = self.cur_index;
+        self.cur_index = last;
+        self.deque[last] = input;
+        self.max_index = self.find_max_index();
+        self.cur_index = cur_index;
+
+        self.deque[self.max_index]
+    }
+}
+
+impl<T: High> UpdateLast<&T> for Maximum {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.high())
+    }
+}
+
 impl Reset for Maximum {
     fn reset(&mut self) {
+        self.max_index = 0;
+        self.cur_index = 0;
+        self.is_new = true;
         for i in 0..self.period {
-            self.deque[i] = -INFINITY;
+            self.deque[i] = f64::NEG_INFINITY;
         }
     }
 }
@@ -160,6 +231,46 @@ mod tests {
         assert_eq!(max.next(&bar(2.0)), 3.5);
     }
 
+    #[test]
+    fn test_bars_since_max() {
+        let mut max = Maximum::new(3).unwrap();
+
+        max.next(4.0);
+        assert_eq!(max.bars_since_max(), 0);
+        max.next(1.2);
+        assert_eq!(max.bars_since_max(), 1);
+        max.next(5.0);
+        assert_eq!(max.bars_since_max(), 0);
+        max.next(3.0);
+        assert_eq!(max.bars_since_max(), 1);
+        max.next(5.0);
+        assert_eq!(max.bars_since_max(), 0);
+        max.next(0.0);
+        assert_eq!(max.bars_since_max(), 1);
+        max.next(-1.0);
+        assert_eq!(max.bars_since_max(), 2);
+        max.next(-2.0);
+        assert_eq!(max.bars_since_max(), 2);
+    }
+
+    #[test]
+    fn test_update_last() {
+        let mut max = Maximum::new(3).unwrap();
+        let mut expected = Maximum::new(3).unwrap();
+        assert_eq!(max.update_last(4.0), expected.next(4.0));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            max.next(close + 3.0);
+            max.update_last(close - 1.0);
+            assert_eq!(round(max.update_last(close)), round(expected.next(close)));
+        }
+    }
+
+    #[test]
+    fn test_next_slice() {
+        assert_next_slice(Maximum::new(9).unwrap());
+    }
+
     #[test]
     fn test_reset() {
         let mut max = Maximum::new(100).unwrap();
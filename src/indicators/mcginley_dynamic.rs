@@ -0,0 +1,224 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Current, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// McGinley Dynamic (MD).
+///
+/// Developed by John R. McGinley, the McGinley Dynamic is a moving average that adjusts
+/// its speed to the market. It speeds up in falling markets and slows down in rising ones,
+/// reducing separation from the price and avoiding whipsaws.
+///
+/// # Formula
+///
+/// MD<sub>t</sub> = MD<sub>t-1</sub> + (p<sub>t</sub> - MD<sub>t-1</sub>) / (_period_ * (p<sub>t</sub> / MD<sub>t-1</sub>)<sup>4</sup>)
+///
+/// Where:
+///
+/// * _MD<sub>t</sub>_ - value of McGinley Dynamic at a point of time _t_
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// The first input value is used as the initial value of MD. MD also starts over from the
+/// input value when the ratio of the input to the previous value isn't positive, e.g. for an
+/// input of 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::McGinleyDynamic;
+/// use ta::Next;
+///
+/// let mut md = McGinleyDynamic::new(3).unwrap();
+/// assert_eq!(md.next(10.0), 10.0);
+/// assert_eq!((md.next(11.0) * 1000.0).round() / 1000.0, 10.228);
+/// ```
+///
+/// # Links
+///
+/// * [McGinley Dynamic, Investopedia](https://www.investopedia.com/articles/forex/09/mcginley-dynamic-indicator.asp)
+///
+#[doc(alias = "MD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct McGinleyDynamic {
+    period: usize,
+    current: f64,
+    is_new: bool,
+}
+
+impl McGinleyDynamic {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                current: 0.0,
+                is_new: true,
+            }),
+        }
+    }
+}
+
+impl Period for McGinleyDynamic {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for McGinleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            self.is_new = false;
+            self.current = input;
+            return self.current;
+        }
+
+        // The update is undefined when the ratio isn't positive, e.g. for an input or a previous
+        // value of 0, or when the divisor underflows, so start over from the input
+        let ratio = input / self.current;
+        let divisor = self.period as f64 * ratio.powi(4);
+        if ratio > 0.0 && divisor > 0.0 && divisor.is_finite() {
+            self.current += (input - self.current) / divisor;
+        } else {
+            self.current = input;
+        }
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for McGinleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for McGinleyDynamic {
+    fn reset(&mut self) {
+        self.current = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Current for McGinleyDynamic {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.is_new {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
+}
+
+impl Default for McGinleyDynamic {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for McGinleyDynamic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MD({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(McGinleyDynamic);
+
+    #[test]
+    fn test_new() {
+        assert!(McGinleyDynamic::new(0).is_err());
+        assert!(McGinleyDynamic::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(round(md.next(11.0)), 10.228);
+        assert_eq!(round(md.next(12.0)), 10.539);
+        assert_eq!(round(md.next(11.0)), 10.669);
+        assert_eq!(round(md.next(13.0)), 11.021);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(0.0), 0.0);
+        assert_eq!(md.next(5.0), 5.0);
+        assert_eq!(md.next(5.0), 5.0);
+
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(md.next(0.0), 0.0);
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(round(md.next(11.0)), 10.228);
+    }
+
+    #[test]
+    fn test_next_invalid_ratio() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(md.next(-1.0), -1.0);
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(md.next(1e-100), 1e-100);
+        assert_eq!(md.next(10.0), 10.0);
+        assert!(md.next(f64::NAN).is_nan());
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(round(md.next(11.0)), 10.228);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(&bar(10.0)), 10.0);
+        assert_eq!(round(md.next(&bar(11.0))), 10.228);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(round(md.next(11.0)), 10.228);
+
+        md.reset();
+
+        assert_eq!(md.next(11.0), 11.0);
+    }
+
+    #[test]
+    fn test_default() {
+        McGinleyDynamic::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = McGinleyDynamic::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "MD(14)");
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use crate::{High, Lookback, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Median price (MEDPRICE).
+///
+/// The middle of the range of a single bar. It is often used as the input of other
+/// indicators, e.g. [Awesome Oscillator](struct.AwesomeOscillator.html).
+///
+/// # Formula
+///
+/// MEDPRICE = (High + Low) / 2
+///
+/// A plain value is returned unchanged, as its high and low are the same.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MedianPrice;
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(12.0).low(9.0).close(11.0).volume(1.0).build().unwrap();
+///
+/// let mut median = MedianPrice::new();
+/// assert_eq!(median.next(&bar), 10.5);
+/// ```
+///
+/// # Links
+///
+/// * [MEDPRICE, TA-Lib](https://ta-lib.org/functions/medprice/)
+///
+#[doc(alias = "MEDPRICE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MedianPrice {}
+
+impl MedianPrice {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Next<f64> for MedianPrice {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        input
+    }
+}
+
+impl<T: High + Low> Next<&T> for MedianPrice {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        (input.high() + input.low()) / 2.0
+    }
+}
+
+impl Reset for MedianPrice {
+    fn reset(&mut self) {}
+}
+
+impl Lookback for MedianPrice {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl Default for MedianPrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for MedianPrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDPRICE()")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(MedianPrice);
+
+    #[test]
+    fn test_next() {
+        let mut median = MedianPrice::new();
+
+        assert_eq!(median.next(10.0), 10.0);
+        assert_eq!(median.next(12.5), 12.5);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut median = MedianPrice::new();
+
+        assert_eq!(median.next(&bar(12.0, 9.0)), 10.5);
+        assert_eq!(median.next(&bar(10.0, 10.0)), 10.0);
+    }
+
+    #[test]
+    fn test_default() {
+        MedianPrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = MedianPrice::new();
+        assert_eq!(format!("{}", indicator), "MEDPRICE()");
+    }
+}
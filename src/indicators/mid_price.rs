@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Midpoint price (MIDPRICE).
+///
+/// Returns the middle of the price channel, i.e. the average of the highest high and the
+/// lowest low over the last _period_ bars. When given plain values, it is the midpoint
+/// of the highest and the lowest value.
+///
+/// # Formula
+///
+/// MIDPRICE = (Max(High, _period_) + Min(Low, _period_)) / 2
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MidPrice;
+/// use ta::Next;
+///
+/// let mut mid = MidPrice::new(3).unwrap();
+/// assert_eq!(mid.next(10.0), 10.0);
+/// assert_eq!(mid.next(14.0), 12.0);
+/// assert_eq!(mid.next(12.0), 12.0);
+/// assert_eq!(mid.next(16.0), 14.0);
+/// ```
+///
+/// # Links
+///
+/// * [MIDPRICE, TA-Lib](https://ta-lib.org/functions/midprice/)
+///
+#[doc(alias = "MIDPRICE")]
+#[doc(alias = "MIDPOINT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MidPrice {
+    max: Maximum,
+    min: Minimum,
+}
+
+impl MidPrice {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            max: Maximum::new(period)?,
+            min: Minimum::new(period)?,
+        })
+    }
+}
+
+impl Period for MidPrice {
+    fn period(&self) -> usize {
+        self.max.period()
+    }
+}
+
+impl Lookback for MidPrice {
+    fn lookback(&self) -> usize {
+        self.max.lookback().max(self.min.lookback())
+    }
+}
+
+impl Next<f64> for MidPrice {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        (self.max.next(input) + self.min.next(input)) / 2.0
+    }
+}
+
+impl<T: High + Low> Next<&T> for MidPrice {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        (self.max.next(input) + self.min.next(input)) / 2.0
+    }
+}
+
+impl Reset for MidPrice {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+    }
+}
+
+impl Default for MidPrice {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for MidPrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MIDPRICE({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(MidPrice);
+
+    #[test]
+    fn test_new() {
+        assert!(MidPrice::new(0).is_err());
+        assert!(MidPrice::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mid = MidPrice::new(3).unwrap();
+
+        assert_eq!(mid.next(10.0), 10.0);
+        assert_eq!(mid.next(14.0), 12.0);
+        assert_eq!(mid.next(12.0), 12.0);
+        assert_eq!(mid.next(16.0), 14.0);
+        assert_eq!(mid.next(15.0), 14.0);
+        assert_eq!(mid.next(15.0), 15.5);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut mid = MidPrice::new(2).unwrap();
+
+        assert_eq!(mid.next(&bar(11.0, 9.0)), 10.0);
+        assert_eq!(mid.next(&bar(13.0, 10.0)), 11.0);
+        assert_eq!(mid.next(&bar(12.0, 11.0)), 11.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mid = MidPrice::new(3).unwrap();
+
+        assert_eq!(mid.next(10.0), 10.0);
+        assert_eq!(mid.next(14.0), 12.0);
+
+        mid.reset();
+
+        assert_eq!(mid.next(20.0), 20.0);
+    }
+
+    #[test]
+    fn test_default() {
+        MidPrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = MidPrice::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "MIDPRICE(14)");
+    }
+}
@@ -1,8 +1,8 @@
-use std::f64::INFINITY;
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Low, Next, Period, Reset};
+use crate::helpers::{push_ring, sliding_extreme};
+use crate::{Lookback, Low, Next, NextSlice, Period, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -31,6 +31,7 @@ pub struct Minimum {
     min_index: usize,
     cur_index: usize,
     deque: Box<[f64]>,
+    is_new: bool,
 }
 
 impl Minimum {
@@ -41,17 +42,31 @@ impl Minimum {
                 period,
                 min_index: 0,
                 cur_index: 0,
-                deque: vec![INFINITY; period].into_boxed_slice(),
+                deque: vec![f64::INFINITY; period].into_boxed_slice(),
+                is_new: true,
             }),
         }
     }
 
+    /// Returns the number of periods passed since the lowest value in the time frame.
+    ///
+    /// Returns 0 when the lowest value is the most recent input. If the lowest value
+    /// occurs several times, the most recent occurrence is taken.
+    pub fn bars_since_min(&self) -> usize {
+        let last_index = (self.cur_index + self.period - 1) % self.period;
+        (last_index + self.period - self.min_index) % self.period
+    }
+
+    // Scans the values from the oldest to the newest one, so that the most recent
+    // occurrence wins when there are several equal extremes.
     fn find_min_index(&self) -> usize {
-        let mut min = ::std::f64::INFINITY;
+        let mut min = f64::INFINITY;
         let mut index: usize = 0;
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val < min {
+        for offset in 1..=self.period {
+            let i = (self.cur_index + offset) % self.period;
+            let val = self.deque[i];
+            if val <= min {
                 min = val;
                 index = i;
             }
@@ -67,16 +82,23 @@ impl Period for Minimum {
     }
 }
 
+impl Lookback for Minimum {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for Minimum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.is_new = false;
         self.deque[self.cur_index] = input;
 
-        if input < self.deque[self.min_index] {
-            self.min_index = self.cur_index;
-        } else if self.min_index == self.cur_index {
+        if self.min_index == self.cur_index {
             self.min_index = self.find_min_index();
+        } else if input <= self.deque[self.min_index] {
+            self.min_index = self.cur_index;
         }
 
         self.cur_index = if self.cur_index + 1 < self.period {
@@ -97,10 +119,59 @@ impl<T: Low> Next<&T> for Minimum {
     }
 }
 
+impl NextSlice for Minimum {
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]) {
+        assert_eq!(inputs.len(), out.len());
+        if inputs.is_empty() {
+            return;
+        }
+
+        // The windows of the batch start with the latest values of the ring buffer, where
+        // the unused slots hold infinity, which never wins
+        let mut values = Vec::with_capacity(self.period - 1 + inputs.len());
+        for offset in 1..self.period {
+            values.push(self.deque[(self.cur_index + offset) % self.period]);
+        }
+        values.extend_from_slice(inputs);
+        sliding_extreme(&values, self.period, out, f64::min);
+
+        self.is_new = false;
+        self.cur_index = push_ring(&mut self.deque, self.cur_index, inputs);
+        self.min_index = self.find_min_index();
+    }
+}
+
+impl UpdateLast<f64> for Minimum {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
+        }
+
+        // Scans from the oldest value as next does, with the latest slot as current
+        let last = (self.cur_index + self.period - 1) % self.period;
+        let cur_index = self.cur_index;
+        self.cur_index = last;
+        self.deque[last] = input;
+        self.min_index = self.find_min_index();
+        self.cur_index = cur_index;
+
+        self.deque[self.min_index]
+    }
+}
+
+impl<T: Low> UpdateLast<&T> for Minimum {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.low())
+    }
+}
+
 impl Reset for Minimum {
     fn reset(&mut self) {
+        self.min_index = 0;
+        self.cur_index = 0;
+        self.is_new = true;
         for i in 0..self.period {
-            self.deque[i] = INFINITY;
+            self.deque[i] = f64::INFINITY;
         }
     }
 }
@@ -160,6 +231,46 @@ mod tests {
         assert_eq!(min.next(&bar(5.0)), 1.2);
     }
 
+    #[test]
+    fn test_bars_since_min() {
+        let mut min = Minimum::new(3).unwrap();
+
+        min.next(4.0);
+        assert_eq!(min.bars_since_min(), 0);
+        min.next(5.0);
+        assert_eq!(min.bars_since_min(), 1);
+        min.next(1.0);
+        assert_eq!(min.bars_since_min(), 0);
+        min.next(3.0);
+        assert_eq!(min.bars_since_min(), 1);
+        min.next(1.0);
+        assert_eq!(min.bars_since_min(), 0);
+        min.next(6.0);
+        assert_eq!(min.bars_since_min(), 1);
+        min.next(7.0);
+        assert_eq!(min.bars_since_min(), 2);
+        min.next(8.0);
+        assert_eq!(min.bars_since_min(), 2);
+    }
+
+    #[test]
+    fn test_update_last() {
+        let mut min = Minimum::new(3).unwrap();
+        let mut expected = Minimum::new(3).unwrap();
+        assert_eq!(min.update_last(4.0), expected.next(4.0));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            min.next(close + 3.0);
+            min.update_last(close - 1.0);
+            assert_eq!(round(min.update_last(close)), round(expected.next(close)));
+        }
+    }
+
+    #[test]
+    fn test_next_slice() {
+        assert_next_slice(Minimum::new(9).unwrap());
+    }
+
     #[test]
     fn test_reset() {
         let mut min = Minimum::new(10).unwrap();
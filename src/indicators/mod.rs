@@ -64,3 +64,160 @@ pub use self::money_flow_index::MoneyFlowIndex;
 
 mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
+
+mod stochastic_rsi;
+pub use self::stochastic_rsi::{StochasticRsi, StochasticRsiOutput};
+
+mod aroon;
+pub use self::aroon::{Aroon, AroonOutput};
+
+mod kaufman_adaptive_moving_average;
+pub use self::kaufman_adaptive_moving_average::KaufmanAdaptiveMovingAverage;
+
+mod accumulation_distribution;
+pub use self::accumulation_distribution::AccumulationDistribution;
+
+mod ultimate_oscillator;
+pub use self::ultimate_oscillator::UltimateOscillator;
+
+mod awesome_oscillator;
+pub use self::awesome_oscillator::AwesomeOscillator;
+
+mod accelerator_oscillator;
+pub use self::accelerator_oscillator::AcceleratorOscillator;
+
+mod weighted_moving_average;
+pub use self::weighted_moving_average::WeightedMovingAverage;
+
+mod coppock_curve;
+pub use self::coppock_curve::CoppockCurve;
+
+mod stochastic_momentum_index;
+pub use self::stochastic_momentum_index::{StochasticMomentumIndex, StochasticMomentumIndexOutput};
+
+mod mcginley_dynamic;
+pub use self::mcginley_dynamic::McGinleyDynamic;
+
+mod t3_moving_average;
+pub use self::t3_moving_average::T3MovingAverage;
+
+mod smoothed_moving_average;
+pub use self::smoothed_moving_average::SmoothedMovingAverage;
+
+mod alligator;
+pub use self::alligator::{Alligator, AlligatorOutput};
+
+mod linear_regression;
+pub use self::linear_regression::{LinearRegression, LinearRegressionOutput};
+
+mod linear_regression_channel;
+pub use self::linear_regression_channel::{LinearRegressionChannel, LinearRegressionChannelOutput};
+
+mod rolling_r_squared;
+pub use self::rolling_r_squared::RollingRSquared;
+
+mod z_score;
+pub use self::z_score::ZScore;
+
+mod percent_rank;
+pub use self::percent_rank::PercentRank;
+
+mod rolling_percentile;
+pub use self::rolling_percentile::RollingPercentile;
+
+mod rolling_median;
+pub use self::rolling_median::RollingMedian;
+
+mod rolling_moments;
+
+mod rolling_skewness;
+pub use self::rolling_skewness::RollingSkewness;
+
+mod rolling_kurtosis;
+pub use self::rolling_kurtosis::RollingKurtosis;
+
+mod parkinson_volatility;
+pub use self::parkinson_volatility::ParkinsonVolatility;
+
+mod garman_klass_volatility;
+pub use self::garman_klass_volatility::GarmanKlassVolatility;
+
+mod yang_zhang_volatility;
+pub use self::yang_zhang_volatility::YangZhangVolatility;
+
+mod normalized_average_true_range;
+pub use self::normalized_average_true_range::NormalizedAverageTrueRange;
+
+mod chande_kroll_stop;
+pub use self::chande_kroll_stop::{ChandeKrollStop, ChandeKrollStopOutput};
+
+mod volatility_stop;
+pub use self::volatility_stop::{TrendDirection, VolatilityStop, VolatilityStopOutput};
+
+mod acceleration_bands;
+pub use self::acceleration_bands::{AccelerationBands, AccelerationBandsOutput};
+
+mod mid_price;
+pub use self::mid_price::MidPrice;
+
+mod median_price;
+pub use self::median_price::MedianPrice;
+
+mod time_weighted_average_price;
+pub use self::time_weighted_average_price::TimeWeightedAveragePrice;
+
+mod spread_z_score;
+pub use self::spread_z_score::{HedgeRatio, SpreadZScore, SpreadZScoreOutput};
+
+mod kalman_filter;
+pub use self::kalman_filter::KalmanFilter;
+
+mod savitzky_golay;
+pub use self::savitzky_golay::{SavitzkyGolay, SavitzkyGolayOutput};
+
+mod fractal_dimension_index;
+pub use self::fractal_dimension_index::FractalDimensionIndex;
+
+mod sample_entropy;
+pub use self::sample_entropy::SampleEntropy;
+
+mod drawdown;
+pub use self::drawdown::{Drawdown, DrawdownOutput};
+
+mod rolling_sortino;
+pub use self::rolling_sortino::RollingSortino;
+
+mod equity_stats;
+pub use self::equity_stats::{EquityStats, EquityStatsOutput};
+
+mod simple_returns;
+pub use self::simple_returns::SimpleReturns;
+
+mod log_returns;
+pub use self::log_returns::LogReturns;
+
+mod cumulative_sum;
+pub use self::cumulative_sum::CumulativeSum;
+
+mod lag;
+pub use self::lag::Lag;
+
+mod momentum;
+pub use self::momentum::Momentum;
+
+mod cross;
+pub use self::cross::{Cross, CrossEvent};
+
+mod pivot_detector;
+pub use self::pivot_detector::{Pivot, PivotDetector, PivotDetectorOutput};
+
+mod divergence;
+pub use self::divergence::{Divergence, DivergenceEvent, DivergenceKind, DivergencePoint};
+
+mod trend_lines;
+pub use self::trend_lines::{TrendLine, TrendLines, TrendLinesOutput};
+
+mod indicator_kind;
+pub use self::indicator_kind::{IndicatorKind, IndicatorKindOutput};
+
+mod output_info;
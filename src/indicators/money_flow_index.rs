@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::signal::Thresholds;
+use crate::{Action, Close, Current, High, Low, Next, Period, Reset, Signal, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -27,6 +28,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// Money Flow Index(MFI) = PMF / (PMF + NMF) * 100
 ///
+/// The MFI implements [Signal](../trait.Signal.html), with the oversold and overbought
+/// thresholds of 20 and 80 by default.
 ///
 /// # Parameters
 ///
@@ -52,7 +55,6 @@ use serde::{Deserialize, Serialize};
 /// # Links
 /// * [Money Flow Index, Wikipedia](https://en.wikipedia.org/wiki/Money_flow_index)
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
-
 #[doc(alias = "MFI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -64,6 +66,8 @@ pub struct MoneyFlowIndex {
     total_positive_money_flow: f64,
     total_negative_money_flow: f64,
     deque: Box<[f64]>,
+    current: f64,
+    thresholds: Thresholds,
 }
 
 impl MoneyFlowIndex {
@@ -78,9 +82,17 @@ impl MoneyFlowIndex {
                 total_positive_money_flow: 0.0,
                 total_negative_money_flow: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                current: f64::NAN,
+                thresholds: Thresholds::new(20.0, 80.0)?,
             }),
         }
     }
+
+    /// Sets the oversold and overbought thresholds of the [signal](../trait.Signal.html).
+    pub fn with_thresholds(mut self, oversold: f64, overbought: f64) -> Result<Self> {
+        self.thresholds = Thresholds::new(oversold, overbought)?;
+        Ok(self)
+    }
 }
 
 impl Period for MoneyFlowIndex {
@@ -102,10 +114,11 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         };
 
         if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             if self.count == 1 {
                 self.previous_typical_price = tp;
-                return 50.0;
+                self.current = 50.0;
+                return self.current;
             }
         } else {
             let popped = self.deque[self.index];
@@ -129,9 +142,16 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         }
         self.previous_typical_price = tp;
 
-        self.total_positive_money_flow
+        self.current = self.total_positive_money_flow
             / (self.total_positive_money_flow + self.total_negative_money_flow)
-            * 100.0
+            * 100.0;
+        self.current
+    }
+}
+
+impl Signal for MoneyFlowIndex {
+    fn signal(&self) -> Action {
+        self.thresholds.action(self.current)
     }
 }
 
@@ -154,12 +174,21 @@ impl Reset for MoneyFlowIndex {
         self.previous_typical_price = 0.0;
         self.total_positive_money_flow = 0.0;
         self.total_negative_money_flow = 0.0;
+        self.current = f64::NAN;
         for i in 0..self.period {
             self.deque[i] = 0.0;
         }
     }
 }
 
+impl Current for MoneyFlowIndex {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(self.current).filter(|current| !current.is_nan())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +229,25 @@ mod tests {
         assert_eq!(round(mfi.next(&bar8)), 60.87);
     }
 
+    #[test]
+    fn test_signal() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+        assert_eq!(mfi.signal(), Action::Neutral);
+
+        mfi.next(&Bar::new().high(3).low(1).close(2).volume(500.0));
+        assert_eq!(mfi.signal(), Action::Neutral);
+        mfi.next(&Bar::new().high(2.3).low(2.0).close(2.3).volume(1000.0));
+        assert_eq!(mfi.signal(), Action::Sell);
+        mfi.next(&Bar::new().high(9).low(7).close(8).volume(200.0));
+        mfi.next(&Bar::new().high(5).low(3).close(4).volume(500.0));
+        assert_eq!(mfi.signal(), Action::Neutral);
+        mfi.next(&Bar::new().high(4).low(2).close(3).volume(5000.0));
+        assert_eq!(mfi.signal(), Action::Buy);
+
+        mfi.reset();
+        assert_eq!(mfi.signal(), Action::Neutral);
+    }
+
     #[test]
     fn test_reset() {
         let mut mfi = MoneyFlowIndex::new(3).unwrap();
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -117,6 +117,13 @@ impl Reset for MovingAverageConvergenceDivergence {
     }
 }
 
+impl Lookback for MovingAverageConvergenceDivergence {
+    fn lookback(&self) -> usize {
+        let macd = self.fast_ema.lookback().max(self.slow_ema.lookback());
+        macd + self.signal_ema.lookback()
+    }
+}
+
 impl Default for MovingAverageConvergenceDivergence {
     fn default() -> Self {
         Self::new(12, 26, 9).unwrap()
@@ -170,6 +177,16 @@ mod tests {
         assert_eq!(round(macd.next(6.5).into()), (0.94, 0.87, 0.07));
     }
 
+    #[test]
+    fn test_lookback() {
+        assert_eq!(
+            MovingAverageConvergenceDivergence::new(12, 26, 9)
+                .unwrap()
+                .lookback(),
+            33
+        );
+    }
+
     #[test]
     fn test_reset() {
         let mut macd = Macd::new(3, 6, 4).unwrap();
@@ -0,0 +1,319 @@
+use crate::indicators::*;
+use crate::{OutputInfo, OutputValues, Pane};
+
+// Implements OutputInfo for indicators returning a number, with their pane and typical range
+macro_rules! value_info {
+    ($($name:ident => $pane:ident $(, $min:expr, $max:expr)?;)*) => {
+        $(
+            impl OutputInfo for $name {
+                fn output_fields(&self) -> &[&'static str] {
+                    &["value"]
+                }
+
+                $(
+                    fn output_range(&self) -> Option<(f64, f64)> {
+                        Some(($min, $max))
+                    }
+                )?
+
+                fn pane(&self) -> Pane {
+                    Pane::$pane
+                }
+            }
+        )*
+    };
+}
+
+// Implements OutputInfo for output structs and the indicators returning them
+macro_rules! struct_info {
+    ($($name:ident => $output:ident [$($field:ident),*] $pane:ident $(, $min:expr, $max:expr)?;)*) => {
+        $(
+            impl OutputInfo for $output {
+                fn output_fields(&self) -> &[&'static str] {
+                    &[$(stringify!($field)),*]
+                }
+
+                $(
+                    fn output_range(&self) -> Option<(f64, f64)> {
+                        Some(($min, $max))
+                    }
+                )?
+
+                fn pane(&self) -> Pane {
+                    Pane::$pane
+                }
+            }
+
+            impl OutputInfo for $name {
+                fn output_fields(&self) -> &[&'static str] {
+                    &[$(stringify!($field)),*]
+                }
+
+                $(
+                    fn output_range(&self) -> Option<(f64, f64)> {
+                        Some(($min, $max))
+                    }
+                )?
+
+                fn pane(&self) -> Pane {
+                    Pane::$pane
+                }
+            }
+        )*
+    };
+}
+
+// Implements OutputValues for output structs of numbers
+macro_rules! struct_values {
+    ($($output:ident [$($field:ident),*];)*) => {
+        $(
+            impl OutputValues for $output {
+                fn output_values(&self) -> Vec<f64> {
+                    vec![$(self.$field),*]
+                }
+            }
+        )*
+    };
+}
+
+value_info! {
+    AcceleratorOscillator => Separate;
+    AccumulationDistribution => Separate;
+    AverageTrueRange => Separate;
+    AwesomeOscillator => Separate;
+    CommodityChannelIndex => Separate;
+    CoppockCurve => Separate;
+    CumulativeSum => Separate;
+    EfficiencyRatio => Separate, 0.0, 1.0;
+    ExponentialMovingAverage => Overlay;
+    FastStochastic => Separate, 0.0, 100.0;
+    FractalDimensionIndex => Separate, 1.0, 2.0;
+    GarmanKlassVolatility => Separate;
+    KalmanFilter => Overlay;
+    KaufmanAdaptiveMovingAverage => Overlay;
+    Lag => Overlay;
+    LogReturns => Separate;
+    Maximum => Overlay;
+    McGinleyDynamic => Overlay;
+    MeanAbsoluteDeviation => Separate;
+    MedianPrice => Overlay;
+    MidPrice => Overlay;
+    Minimum => Overlay;
+    Momentum => Separate;
+    MoneyFlowIndex => Separate, 0.0, 100.0;
+    NormalizedAverageTrueRange => Separate;
+    OnBalanceVolume => Separate;
+    ParkinsonVolatility => Separate;
+    PercentRank => Separate, 0.0, 100.0;
+    RateOfChange => Separate;
+    RelativeStrengthIndex => Separate, 0.0, 100.0;
+    RollingKurtosis => Separate;
+    RollingMedian => Overlay;
+    RollingPercentile => Overlay;
+    RollingRSquared => Separate, 0.0, 1.0;
+    RollingSkewness => Separate;
+    RollingSortino => Separate;
+    SampleEntropy => Separate;
+    SimpleMovingAverage => Overlay;
+    SimpleReturns => Separate;
+    SlowStochastic => Separate, 0.0, 100.0;
+    SmoothedMovingAverage => Overlay;
+    StandardDeviation => Separate;
+    T3MovingAverage => Overlay;
+    TimeWeightedAveragePrice => Overlay;
+    TrueRange => Separate;
+    UltimateOscillator => Separate, 0.0, 100.0;
+    WeightedMovingAverage => Overlay;
+    YangZhangVolatility => Separate;
+    ZScore => Separate;
+}
+
+struct_info! {
+    AccelerationBands => AccelerationBandsOutput [average, upper, lower] Overlay;
+    Alligator => AlligatorOutput [jaw, teeth, lips] Overlay;
+    Aroon => AroonOutput [up, down, oscillator] Separate, -100.0, 100.0;
+    BollingerBands => BollingerBandsOutput [average, upper, lower] Overlay;
+    ChandeKrollStop => ChandeKrollStopOutput [stop_long, stop_short] Overlay;
+    ChandelierExit => ChandelierExitOutput [long, short] Overlay;
+    Drawdown => DrawdownOutput [drawdown, max_drawdown] Separate, 0.0, 100.0;
+    EquityStats => EquityStatsOutput
+        [cagr, max_drawdown, calmar_ratio, recovery_factor, max_drawdown_duration] Separate;
+    KeltnerChannel => KeltnerChannelOutput [average, upper, lower] Overlay;
+    LinearRegression => LinearRegressionOutput [slope, intercept, forecast, r_squared] Separate;
+    LinearRegressionChannel => LinearRegressionChannelOutput [middle, upper, lower] Overlay;
+    MovingAverageConvergenceDivergence => MovingAverageConvergenceDivergenceOutput
+        [macd, signal, histogram] Separate;
+    PercentagePriceOscillator => PercentagePriceOscillatorOutput [ppo, signal, histogram] Separate;
+    PivotDetector => PivotDetectorOutput [high, low] Overlay;
+    SavitzkyGolay => SavitzkyGolayOutput [value, velocity, acceleration] Separate;
+    SpreadZScore => SpreadZScoreOutput [spread, hedge_ratio, zscore] Separate;
+    StochasticMomentumIndex => StochasticMomentumIndexOutput [smi, signal] Separate, -100.0, 100.0;
+    StochasticRsi => StochasticRsiOutput [k, d] Separate, 0.0, 100.0;
+    TrendLines => TrendLinesOutput [support, resistance] Overlay;
+    VolatilityStop => VolatilityStopOutput [stop, direction] Overlay;
+}
+
+struct_values! {
+    AccelerationBandsOutput [average, upper, lower];
+    AlligatorOutput [jaw, teeth, lips];
+    AroonOutput [up, down, oscillator];
+    BollingerBandsOutput [average, upper, lower];
+    ChandeKrollStopOutput [stop_long, stop_short];
+    ChandelierExitOutput [long, short];
+    DrawdownOutput [drawdown, max_drawdown];
+    KeltnerChannelOutput [average, upper, lower];
+    LinearRegressionOutput [slope, intercept, forecast, r_squared];
+    LinearRegressionChannelOutput [middle, upper, lower];
+    MovingAverageConvergenceDivergenceOutput [macd, signal, histogram];
+    PercentagePriceOscillatorOutput [ppo, signal, histogram];
+    SavitzkyGolayOutput [value, velocity, acceleration];
+    SpreadZScoreOutput [spread, hedge_ratio, zscore];
+    StochasticMomentumIndexOutput [smi, signal];
+    StochasticRsiOutput [k, d];
+}
+
+impl OutputInfo for Cross {
+    fn output_fields(&self) -> &[&'static str] {
+        &["event"]
+    }
+
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    fn pane(&self) -> Pane {
+        Pane::Separate
+    }
+}
+
+impl OutputInfo for Divergence {
+    fn output_fields(&self) -> &[&'static str] {
+        &["events"]
+    }
+
+    fn pane(&self) -> Pane {
+        Pane::Separate
+    }
+}
+
+impl OutputValues for f64 {
+    fn output_values(&self) -> Vec<f64> {
+        vec![*self]
+    }
+}
+
+impl OutputValues for Option<f64> {
+    fn output_values(&self) -> Vec<f64> {
+        vec![self.unwrap_or(f64::NAN)]
+    }
+}
+
+/// 1 for a cross over, -1 for a cross under and 0 otherwise.
+impl OutputValues for CrossEvent {
+    fn output_values(&self) -> Vec<f64> {
+        let value = match self {
+            CrossEvent::Over => 1.0,
+            CrossEvent::Under => -1.0,
+            CrossEvent::None => 0.0,
+        };
+        vec![value]
+    }
+}
+
+/// The prices of the pivots, NaN without pivot.
+impl OutputValues for PivotDetectorOutput {
+    fn output_values(&self) -> Vec<f64> {
+        let price = |pivot: Option<Pivot>| pivot.map_or(f64::NAN, |pivot| pivot.price);
+        vec![price(self.high), price(self.low)]
+    }
+}
+
+/// The prices of the lines projected to the current bar, NaN without line.
+impl OutputValues for TrendLinesOutput {
+    fn output_values(&self) -> Vec<f64> {
+        let price = |line: &Option<TrendLine>| line.as_ref().map_or(f64::NAN, |line| line.price);
+        vec![price(&self.support), price(&self.resistance)]
+    }
+}
+
+/// The direction is 1 for an up trend and -1 for a down trend.
+impl OutputValues for VolatilityStopOutput {
+    fn output_values(&self) -> Vec<f64> {
+        let direction = match self.direction {
+            TrendDirection::Up => 1.0,
+            TrendDirection::Down => -1.0,
+        };
+        vec![self.stop, direction]
+    }
+}
+
+/// The duration of the maximum drawdown is in milliseconds.
+impl OutputValues for EquityStatsOutput {
+    fn output_values(&self) -> Vec<f64> {
+        vec![
+            self.cagr,
+            self.max_drawdown,
+            self.calmar_ratio,
+            self.recovery_factor,
+            self.max_drawdown_duration as f64,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::Next;
+
+    #[test]
+    fn test_value_info() {
+        let sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(sma.output_fields(), ["value"]);
+        assert_eq!(sma.output_count(), 1);
+        assert_eq!(sma.output_range(), None);
+        assert_eq!(sma.pane(), Pane::Overlay);
+
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+        assert_eq!(rsi.output_range(), Some((0.0, 100.0)));
+        assert_eq!(rsi.pane(), Pane::Separate);
+    }
+
+    #[test]
+    fn test_struct_info() {
+        let mut bb = BollingerBands::new(3, 2.0).unwrap();
+        assert_eq!(bb.output_fields(), ["average", "upper", "lower"]);
+        assert_eq!(bb.pane(), Pane::Overlay);
+
+        let output = bb.next(2.0);
+        assert_eq!(output.output_fields(), bb.output_fields());
+        assert_eq!(output.output_values(), [2.0, 2.0, 2.0]);
+
+        let macd = MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap();
+        assert_eq!(macd.output_count(), 3);
+        assert_eq!(macd.pane(), Pane::Separate);
+    }
+
+    #[test]
+    fn test_output_values() {
+        assert_eq!(2.0.output_values(), [2.0]);
+        assert!(None::<f64>.output_values()[0].is_nan());
+        assert_eq!(CrossEvent::Under.output_values(), [-1.0]);
+
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+        for &(high, low) in &[(2.0, 1.0), (3.0, 2.0)] {
+            pivots.next((high, low));
+        }
+        let output = pivots.next((2.0, 1.0));
+        let values = output.output_values();
+        assert_eq!(values[0], 3.0);
+        assert!(values[1].is_nan());
+
+        let mut stop = VolatilityStop::new(3, 2.0).unwrap();
+        assert_eq!(stop.next(&bar(10.0)).output_values().len(), 2);
+    }
+
+    fn bar(close: f64) -> Bar {
+        Bar::new().high(close + 1.0).low(close - 1.0).close(close)
+    }
+}
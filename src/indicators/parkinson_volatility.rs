@@ -0,0 +1,154 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parkinson volatility.
+///
+/// A range-based volatility estimator developed by Michael Parkinson. Using the high and low
+/// prices, it captures the movement within a bar and is less noisy than the standard deviation
+/// of close-to-close returns.
+///
+/// # Formula
+///
+/// σ = sqrt(Σ ln(High / Low)<sup>2</sup> / (4 * n * ln(2)))
+///
+/// Where:
+///
+/// * _n_ - number of bars in the time frame
+///
+/// The result is the volatility per bar, not annualized. Prices are expected to be positive.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ParkinsonVolatility;
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(10.0).low(9.0).close(9.5).volume(1.0).build().unwrap();
+///
+/// let mut pv = ParkinsonVolatility::new(3).unwrap();
+/// assert_eq!((pv.next(&bar) * 10000.0).round() / 10000.0, 0.0633);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility estimators, Portfolio Optimizer](https://portfoliooptimizer.io/blog/range-based-volatility-estimators-overview-and-examples-of-usage/)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ParkinsonVolatility {
+    sma: Sma,
+}
+
+impl ParkinsonVolatility {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for ParkinsonVolatility {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: High + Low> Next<&T> for ParkinsonVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let log_range = (input.high() / input.low()).ln();
+        let mean = self.sma.next(log_range * log_range);
+        (mean / (4.0 * std::f64::consts::LN_2)).sqrt()
+    }
+}
+
+impl Reset for ParkinsonVolatility {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for ParkinsonVolatility {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ParkinsonVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PARKINSON({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(num: f64) -> f64 {
+        (num * 10000.0).round() / 10000.0
+    }
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ParkinsonVolatility::new(0).is_err());
+        assert!(ParkinsonVolatility::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pv = ParkinsonVolatility::new(3).unwrap();
+
+        assert_eq!(round(pv.next(&bar(10.0, 9.0))), 0.0633);
+        assert_eq!(round(pv.next(&bar(11.0, 10.0))), 0.0603);
+        assert_eq!(round(pv.next(&bar(12.0, 10.0))), 0.0801);
+        assert_eq!(round(pv.next(&bar(11.0, 10.5))), 0.0731);
+        assert_eq!(round(pv.next(&bar(12.0, 11.0))), 0.0719);
+    }
+
+    #[test]
+    fn test_next_no_range() {
+        let mut pv = ParkinsonVolatility::new(3).unwrap();
+
+        assert_eq!(pv.next(&bar(10.0, 10.0)), 0.0);
+        assert_eq!(pv.next(&bar(11.0, 11.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pv = ParkinsonVolatility::new(3).unwrap();
+
+        assert_eq!(round(pv.next(&bar(10.0, 9.0))), 0.0633);
+        assert_eq!(round(pv.next(&bar(11.0, 10.0))), 0.0603);
+
+        pv.reset();
+
+        assert_eq!(round(pv.next(&bar(11.0, 10.0))), 0.0572);
+    }
+
+    #[test]
+    fn test_default() {
+        ParkinsonVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ParkinsonVolatility::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "PARKINSON(20)");
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percent rank (PR).
+///
+/// Returns the percentage of the previous _period_ values which are lower than the
+/// current input value. It is used, among others, by Connors RSI.
+///
+/// # Formula
+///
+/// PR = count of previous values lower than p / number of previous values * 100
+///
+/// Where:
+///
+/// * _p_ - current input value
+///
+/// The current value itself is not part of the time frame. While no previous values
+/// are available, PR is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of previous values to compare with (integer greater than 0). Default is 100.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::PercentRank;
+/// use ta::Next;
+///
+/// let mut pr = PercentRank::new(3).unwrap();
+/// assert_eq!(pr.next(5.0), 0.0);
+/// assert_eq!(pr.next(3.0), 0.0);
+/// assert_eq!(pr.next(4.0), 50.0);
+/// assert_eq!(pr.next(6.0), 100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentile rank, Wikipedia](https://en.wikipedia.org/wiki/Percentile_rank)
+///
+#[doc(alias = "PR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentRank {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl PercentRank {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for PercentRank {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for PercentRank {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let lower = self.deque[..self.count]
+            .iter()
+            .filter(|&&val| val < input)
+            .count();
+        let rank = if self.count == 0 {
+            0.0
+        } else {
+            lower as f64 / self.count as f64 * 100.0
+        };
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        rank
+    }
+}
+
+impl<T: Close> Next<&T> for PercentRank {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for PercentRank {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for PercentRank {
+    fn default() -> Self {
+        Self::new(100).unwrap()
+    }
+}
+
+impl fmt::Display for PercentRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(PercentRank);
+
+    #[test]
+    fn test_new() {
+        assert!(PercentRank::new(0).is_err());
+        assert!(PercentRank::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pr = PercentRank::new(3).unwrap();
+
+        assert_eq!(pr.next(5.0), 0.0);
+        assert_eq!(pr.next(3.0), 0.0);
+        assert_eq!(pr.next(4.0), 50.0);
+        assert_eq!(pr.next(6.0), 100.0);
+        assert_eq!(pr.next(1.0), 0.0);
+        assert_eq!(round(pr.next(2.0)), 33.333);
+        assert_eq!(round(pr.next(2.0)), 33.333);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut pr = PercentRank::new(3).unwrap();
+
+        assert_eq!(pr.next(&bar(5.0)), 0.0);
+        assert_eq!(pr.next(&bar(3.0)), 0.0);
+        assert_eq!(pr.next(&bar(4.0)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pr = PercentRank::new(3).unwrap();
+
+        assert_eq!(pr.next(5.0), 0.0);
+        assert_eq!(pr.next(6.0), 100.0);
+
+        pr.reset();
+
+        assert_eq!(pr.next(6.0), 0.0);
+        assert_eq!(pr.next(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        PercentRank::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = PercentRank::new(100).unwrap();
+        assert_eq!(format!("{}", indicator), "PR(100)");
+    }
+}
@@ -1,8 +1,8 @@
 use std::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, Next, Period, Reset, TryNext};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -109,6 +109,41 @@ impl<T: Close> Next<&T> for PercentagePriceOscillator {
     }
 }
 
+impl TryNext<f64> for PercentagePriceOscillator {
+    type Output = PercentagePriceOscillatorOutput;
+
+    /// Returns a `DivisionByZero` error when the slow EMA is 0. The EMAs consume the input,
+    /// while the signal line is left unchanged.
+    fn try_next(&mut self, input: f64) -> Result<Self::Output> {
+        if !input.is_finite() {
+            return Err(TaError::InvalidInput);
+        }
+
+        let fast_val = self.fast_ema.next(input);
+        let slow_val = self.slow_ema.next(input);
+        if slow_val == 0.0 {
+            return Err(TaError::DivisionByZero);
+        }
+
+        let ppo = (fast_val - slow_val) / slow_val * 100.0;
+        let signal = self.signal_ema.next(ppo);
+
+        Ok(PercentagePriceOscillatorOutput {
+            ppo,
+            signal,
+            histogram: ppo - signal,
+        })
+    }
+}
+
+impl<T: Close> TryNext<&T> for PercentagePriceOscillator {
+    type Output = PercentagePriceOscillatorOutput;
+
+    fn try_next(&mut self, input: &T) -> Result<Self::Output> {
+        self.try_next(input.close())
+    }
+}
+
 impl Reset for PercentagePriceOscillator {
     fn reset(&mut self) {
         self.fast_ema.reset();
@@ -117,6 +152,13 @@ impl Reset for PercentagePriceOscillator {
     }
 }
 
+impl Lookback for PercentagePriceOscillator {
+    fn lookback(&self) -> usize {
+        let ppo = self.fast_ema.lookback().max(self.slow_ema.lookback());
+        ppo + self.signal_ema.lookback()
+    }
+}
+
 impl Default for PercentagePriceOscillator {
     fn default() -> Self {
         Self::new(12, 26, 9).unwrap()
@@ -170,6 +212,26 @@ mod tests {
         assert_eq!(round(ppo.next(6.5).into()), (17.84, 19.08, -1.24));
     }
 
+    #[test]
+    fn test_lookback() {
+        assert_eq!(
+            PercentagePriceOscillator::new(12, 26, 9)
+                .unwrap()
+                .lookback(),
+            33
+        );
+    }
+
+    #[test]
+    fn test_try_next() {
+        let mut ppo = PercentagePriceOscillator::new(2, 3, 2).unwrap();
+        assert!(matches!(ppo.try_next(f64::NAN), Err(TaError::InvalidInput)));
+        assert!(matches!(ppo.try_next(0.0), Err(TaError::DivisionByZero)));
+
+        let output = ppo.try_next(&Bar::new().close(4)).unwrap();
+        assert_eq!(round(output.into()), (33.33, 33.33, 0.0));
+    }
+
     #[test]
     fn test_reset() {
         let mut ppo = Ppo::new(3, 6, 4).unwrap();
@@ -0,0 +1,275 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Swing high or swing low found by [PivotDetector](struct.PivotDetector.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pivot {
+    /// Index of the bar of the pivot, counted from 0 since the detector was created or reset.
+    pub index: usize,
+    /// High of the bar for pivot highs, low of the bar for pivot lows.
+    pub price: f64,
+}
+
+/// Pivot (swing point) detector.
+///
+/// Finds swing highs and swing lows: bars whose high (low) is above (below) the highs (lows)
+/// of _left_ bars before and _right_ bars after them. A pivot is therefore confirmed, and
+/// returned, _right_ bars after it occurred.
+///
+/// A pivot high must be strictly higher than the bars on the left and at least as high as the
+/// bars on the right, so only the first bar of a flat top is a pivot. Pivot lows are
+/// symmetrical.
+///
+/// # Parameters
+///
+/// * _left_ - number of bars before the pivot (integer greater than 0). Default is 5.
+/// * _right_ - number of bars after the pivot (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Pivot, PivotDetector};
+/// use ta::Next;
+///
+/// let mut pivots = PivotDetector::new(1, 1).unwrap();
+///
+/// assert_eq!(pivots.next(1.0).high, None);
+/// assert_eq!(pivots.next(3.0).high, None);
+/// let out = pivots.next(2.0);
+/// assert_eq!(out.high, Some(Pivot { index: 1, price: 3.0 }));
+/// assert_eq!(out.low, None);
+/// ```
+///
+/// # Links
+///
+/// * [Swing high and swing low, Investopedia](https://www.investopedia.com/terms/s/swinghigh.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PivotDetector {
+    left: usize,
+    right: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[(f64, f64)]>,
+}
+
+impl PivotDetector {
+    pub fn new(left: usize, right: usize) -> Result<Self> {
+        if left == 0 || right == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            left,
+            right,
+            index: 0,
+            count: 0,
+            deque: vec![(0.0, 0.0); left + right + 1].into_boxed_slice(),
+        })
+    }
+
+    pub fn left(&self) -> usize {
+        self.left
+    }
+
+    pub fn right(&self) -> usize {
+        self.right
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotDetectorOutput {
+    pub high: Option<Pivot>,
+    pub low: Option<Pivot>,
+}
+
+impl Next<(f64, f64)> for PivotDetector {
+    type Output = PivotDetectorOutput;
+
+    fn next(&mut self, (high, low): (f64, f64)) -> Self::Output {
+        let size = self.deque.len();
+        self.deque[self.index] = (high, low);
+        self.index = (self.index + 1) % size;
+        self.count += 1;
+
+        if self.count < size {
+            return PivotDetectorOutput {
+                high: None,
+                low: None,
+            };
+        }
+
+        // The oldest bar is at the current index, the candidate is _left_ bars after it
+        let bar = |i: usize| self.deque[(self.index + i) % size];
+        let (candidate_high, candidate_low) = bar(self.left);
+
+        let is_high = (0..self.left).all(|i| bar(i).0 < candidate_high)
+            && (self.left + 1..size).all(|i| bar(i).0 <= candidate_high);
+        let is_low = (0..self.left).all(|i| bar(i).1 > candidate_low)
+            && (self.left + 1..size).all(|i| bar(i).1 >= candidate_low);
+
+        let index = self.count - 1 - self.right;
+        PivotDetectorOutput {
+            high: if is_high {
+                Some(Pivot {
+                    index,
+                    price: candidate_high,
+                })
+            } else {
+                None
+            },
+            low: if is_low {
+                Some(Pivot {
+                    index,
+                    price: candidate_low,
+                })
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl Next<f64> for PivotDetector {
+    type Output = PivotDetectorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.next((input, input))
+    }
+}
+
+impl<T: High + Low> Next<&T> for PivotDetector {
+    type Output = PivotDetectorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high(), input.low()))
+    }
+}
+
+impl Reset for PivotDetector {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for PivotDetector {
+    fn default() -> Self {
+        Self::new(5, 5).unwrap()
+    }
+}
+
+impl fmt::Display for PivotDetector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PIVOTS({}, {})", self.left, self.right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(PivotDetector);
+
+    fn pivot(index: usize, price: f64) -> Option<Pivot> {
+        Some(Pivot { index, price })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(PivotDetector::new(0, 1).is_err());
+        assert!(PivotDetector::new(1, 0).is_err());
+        assert!(PivotDetector::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+
+        let outputs: Vec<_> = [1.0, 3.0, 2.0, 4.0, 1.0, 5.0]
+            .iter()
+            .map(|&value| {
+                let out = pivots.next(value);
+                (out.high, out.low)
+            })
+            .collect();
+
+        assert_eq!(
+            outputs,
+            vec![
+                (None, None),
+                (None, None),
+                (pivot(1, 3.0), None),
+                (None, pivot(2, 2.0)),
+                (pivot(3, 4.0), None),
+                (None, pivot(4, 1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_flat_top() {
+        let mut pivots = PivotDetector::new(2, 2).unwrap();
+
+        let highs: Vec<_> = [1.0, 2.0, 5.0, 5.0, 3.0, 2.0, 1.0]
+            .iter()
+            .map(|&value| pivots.next(value).high)
+            .collect();
+
+        assert_eq!(
+            highs,
+            vec![None, None, None, None, pivot(2, 5.0), None, None]
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+
+        pivots.next(&bar(10.0, 8.0));
+        pivots.next(&bar(12.0, 7.0));
+        let out = pivots.next(&bar(11.0, 9.0));
+
+        assert_eq!(out.high, pivot(1, 12.0));
+        assert_eq!(out.low, pivot(1, 7.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+
+        pivots.next(1.0);
+        pivots.next(3.0);
+
+        pivots.reset();
+
+        assert_eq!(pivots.next(2.0).high, None);
+        assert_eq!(pivots.next(4.0).high, None);
+        assert_eq!(pivots.next(3.0).high, pivot(1, 4.0));
+    }
+
+    #[test]
+    fn test_default() {
+        PivotDetector::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = PivotDetector::new(5, 3).unwrap();
+        assert_eq!(format!("{}", indicator), "PIVOTS(5, 3)");
+    }
+}
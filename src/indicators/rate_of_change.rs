@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::helpers::{map_lagged, push_ring};
+use crate::traits::{Close, IsReady, Lookback, Next, NextSlice, Period, Reset, TryNext};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -68,6 +69,18 @@ impl Period for RateOfChange {
     }
 }
 
+impl Lookback for RateOfChange {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl IsReady for RateOfChange {
+    fn is_ready(&self) -> bool {
+        self.count > self.period
+    }
+}
+
 impl Next<f64> for RateOfChange {
     type Output = f64;
 
@@ -102,6 +115,51 @@ impl<T: Close> Next<&T> for RateOfChange {
     }
 }
 
+impl NextSlice for RateOfChange {
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]) {
+        assert_eq!(inputs.len(), out.len());
+
+        // Warms up one input at a time, then every input is compared to the input which
+        // left the window
+        let warmup = (self.period + 1 - self.count).min(inputs.len());
+        for (out, &input) in out[..warmup].iter_mut().zip(&inputs[..warmup]) {
+            *out = self.next(input);
+        }
+        let (inputs, out) = (&inputs[warmup..], &mut out[warmup..]);
+
+        map_lagged(&self.deque, self.index, inputs, out, |input, previous| {
+            (input - previous) / previous * 100.0
+        });
+        self.index = push_ring(&mut self.deque, self.index, inputs);
+    }
+}
+
+impl TryNext<f64> for RateOfChange {
+    type Output = f64;
+
+    /// Returns a `DivisionByZero` error when the price `period` inputs back is 0.
+    fn try_next(&mut self, input: f64) -> Result<Self::Output> {
+        if !input.is_finite() {
+            return Err(TaError::InvalidInput);
+        }
+
+        let roc = self.next(input);
+        if roc.is_finite() {
+            Ok(roc)
+        } else {
+            Err(TaError::DivisionByZero)
+        }
+    }
+}
+
+impl<T: Close> TryNext<&T> for RateOfChange {
+    type Output = f64;
+
+    fn try_next(&mut self, input: &T) -> Result<Self::Output> {
+        self.try_next(input.close())
+    }
+}
+
 impl Default for RateOfChange {
     fn default() -> Self {
         Self::new(9).unwrap()
@@ -163,6 +221,40 @@ mod tests {
         assert_eq!(round(roc.next(&bar(10.57))), 5.7);
     }
 
+    #[test]
+    fn test_lookback() {
+        assert_eq!(RateOfChange::new(3).unwrap().lookback(), 3);
+    }
+
+    #[test]
+    fn test_is_ready() {
+        let mut roc = RateOfChange::new(2).unwrap();
+        for _ in 0..3 {
+            assert!(!roc.is_ready());
+            roc.next(1.0);
+        }
+        assert!(roc.is_ready());
+
+        roc.reset();
+        assert!(!roc.is_ready());
+    }
+
+    #[test]
+    fn test_try_next() {
+        let mut roc = RateOfChange::new(2).unwrap();
+        assert!(matches!(roc.try_next(f64::NAN), Err(TaError::InvalidInput)));
+        assert_eq!(roc.try_next(2.0).unwrap(), 0.0);
+        assert_eq!(roc.try_next(0.0).unwrap(), -100.0);
+        assert_eq!(roc.try_next(&Bar::new().close(4)).unwrap(), 100.0);
+        assert!(matches!(roc.try_next(6.0), Err(TaError::DivisionByZero)));
+        assert_eq!(roc.try_next(6.0).unwrap(), 50.0);
+    }
+
+    #[test]
+    fn test_next_slice() {
+        assert_next_slice(RateOfChange::new(9).unwrap());
+    }
+
     #[test]
     fn test_reset() {
         let mut roc = RateOfChange::new(3).unwrap();
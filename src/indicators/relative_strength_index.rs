@@ -2,7 +2,8 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::signal::Thresholds;
+use crate::{Action, Close, Current, Lookback, Next, Period, Reset, Signal, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -47,6 +48,9 @@ use serde::{Deserialize, Serialize};
 /// * p<sub>t</sub> - input value in a moment of time _t_
 /// * p<sub>t-1</sub> - input value in a moment of time _t-1_
 ///
+/// The RSI implements [Signal](../trait.Signal.html), with the oversold and overbought
+/// thresholds of 30 and 70 by default.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 14.
@@ -76,7 +80,11 @@ pub struct RelativeStrengthIndex {
     up_ema_indicator: Ema,
     down_ema_indicator: Ema,
     prev_val: f64,
+    // Input before the latest one, None when the latest input was the first one
+    before_prev_val: Option<f64>,
     is_new: bool,
+    current: f64,
+    thresholds: Thresholds,
 }
 
 impl RelativeStrengthIndex {
@@ -86,9 +94,28 @@ impl RelativeStrengthIndex {
             up_ema_indicator: Ema::new(period)?,
             down_ema_indicator: Ema::new(period)?,
             prev_val: 0.0,
+            before_prev_val: None,
             is_new: true,
+            current: f64::NAN,
+            thresholds: Thresholds::new(30.0, 70.0)?,
         })
     }
+
+    // Returns the upward and downward changes from the previous input
+    fn changes(previous: Option<f64>, input: f64) -> (f64, f64) {
+        match previous {
+            // Initialize with some small seed numbers to avoid division by zero
+            None => (0.1, 0.1),
+            Some(previous) if input > previous => (input - previous, 0.0),
+            Some(previous) => (0.0, previous - input),
+        }
+    }
+
+    /// Sets the oversold and overbought thresholds of the [signal](../trait.Signal.html).
+    pub fn with_thresholds(mut self, oversold: f64, overbought: f64) -> Result<Self> {
+        self.thresholds = Thresholds::new(oversold, overbought)?;
+        Ok(self)
+    }
 }
 
 impl Period for RelativeStrengthIndex {
@@ -97,30 +124,36 @@ impl Period for RelativeStrengthIndex {
     }
 }
 
+impl Lookback for RelativeStrengthIndex {
+    fn lookback(&self) -> usize {
+        // One input for the first change
+        1 + self.up_ema_indicator.lookback()
+    }
+}
+
 impl Next<f64> for RelativeStrengthIndex {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let mut up = 0.0;
-        let mut down = 0.0;
-
-        if self.is_new {
-            self.is_new = false;
-            // Initialize with some small seed numbers to avoid division by zero
-            up = 0.1;
-            down = 0.1;
+        self.before_prev_val = if self.is_new {
+            None
         } else {
-            if input > self.prev_val {
-                up = input - self.prev_val;
-            } else {
-                down = self.prev_val - input;
-            }
-        }
+            Some(self.prev_val)
+        };
+        self.is_new = false;
+        let (up, down) = Self::changes(self.before_prev_val, input);
 
         self.prev_val = input;
         let up_ema = self.up_ema_indicator.next(up);
         let down_ema = self.down_ema_indicator.next(down);
-        100.0 * up_ema / (up_ema + down_ema)
+        self.current = 100.0 * up_ema / (up_ema + down_ema);
+        self.current
+    }
+}
+
+impl Signal for RelativeStrengthIndex {
+    fn signal(&self) -> Action {
+        self.thresholds.action(self.current)
     }
 }
 
@@ -132,15 +165,47 @@ impl<T: Close> Next<&T> for RelativeStrengthIndex {
     }
 }
 
+impl UpdateLast<f64> for RelativeStrengthIndex {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
+        }
+
+        let (up, down) = Self::changes(self.before_prev_val, input);
+
+        self.prev_val = input;
+        let up_ema = self.up_ema_indicator.update_last(up);
+        let down_ema = self.down_ema_indicator.update_last(down);
+        self.current = 100.0 * up_ema / (up_ema + down_ema);
+        self.current
+    }
+}
+
+impl<T: Close> UpdateLast<&T> for RelativeStrengthIndex {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.close())
+    }
+}
+
 impl Reset for RelativeStrengthIndex {
     fn reset(&mut self) {
         self.is_new = true;
         self.prev_val = 0.0;
+        self.before_prev_val = None;
+        self.current = f64::NAN;
         self.up_ema_indicator.reset();
         self.down_ema_indicator.reset();
     }
 }
 
+impl Current for RelativeStrengthIndex {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(self.current).filter(|current| !current.is_nan())
+    }
+}
+
 impl Default for RelativeStrengthIndex {
     fn default() -> Self {
         Self::new(14).unwrap()
@@ -175,6 +240,71 @@ mod tests {
         assert_eq!(rsi.next(9.5).round(), 16.0);
     }
 
+    #[test]
+    fn test_signal() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        assert_eq!(rsi.signal(), Action::Neutral);
+
+        rsi.next(10.0);
+        assert_eq!(rsi.signal(), Action::Neutral);
+        rsi.next(10.5);
+        assert_eq!(rsi.signal(), Action::Sell);
+        rsi.next(10.0);
+        assert_eq!(rsi.signal(), Action::Neutral);
+        rsi.next(9.5);
+        assert_eq!(rsi.signal(), Action::Buy);
+
+        rsi.reset();
+        assert_eq!(rsi.signal(), Action::Neutral);
+    }
+
+    #[test]
+    fn test_with_thresholds() {
+        assert!(RelativeStrengthIndex::default()
+            .with_thresholds(70.0, 30.0)
+            .is_err());
+
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_thresholds(10.0, 90.0)
+            .unwrap();
+        rsi.next(10.0);
+        rsi.next(10.5);
+        assert_eq!(rsi.signal(), Action::Neutral);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        assert_eq!(rsi.current(), None);
+
+        for &input in &[4.0, 5.0, 3.0] {
+            let output = rsi.next(input);
+            assert_eq!(rsi.current(), Some(output));
+        }
+
+        rsi.reset();
+        assert_eq!(rsi.current(), None);
+    }
+
+    #[test]
+    fn test_update_last() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        let mut expected = RelativeStrengthIndex::new(3).unwrap();
+        assert_eq!(rsi.update_last(4.0), expected.next(4.0));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            rsi.next(close + 3.0);
+            rsi.update_last(close - 1.0);
+            assert_eq!(round(rsi.update_last(close)), round(expected.next(close)));
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(RelativeStrengthIndex::new(14).unwrap().lookback(), 14);
+    }
+
     #[test]
     fn test_reset() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::rolling_moments::RollingMoments;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling kurtosis (KURT).
+///
+/// Returns the excess kurtosis of the last _period_ values, a measure of the weight of the
+/// tails of their distribution. Positive values indicate heavier tails than the normal distribution.
+///
+/// # Formula
+///
+/// KURT = m<sub>4</sub> / m<sub>2</sub><sup>2</sup> - 3
+///
+/// Where:
+///
+/// * _m<sub>k</sub>_ = Σ(p - μ)<sup>k</sup> / n, k-th central moment of the time frame
+/// * _μ_ - mean of the time frame
+/// * _n_ - number of values in the time frame
+///
+/// It is the population excess kurtosis, without a bias correction. When all values are equal, KURT is 0.
+///
+/// The moments are updated online, so every update takes constant time.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingKurtosis;
+/// use ta::Next;
+///
+/// let mut kurt = RollingKurtosis::new(3).unwrap();
+/// assert_eq!(kurt.next(1.0), 0.0);
+/// assert_eq!(kurt.next(2.0), -2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kurtosis, Wikipedia](https://en.wikipedia.org/wiki/Kurtosis)
+///
+#[doc(alias = "KURT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingKurtosis {
+    moments: RollingMoments,
+}
+
+impl RollingKurtosis {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                moments: RollingMoments::new(period),
+            }),
+        }
+    }
+}
+
+impl Period for RollingKurtosis {
+    fn period(&self) -> usize {
+        self.moments.period()
+    }
+}
+
+impl Next<f64> for RollingKurtosis {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.moments.next(input);
+        self.moments.kurtosis()
+    }
+}
+
+impl<T: Close> Next<&T> for RollingKurtosis {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingKurtosis {
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+impl Default for RollingKurtosis {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingKurtosis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KURT({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingKurtosis);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingKurtosis::new(0).is_err());
+        assert!(RollingKurtosis::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kurt = RollingKurtosis::new(3).unwrap();
+
+        assert_eq!(round(kurt.next(1.0)), 0.0);
+        assert_eq!(round(kurt.next(2.0)), -2.0);
+        assert_eq!(round(kurt.next(3.0)), -1.5);
+        assert_eq!(round(kurt.next(10.0)), -1.5);
+        assert_eq!(round(kurt.next(2.0)), -1.5);
+        assert_eq!(round(kurt.next(2.0)), -1.5);
+        assert_eq!(round(kurt.next(2.0)), 0.0);
+        assert_eq!(round(kurt.next(2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut kurt = RollingKurtosis::new(3).unwrap();
+
+        assert_eq!(round(kurt.next(&bar(1.0))), 0.0);
+        assert_eq!(round(kurt.next(&bar(2.0))), -2.0);
+        assert_eq!(round(kurt.next(&bar(3.0))), -1.5);
+        assert_eq!(round(kurt.next(&bar(10.0))), -1.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kurt = RollingKurtosis::new(3).unwrap();
+
+        kurt.next(1.0);
+        kurt.next(2.0);
+        kurt.next(3.0);
+
+        kurt.reset();
+
+        assert_eq!(kurt.next(10.0), 0.0);
+        assert_eq!(round(kurt.next(2.0)), -2.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingKurtosis::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingKurtosis::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "KURT(20)");
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::RollingPercentile;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling median.
+///
+/// Returns the median of the last _period_ values. Unlike moving averages,
+/// the median is not affected by single outliers, which makes it a robust smoothing.
+///
+/// # Formula
+///
+/// Median = [rolling percentile](struct.RollingPercentile.html) 50 of the time frame
+///
+/// With an even number of values, the median is the average of the two middle values.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingMedian;
+/// use ta::Next;
+///
+/// let mut median = RollingMedian::new(3).unwrap();
+/// assert_eq!(median.next(10.0), 10.0);
+/// assert_eq!(median.next(20.0), 15.0);
+/// assert_eq!(median.next(100.0), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median, Wikipedia](https://en.wikipedia.org/wiki/Median)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMedian {
+    percentile: RollingPercentile,
+}
+
+impl RollingMedian {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            percentile: RollingPercentile::new(period, 50.0)?,
+        })
+    }
+}
+
+impl Period for RollingMedian {
+    fn period(&self) -> usize {
+        self.percentile.period()
+    }
+}
+
+impl Next<f64> for RollingMedian {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.percentile.next(input)
+    }
+}
+
+impl<T: Close> Next<&T> for RollingMedian {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingMedian {
+    fn reset(&mut self) {
+        self.percentile.reset();
+    }
+}
+
+impl Default for RollingMedian {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMedian {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingMedian);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMedian::new(0).is_err());
+        assert!(RollingMedian::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut median = RollingMedian::new(4).unwrap();
+
+        assert_eq!(median.next(10.0), 10.0);
+        assert_eq!(median.next(20.0), 15.0);
+        assert_eq!(median.next(30.0), 20.0);
+        assert_eq!(median.next(20.0), 20.0);
+        assert_eq!(median.next(10.0), 20.0);
+        assert_eq!(median.next(100.0), 25.0);
+        assert_eq!(median.next(100.0), 60.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut median = RollingMedian::new(4).unwrap();
+
+        assert_eq!(median.next(&bar(10.0)), 10.0);
+        assert_eq!(median.next(&bar(20.0)), 15.0);
+        assert_eq!(median.next(&bar(30.0)), 20.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut median = RollingMedian::new(4).unwrap();
+
+        assert_eq!(median.next(10.0), 10.0);
+        assert_eq!(median.next(20.0), 15.0);
+
+        median.reset();
+
+        assert_eq!(median.next(30.0), 30.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingMedian::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingMedian::new(9).unwrap();
+        assert_eq!(format!("{}", indicator), "MEDIAN(9)");
+    }
+}
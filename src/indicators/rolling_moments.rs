@@ -0,0 +1,149 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Central moments of the last _period_ values.
+///
+/// Values are added and removed with the pairwise update formulas of Pébay,
+/// which avoid the cancellation of the naive power sums.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(super) struct RollingMoments {
+    period: usize,
+    index: usize,
+    count: usize,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    deque: Box<[f64]>,
+}
+
+impl RollingMoments {
+    // The period must be greater than 0
+    pub(super) fn new(period: usize) -> Self {
+        Self {
+            period,
+            index: 0,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        }
+    }
+
+    pub(super) fn period(&self) -> usize {
+        self.period
+    }
+
+    pub(super) fn next(&mut self, input: f64) {
+        if self.count == self.period {
+            self.remove(self.deque[self.index]);
+        }
+        self.add(input);
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+    }
+
+    fn add(&mut self, input: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+        let delta = input - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * (n - 1.0);
+
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    fn remove(&mut self, old_val: f64) {
+        if self.count == 1 {
+            self.clear();
+            return;
+        }
+
+        let n = self.count as f64;
+        let m = n - 1.0;
+        let mean = (n * self.mean - old_val) / m;
+        let delta = old_val - mean;
+        let delta2 = delta * delta;
+
+        let m2 = (self.m2 - delta2 * m / n).max(0.0);
+        let m3 = self.m3 - delta2 * delta * m * (m - 1.0) / (n * n) + 3.0 * delta * m2 / n;
+        let m4 = self.m4
+            - delta2 * delta2 * m * (m * m - m + 1.0) / (n * n * n)
+            - 6.0 * delta2 * m2 / (n * n)
+            + 4.0 * delta * m3 / n;
+
+        self.count -= 1;
+        self.mean = mean;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4.max(0.0);
+    }
+
+    // Returns the variance, treating rounding noise left by removed values as no variance.
+    fn variance(&self) -> f64 {
+        let variance = self.m2 / self.count as f64;
+        if variance <= 1e-12 * (self.mean * self.mean).max(1.0) {
+            0.0
+        } else {
+            variance
+        }
+    }
+
+    /// Sample variance, 0 while less than 2 values were given.
+    pub(super) fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    /// Population skewness, 0 when all values are equal.
+    pub(super) fn skewness(&self) -> f64 {
+        let variance = self.variance();
+        if variance == 0.0 {
+            0.0
+        } else {
+            self.m3 / self.count as f64 / variance.powf(1.5)
+        }
+    }
+
+    /// Population excess kurtosis, 0 when all values are equal.
+    pub(super) fn kurtosis(&self) -> f64 {
+        let variance = self.variance();
+        if variance == 0.0 {
+            0.0
+        } else {
+            self.m4 / self.count as f64 / (variance * variance) - 3.0
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.count = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+        self.m3 = 0.0;
+        self.m4 = 0.0;
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.clear();
+        self.index = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
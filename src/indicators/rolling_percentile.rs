@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling percentile.
+///
+/// Returns the given percentile of the last _period_ values. The values of the time frame
+/// are kept sorted, so the percentile is found without sorting on every update.
+///
+/// # Formula
+///
+/// rank = _percentile_ / 100 * (n - 1)
+///
+/// P = s<sub>⌊rank⌋</sub> + (s<sub>⌈rank⌉</sub> - s<sub>⌊rank⌋</sub>) * (rank - ⌊rank⌋)
+///
+/// Where:
+///
+/// * _n_ - number of values in the time frame
+/// * _s_ - values of the time frame sorted in ascending order, indexed from 0
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _percentile_ - percentile to return, in the range of 0..100. Default is 90.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingPercentile;
+/// use ta::Next;
+///
+/// let mut p = RollingPercentile::new(3, 75.0).unwrap();
+/// assert_eq!(p.next(10.0), 10.0);
+/// assert_eq!(p.next(20.0), 17.5);
+/// assert_eq!(p.next(30.0), 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentile, Wikipedia](https://en.wikipedia.org/wiki/Percentile)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingPercentile {
+    period: usize,
+    percentile: f64,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    sorted: Vec<f64>,
+}
+
+impl RollingPercentile {
+    pub fn new(period: usize, percentile: f64) -> Result<Self> {
+        if period == 0 || !(0.0..=100.0).contains(&percentile) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            percentile,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; period].into_boxed_slice(),
+            sorted: Vec::with_capacity(period),
+        })
+    }
+
+    pub fn percentile(&self) -> f64 {
+        self.percentile
+    }
+}
+
+impl Period for RollingPercentile {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for RollingPercentile {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            let pos = self
+                .sorted
+                .partition_point(|val| val.total_cmp(&old_val).is_lt());
+            self.sorted.remove(pos);
+        }
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        // NaN sorts above every number, so it is found again when it leaves the window
+        let pos = self
+            .sorted
+            .partition_point(|val| val.total_cmp(&input).is_lt());
+        self.sorted.insert(pos, input);
+
+        let rank = self.percentile / 100.0 * (self.count - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+
+        self.sorted[lower] + (self.sorted[upper] - self.sorted[lower]) * (rank - lower as f64)
+    }
+}
+
+impl<T: Close> Next<&T> for RollingPercentile {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingPercentile {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sorted.clear();
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RollingPercentile {
+    fn default() -> Self {
+        Self::new(20, 90.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingPercentile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PERCENTILE({}, {})", self.period, self.percentile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingPercentile);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingPercentile::new(0, 50.0).is_err());
+        assert!(RollingPercentile::new(3, -1.0).is_err());
+        assert!(RollingPercentile::new(3, 100.1).is_err());
+        assert!(RollingPercentile::new(3, f64::NAN).is_err());
+        assert!(RollingPercentile::new(1, 0.0).is_ok());
+        assert!(RollingPercentile::new(1, 100.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut p = RollingPercentile::new(4, 75.0).unwrap();
+
+        assert_eq!(p.next(10.0), 10.0);
+        assert_eq!(p.next(20.0), 17.5);
+        assert_eq!(p.next(30.0), 25.0);
+        assert_eq!(p.next(20.0), 22.5);
+        assert_eq!(p.next(10.0), 22.5);
+        assert_eq!(p.next(100.0), 47.5);
+    }
+
+    #[test]
+    fn test_next_min_max() {
+        let mut min = RollingPercentile::new(3, 0.0).unwrap();
+        let mut max = RollingPercentile::new(3, 100.0).unwrap();
+
+        for &(input, lowest, highest) in &[
+            (5.0, 5.0, 5.0),
+            (3.0, 3.0, 5.0),
+            (4.0, 3.0, 5.0),
+            (6.0, 3.0, 6.0),
+            (7.0, 4.0, 7.0),
+        ] {
+            assert_eq!(min.next(input), lowest);
+            assert_eq!(max.next(input), highest);
+        }
+    }
+
+    #[test]
+    fn test_next_nan() {
+        let mut p = RollingPercentile::new(3, 0.0).unwrap();
+
+        assert_eq!(p.next(1.0), 1.0);
+        assert_eq!(p.next(f64::NAN), 1.0);
+        assert_eq!(p.next(3.0), 1.0);
+        assert_eq!(p.next(2.0), 2.0);
+        assert_eq!(p.next(4.0), 2.0);
+        assert_eq!(p.next(5.0), 2.0);
+        assert_eq!(p.next(6.0), 4.0);
+        assert_eq!(p.sorted, vec![4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut p = RollingPercentile::new(4, 75.0).unwrap();
+
+        assert_eq!(p.next(&bar(10.0)), 10.0);
+        assert_eq!(p.next(&bar(20.0)), 17.5);
+        assert_eq!(p.next(&bar(30.0)), 25.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut p = RollingPercentile::new(4, 75.0).unwrap();
+
+        assert_eq!(p.next(10.0), 10.0);
+        assert_eq!(p.next(20.0), 17.5);
+
+        p.reset();
+
+        assert_eq!(p.next(30.0), 30.0);
+        assert_eq!(p.next(10.0), 25.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingPercentile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingPercentile::new(20, 95.0).unwrap();
+        assert_eq!(format!("{}", indicator), "PERCENTILE(20, 95)");
+    }
+}
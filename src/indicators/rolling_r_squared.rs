@@ -0,0 +1,168 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::LinearRegression;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling R-squared (RSQ).
+///
+/// Coefficient of determination of the values against time over the last _period_ values.
+/// It measures how well the values fit a straight line, and so the quality of a trend,
+/// regardless of its direction. Values close to 1 indicate a strong linear trend,
+/// values close to 0 indicate the absence of one.
+///
+/// # Formula
+///
+/// RSQ = r<sup>2</sup> of the [linear regression](struct.LinearRegression.html) over the time frame
+///
+/// While less than 2 values were given, or when all values are equal, RSQ is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingRSquared;
+/// use ta::Next;
+///
+/// let mut rsq = RollingRSquared::new(3).unwrap();
+/// assert_eq!(rsq.next(1.0), 0.0);
+/// assert_eq!(rsq.next(2.0), 1.0);
+/// assert_eq!(rsq.next(1.0), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Coefficient of determination, Wikipedia](https://en.wikipedia.org/wiki/Coefficient_of_determination)
+///
+#[doc(alias = "RSQ")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingRSquared {
+    linreg: LinearRegression,
+}
+
+impl RollingRSquared {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            linreg: LinearRegression::new(period)?,
+        })
+    }
+}
+
+impl Period for RollingRSquared {
+    fn period(&self) -> usize {
+        self.linreg.period()
+    }
+}
+
+impl Next<f64> for RollingRSquared {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.linreg.next(input).r_squared
+    }
+}
+
+impl<T: Close> Next<&T> for RollingRSquared {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingRSquared {
+    fn reset(&mut self) {
+        self.linreg.reset();
+    }
+}
+
+impl Default for RollingRSquared {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for RollingRSquared {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RSQ({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingRSquared);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingRSquared::new(0).is_err());
+        assert!(RollingRSquared::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rsq = RollingRSquared::new(4).unwrap();
+
+        assert_eq!(round(rsq.next(10.0)), 0.0);
+        assert_eq!(round(rsq.next(12.0)), 1.0);
+        assert_eq!(round(rsq.next(11.0)), 0.25);
+        assert_eq!(round(rsq.next(14.0)), 0.691);
+        assert_eq!(round(rsq.next(13.0)), 0.36);
+        assert_eq!(round(rsq.next(15.0)), 0.691);
+        assert_eq!(round(rsq.next(15.0)), 0.455);
+    }
+
+    #[test]
+    fn test_next_downtrend() {
+        let mut rsq = RollingRSquared::new(3).unwrap();
+
+        rsq.next(9.0);
+        rsq.next(6.0);
+        assert_eq!(round(rsq.next(3.0)), 1.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut rsq = RollingRSquared::new(4).unwrap();
+
+        assert_eq!(round(rsq.next(&bar(10.0))), 0.0);
+        assert_eq!(round(rsq.next(&bar(12.0))), 1.0);
+        assert_eq!(round(rsq.next(&bar(11.0))), 0.25);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rsq = RollingRSquared::new(4).unwrap();
+
+        rsq.next(10.0);
+        rsq.next(12.0);
+        assert_eq!(round(rsq.next(11.0)), 0.25);
+
+        rsq.reset();
+
+        assert_eq!(round(rsq.next(11.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingRSquared::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingRSquared::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "RSQ(20)");
+    }
+}
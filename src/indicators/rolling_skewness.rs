@@ -0,0 +1,173 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::rolling_moments::RollingMoments;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling skewness (SKEW).
+///
+/// Returns the skewness of the last _period_ values, a measure of the asymmetry of their
+/// distribution. Positive values indicate a longer right tail, negative values a longer left tail.
+///
+/// # Formula
+///
+/// SKEW = m<sub>3</sub> / m<sub>2</sub><sup>3/2</sup>
+///
+/// Where:
+///
+/// * _m<sub>k</sub>_ = Σ(p - μ)<sup>k</sup> / n, k-th central moment of the time frame
+/// * _μ_ - mean of the time frame
+/// * _n_ - number of values in the time frame
+///
+/// It is the population skewness, without a bias correction. When all values are equal, SKEW is 0.
+///
+/// The moments are updated online, so every update takes constant time.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingSkewness;
+/// use ta::Next;
+///
+/// let mut skew = RollingSkewness::new(3).unwrap();
+/// skew.next(1.0);
+/// skew.next(2.0);
+/// assert_eq!(skew.next(3.0), 0.0);
+/// assert_eq!((skew.next(10.0) * 1000.0).round() / 1000.0, 0.665);
+/// ```
+///
+/// # Links
+///
+/// * [Skewness, Wikipedia](https://en.wikipedia.org/wiki/Skewness)
+///
+#[doc(alias = "SKEW")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSkewness {
+    moments: RollingMoments,
+}
+
+impl RollingSkewness {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                moments: RollingMoments::new(period),
+            }),
+        }
+    }
+}
+
+impl Period for RollingSkewness {
+    fn period(&self) -> usize {
+        self.moments.period()
+    }
+}
+
+impl Next<f64> for RollingSkewness {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.moments.next(input);
+        self.moments.skewness()
+    }
+}
+
+impl<T: Close> Next<&T> for RollingSkewness {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingSkewness {
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+impl Default for RollingSkewness {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSkewness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SKEW({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingSkewness);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSkewness::new(0).is_err());
+        assert!(RollingSkewness::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut skew = RollingSkewness::new(3).unwrap();
+
+        assert_eq!(round(skew.next(1.0)), 0.0);
+        assert_eq!(round(skew.next(2.0)), 0.0);
+        assert_eq!(round(skew.next(3.0)), 0.0);
+        assert_eq!(round(skew.next(10.0)), 0.665);
+        assert_eq!(round(skew.next(2.0)), 0.665);
+        assert_eq!(round(skew.next(2.0)), 0.707);
+        assert_eq!(round(skew.next(2.0)), 0.0);
+        assert_eq!(round(skew.next(2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut skew = RollingSkewness::new(3).unwrap();
+
+        assert_eq!(round(skew.next(&bar(1.0))), 0.0);
+        assert_eq!(round(skew.next(&bar(2.0))), 0.0);
+        assert_eq!(round(skew.next(&bar(3.0))), 0.0);
+        assert_eq!(round(skew.next(&bar(10.0))), 0.665);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut skew = RollingSkewness::new(3).unwrap();
+
+        skew.next(1.0);
+        skew.next(2.0);
+        skew.next(3.0);
+
+        skew.reset();
+
+        assert_eq!(skew.next(10.0), 0.0);
+        assert_eq!(round(skew.next(2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingSkewness::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingSkewness::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "SKEW(20)");
+    }
+}
@@ -0,0 +1,279 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Sortino ratio (SORTINO).
+///
+/// Risk adjusted return over the last _period_ returns, like the Sharpe ratio, but only
+/// returns below the _target_ count as risk. The ratio is not annualized.
+///
+/// # Formula
+///
+/// Sortino = (mean(r) - T) / DD
+///
+/// DD = sqrt(mean(min(0, r - T)<sup>2</sup>))
+///
+/// Where:
+///
+/// * _r_ - simple returns, p<sub>t</sub> / p<sub>t-1</sub> - 1, of the last _period_ periods
+/// * _T_ - target return
+/// * _DD_ - downside deviation
+///
+/// When the downside deviation is 0, e.g. when there are no returns below the target, the
+/// ratio is 0. While less than _period_ returns were given, the ratio of the given returns is
+/// returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+/// * _target_ - target return per period, e.g. 0.001 for 0.1%. Default is 0.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingSortino;
+/// use ta::Next;
+///
+/// let mut sortino = RollingSortino::new(4, 0.0).unwrap();
+/// assert_eq!(sortino.next(100.0), 0.0);
+/// assert_eq!(sortino.next(102.0), 0.0);
+/// assert_eq!((sortino.next(101.0) * 1000.0).round() / 1000.0, 0.735);
+/// ```
+///
+/// # Links
+///
+/// * [Sortino ratio, Wikipedia](https://en.wikipedia.org/wiki/Sortino_ratio)
+///
+#[doc(alias = "SORTINO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSortino {
+    period: usize,
+    target: f64,
+    prev_price: Option<f64>,
+    index: usize,
+    count: usize,
+    sum: f64,
+    downside_sum: f64,
+    // Number of returns of the window below the target
+    downside_count: usize,
+    deque: Box<[f64]>,
+}
+
+impl RollingSortino {
+    pub fn new(period: usize, target: f64) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                target,
+                prev_price: None,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                downside_sum: 0.0,
+                downside_count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    pub fn target(&self) -> f64 {
+        self.target
+    }
+
+    fn downside(&self, ret: f64) -> f64 {
+        let shortfall = (ret - self.target).min(0.0);
+        shortfall * shortfall
+    }
+}
+
+impl Period for RollingSortino {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for RollingSortino {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let prev_price = match self.prev_price.replace(input) {
+            Some(price) => price,
+            None => return 0.0,
+        };
+        let ret = if prev_price == 0.0 {
+            0.0
+        } else {
+            input / prev_price - 1.0
+        };
+
+        let old_ret = self.deque[self.index];
+        self.deque[self.index] = ret;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            self.sum -= old_ret;
+            self.downside_sum -= self.downside(old_ret);
+            if old_ret < self.target {
+                self.downside_count -= 1;
+            }
+        }
+        self.sum += ret;
+        self.downside_sum += self.downside(ret);
+        if ret < self.target {
+            self.downside_count += 1;
+        }
+
+        // Without returns below the target, the sum is 0 but for the rounding errors left by
+        // the returns which left the window
+        if self.downside_count == 0 {
+            self.downside_sum = 0.0;
+            return 0.0;
+        }
+
+        let n = self.count as f64;
+        let downside_deviation = (self.downside_sum.max(0.0) / n).sqrt();
+        if downside_deviation == 0.0 {
+            0.0
+        } else {
+            (self.sum / n - self.target) / downside_deviation
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for RollingSortino {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingSortino {
+    fn reset(&mut self) {
+        self.prev_price = None;
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.downside_sum = 0.0;
+        self.downside_count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RollingSortino {
+    fn default() -> Self {
+        Self::new(20, 0.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSortino {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SORTINO({}, {})", self.period, self.target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingSortino);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSortino::new(0, 0.0).is_err());
+        assert!(RollingSortino::new(1, 0.01).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sortino = RollingSortino::new(4, 0.0).unwrap();
+
+        assert_eq!(sortino.next(100.0), 0.0);
+        assert_eq!(sortino.next(102.0), 0.0);
+        assert_eq!(round(sortino.next(101.0)), 0.735);
+        assert_eq!(round(sortino.next(103.0)), 1.767);
+        assert_eq!(round(sortino.next(100.0)), 0.014);
+        assert_eq!(round(sortino.next(104.0)), 0.34);
+        assert_eq!(round(sortino.next(103.0)), 0.343);
+    }
+
+    #[test]
+    fn test_next_with_target() {
+        let mut sortino = RollingSortino::new(4, 0.05).unwrap();
+
+        sortino.next(100.0);
+        // 2% return is below the 5% target
+        assert_eq!(round(sortino.next(102.0)), -1.0);
+    }
+
+    #[test]
+    fn test_next_without_downside() {
+        let mut sortino = RollingSortino::new(5, 0.0).unwrap();
+
+        // Alternate losing and winning stretches: once the losing returns left the window, the
+        // ratio is 0, not a huge ratio over the rounding errors of the downside sum
+        let mut price = 100.0;
+        for stretch in 0..20 {
+            let change = if stretch % 2 == 0 { 0.987 } else { 1.013 };
+            for step in 0..8 {
+                price *= change + 0.001 * step as f64;
+                let ratio = sortino.next(price);
+                if stretch % 2 == 1 && step >= 5 {
+                    assert_eq!(ratio, 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut sortino = RollingSortino::new(4, 0.0).unwrap();
+
+        sortino.next(&bar(100.0));
+        sortino.next(&bar(102.0));
+        assert_eq!(round(sortino.next(&bar(101.0))), 0.735);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sortino = RollingSortino::new(4, 0.0).unwrap();
+
+        sortino.next(100.0);
+        sortino.next(102.0);
+        sortino.next(101.0);
+
+        sortino.reset();
+
+        assert_eq!(sortino.next(101.0), 0.0);
+        assert_eq!(sortino.next(103.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingSortino::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingSortino::new(20, 0.0).unwrap();
+        assert_eq!(format!("{}", indicator), "SORTINO(20, 0)");
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sample entropy (SAMPEN).
+///
+/// Measures the irregularity of the returns over the last _period_ periods: the negative
+/// logarithm of the conditional probability that sequences of returns which are similar for
+/// _dimension_ points remain similar for one more point. Low values indicate regular,
+/// more predictable returns, high values indicate random ones.
+///
+/// # Formula
+///
+/// SampEn = -ln(A / B)
+///
+/// Where:
+///
+/// * _B_ - number of pairs of sequences of _dimension_ returns within the distance _r_
+/// * _A_ - number of pairs of sequences of _dimension_ + 1 returns within the distance _r_
+/// * _r_ = _tolerance_ * standard deviation of the returns in the window
+///
+/// The distance of two sequences is the largest absolute difference of their elements.
+/// Returns are simple returns, p<sub>t</sub> / p<sub>t-1</sub> - 1.
+///
+/// The entropy is undefined, and NaN is returned, until _period_ returns were given, and when
+/// no similar sequences are found.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than _dimension_ + 1). Default is 50.
+/// * _dimension_ - embedding dimension, i.e. the length of the compared sequences (integer greater than 0). Default is 2.
+/// * _tolerance_ - tolerance as a multiple of the standard deviation (number greater than or equal to 0). Default is 0.2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SampleEntropy;
+/// use ta::Next;
+///
+/// let mut sampen = SampleEntropy::new(4, 1, 0.5).unwrap();
+///
+/// // Alternating returns are perfectly regular
+/// for &price in &[10.0, 11.0, 10.0, 11.0] {
+///     assert!(sampen.next(price).is_nan());
+/// }
+/// assert_eq!(sampen.next(10.0), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Sample entropy, Wikipedia](https://en.wikipedia.org/wiki/Sample_entropy)
+///
+#[doc(alias = "SAMPEN")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SampleEntropy {
+    period: usize,
+    dimension: usize,
+    tolerance: f64,
+    prev_price: Option<f64>,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl SampleEntropy {
+    pub fn new(period: usize, dimension: usize, tolerance: f64) -> Result<Self> {
+        if dimension == 0 || period <= dimension + 1 || tolerance < 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            dimension,
+            tolerance,
+            prev_price: None,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    fn entropy(&self) -> f64 {
+        // Returns from the oldest one
+        let returns: Vec<f64> = (0..self.period)
+            .map(|i| self.deque[(self.index + i) % self.period])
+            .collect();
+
+        let n = self.period as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let variance = returns.iter().map(|r| (r - mean) * (r - mean)).sum::<f64>() / n;
+        let r = self.tolerance * variance.sqrt();
+
+        // Both lengths use the same number of sequences
+        let sequences = self.period - self.dimension;
+        let mut b = 0usize;
+        let mut a = 0usize;
+        for i in 0..sequences {
+            for j in (i + 1)..sequences {
+                let similar =
+                    (0..self.dimension).all(|k| (returns[i + k] - returns[j + k]).abs() <= r);
+                if similar {
+                    b += 1;
+                    let k = self.dimension;
+                    if (returns[i + k] - returns[j + k]).abs() <= r {
+                        a += 1;
+                    }
+                }
+            }
+        }
+
+        if a == 0 || b == 0 {
+            f64::NAN
+        } else {
+            -(a as f64 / b as f64).ln()
+        }
+    }
+}
+
+impl Period for SampleEntropy {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for SampleEntropy {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let prev_price = self.prev_price.replace(input);
+        let prev_price = match prev_price {
+            Some(price) => price,
+            None => return f64::NAN,
+        };
+
+        let ret = if prev_price == 0.0 {
+            0.0
+        } else {
+            input / prev_price - 1.0
+        };
+
+        self.deque[self.index] = ret;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < self.period {
+            return f64::NAN;
+        }
+
+        // -ln(1) is -0.0
+        self.entropy() + 0.0
+    }
+}
+
+impl<T: Close> Next<&T> for SampleEntropy {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SampleEntropy {
+    fn reset(&mut self) {
+        self.prev_price = None;
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for SampleEntropy {
+    fn default() -> Self {
+        Self::new(50, 2, 0.2).unwrap()
+    }
+}
+
+impl fmt::Display for SampleEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SAMPEN({}, {}, {})",
+            self.period, self.dimension, self.tolerance
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SampleEntropy::new(50, 0, 0.2).is_err());
+        assert!(SampleEntropy::new(3, 2, 0.2).is_err());
+        assert!(SampleEntropy::new(50, 2, -0.2).is_err());
+        assert!(SampleEntropy::new(4, 2, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sampen = SampleEntropy::new(6, 1, 0.5).unwrap();
+
+        for &price in &[10.0, 11.0, 10.0, 11.0, 10.0, 11.0] {
+            assert!(sampen.next(price).is_nan());
+        }
+        assert_eq!(sampen.next(10.0), 0.0);
+        assert_eq!(sampen.next(11.5), 0.0);
+        assert_eq!(sampen.next(10.0), 0.0);
+        assert_eq!(sampen.next(11.0), 0.0);
+        assert_eq!(round(sampen.next(10.2)), 0.288);
+        assert_eq!(round(sampen.next(11.0)), 1.099);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut sampen = SampleEntropy::new(4, 1, 0.5).unwrap();
+
+        for &price in &[10.0, 11.0, 10.0, 11.0] {
+            assert!(sampen.next(&bar(price)).is_nan());
+        }
+        assert_eq!(sampen.next(&bar(10.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sampen = SampleEntropy::new(4, 1, 0.5).unwrap();
+
+        for &price in &[10.0, 11.0, 10.0, 11.0, 10.0] {
+            sampen.next(price);
+        }
+
+        sampen.reset();
+
+        assert!(sampen.next(10.0).is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        SampleEntropy::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SampleEntropy::new(50, 2, 0.2).unwrap();
+        assert_eq!(format!("{}", indicator), "SAMPEN(50, 2, 0.2)");
+    }
+}
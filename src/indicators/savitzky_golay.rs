@@ -0,0 +1,376 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Savitzky-Golay filter (SG).
+///
+/// Fits a polynomial of the given _order_ through the last _window_ values using the least
+/// squares method, and evaluates it and its derivatives at the most recent value. This smooths
+/// the input while preserving the shape of its peaks better than moving averages, and gives
+/// smooth estimates of the velocity and acceleration of the price.
+///
+/// The fit only depends on the positions of the values in the window, so the convolution
+/// coefficients are computed once, when the filter is created.
+///
+/// # Formula
+///
+/// value = Σ c0<sub>i</sub> * p<sub>i</sub>
+///
+/// velocity = Σ c1<sub>i</sub> * p<sub>i</sub>
+///
+/// acceleration = 2 * Σ c2<sub>i</sub> * p<sub>i</sub>
+///
+/// Where:
+///
+/// * _p<sub>i</sub>_ - values of the window, at positions -(_window_ - 1)..0
+/// * _cj_ - coefficients of the _j_-th power of the least squares polynomial, the rows of
+///   (A<sup>T</sup>A)<sup>-1</sup>A<sup>T</sup>, where A<sub>ij</sub> = x<sub>i</sub><sup>j</sup>
+///
+/// Velocity and acceleration are per period. Acceleration is 0 for polynomials of order lower than 2.
+/// Until _window_ values were given, the input value is returned with the derivatives of 0.
+///
+/// # Parameters
+///
+/// * _window_ - number of values in the fit (integer greater than _order_). Default is 9.
+/// * _order_ - order of the polynomial. Default is 2.
+///
+/// High orders make the least squares fit ill-conditioned: the filter can't be created when its
+/// coefficients can't be computed accurately in floating point.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SavitzkyGolay;
+/// use ta::Next;
+///
+/// let mut sg = SavitzkyGolay::new(3, 1).unwrap();
+/// sg.next(10.0);
+/// sg.next(11.0);
+/// let out = sg.next(12.0);
+///
+/// assert_eq!((out.value * 1000.0).round() / 1000.0, 12.0);
+/// assert_eq!((out.velocity * 1000.0).round() / 1000.0, 1.0);
+/// assert_eq!(out.acceleration, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Savitzky–Golay filter, Wikipedia](https://en.wikipedia.org/wiki/Savitzky%E2%80%93Golay_filter)
+///
+#[doc(alias = "SG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SavitzkyGolay {
+    window: usize,
+    order: usize,
+    // Coefficients of the value, velocity and acceleration, from the oldest value
+    coefficients: [Box<[f64]>; 3],
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl SavitzkyGolay {
+    pub fn new(window: usize, order: usize) -> Result<Self> {
+        if window <= order {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let coefficients = fit_coefficients(window, order).ok_or(TaError::InvalidParameter)?;
+
+        Ok(Self {
+            window,
+            order,
+            coefficients,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; window].into_boxed_slice(),
+        })
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+}
+
+/// Returns the coefficients of the constant, linear and quadratic terms of the least squares
+/// polynomial of the given order, for positions -(window - 1)..0.
+///
+/// Returns `None` when the fit is too ill-conditioned to be computed in floating point.
+fn fit_coefficients(window: usize, order: usize) -> Option<[Box<[f64]>; 3]> {
+    // Tolerance on the reproduction of polynomials by the computed coefficients
+    const TOLERANCE: f64 = 1e-6;
+
+    let size = order + 1;
+    // The positions are scaled to [-1, 0], so the powers stay bounded for large windows
+    let scale = (window - 1).max(1) as f64;
+    let us: Vec<f64> = (0..window)
+        .map(|i| (i as f64 - (window - 1) as f64) / scale)
+        .collect();
+
+    // Augmented matrix [AᵀA | I] for Gauss-Jordan elimination
+    let mut matrix = vec![vec![0.0; 2 * size]; size];
+    for (j, row) in matrix.iter_mut().enumerate() {
+        for (l, value) in row.iter_mut().take(size).enumerate() {
+            *value = us.iter().map(|u| u.powi((j + l) as i32)).sum();
+        }
+        row[size + j] = 1.0;
+    }
+
+    for col in 0..size {
+        let pivot =
+            (col..size).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        if matrix[pivot][col] == 0.0 || !matrix[pivot][col].is_finite() {
+            return None;
+        }
+        matrix.swap(col, pivot);
+
+        let divisor = matrix[col][col];
+        for value in matrix[col].iter_mut() {
+            *value /= divisor;
+        }
+        let pivot_row = matrix[col].clone();
+        for (r, row) in matrix.iter_mut().enumerate() {
+            if r != col {
+                let factor = row[col];
+                for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+    }
+
+    // Coefficients of the j-th power of the scaled positions
+    let term = |j: usize| -> Vec<f64> {
+        us.iter()
+            .map(|u| {
+                if j < size {
+                    (0..size)
+                        .map(|l| matrix[j][size + l] * u.powi(l as i32))
+                        .sum()
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    };
+    let terms = [term(0), term(1), term(2)];
+
+    // The fit must reproduce the polynomials of its order: check it on 1 and the position
+    // itself, which rejects the coefficients lost to rounding errors
+    let reproduces = |coefficients: &[f64], power: i32, expected: f64| {
+        let sum: f64 = coefficients
+            .iter()
+            .zip(us.iter())
+            .map(|(c, u)| c * u.powi(power))
+            .sum();
+        (sum - expected).abs() <= TOLERANCE
+    };
+    if terms.iter().flatten().any(|c| !c.is_finite())
+        || !reproduces(&terms[0], 0, 1.0)
+        || (order >= 1 && !reproduces(&terms[1], 1, 1.0))
+    {
+        return None;
+    }
+
+    // Back to the coefficients of the powers of the unscaled positions
+    let unscale = |j: usize| -> Box<[f64]> {
+        let factor = scale.powi(j as i32);
+        terms[j].iter().map(|c| c / factor).collect()
+    };
+
+    Some([unscale(0), unscale(1), unscale(2)])
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavitzkyGolayOutput {
+    pub value: f64,
+    pub velocity: f64,
+    pub acceleration: f64,
+}
+
+impl From<SavitzkyGolayOutput> for (f64, f64, f64) {
+    fn from(so: SavitzkyGolayOutput) -> Self {
+        (so.value, so.velocity, so.acceleration)
+    }
+}
+
+impl Period for SavitzkyGolay {
+    fn period(&self) -> usize {
+        self.window
+    }
+}
+
+impl Next<f64> for SavitzkyGolay {
+    type Output = SavitzkyGolayOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.window {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.window {
+            self.count += 1;
+        }
+        if self.count < self.window {
+            return SavitzkyGolayOutput {
+                value: input,
+                velocity: 0.0,
+                acceleration: 0.0,
+            };
+        }
+
+        // The oldest value is at the current index
+        let convolve = |coefficients: &[f64]| -> f64 {
+            (0..self.window)
+                .map(|i| coefficients[i] * self.deque[(self.index + i) % self.window])
+                .sum()
+        };
+
+        SavitzkyGolayOutput {
+            value: convolve(&self.coefficients[0]),
+            velocity: convolve(&self.coefficients[1]),
+            acceleration: 2.0 * convolve(&self.coefficients[2]),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for SavitzkyGolay {
+    type Output = SavitzkyGolayOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SavitzkyGolay {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.window {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for SavitzkyGolay {
+    fn default() -> Self {
+        Self::new(9, 2).unwrap()
+    }
+}
+
+impl fmt::Display for SavitzkyGolay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SG({}, {})", self.window, self.order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SavitzkyGolay);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        (n0, n1, n2)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SavitzkyGolay::new(0, 0).is_err());
+        assert!(SavitzkyGolay::new(3, 3).is_err());
+        assert!(SavitzkyGolay::new(1, 0).is_ok());
+        assert!(SavitzkyGolay::new(4, 3).is_ok());
+    }
+
+    #[test]
+    fn test_new_large() {
+        assert!(SavitzkyGolay::new(1001, 200).is_err());
+        assert!(SavitzkyGolay::new(500, 60).is_err());
+
+        let mut sg = SavitzkyGolay::new(1001, 4).unwrap();
+        let mut out = sg.next(0.0);
+        for i in 1..1001 {
+            out = sg.next(2.0 * i as f64);
+        }
+        assert_eq!(round(out.into()), (2000.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_fit_coefficients() {
+        let coefficients = fit_coefficients(5, 2).unwrap();
+        let scaled: Vec<f64> = coefficients[0].iter().map(|c| (c * 35.0).round()).collect();
+
+        assert_eq!(scaled, vec![3.0, -5.0, -3.0, 9.0, 31.0]);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sg = SavitzkyGolay::new(5, 2).unwrap();
+
+        assert_eq!(round(sg.next(10.0).into()), (10.0, 0.0, 0.0));
+        assert_eq!(round(sg.next(11.0).into()), (11.0, 0.0, 0.0));
+        assert_eq!(round(sg.next(12.0).into()), (12.0, 0.0, 0.0));
+        assert_eq!(round(sg.next(11.0).into()), (11.0, 0.0, 0.0));
+        assert_eq!(round(sg.next(13.0).into()), (12.6, 0.6, 0.0));
+        assert_eq!(round(sg.next(14.0).into()), (14.029, 1.557, 0.429));
+        assert_eq!(round(sg.next(12.0).into()), (12.571, -0.557, -0.429));
+    }
+
+    #[test]
+    fn test_next_linear() {
+        let mut sg = SavitzkyGolay::new(3, 1).unwrap();
+
+        sg.next(10.0);
+        sg.next(11.0);
+        assert_eq!(round(sg.next(12.0).into()), (12.0, 1.0, 0.0));
+        assert_eq!(round(sg.next(11.0).into()), (11.333, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut sg = SavitzkyGolay::new(3, 1).unwrap();
+
+        sg.next(&bar(10.0));
+        sg.next(&bar(11.0));
+        assert_eq!(round(sg.next(&bar(12.0)).into()), (12.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sg = SavitzkyGolay::new(3, 1).unwrap();
+
+        sg.next(10.0);
+        sg.next(11.0);
+        sg.next(12.0);
+
+        sg.reset();
+
+        assert_eq!(round(sg.next(20.0).into()), (20.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SavitzkyGolay::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SavitzkyGolay::new(11, 3).unwrap();
+        assert_eq!(format!("{}", indicator), "SG(11, 3)");
+    }
+}
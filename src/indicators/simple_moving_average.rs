@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::helpers::{map_lagged, push_ring};
+use crate::{Close, Current, IsReady, Lookback, Next, NextSlice, Period, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -70,6 +71,18 @@ impl Period for SimpleMovingAverage {
     }
 }
 
+impl Lookback for SimpleMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl IsReady for SimpleMovingAverage {
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
 impl Next<f64> for SimpleMovingAverage {
     type Output = f64;
 
@@ -100,6 +113,52 @@ impl<T: Close> Next<&T> for SimpleMovingAverage {
     }
 }
 
+impl NextSlice for SimpleMovingAverage {
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]) {
+        assert_eq!(inputs.len(), out.len());
+
+        // Warms up one input at a time, then the sum moves by the input leaving the window
+        let warmup = (self.period - self.count).min(inputs.len());
+        for (out, &input) in out[..warmup].iter_mut().zip(&inputs[..warmup]) {
+            *out = self.next(input);
+        }
+        let (inputs, out) = (&inputs[warmup..], &mut out[warmup..]);
+
+        map_lagged(&self.deque, self.index, inputs, out, |input, old| {
+            input - old
+        });
+        self.index = push_ring(&mut self.deque, self.index, inputs);
+        for out in out.iter_mut() {
+            self.sum += *out;
+            *out = self.sum;
+        }
+
+        let period = self.period as f64;
+        for out in out.iter_mut() {
+            *out /= period;
+        }
+    }
+}
+
+impl UpdateLast<f64> for SimpleMovingAverage {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = (self.index + self.period - 1) % self.period;
+        self.sum += input - self.deque[last];
+        self.deque[last] = input;
+        self.sum / (self.count as f64)
+    }
+}
+
+impl<T: Close> UpdateLast<&T> for SimpleMovingAverage {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.close())
+    }
+}
+
 impl Reset for SimpleMovingAverage {
     fn reset(&mut self) {
         self.index = 0;
@@ -111,6 +170,17 @@ impl Reset for SimpleMovingAverage {
     }
 }
 
+impl Current for SimpleMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.count {
+            0 => None,
+            count => Some(self.sum / count as f64),
+        }
+    }
+}
+
 impl Default for SimpleMovingAverage {
     fn default() -> Self {
         Self::new(9).unwrap()
@@ -161,6 +231,56 @@ mod tests {
         assert_eq!(sma.next(&bar(1.0)), 4.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(sma.current(), None);
+
+        for &input in &[4.0, 5.0, 9.0] {
+            let output = sma.next(input);
+            assert_eq!(sma.current(), Some(output));
+        }
+
+        sma.reset();
+        assert_eq!(sma.current(), None);
+    }
+
+    #[test]
+    fn test_update_last() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let mut expected = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(sma.update_last(4.0), expected.next(4.0));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            sma.next(close + 3.0);
+            sma.update_last(close - 1.0);
+            assert_eq!(round(sma.update_last(close)), round(expected.next(close)));
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(SimpleMovingAverage::new(5).unwrap().lookback(), 4);
+    }
+
+    #[test]
+    fn test_is_ready() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        for _ in 0..3 {
+            assert!(!sma.is_ready());
+            sma.next(1.0);
+        }
+        assert!(sma.is_ready());
+
+        sma.reset();
+        assert!(!sma.is_ready());
+    }
+
+    #[test]
+    fn test_next_slice() {
+        assert_next_slice(SimpleMovingAverage::new(9).unwrap());
+    }
+
     #[test]
     fn test_reset() {
         let mut sma = SimpleMovingAverage::new(4).unwrap();
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Simple returns.
+///
+/// Relative change of the input since the previous value. It is the usual first step before
+/// computing statistics of a price series, e.g. volatility or risk adjusted returns.
+///
+/// # Formula
+///
+/// r<sub>t</sub> = p<sub>t</sub> / p<sub>t-1</sub> - 1
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// The first return, and returns after a value of 0, are 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleReturns;
+/// use ta::Next;
+///
+/// let mut returns = SimpleReturns::new();
+/// assert_eq!(returns.next(10.0), 0.0);
+/// assert_eq!(returns.next(15.0), 0.5);
+/// assert_eq!(returns.next(7.5), -0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Rate of return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SimpleReturns {
+    prev: Option<f64>,
+}
+
+impl SimpleReturns {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl Next<f64> for SimpleReturns {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        match self.prev.replace(input) {
+            Some(prev) if prev != 0.0 => input / prev - 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for SimpleReturns {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SimpleReturns {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for SimpleReturns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for SimpleReturns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SIMPLE_RETURNS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SimpleReturns);
+
+    #[test]
+    fn test_next() {
+        let mut returns = SimpleReturns::new();
+
+        assert_eq!(returns.next(10.0), 0.0);
+        assert_eq!(round(returns.next(12.0)), 0.2);
+        assert_eq!(returns.next(9.0), -0.25);
+        assert_eq!(returns.next(9.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut returns = SimpleReturns::new();
+
+        assert_eq!(returns.next(0.0), 0.0);
+        assert_eq!(returns.next(5.0), 0.0);
+        assert_eq!(returns.next(10.0), 1.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut returns = SimpleReturns::new();
+
+        assert_eq!(returns.next(&bar(10.0)), 0.0);
+        assert_eq!(round(returns.next(&bar(12.0))), 0.2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut returns = SimpleReturns::new();
+
+        returns.next(10.0);
+        returns.reset();
+
+        assert_eq!(returns.next(12.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        SimpleReturns::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SimpleReturns::new();
+        assert_eq!(format!("{}", indicator), "SIMPLE_RETURNS");
+    }
+}
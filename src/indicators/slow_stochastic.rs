@@ -2,7 +2,8 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, FastStochastic};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::signal::Thresholds;
+use crate::{Action, Close, Current, High, Lookback, Low, Next, Period, Reset, Signal, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Basically it is a fast stochastic oscillator smoothed with exponential moving average.
 ///
+/// The oscillator implements [Signal](../trait.Signal.html), with the oversold and overbought
+/// thresholds of 20 and 80 by default.
+///
 /// # Parameters
 ///
 /// * _stochastic_period_ - number of periods for fast stochastic (integer greater than 0). Default is 14.
@@ -33,6 +37,8 @@ use serde::{Deserialize, Serialize};
 pub struct SlowStochastic {
     fast_stochastic: FastStochastic,
     ema: ExponentialMovingAverage,
+    current: f64,
+    thresholds: Thresholds,
 }
 
 impl SlowStochastic {
@@ -40,15 +46,24 @@ impl SlowStochastic {
         Ok(Self {
             fast_stochastic: FastStochastic::new(stochastic_period)?,
             ema: ExponentialMovingAverage::new(ema_period)?,
+            current: f64::NAN,
+            thresholds: Thresholds::new(20.0, 80.0)?,
         })
     }
+
+    /// Sets the oversold and overbought thresholds of the [signal](../trait.Signal.html).
+    pub fn with_thresholds(mut self, oversold: f64, overbought: f64) -> Result<Self> {
+        self.thresholds = Thresholds::new(oversold, overbought)?;
+        Ok(self)
+    }
 }
 
 impl Next<f64> for SlowStochastic {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.ema.next(self.fast_stochastic.next(input))
+        self.current = self.ema.next(self.fast_stochastic.next(input));
+        self.current
     }
 }
 
@@ -56,7 +71,32 @@ impl<T: High + Low + Close> Next<&T> for SlowStochastic {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        self.ema.next(self.fast_stochastic.next(input))
+        self.current = self.ema.next(self.fast_stochastic.next(input));
+        self.current
+    }
+}
+
+impl UpdateLast<f64> for SlowStochastic {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        self.current = self
+            .ema
+            .update_last(self.fast_stochastic.update_last(input));
+        self.current
+    }
+}
+
+impl<T: High + Low + Close> UpdateLast<&T> for SlowStochastic {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.current = self
+            .ema
+            .update_last(self.fast_stochastic.update_last(input));
+        self.current
+    }
+}
+
+impl Signal for SlowStochastic {
+    fn signal(&self) -> Action {
+        self.thresholds.action(self.current)
     }
 }
 
@@ -64,6 +104,21 @@ impl Reset for SlowStochastic {
     fn reset(&mut self) {
         self.fast_stochastic.reset();
         self.ema.reset();
+        self.current = f64::NAN;
+    }
+}
+
+impl Lookback for SlowStochastic {
+    fn lookback(&self) -> usize {
+        self.fast_stochastic.lookback() + self.ema.lookback()
+    }
+}
+
+impl Current for SlowStochastic {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(self.current).filter(|current| !current.is_nan())
     }
 }
 
@@ -128,6 +183,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_signal() {
+        assert!(SlowStochastic::default()
+            .with_thresholds(80.0, 20.0)
+            .is_err());
+
+        let mut stoch = SlowStochastic::new(3, 2)
+            .unwrap()
+            .with_thresholds(40.0, 90.0)
+            .unwrap();
+        assert_eq!(stoch.signal(), Action::Neutral);
+
+        stoch.next(10.0);
+        assert_eq!(stoch.signal(), Action::Neutral);
+        stoch.next(50.0);
+        assert_eq!(stoch.signal(), Action::Neutral);
+        stoch.next(50.0);
+        assert_eq!(stoch.signal(), Action::Sell);
+        stoch.next(30.0);
+        assert_eq!(stoch.signal(), Action::Buy);
+    }
+
+    #[test]
+    fn test_update_last() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().high(close + 1.0).low(close - 2.0).close(close)
+        }
+
+        let mut stoch = SlowStochastic::new(3, 2).unwrap();
+        let mut expected = SlowStochastic::new(3, 2).unwrap();
+        assert_eq!(stoch.update_last(&bar(4.0)), expected.next(&bar(4.0)));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            stoch.next(&bar(close + 3.0));
+            stoch.update_last(&bar(close - 1.0));
+            assert_eq!(
+                round(stoch.update_last(&bar(close))),
+                round(expected.next(&bar(close)))
+            );
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut stoch = SlowStochastic::new(3, 2).unwrap();
@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Current, IsReady, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Smoothed moving average (SMMA), also known as running moving average (RMA)
+/// or Wilder's moving average.
+///
+/// It is an exponential moving average with the smoothing factor 1 / _period_,
+/// initialized with the simple moving average of the first _period_ values.
+///
+/// # Formula
+///
+/// SMMA<sub>t</sub> = (SMMA<sub>t-1</sub> * (_period_ - 1) + p<sub>t</sub>) / _period_
+///
+/// Where:
+///
+/// * _SMMA<sub>t</sub>_ - value of smoothed moving average at a point of time _t_
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// While less than _period_ values were given, the average of the given values is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SmoothedMovingAverage;
+/// use ta::Next;
+///
+/// let mut smma = SmoothedMovingAverage::new(3).unwrap();
+/// assert_eq!(smma.next(10.0), 10.0);
+/// assert_eq!(smma.next(11.0), 10.5);
+/// assert_eq!(smma.next(12.0), 11.0);
+/// assert_eq!(smma.next(11.0), 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [Modified moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average)
+///
+#[doc(alias = "SMMA")]
+#[doc(alias = "RMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SmoothedMovingAverage {
+    period: usize,
+    count: usize,
+    current: f64,
+}
+
+impl SmoothedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                current: 0.0,
+            }),
+        }
+    }
+}
+
+impl Period for SmoothedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for SmoothedMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl IsReady for SmoothedMovingAverage {
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl Next<f64> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        }
+        // Cumulative average while warming up, then the recursive smoothing
+        self.current += (input - self.current) / self.count as f64;
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SmoothedMovingAverage {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = 0.0;
+    }
+}
+
+impl Current for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.count {
+            0 => None,
+            _ => Some(self.current),
+        }
+    }
+}
+
+impl Default for SmoothedMovingAverage {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for SmoothedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SMMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SmoothedMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(SmoothedMovingAverage::new(0).is_err());
+        assert!(SmoothedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        assert_eq!(round(smma.next(10.0)), 10.0);
+        assert_eq!(round(smma.next(11.0)), 10.5);
+        assert_eq!(round(smma.next(12.0)), 11.0);
+        assert_eq!(round(smma.next(11.0)), 11.0);
+        assert_eq!(round(smma.next(13.0)), 11.667);
+        assert_eq!(round(smma.next(14.0)), 12.444);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        assert_eq!(smma.next(&bar(10.0)), 10.0);
+        assert_eq!(smma.next(&bar(11.0)), 10.5);
+        assert_eq!(smma.next(&bar(12.0)), 11.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        assert_eq!(smma.next(10.0), 10.0);
+        assert_eq!(smma.next(11.0), 10.5);
+
+        smma.reset();
+
+        assert_eq!(smma.next(20.0), 20.0);
+    }
+
+    #[test]
+    fn test_default() {
+        SmoothedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SmoothedMovingAverage::new(13).unwrap();
+        assert_eq!(format!("{}", indicator), "SMMA(13)");
+    }
+}
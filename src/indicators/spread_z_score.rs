@@ -0,0 +1,317 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ZScore;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hedge ratio of a [spread](struct.SpreadZScore.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HedgeRatio {
+    /// The given hedge ratio.
+    Fixed(f64),
+    /// Slope of the ordinary least squares regression of the first price on the second one
+    /// over the given number of periods (integer greater than 0).
+    Rolling(usize),
+}
+
+impl fmt::Display for HedgeRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HedgeRatio::Fixed(ratio) => write!(f, "{}", ratio),
+            HedgeRatio::Rolling(period) => write!(f, "OLS({})", period),
+        }
+    }
+}
+
+/// Spread Z-Score.
+///
+/// Computes the spread between two prices and its [Z-Score](struct.ZScore.html),
+/// the core statistic of pairs trading. The spread is expected to revert to its mean, so
+/// entries are usually taken when the Z-Score moves far from 0, e.g. beyond ±2.
+///
+/// The inputs are `(a, b)` pairs of prices of the two assets.
+///
+/// # Formula
+///
+/// spread = a - β * b
+///
+/// Z = ZSCORE(_period_) of spread
+///
+/// Where:
+///
+/// * _β_ - hedge ratio, either fixed or the rolling least squares slope of _a_ on _b_:
+///   (n * Σab - Σa * Σb) / (n * Σb<sup>2</sup> - (Σb)<sup>2</sup>)
+///
+/// While the rolling variance of _b_ is 0, e.g. for the first pair, the last hedge ratio is
+/// kept, starting with 1.
+///
+/// # Parameters
+///
+/// * _hedge_ratio_ - fixed or rolling [hedge ratio](enum.HedgeRatio.html). Default is `HedgeRatio::Rolling(60)`.
+/// * _period_ - number of periods of the Z-Score (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{HedgeRatio, SpreadZScore};
+/// use ta::Next;
+///
+/// let mut spread = SpreadZScore::new(HedgeRatio::Fixed(2.0), 3).unwrap();
+///
+/// assert_eq!(spread.next((10.0, 5.0)).zscore, 0.0);
+/// let out = spread.next((12.0, 5.5));
+/// assert_eq!(out.spread, 1.0);
+/// assert_eq!(out.zscore, 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Pairs trade, Wikipedia](https://en.wikipedia.org/wiki/Pairs_trade)
+///
+#[doc(alias = "SPREADZ")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SpreadZScore {
+    hedge_ratio: HedgeRatio,
+    current_ratio: f64,
+    index: usize,
+    count: usize,
+    sum_a: f64,
+    sum_b: f64,
+    sum_ab: f64,
+    sum_b2: f64,
+    deque: Box<[(f64, f64)]>,
+    zscore: ZScore,
+}
+
+impl SpreadZScore {
+    pub fn new(hedge_ratio: HedgeRatio, period: usize) -> Result<Self> {
+        let (current_ratio, ols_period) = match hedge_ratio {
+            HedgeRatio::Fixed(ratio) => (ratio, 0),
+            HedgeRatio::Rolling(0) => return Err(TaError::InvalidParameter),
+            HedgeRatio::Rolling(ols_period) => (1.0, ols_period),
+        };
+
+        Ok(Self {
+            hedge_ratio,
+            current_ratio,
+            index: 0,
+            count: 0,
+            sum_a: 0.0,
+            sum_b: 0.0,
+            sum_ab: 0.0,
+            sum_b2: 0.0,
+            deque: vec![(0.0, 0.0); ols_period].into_boxed_slice(),
+            zscore: ZScore::new(period)?,
+        })
+    }
+
+    pub fn hedge_ratio(&self) -> HedgeRatio {
+        self.hedge_ratio
+    }
+
+    fn update_ratio(&mut self, a: f64, b: f64) {
+        let (old_a, old_b) = self.deque[self.index];
+        self.deque[self.index] = (a, b);
+
+        self.index = if self.index + 1 < self.deque.len() {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.deque.len() {
+            self.count += 1;
+        } else {
+            self.sum_a -= old_a;
+            self.sum_b -= old_b;
+            self.sum_ab -= old_a * old_b;
+            self.sum_b2 -= old_b * old_b;
+        }
+        self.sum_a += a;
+        self.sum_b += b;
+        self.sum_ab += a * b;
+        self.sum_b2 += b * b;
+
+        let n = self.count as f64;
+        let b_variance = n * self.sum_b2 - self.sum_b * self.sum_b;
+        if b_variance > 0.0 {
+            self.current_ratio = (n * self.sum_ab - self.sum_a * self.sum_b) / b_variance;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadZScoreOutput {
+    pub spread: f64,
+    pub hedge_ratio: f64,
+    pub zscore: f64,
+}
+
+impl From<SpreadZScoreOutput> for (f64, f64, f64) {
+    fn from(so: SpreadZScoreOutput) -> Self {
+        (so.spread, so.hedge_ratio, so.zscore)
+    }
+}
+
+impl Period for SpreadZScore {
+    fn period(&self) -> usize {
+        self.zscore.period()
+    }
+}
+
+impl Next<(f64, f64)> for SpreadZScore {
+    type Output = SpreadZScoreOutput;
+
+    fn next(&mut self, (a, b): (f64, f64)) -> Self::Output {
+        if let HedgeRatio::Rolling(_) = self.hedge_ratio {
+            self.update_ratio(a, b);
+        }
+
+        let spread = a - self.current_ratio * b;
+
+        SpreadZScoreOutput {
+            spread,
+            hedge_ratio: self.current_ratio,
+            zscore: self.zscore.next(spread),
+        }
+    }
+}
+
+impl<T: Close> Next<(&T, &T)> for SpreadZScore {
+    type Output = SpreadZScoreOutput;
+
+    fn next(&mut self, (a, b): (&T, &T)) -> Self::Output {
+        self.next((a.close(), b.close()))
+    }
+}
+
+impl Reset for SpreadZScore {
+    fn reset(&mut self) {
+        if let HedgeRatio::Rolling(_) = self.hedge_ratio {
+            self.current_ratio = 1.0;
+        }
+        self.index = 0;
+        self.count = 0;
+        self.sum_a = 0.0;
+        self.sum_b = 0.0;
+        self.sum_ab = 0.0;
+        self.sum_b2 = 0.0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = (0.0, 0.0);
+        }
+        self.zscore.reset();
+    }
+}
+
+impl Default for SpreadZScore {
+    fn default() -> Self {
+        Self::new(HedgeRatio::Rolling(60), 20).unwrap()
+    }
+}
+
+impl fmt::Display for SpreadZScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SPREADZ({}, {})", self.hedge_ratio, self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        let n2 = (nums.2 * 1000.0).round() / 1000.0;
+        (n0, n1, n2)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SpreadZScore::new(HedgeRatio::Fixed(1.0), 0).is_err());
+        assert!(SpreadZScore::new(HedgeRatio::Rolling(0), 20).is_err());
+        assert!(SpreadZScore::new(HedgeRatio::Rolling(1), 1).is_ok());
+        assert!(SpreadZScore::new(HedgeRatio::Fixed(-0.5), 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_fixed() {
+        let mut spread = SpreadZScore::new(HedgeRatio::Fixed(2.0), 3).unwrap();
+
+        assert_eq!(round(spread.next((10.0, 5.0)).into()), (0.0, 2.0, 0.0));
+        assert_eq!(round(spread.next((12.0, 5.5)).into()), (1.0, 2.0, 1.0));
+        assert_eq!(round(spread.next((11.0, 5.2)).into()), (0.6, 2.0, 0.162));
+        assert_eq!(round(spread.next((14.0, 6.5)).into()), (1.0, 2.0, 0.707));
+        assert_eq!(round(spread.next((13.0, 6.0)).into()), (1.0, 2.0, 0.707));
+    }
+
+    #[test]
+    fn test_next_rolling() {
+        let mut spread = SpreadZScore::new(HedgeRatio::Rolling(3), 3).unwrap();
+
+        assert_eq!(round(spread.next((10.0, 5.0)).into()), (5.0, 1.0, 0.0));
+        assert_eq!(round(spread.next((12.0, 5.5)).into()), (-10.0, 4.0, -1.0));
+        assert_eq!(
+            round(spread.next((11.0, 5.2)).into()),
+            (-9.526, 3.947, -0.673)
+        );
+        assert_eq!(
+            round(spread.next((14.0, 6.5)).into()),
+            (-0.496, 2.23, 1.413)
+        );
+        assert_eq!(
+            round(spread.next((13.0, 6.0)).into()),
+            (-0.953, 2.326, 0.651)
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut spread = SpreadZScore::new(HedgeRatio::Fixed(2.0), 3).unwrap();
+
+        assert_eq!(
+            round(spread.next((&bar(10.0), &bar(5.0))).into()),
+            (0.0, 2.0, 0.0)
+        );
+        assert_eq!(
+            round(spread.next((&bar(12.0), &bar(5.5))).into()),
+            (1.0, 2.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut spread = SpreadZScore::new(HedgeRatio::Rolling(3), 3).unwrap();
+
+        spread.next((10.0, 5.0));
+        spread.next((12.0, 5.5));
+
+        spread.reset();
+
+        assert_eq!(round(spread.next((10.0, 5.0)).into()), (5.0, 1.0, 0.0));
+        assert_eq!(round(spread.next((12.0, 5.5)).into()), (-10.0, 4.0, -1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SpreadZScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let spread = SpreadZScore::new(HedgeRatio::Fixed(1.5), 20).unwrap();
+        assert_eq!(format!("{}", spread), "SPREADZ(1.5, 20)");
+
+        let spread = SpreadZScore::default();
+        assert_eq!(format!("{}", spread), "SPREADZ(OLS(60), 20)");
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::helpers::{map_lagged, push_ring};
+use crate::{Close, IsReady, Lookback, Next, NextSlice, Period, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -76,6 +77,18 @@ impl Period for StandardDeviation {
     }
 }
 
+impl Lookback for StandardDeviation {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl IsReady for StandardDeviation {
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
 impl Next<f64> for StandardDeviation {
     type Output = f64;
 
@@ -118,6 +131,68 @@ impl<T: Close> Next<&T> for StandardDeviation {
     }
 }
 
+impl NextSlice for StandardDeviation {
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]) {
+        assert_eq!(inputs.len(), out.len());
+
+        // Warms up one input at a time, then the mean moves by the input leaving the window
+        let warmup = (self.period - self.count).min(inputs.len());
+        for (out, &input) in out[..warmup].iter_mut().zip(&inputs[..warmup]) {
+            *out = self.next(input);
+        }
+        let (inputs, out) = (&inputs[warmup..], &mut out[warmup..]);
+
+        map_lagged(&self.deque, self.index, inputs, out, |input, old| {
+            input - old
+        });
+        self.index = push_ring(&mut self.deque, self.index, inputs);
+
+        let period = self.period as f64;
+        for (out, &input) in out.iter_mut().zip(inputs) {
+            let delta = *out;
+            let old_m = self.m;
+            self.m += delta / period;
+            let delta2 = input - self.m + (input - delta) - old_m;
+            self.m2 = (self.m2 + delta * delta2).max(0.0);
+            *out = self.m2;
+        }
+
+        for out in out.iter_mut() {
+            *out = (*out / period).sqrt();
+        }
+    }
+}
+
+impl UpdateLast<f64> for StandardDeviation {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = (self.index + self.period - 1) % self.period;
+        let old_val = self.deque[last];
+        self.deque[last] = input;
+
+        // Replaces the latest value among the count values
+        let delta = input - old_val;
+        let old_m = self.m;
+        self.m += delta / self.count as f64;
+        let delta2 = input - self.m + old_val - old_m;
+        self.m2 += delta * delta2;
+        if self.m2 < 0.0 {
+            self.m2 = 0.0;
+        }
+
+        (self.m2 / self.count as f64).sqrt()
+    }
+}
+
+impl<T: Close> UpdateLast<&T> for StandardDeviation {
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        self.update_last(input.close())
+    }
+}
+
 impl Reset for StandardDeviation {
     fn reset(&mut self) {
         self.index = 0;
@@ -202,6 +277,24 @@ mod tests {
         assert_eq!(sd.next(4.2), 0.0);
     }
 
+    #[test]
+    fn test_update_last() {
+        let mut sd = StandardDeviation::new(3).unwrap();
+        let mut expected = StandardDeviation::new(3).unwrap();
+        assert_eq!(sd.update_last(4.0), expected.next(4.0));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            sd.next(close + 3.0);
+            sd.update_last(close - 1.0);
+            assert_eq!(round(sd.update_last(close)), round(expected.next(close)));
+        }
+    }
+
+    #[test]
+    fn test_next_slice() {
+        assert_next_slice(StandardDeviation::new(9).unwrap());
+    }
+
     #[test]
     fn test_reset() {
         let mut sd = StandardDeviation::new(4).unwrap();
@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic Momentum Index (SMI).
+///
+/// Developed by William Blau, the SMI is a refinement of the stochastic oscillator.
+/// Instead of measuring the close relative to the high-low range, it measures the close
+/// relative to the midpoint of the range. Both the distance and the range are double smoothed
+/// with exponential moving averages.
+///
+/// The oscillator returns output in the range of -100..100.
+///
+/// # Formula
+///
+/// D = Close - (HH + LL) / 2
+///
+/// SMI = 100 * EMA(EMA(D)) / (EMA(EMA(HH - LL)) / 2)
+///
+/// Signal = EMA(SMI)
+///
+/// Where:
+///
+/// * _HH_, _LL_ - highest high and lowest low for the last _k_period_ periods
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html) with _d_period_
+///
+/// When the smoothed range is 0, SMI is 0.
+///
+/// # Parameters
+///
+/// * _k_period_ - number of periods for the high-low range (integer greater than 0). Default is 10.
+/// * _d_period_ - smoothing period for the EMAs (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticMomentumIndex;
+/// use ta::Next;
+///
+/// let mut smi = StochasticMomentumIndex::new(3, 2).unwrap();
+///
+/// let out = smi.next(10.0);
+/// assert_eq!(out.smi, 0.0);
+/// assert_eq!(out.signal, 0.0);
+///
+/// let out = smi.next(11.0);
+/// assert_eq!(out.smi.round(), 100.0);
+/// assert_eq!(out.signal.round(), 67.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic Momentum Index, TradingView](https://www.tradingview.com/script/HLIT6vQc-Stochastic-Momentum-Index-SMI/)
+///
+#[doc(alias = "SMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticMomentumIndex {
+    period: usize,
+    maximum: Maximum,
+    minimum: Minimum,
+    distance_ema1: Ema,
+    distance_ema2: Ema,
+    range_ema1: Ema,
+    range_ema2: Ema,
+    signal_ema: Ema,
+}
+
+impl StochasticMomentumIndex {
+    pub fn new(k_period: usize, d_period: usize) -> Result<Self> {
+        Ok(Self {
+            period: k_period,
+            maximum: Maximum::new(k_period)?,
+            minimum: Minimum::new(k_period)?,
+            distance_ema1: Ema::new(d_period)?,
+            distance_ema2: Ema::new(d_period)?,
+            range_ema1: Ema::new(d_period)?,
+            range_ema2: Ema::new(d_period)?,
+            signal_ema: Ema::new(d_period)?,
+        })
+    }
+
+    fn calc(&mut self, highest: f64, lowest: f64, close: f64) -> StochasticMomentumIndexOutput {
+        let distance = close - (highest + lowest) / 2.0;
+        let range = highest - lowest;
+
+        let distance = self.distance_ema2.next(self.distance_ema1.next(distance));
+        let range = self.range_ema2.next(self.range_ema1.next(range));
+
+        let smi = if range == 0.0 {
+            0.0
+        } else {
+            100.0 * distance / (range / 2.0)
+        };
+        let signal = self.signal_ema.next(smi);
+
+        StochasticMomentumIndexOutput { smi, signal }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticMomentumIndexOutput {
+    pub smi: f64,
+    pub signal: f64,
+}
+
+impl From<StochasticMomentumIndexOutput> for (f64, f64) {
+    fn from(so: StochasticMomentumIndexOutput) -> Self {
+        (so.smi, so.signal)
+    }
+}
+
+impl Period for StochasticMomentumIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for StochasticMomentumIndex {
+    type Output = StochasticMomentumIndexOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let highest = self.maximum.next(input);
+        let lowest = self.minimum.next(input);
+        self.calc(highest, lowest, input)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for StochasticMomentumIndex {
+    type Output = StochasticMomentumIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let highest = self.maximum.next(input.high());
+        let lowest = self.minimum.next(input.low());
+        self.calc(highest, lowest, input.close())
+    }
+}
+
+impl Reset for StochasticMomentumIndex {
+    fn reset(&mut self) {
+        self.maximum.reset();
+        self.minimum.reset();
+        self.distance_ema1.reset();
+        self.distance_ema2.reset();
+        self.range_ema1.reset();
+        self.range_ema2.reset();
+        self.signal_ema.reset();
+    }
+}
+
+impl Default for StochasticMomentumIndex {
+    fn default() -> Self {
+        Self::new(10, 3).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticMomentumIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SMI({}, {})", self.period, self.signal_ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Smi = StochasticMomentumIndex;
+
+    test_indicator!(Smi);
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 1000.0).round() / 1000.0;
+        let n1 = (nums.1 * 1000.0).round() / 1000.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Smi::new(0, 1).is_err());
+        assert!(Smi::new(1, 0).is_err());
+        assert!(Smi::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut smi = Smi::new(3, 2).unwrap();
+
+        assert_eq!(round(smi.next(10.0).into()), (0.0, 0.0));
+        assert_eq!(round(smi.next(11.0).into()), (100.0, 66.667));
+        assert_eq!(round(smi.next(12.0).into()), (100.0, 88.889));
+        assert_eq!(round(smi.next(11.0).into()), (25.0, 46.296));
+        assert_eq!(round(smi.next(13.0).into()), (61.702, 56.567));
+        assert_eq!(round(smi.next(12.0).into()), (33.742, 41.351));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low, close, smi, signal
+            (12.0, 10.0, 11.0, 0.0, 0.0),
+            (13.0, 11.0, 12.5, 36.364, 24.242),
+            (12.5, 11.0, 11.5, 21.622, 22.495),
+            (14.0, 12.0, 13.5, 41.026, 34.849),
+        ];
+
+        let mut smi = Smi::new(3, 2).unwrap();
+
+        for (high, low, close, expected_smi, expected_signal) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(
+                round(smi.next(&input_bar).into()),
+                (expected_smi, expected_signal)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut smi = Smi::new(3, 2).unwrap();
+
+        assert_eq!(round(smi.next(10.0).into()), (0.0, 0.0));
+        assert_eq!(round(smi.next(11.0).into()), (100.0, 66.667));
+
+        smi.reset();
+
+        assert_eq!(round(smi.next(10.0).into()), (0.0, 0.0));
+        assert_eq!(round(smi.next(11.0).into()), (100.0, 66.667));
+    }
+
+    #[test]
+    fn test_default() {
+        Smi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Smi::new(10, 3).unwrap();
+        assert_eq!(format!("{}", indicator), "SMI(10, 3)");
+    }
+}
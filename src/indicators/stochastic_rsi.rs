@@ -0,0 +1,228 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{FastStochastic, RelativeStrengthIndex, SimpleMovingAverage as Sma};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic RSI (StochRSI).
+///
+/// The Stochastic RSI applies the stochastic oscillator formula to the values of the
+/// [Relative Strength Index](struct.RelativeStrengthIndex.html) instead of the price.
+/// It measures where the current RSI value is relative to its own high-low range
+/// over a given number of periods, which makes it more sensitive than the plain RSI.
+///
+/// The raw value is smoothed with a simple moving average to produce the _%K_ line,
+/// and _%K_ is smoothed again to produce the _%D_ (signal) line.
+///
+/// # Formula
+///
+/// StochRSI<sub>t</sub> = (RSI<sub>t</sub> - min(RSI)) / (max(RSI) - min(RSI)) * 100
+///
+/// %K<sub>t</sub> = SMA(_k_period_) of StochRSI<sub>t</sub>
+///
+/// %D<sub>t</sub> = SMA(_d_period_) of %K<sub>t</sub>
+///
+/// Where:
+///
+/// * _min(RSI)_, _max(RSI)_ - lowest and highest RSI values for the last _stoch_period_ periods
+///
+/// # Parameters
+///
+/// * _rsi_period_ - period for the RSI (integer greater than 0). Default is 14.
+/// * _stoch_period_ - number of RSI values used for the stochastic calculation (integer greater than 0). Default is 14.
+/// * _k_period_ - smoothing period for %K (integer greater than 0). Default is 3.
+/// * _d_period_ - smoothing period for %D (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticRsi;
+/// use ta::Next;
+///
+/// let mut stoch_rsi = StochasticRsi::new(3, 3, 1, 1).unwrap();
+///
+/// let out = stoch_rsi.next(10.0);
+/// assert_eq!(out.k, 50.0);
+/// assert_eq!(out.d, 50.0);
+///
+/// let out = stoch_rsi.next(10.5);
+/// assert_eq!(out.k, 100.0);
+///
+/// let out = stoch_rsi.next(10.0);
+/// assert_eq!(out.k, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochrsi)
+///
+#[doc(alias = "StochRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticRsi {
+    rsi: RelativeStrengthIndex,
+    stochastic: FastStochastic,
+    k_sma: Sma,
+    d_sma: Sma,
+}
+
+impl StochasticRsi {
+    pub fn new(
+        rsi_period: usize,
+        stoch_period: usize,
+        k_period: usize,
+        d_period: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            rsi: RelativeStrengthIndex::new(rsi_period)?,
+            stochastic: FastStochastic::new(stoch_period)?,
+            k_sma: Sma::new(k_period)?,
+            d_sma: Sma::new(d_period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticRsiOutput {
+    pub k: f64,
+    pub d: f64,
+}
+
+impl From<StochasticRsiOutput> for (f64, f64) {
+    fn from(so: StochasticRsiOutput) -> Self {
+        (so.k, so.d)
+    }
+}
+
+impl Next<f64> for StochasticRsi {
+    type Output = StochasticRsiOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rsi = self.rsi.next(input);
+        let stoch_rsi = self.stochastic.next(rsi);
+        let k = self.k_sma.next(stoch_rsi);
+        let d = self.d_sma.next(k);
+
+        StochasticRsiOutput { k, d }
+    }
+}
+
+impl<T: Close> Next<&T> for StochasticRsi {
+    type Output = StochasticRsiOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for StochasticRsi {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.stochastic.reset();
+        self.k_sma.reset();
+        self.d_sma.reset();
+    }
+}
+
+impl Lookback for StochasticRsi {
+    fn lookback(&self) -> usize {
+        self.rsi.lookback()
+            + self.stochastic.lookback()
+            + self.k_sma.lookback()
+            + self.d_sma.lookback()
+    }
+}
+
+impl Default for StochasticRsi {
+    fn default() -> Self {
+        Self::new(14, 14, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STOCH_RSI({}, {}, {}, {})",
+            self.rsi.period(),
+            self.stochastic.period(),
+            self.k_sma.period(),
+            self.d_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(StochasticRsi);
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        let n0 = (nums.0 * 100.0).round() / 100.0;
+        let n1 = (nums.1 * 100.0).round() / 100.0;
+        (n0, n1)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticRsi::new(0, 1, 1, 1).is_err());
+        assert!(StochasticRsi::new(1, 0, 1, 1).is_err());
+        assert!(StochasticRsi::new(1, 1, 0, 1).is_err());
+        assert!(StochasticRsi::new(1, 1, 1, 0).is_err());
+        assert!(StochasticRsi::new(1, 1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+        assert_eq!(round(stoch_rsi.next(10.0).into()), (50.0, 50.0));
+        assert_eq!(round(stoch_rsi.next(10.5).into()), (75.0, 62.5));
+        assert_eq!(round(stoch_rsi.next(10.0).into()), (50.0, 62.5));
+        assert_eq!(round(stoch_rsi.next(9.5).into()), (0.0, 25.0));
+        assert_eq!(round(stoch_rsi.next(11.0).into()), (50.0, 25.0));
+        assert_eq!(round(stoch_rsi.next(11.5).into()), (100.0, 75.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+        assert_eq!(
+            round(stoch_rsi.next(&Bar::new().close(10.0)).into()),
+            (50.0, 50.0)
+        );
+        assert_eq!(
+            round(stoch_rsi.next(&Bar::new().close(10.5)).into()),
+            (75.0, 62.5)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+        assert_eq!(round(stoch_rsi.next(10.0).into()), (50.0, 50.0));
+        assert_eq!(round(stoch_rsi.next(10.5).into()), (75.0, 62.5));
+
+        stoch_rsi.reset();
+
+        assert_eq!(round(stoch_rsi.next(10.0).into()), (50.0, 50.0));
+        assert_eq!(round(stoch_rsi.next(10.5).into()), (75.0, 62.5));
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = StochasticRsi::new(14, 10, 3, 5).unwrap();
+        assert_eq!(format!("{}", indicator), "STOCH_RSI(14, 10, 3, 5)");
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tillson T3 moving average (T3).
+///
+/// Developed by Tim Tillson, T3 is a smooth moving average with reduced lag.
+/// It is built from six cascaded [EMAs](struct.ExponentialMovingAverage.html),
+/// combined with coefficients depending on the volume factor.
+///
+/// # Formula
+///
+/// T3 = c1 * e6 + c2 * e5 + c3 * e4 + c4 * e3
+///
+/// Where:
+///
+/// * _e1_ = EMA(_period_) of the input, _e2_ = EMA(_period_) of _e1_, ..., _e6_ = EMA(_period_) of _e5_
+/// * _c1_ = -b<sup>3</sup>
+/// * _c2_ = 3b<sup>2</sup> + 3b<sup>3</sup>
+/// * _c3_ = -6b<sup>2</sup> - 3b - 3b<sup>3</sup>
+/// * _c4_ = 1 + 3b + b<sup>3</sup> + 3b<sup>2</sup>
+/// * _b_ - volume factor
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMAs (integer greater than 0). Default is 5.
+/// * _volume_factor_ - volume factor _b_ (number between 0 and 1). Default is 0.7.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::T3MovingAverage;
+/// use ta::Next;
+///
+/// let mut t3 = T3MovingAverage::new(3, 0.7).unwrap();
+/// assert_eq!(t3.next(10.0), 10.0);
+/// assert_eq!((t3.next(11.0) * 1000.0).round() / 1000.0, 10.308);
+/// ```
+///
+/// # Links
+///
+/// * [T3 Moving Average, TradingView](https://www.tradingview.com/script/cPQ3RYdR-T3-Moving-Average/)
+///
+#[doc(alias = "T3")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct T3MovingAverage {
+    volume_factor: f64,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    c4: f64,
+    emas: [Ema; 6],
+}
+
+impl T3MovingAverage {
+    pub fn new(period: usize, volume_factor: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&volume_factor) {
+            return Err(TaError::InvalidParameter);
+        }
+        let b = volume_factor;
+        let ema = Ema::new(period)?;
+
+        Ok(Self {
+            volume_factor,
+            c1: -b.powi(3),
+            c2: 3.0 * b.powi(2) + 3.0 * b.powi(3),
+            c3: -6.0 * b.powi(2) - 3.0 * b - 3.0 * b.powi(3),
+            c4: 1.0 + 3.0 * b + b.powi(3) + 3.0 * b.powi(2),
+            emas: [
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema,
+            ],
+        })
+    }
+
+    pub fn volume_factor(&self) -> f64 {
+        self.volume_factor
+    }
+}
+
+impl Period for T3MovingAverage {
+    fn period(&self) -> usize {
+        self.emas[0].period()
+    }
+}
+
+impl Next<f64> for T3MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut values = [0.0; 6];
+        let mut value = input;
+        for (ema, out) in self.emas.iter_mut().zip(values.iter_mut()) {
+            value = ema.next(value);
+            *out = value;
+        }
+
+        self.c1 * values[5] + self.c2 * values[4] + self.c3 * values[3] + self.c4 * values[2]
+    }
+}
+
+impl<T: Close> Next<&T> for T3MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for T3MovingAverage {
+    fn reset(&mut self) {
+        for ema in self.emas.iter_mut() {
+            ema.reset();
+        }
+    }
+}
+
+impl Default for T3MovingAverage {
+    fn default() -> Self {
+        Self::new(5, 0.7).unwrap()
+    }
+}
+
+impl fmt::Display for T3MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "T3({}, {})", self.period(), self.volume_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(T3MovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(T3MovingAverage::new(0, 0.7).is_err());
+        assert!(T3MovingAverage::new(1, -0.1).is_err());
+        assert!(T3MovingAverage::new(1, 1.1).is_err());
+        assert!(T3MovingAverage::new(1, f64::NAN).is_err());
+        assert!(T3MovingAverage::new(1, f64::INFINITY).is_err());
+        assert!(T3MovingAverage::new(1, 0.7).is_ok());
+        assert!(T3MovingAverage::new(1, 0.0).is_ok());
+        assert!(T3MovingAverage::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut t3 = T3MovingAverage::new(3, 0.7).unwrap();
+
+        assert_eq!(round(t3.next(10.0)), 10.0);
+        assert_eq!(round(t3.next(11.0)), 10.308);
+        assert_eq!(round(t3.next(12.0)), 10.957);
+        assert_eq!(round(t3.next(11.0)), 11.229);
+        assert_eq!(round(t3.next(13.0)), 11.863);
+        assert_eq!(round(t3.next(14.0)), 12.785);
+    }
+
+    #[test]
+    fn test_next_with_zero_volume_factor() {
+        // With b = 0, T3 is a triple smoothed EMA
+        let mut t3 = T3MovingAverage::new(3, 0.0).unwrap();
+        let mut ema1 = Ema::new(3).unwrap();
+        let mut ema2 = Ema::new(3).unwrap();
+        let mut ema3 = Ema::new(3).unwrap();
+
+        for &input in &[10.0, 11.0, 12.0, 11.0, 13.0, 14.0] {
+            let expected = ema3.next(ema2.next(ema1.next(input)));
+            assert_eq!(round(t3.next(input)), round(expected));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut t3 = T3MovingAverage::new(3, 0.7).unwrap();
+
+        assert_eq!(round(t3.next(&bar(10.0))), 10.0);
+        assert_eq!(round(t3.next(&bar(11.0))), 10.308);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut t3 = T3MovingAverage::new(3, 0.7).unwrap();
+
+        assert_eq!(round(t3.next(10.0)), 10.0);
+        assert_eq!(round(t3.next(11.0)), 10.308);
+
+        t3.reset();
+
+        assert_eq!(round(t3.next(10.0)), 10.0);
+        assert_eq!(round(t3.next(11.0)), 10.308);
+    }
+
+    #[test]
+    fn test_default() {
+        T3MovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = T3MovingAverage::new(5, 0.7).unwrap();
+        assert_eq!(format!("{}", indicator), "T3(5, 0.7)");
+    }
+}
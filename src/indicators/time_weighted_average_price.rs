@@ -0,0 +1,278 @@
+use std::fmt;
+
+use crate::bars::Timeframe;
+use crate::errors::{Result, TaError};
+#[cfg(feature = "chrono")]
+use crate::Timestamp;
+use crate::{Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Time weighted average price (TWAP).
+///
+/// Average of the typical prices of the bars, each weighted by the time elapsed since the
+/// previous bar. It is commonly used as a benchmark for the quality of order execution.
+///
+/// The inputs are `(timestamp, bar)` pairs, or `(timestamp, price)` pairs, where timestamps are
+/// Unix milliseconds of the close of the bars. The first bar only sets the starting time, so
+/// its typical price is returned until the next bar arrives.
+///
+/// With the `chrono` feature, bars with a [Timestamp](../trait.Timestamp.html) can be given
+/// directly. Bars without a timestamp are then given no weight.
+///
+/// When a _session_ is given, the average starts over with the first bar of every new session,
+/// e.g. every UTC day for `Timeframe::Days(1)`. Otherwise it runs until it is
+/// [reset](../trait.Reset.html).
+///
+/// # Formula
+///
+/// TWAP = Σ(TP<sub>i</sub> * (t<sub>i</sub> - t<sub>i-1</sub>)) / (t<sub>n</sub> - t<sub>0</sub>)
+///
+/// Where:
+///
+/// * _TP<sub>i</sub>_ - typical price, (High + Low + Close) / 3, of the bar _i_
+/// * _t<sub>i</sub>_ - timestamp of the bar _i_
+///
+/// # Parameters
+///
+/// * _session_ - optional [timeframe](../bars/enum.Timeframe.html) of the sessions, which must be
+///   [valid](../bars/enum.Timeframe.html#method.is_valid). Default is `None`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TimeWeightedAveragePrice;
+/// use ta::Next;
+///
+/// let mut twap = TimeWeightedAveragePrice::new(None).unwrap();
+/// assert_eq!(twap.next((0, 10.0)), 10.0);
+/// assert_eq!(twap.next((60_000, 11.0)), 11.0);
+/// assert_eq!(twap.next((120_000, 12.0)), 11.5);
+/// assert_eq!(twap.next((240_000, 13.0)), 12.25);
+/// ```
+///
+/// # Links
+///
+/// * [Time weighted average price, Wikipedia](https://en.wikipedia.org/wiki/Time-weighted_average_price)
+///
+#[doc(alias = "TWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TimeWeightedAveragePrice {
+    session: Option<Timeframe>,
+    session_start: Option<i64>,
+    last_timestamp: Option<i64>,
+    weighted_sum: f64,
+    elapsed: f64,
+    current: f64,
+}
+
+impl TimeWeightedAveragePrice {
+    pub fn new(session: Option<Timeframe>) -> Result<Self> {
+        match session {
+            Some(timeframe) if !timeframe.is_valid() => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                session,
+                session_start: None,
+                last_timestamp: None,
+                weighted_sum: 0.0,
+                elapsed: 0.0,
+                current: 0.0,
+            }),
+        }
+    }
+
+    pub fn session(&self) -> Option<Timeframe> {
+        self.session
+    }
+}
+
+impl Next<(i64, f64)> for TimeWeightedAveragePrice {
+    type Output = f64;
+
+    fn next(&mut self, (timestamp, price): (i64, f64)) -> Self::Output {
+        if let Some(session) = self.session {
+            let start = session.saturating_start_of(timestamp);
+            if self.session_start.is_some_and(|current| start > current) {
+                self.reset();
+            }
+            self.session_start = Some(start);
+        }
+
+        match self.last_timestamp {
+            Some(last) => {
+                // Out of order timestamps are given no weight
+                let elapsed = (timestamp - last).max(0) as f64;
+                self.weighted_sum += price * elapsed;
+                self.elapsed += elapsed;
+                if self.elapsed > 0.0 {
+                    self.current = self.weighted_sum / self.elapsed;
+                }
+                self.last_timestamp = Some(last.max(timestamp));
+            }
+            None => {
+                self.current = price;
+                self.last_timestamp = Some(timestamp);
+            }
+        }
+
+        self.current
+    }
+}
+
+impl<T: High + Low + Close> Next<(i64, &T)> for TimeWeightedAveragePrice {
+    type Output = f64;
+
+    fn next(&mut self, (timestamp, input): (i64, &T)) -> Self::Output {
+        let typical_price = (input.high() + input.low() + input.close()) / 3.0;
+        self.next((timestamp, typical_price))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: High + Low + Close + Timestamp> Next<&T> for TimeWeightedAveragePrice {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let timestamp = input
+            .timestamp()
+            .map(|timestamp| timestamp.timestamp_millis())
+            .or(self.last_timestamp);
+        match timestamp {
+            Some(timestamp) => self.next((timestamp, input)),
+            None => self.current,
+        }
+    }
+}
+
+impl Reset for TimeWeightedAveragePrice {
+    fn reset(&mut self) {
+        self.session_start = None;
+        self.last_timestamp = None;
+        self.weighted_sum = 0.0;
+        self.elapsed = 0.0;
+        self.current = 0.0;
+    }
+}
+
+impl Default for TimeWeightedAveragePrice {
+    fn default() -> Self {
+        Self::new(None).unwrap()
+    }
+}
+
+impl fmt::Display for TimeWeightedAveragePrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.session {
+            Some(session) => write!(f, "TWAP({})", session),
+            None => write!(f, "TWAP()"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    const MINUTE: i64 = 60_000;
+    const DAY: i64 = 24 * 60 * MINUTE;
+
+    #[test]
+    fn test_new() {
+        assert!(TimeWeightedAveragePrice::new(Some(Timeframe::Days(0))).is_err());
+        assert!(TimeWeightedAveragePrice::new(Some(Timeframe::Days(1))).is_ok());
+        assert!(TimeWeightedAveragePrice::new(None).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut twap = TimeWeightedAveragePrice::new(None).unwrap();
+
+        assert_eq!(twap.next((0, 10.0)), 10.0);
+        assert_eq!(twap.next((MINUTE, 11.0)), 11.0);
+        assert_eq!(twap.next((2 * MINUTE, 12.0)), 11.5);
+        assert_eq!(twap.next((4 * MINUTE, 13.0)), 12.25);
+        assert_eq!(round(twap.next((5 * MINUTE, 9.0))), 11.6);
+    }
+
+    #[test]
+    fn test_next_same_timestamps() {
+        let mut twap = TimeWeightedAveragePrice::new(None).unwrap();
+
+        assert_eq!(twap.next((0, 10.0)), 10.0);
+        assert_eq!(twap.next((0, 11.0)), 10.0);
+        assert_eq!(twap.next((MINUTE, 12.0)), 12.0);
+        // Older than the last bar
+        assert_eq!(twap.next((0, 20.0)), 12.0);
+    }
+
+    #[test]
+    fn test_next_with_session() {
+        let mut twap = TimeWeightedAveragePrice::new(Some(Timeframe::Days(1))).unwrap();
+
+        assert_eq!(twap.next((DAY - 2 * MINUTE, 10.0)), 10.0);
+        assert_eq!(twap.next((DAY - MINUTE, 12.0)), 12.0);
+        assert_eq!(twap.next((DAY, 14.0)), 14.0);
+        assert_eq!(twap.next((DAY + MINUTE, 16.0)), 16.0);
+        assert_eq!(twap.next((DAY + 3 * MINUTE, 10.0)), 12.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64, close: f64) -> Bar {
+            Bar::new().high(high).low(low).close(close)
+        }
+
+        let mut twap = TimeWeightedAveragePrice::new(None).unwrap();
+
+        assert_eq!(twap.next((0, &bar(11.0, 9.0, 10.0))), 10.0);
+        assert_eq!(twap.next((MINUTE, &bar(13.0, 10.0, 10.0))), 11.0);
+        assert_eq!(
+            round(twap.next((3 * MINUTE, &bar(14.0, 12.0, 13.0)))),
+            12.333
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_with_timestamps() {
+        let mut twap = TimeWeightedAveragePrice::new(None).unwrap();
+
+        assert_eq!(twap.next(&timed_item(Some(0), 10.0, 10.0, 1.0)), 10.0);
+        // No weight without a timestamp
+        assert_eq!(twap.next(&timed_item(None, 30.0, 30.0, 1.0)), 10.0);
+        assert_eq!(twap.next(&timed_item(Some(MINUTE), 12.0, 12.0, 1.0)), 12.0);
+        assert_eq!(
+            twap.next(&timed_item(Some(2 * MINUTE), 15.0, 12.0, 1.0)),
+            13.0
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut twap = TimeWeightedAveragePrice::new(None).unwrap();
+
+        twap.next((0, 10.0));
+        twap.next((MINUTE, 11.0));
+
+        twap.reset();
+
+        assert_eq!(twap.next((2 * MINUTE, 20.0)), 20.0);
+        assert_eq!(twap.next((3 * MINUTE, 22.0)), 22.0);
+    }
+
+    #[test]
+    fn test_default() {
+        TimeWeightedAveragePrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let twap = TimeWeightedAveragePrice::new(None).unwrap();
+        assert_eq!(format!("{}", twap), "TWAP()");
+
+        let twap = TimeWeightedAveragePrice::new(Some(Timeframe::Days(1))).unwrap();
+        assert_eq!(format!("{}", twap), "TWAP(1d)");
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Pivot, PivotDetector};
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of swing points of each kind considered for new lines.
+const MAX_PIVOTS: usize = 20;
+
+/// Trend line found by [TrendLines](struct.TrendLines.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendLine {
+    /// The first swing point of the line.
+    pub start: Pivot,
+    /// The last swing point of the line.
+    pub end: Pivot,
+    /// Change of the price per bar. Positive for ascending lines, negative for descending ones.
+    pub slope: f64,
+    /// Number of swing points on the line, within the tolerance.
+    pub touches: usize,
+    /// Price of the line projected to the current bar.
+    pub price: f64,
+}
+
+impl TrendLine {
+    fn price_at(&self, index: usize) -> f64 {
+        self.end.price + self.slope * (index as f64 - self.end.index as f64)
+    }
+}
+
+/// Automatic trend lines.
+///
+/// Fits a support line through swing lows and a resistance line through swing highs, which
+/// are found by a [PivotDetector](struct.PivotDetector.html).
+///
+/// When a new swing point is confirmed, lines from every recent earlier swing point of the same
+/// kind to the new one are considered. A line is valid when no swing point between its ends
+/// lies beyond it, i.e. below a support line or above a resistance line, by more than the
+/// _tolerance_. The valid line with the most touches, i.e. swing points within the _tolerance_,
+/// becomes the active line when it has at least _min_touches_ touches.
+///
+/// An active line is kept until a bar breaks it by more than the _tolerance_, e.g. a low below
+/// the support line, or until a new line replaces it.
+///
+/// # Parameters
+///
+/// * _left_ - number of bars before a swing point (integer greater than 0). Default is 5.
+/// * _right_ - number of bars after a swing point (integer greater than 0). Default is 5.
+/// * _min_touches_ - minimum number of swing points on a line (integer greater than 1). Default is 3.
+/// * _tolerance_ - distance from a line, in percent of its price, within which a swing point touches it. Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TrendLines;
+/// use ta::Next;
+///
+/// let mut lines = TrendLines::new(1, 1, 2, 0.0).unwrap();
+///
+/// let mut out = None;
+/// for &price in &[10.0, 8.0, 11.0, 9.0, 12.0] {
+///     out = Some(lines.next(price));
+/// }
+/// let support = out.unwrap().support.unwrap();
+///
+/// assert_eq!(support.slope, 0.5);
+/// assert_eq!(support.price, 9.5);
+/// ```
+///
+/// # Links
+///
+/// * [Trendline, Wikipedia](https://en.wikipedia.org/wiki/Trend_line_(technical_analysis))
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TrendLines {
+    min_touches: usize,
+    tolerance: f64,
+    pivots: PivotDetector,
+    index: usize,
+    highs: VecDeque<Pivot>,
+    lows: VecDeque<Pivot>,
+    support: Option<TrendLine>,
+    resistance: Option<TrendLine>,
+}
+
+impl TrendLines {
+    pub fn new(left: usize, right: usize, min_touches: usize, tolerance: f64) -> Result<Self> {
+        if min_touches < 2 || tolerance < 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            min_touches,
+            tolerance,
+            pivots: PivotDetector::new(left, right)?,
+            index: 0,
+            highs: VecDeque::with_capacity(MAX_PIVOTS),
+            lows: VecDeque::with_capacity(MAX_PIVOTS),
+            support: None,
+            resistance: None,
+        })
+    }
+
+    /// Returns the best valid line ending at the last of the given swing points.
+    /// `side` is -1 for support lines and 1 for resistance lines.
+    fn fit(&self, pivots: &VecDeque<Pivot>, side: f64) -> Option<TrendLine> {
+        let end = *pivots.back()?;
+        let mut best: Option<TrendLine> = None;
+
+        for (i, &start) in pivots.iter().enumerate().rev().skip(1) {
+            let mut line = TrendLine {
+                start,
+                end,
+                slope: (end.price - start.price) / (end.index - start.index) as f64,
+                touches: 0,
+                price: 0.0,
+            };
+
+            let mut is_valid = true;
+            for pivot in pivots.iter().skip(i) {
+                let price = line.price_at(pivot.index);
+                let distance = (pivot.price - price) * side;
+                let tolerance = price.abs() * self.tolerance / 100.0;
+                if distance > tolerance {
+                    is_valid = false;
+                    break;
+                }
+                if distance.abs() <= tolerance {
+                    line.touches += 1;
+                }
+            }
+
+            if is_valid && best.is_none_or(|best| line.touches > best.touches) {
+                best = Some(line);
+            }
+        }
+
+        best.filter(|line| line.touches >= self.min_touches)
+    }
+
+    fn push(pivots: &mut VecDeque<Pivot>, pivot: Pivot) {
+        if pivots.len() == MAX_PIVOTS {
+            pivots.pop_front();
+        }
+        pivots.push_back(pivot);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendLinesOutput {
+    pub support: Option<TrendLine>,
+    pub resistance: Option<TrendLine>,
+}
+
+impl Next<(f64, f64)> for TrendLines {
+    type Output = TrendLinesOutput;
+
+    fn next(&mut self, (high, low): (f64, f64)) -> Self::Output {
+        let pivots = self.pivots.next((high, low));
+
+        if let Some(pivot) = pivots.low {
+            Self::push(&mut self.lows, pivot);
+            if let Some(line) = self.fit(&self.lows, -1.0) {
+                self.support = Some(line);
+            }
+        }
+        if let Some(pivot) = pivots.high {
+            Self::push(&mut self.highs, pivot);
+            if let Some(line) = self.fit(&self.highs, 1.0) {
+                self.resistance = Some(line);
+            }
+        }
+
+        let index = self.index;
+        self.index += 1;
+        let tolerance = self.tolerance / 100.0;
+
+        self.support = self.support.and_then(|mut line| {
+            line.price = line.price_at(index);
+            if low < line.price - line.price.abs() * tolerance {
+                None
+            } else {
+                Some(line)
+            }
+        });
+        self.resistance = self.resistance.and_then(|mut line| {
+            line.price = line.price_at(index);
+            if high > line.price + line.price.abs() * tolerance {
+                None
+            } else {
+                Some(line)
+            }
+        });
+
+        TrendLinesOutput {
+            support: self.support,
+            resistance: self.resistance,
+        }
+    }
+}
+
+impl Next<f64> for TrendLines {
+    type Output = TrendLinesOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.next((input, input))
+    }
+}
+
+impl<T: High + Low> Next<&T> for TrendLines {
+    type Output = TrendLinesOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high(), input.low()))
+    }
+}
+
+impl Reset for TrendLines {
+    fn reset(&mut self) {
+        self.pivots.reset();
+        self.index = 0;
+        self.highs.clear();
+        self.lows.clear();
+        self.support = None;
+        self.resistance = None;
+    }
+}
+
+impl Default for TrendLines {
+    fn default() -> Self {
+        Self::new(5, 5, 3, 0.5).unwrap()
+    }
+}
+
+impl fmt::Display for TrendLines {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TRENDLINES({}, {}, {}, {})",
+            self.pivots.left(),
+            self.pivots.right(),
+            self.min_touches,
+            self.tolerance
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TrendLines);
+
+    fn pivot(index: usize, price: f64) -> Pivot {
+        Pivot { index, price }
+    }
+
+    fn line(start: Pivot, end: Pivot, slope: f64, touches: usize, price: f64) -> TrendLine {
+        TrendLine {
+            start,
+            end,
+            slope,
+            touches,
+            price,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(TrendLines::new(0, 1, 2, 0.5).is_err());
+        assert!(TrendLines::new(1, 0, 2, 0.5).is_err());
+        assert!(TrendLines::new(1, 1, 1, 0.5).is_err());
+        assert!(TrendLines::new(1, 1, 2, -0.5).is_err());
+        assert!(TrendLines::new(1, 1, 2, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut lines = TrendLines::new(1, 1, 2, 0.0).unwrap();
+
+        for &price in &[10.0, 8.0, 11.0, 9.0] {
+            assert_eq!(
+                lines.next(price),
+                TrendLinesOutput {
+                    support: None,
+                    resistance: None
+                }
+            );
+        }
+
+        let out = lines.next(12.0);
+        assert_eq!(
+            out.support,
+            Some(line(pivot(1, 8.0), pivot(3, 9.0), 0.5, 2, 9.5))
+        );
+        assert_eq!(out.resistance, None);
+
+        let out = lines.next(10.0);
+        assert_eq!(
+            out.support,
+            Some(line(pivot(1, 8.0), pivot(3, 9.0), 0.5, 2, 10.0))
+        );
+        assert_eq!(
+            out.resistance,
+            Some(line(pivot(2, 11.0), pivot(4, 12.0), 0.5, 2, 12.5))
+        );
+
+        // Third touch
+        let out = lines.next(13.0);
+        assert_eq!(
+            out.support,
+            Some(line(pivot(1, 8.0), pivot(5, 10.0), 0.5, 3, 10.5))
+        );
+
+        // Support is broken
+        let out = lines.next(9.0);
+        assert_eq!(out.support, None);
+        assert_eq!(
+            out.resistance,
+            Some(line(pivot(2, 11.0), pivot(6, 13.0), 0.5, 3, 13.5))
+        );
+    }
+
+    #[test]
+    fn test_next_min_touches() {
+        let mut lines = TrendLines::new(1, 1, 3, 0.0).unwrap();
+
+        let supports: Vec<_> = [10.0, 8.0, 11.0, 9.0, 12.0, 10.0, 13.0]
+            .iter()
+            .map(|&price| lines.next(price).support.map(|line| line.touches))
+            .collect();
+
+        assert_eq!(supports, vec![None, None, None, None, None, None, Some(3)]);
+    }
+
+    #[test]
+    fn test_next_invalid_line() {
+        let mut lines = TrendLines::new(1, 1, 2, 0.0).unwrap();
+
+        // The low at 5 lies below the line from 8 to 9, so the support starts at 5
+        let out = [10.0, 8.0, 11.0, 5.0, 12.0, 9.0, 13.0]
+            .iter()
+            .map(|&price| lines.next(price))
+            .last()
+            .unwrap();
+
+        assert_eq!(
+            out.support,
+            Some(line(pivot(3, 5.0), pivot(5, 9.0), 2.0, 2, 11.0))
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut lines = TrendLines::new(1, 1, 2, 0.0).unwrap();
+
+        lines.next(&bar(11.0, 10.0));
+        lines.next(&bar(9.0, 8.0));
+        lines.next(&bar(12.0, 11.0));
+        lines.next(&bar(10.0, 9.0));
+        let out = lines.next(&bar(13.0, 12.0));
+
+        assert_eq!(
+            out.support,
+            Some(line(pivot(1, 8.0), pivot(3, 9.0), 0.5, 2, 9.5))
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut lines = TrendLines::new(1, 1, 2, 0.0).unwrap();
+
+        for &price in &[10.0, 8.0, 11.0, 9.0, 12.0] {
+            lines.next(price);
+        }
+
+        lines.reset();
+
+        assert_eq!(lines.next(12.0).support, None);
+    }
+
+    #[test]
+    fn test_default() {
+        TrendLines::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let lines = TrendLines::default();
+        assert_eq!(format!("{}", lines), "TRENDLINES(5, 5, 3, 0.5)");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::helpers::max3;
-use crate::{Close, High, Low, Next, Reset};
+use crate::{Close, High, Lookback, Low, Next, Reset, UpdateLast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -53,11 +53,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct TrueRange {
     prev_close: Option<f64>,
+    // Close before the latest input, used to revise it
+    before_prev_close: Option<f64>,
 }
 
 impl TrueRange {
     pub fn new() -> Self {
-        Self { prev_close: None }
+        Self {
+            prev_close: None,
+            before_prev_close: None,
+        }
     }
 }
 
@@ -73,14 +78,31 @@ impl fmt::Display for TrueRange {
     }
 }
 
+fn distance(prev_close: Option<f64>, input: f64) -> f64 {
+    match prev_close {
+        Some(prev) => (input - prev).abs(),
+        None => 0.0,
+    }
+}
+
+fn range<T: High + Low>(prev_close: Option<f64>, bar: &T) -> f64 {
+    match prev_close {
+        Some(prev_close) => {
+            let dist1 = bar.high() - bar.low();
+            let dist2 = (bar.high() - prev_close).abs();
+            let dist3 = (bar.low() - prev_close).abs();
+            max3(dist1, dist2, dist3)
+        }
+        None => bar.high() - bar.low(),
+    }
+}
+
 impl Next<f64> for TrueRange {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let distance = match self.prev_close {
-            Some(prev) => (input - prev).abs(),
-            None => 0.0,
-        };
+        self.before_prev_close = self.prev_close;
+        let distance = distance(self.prev_close, input);
         self.prev_close = Some(input);
         distance
     }
@@ -90,23 +112,45 @@ impl<T: High + Low + Close> Next<&T> for TrueRange {
     type Output = f64;
 
     fn next(&mut self, bar: &T) -> Self::Output {
-        let max_dist = match self.prev_close {
-            Some(prev_close) => {
-                let dist1 = bar.high() - bar.low();
-                let dist2 = (bar.high() - prev_close).abs();
-                let dist3 = (bar.low() - prev_close).abs();
-                max3(dist1, dist2, dist3)
-            }
-            None => bar.high() - bar.low(),
-        };
+        self.before_prev_close = self.prev_close;
+        let max_dist = range(self.prev_close, bar);
         self.prev_close = Some(bar.close());
         max_dist
     }
 }
 
+impl UpdateLast<f64> for TrueRange {
+    fn update_last(&mut self, input: f64) -> Self::Output {
+        if self.prev_close.is_none() {
+            return self.next(input);
+        }
+
+        self.prev_close = Some(input);
+        distance(self.before_prev_close, input)
+    }
+}
+
+impl<T: High + Low + Close> UpdateLast<&T> for TrueRange {
+    fn update_last(&mut self, bar: &T) -> Self::Output {
+        if self.prev_close.is_none() {
+            return self.next(bar);
+        }
+
+        self.prev_close = Some(bar.close());
+        range(self.before_prev_close, bar)
+    }
+}
+
 impl Reset for TrueRange {
     fn reset(&mut self) {
         self.prev_close = None;
+        self.before_prev_close = None;
+    }
+}
+
+impl Lookback for TrueRange {
+    fn lookback(&self) -> usize {
+        1
     }
 }
 
@@ -138,6 +182,26 @@ mod tests {
         assert_eq!(tr.next(&bar3), 4.5);
     }
 
+    #[test]
+    fn test_update_last() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().high(close + 1.0).low(close - 2.0).close(close)
+        }
+
+        let mut tr = TrueRange::new();
+        let mut expected = TrueRange::new();
+        assert_eq!(tr.update_last(&bar(4.0)), expected.next(&bar(4.0)));
+
+        for &close in &[7.0, 2.0, 5.0, 5.0, 9.0] {
+            tr.next(&bar(close + 3.0));
+            tr.update_last(&bar(close - 1.0));
+            assert_eq!(
+                round(tr.update_last(&bar(close))),
+                round(expected.next(&bar(close)))
+            );
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut tr = TrueRange::new();
@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ultimate Oscillator (UO).
+///
+/// Developed by Larry Williams, the Ultimate Oscillator is a momentum oscillator
+/// that captures buying pressure across three different time frames.
+/// Using several time frames reduces the volatility and the number of false signals
+/// of oscillators based on a single time frame.
+///
+/// The oscillator returns output in the range of 0..100.
+///
+/// # Formula
+///
+/// BP = Close - min(Low, Close<sub>prev</sub>)
+///
+/// TR = max(High, Close<sub>prev</sub>) - min(Low, Close<sub>prev</sub>)
+///
+/// Average<sub>n</sub> = Sum(BP, _n_) / Sum(TR, _n_)
+///
+/// UO = 100 * (4 * Average<sub>short</sub> + 2 * Average<sub>medium</sub> + Average<sub>long</sub>) / 7
+///
+/// Where:
+///
+/// * _BP_ - buying pressure
+/// * _TR_ - true range
+///
+/// When the sum of true ranges for a time frame is 0, its average is considered to be 0.5.
+///
+/// # Parameters
+///
+/// * _short_period_ - number of periods of the short time frame (integer greater than 0). Default is 7.
+/// * _medium_period_ - number of periods of the medium time frame (integer greater than 0). Default is 14.
+/// * _long_period_ - number of periods of the long time frame (integer greater than 0). Default is 28.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::UltimateOscillator;
+/// use ta::Next;
+///
+/// let mut uo = UltimateOscillator::new(2, 3, 4).unwrap();
+/// assert_eq!(uo.next(10.0), 50.0);
+/// assert_eq!(uo.next(11.0), 100.0);
+/// assert_eq!(uo.next(10.5).round(), 67.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ultimate Oscillator, Wikipedia](https://en.wikipedia.org/wiki/Ultimate_oscillator)
+/// * [Ultimate Oscillator, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ultimate_oscillator)
+///
+#[doc(alias = "UO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UltimateOscillator {
+    short_bp: Sma,
+    short_tr: Sma,
+    medium_bp: Sma,
+    medium_tr: Sma,
+    long_bp: Sma,
+    long_tr: Sma,
+    prev_close: Option<f64>,
+}
+
+impl UltimateOscillator {
+    pub fn new(short_period: usize, medium_period: usize, long_period: usize) -> Result<Self> {
+        Ok(Self {
+            short_bp: Sma::new(short_period)?,
+            short_tr: Sma::new(short_period)?,
+            medium_bp: Sma::new(medium_period)?,
+            medium_tr: Sma::new(medium_period)?,
+            long_bp: Sma::new(long_period)?,
+            long_tr: Sma::new(long_period)?,
+            prev_close: None,
+        })
+    }
+
+    fn calc(&mut self, high: f64, low: f64, close: f64) -> f64 {
+        let (true_low, true_high) = match self.prev_close {
+            Some(prev_close) => (low.min(prev_close), high.max(prev_close)),
+            None => (low, high),
+        };
+        self.prev_close = Some(close);
+
+        let bp = close - true_low;
+        let tr = true_high - true_low;
+
+        // Averages of the same number of values, so their ratio is the ratio of the sums
+        fn ratio(bp: f64, tr: f64) -> f64 {
+            if tr == 0.0 {
+                0.5
+            } else {
+                bp / tr
+            }
+        }
+
+        let short = ratio(self.short_bp.next(bp), self.short_tr.next(tr));
+        let medium = ratio(self.medium_bp.next(bp), self.medium_tr.next(tr));
+        let long = ratio(self.long_bp.next(bp), self.long_tr.next(tr));
+
+        100.0 * (4.0 * short + 2.0 * medium + long) / 7.0
+    }
+}
+
+impl Period for UltimateOscillator {
+    fn period(&self) -> usize {
+        self.long_bp.period()
+    }
+}
+
+impl Next<f64> for UltimateOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.calc(input, input, input)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for UltimateOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.calc(input.high(), input.low(), input.close())
+    }
+}
+
+impl Reset for UltimateOscillator {
+    fn reset(&mut self) {
+        self.short_bp.reset();
+        self.short_tr.reset();
+        self.medium_bp.reset();
+        self.medium_tr.reset();
+        self.long_bp.reset();
+        self.long_tr.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for UltimateOscillator {
+    fn default() -> Self {
+        Self::new(7, 14, 28).unwrap()
+    }
+}
+
+impl fmt::Display for UltimateOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "UO({}, {}, {})",
+            self.short_bp.period(),
+            self.medium_bp.period(),
+            self.long_bp.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(UltimateOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(UltimateOscillator::new(0, 1, 1).is_err());
+        assert!(UltimateOscillator::new(1, 0, 1).is_err());
+        assert!(UltimateOscillator::new(1, 1, 0).is_err());
+        assert!(UltimateOscillator::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut uo = UltimateOscillator::new(2, 3, 4).unwrap();
+
+        assert_eq!(round(uo.next(10.0)), 50.0);
+        assert_eq!(round(uo.next(11.0)), 100.0);
+        assert_eq!(round(uo.next(10.5)), 66.667);
+        assert_eq!(round(uo.next(12.0)), 78.571);
+        assert_eq!(round(uo.next(11.0)), 57.5);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low, close, expected
+            (12.0, 10.0, 11.0, 50.0),
+            (13.0, 11.0, 12.5, 62.5),
+            (12.5, 11.0, 11.5, 56.03),
+            (14.0, 12.0, 13.5, 63.69),
+            (13.5, 11.5, 12.0, 54.067),
+            (15.0, 13.0, 14.5, 62.063),
+        ];
+
+        let mut uo = UltimateOscillator::new(2, 3, 4).unwrap();
+
+        for (high, low, close, expected) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(round(uo.next(&input_bar)), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut uo = UltimateOscillator::new(2, 3, 4).unwrap();
+
+        assert_eq!(round(uo.next(10.0)), 50.0);
+        assert_eq!(round(uo.next(11.0)), 100.0);
+
+        uo.reset();
+
+        assert_eq!(round(uo.next(10.0)), 50.0);
+        assert_eq!(round(uo.next(11.0)), 100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        UltimateOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = UltimateOscillator::new(5, 10, 20).unwrap();
+        assert_eq!(format!("{}", indicator), "UO(5, 10, 20)");
+    }
+}
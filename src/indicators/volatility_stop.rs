@@ -0,0 +1,265 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility Stop (VSTOP).
+///
+/// An [ATR](struct.AverageTrueRange.html) based trailing stop which follows the close price
+/// in the direction of the trend. While the trend is up, the stop stays below the highest close
+/// since the trend started and never moves down; while it is down, the stop stays above the
+/// lowest close and never moves up. When the close price crosses the stop, the trend flips
+/// and the stop moves to the other side of the price.
+///
+/// Unlike [Chandelier Exit](struct.ChandelierExit.html), it keeps the trend direction as state.
+///
+/// # Formula
+///
+/// While the trend is up:
+///
+/// Stop<sub>t</sub> = Max(Stop<sub>t-1</sub>, Highest close - ATR(_period_) * _multiplier_)
+///
+/// While the trend is down:
+///
+/// Stop<sub>t</sub> = Min(Stop<sub>t-1</sub>, Lowest close + ATR(_period_) * _multiplier_)
+///
+/// Where the highest and lowest closes are taken since the last flip of the trend.
+/// The trend starts up with the first value.
+///
+/// # Parameters
+///
+/// * _period_ - period of ATR (integer greater than 0). Default is 20.
+/// * _multiplier_ - ATR factor. Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{TrendDirection, VolatilityStop};
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(10.5).low(9.5).close(10.0).volume(1.0).build().unwrap();
+///
+/// let mut vstop = VolatilityStop::new(3, 1.0).unwrap();
+/// let out = vstop.next(&bar);
+///
+/// assert_eq!(out.stop, 9.0);
+/// assert_eq!(out.direction, TrendDirection::Up);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility Stop, TradingView](https://www.tradingview.com/support/solutions/43000594683-volatility-stop/)
+///
+#[doc(alias = "VSTOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolatilityStop {
+    multiplier: f64,
+    atr: AverageTrueRange,
+    direction: TrendDirection,
+    stop: f64,
+    highest: f64,
+    lowest: f64,
+    is_new: bool,
+}
+
+/// Direction of a trend.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendDirection {
+    Up,
+    Down,
+}
+
+impl VolatilityStop {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            multiplier,
+            atr: AverageTrueRange::new(period)?,
+            direction: TrendDirection::Up,
+            stop: 0.0,
+            highest: 0.0,
+            lowest: 0.0,
+            is_new: true,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    fn update(&mut self, close: f64, atr: f64) -> VolatilityStopOutput {
+        let distance = atr * self.multiplier;
+
+        if self.is_new {
+            self.is_new = false;
+            self.highest = close;
+            self.lowest = close;
+            self.stop = close - distance;
+        } else {
+            self.highest = self.highest.max(close);
+            self.lowest = self.lowest.min(close);
+            self.stop = match self.direction {
+                TrendDirection::Up => self.stop.max(self.highest - distance),
+                TrendDirection::Down => self.stop.min(self.lowest + distance),
+            };
+
+            let direction = if close >= self.stop {
+                TrendDirection::Up
+            } else {
+                TrendDirection::Down
+            };
+            if direction != self.direction {
+                self.direction = direction;
+                self.highest = close;
+                self.lowest = close;
+                self.stop = match direction {
+                    TrendDirection::Up => close - distance,
+                    TrendDirection::Down => close + distance,
+                };
+            }
+        }
+
+        VolatilityStopOutput {
+            stop: self.stop,
+            direction: self.direction,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolatilityStopOutput {
+    pub stop: f64,
+    pub direction: TrendDirection,
+}
+
+impl From<VolatilityStopOutput> for (f64, TrendDirection) {
+    fn from(vs: VolatilityStopOutput) -> Self {
+        (vs.stop, vs.direction)
+    }
+}
+
+impl Period for VolatilityStop {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl Next<f64> for VolatilityStop {
+    type Output = VolatilityStopOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let atr = self.atr.next(input);
+        self.update(input, atr)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for VolatilityStop {
+    type Output = VolatilityStopOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input);
+        self.update(input.close(), atr)
+    }
+}
+
+impl Reset for VolatilityStop {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.direction = TrendDirection::Up;
+        self.stop = 0.0;
+        self.highest = 0.0;
+        self.lowest = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Default for VolatilityStop {
+    fn default() -> Self {
+        Self::new(20, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for VolatilityStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VSTOP({}, {})", self.atr.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    use TrendDirection::{Down, Up};
+
+    test_indicator!(VolatilityStop);
+
+    fn round(out: VolatilityStopOutput) -> (f64, TrendDirection) {
+        ((out.stop * 1000.0).round() / 1000.0, out.direction)
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolatilityStop::new(0, 2.0).is_err());
+        assert!(VolatilityStop::new(1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut vstop = VolatilityStop::new(3, 1.0).unwrap();
+
+        assert_eq!(round(vstop.next(&bar(10.5, 9.5, 10.0))), (9.0, Up));
+        assert_eq!(round(vstop.next(&bar(11.5, 10.0, 11.0))), (9.75, Up));
+        assert_eq!(round(vstop.next(&bar(12.5, 11.0, 12.0))), (10.625, Up));
+        assert_eq!(round(vstop.next(&bar(12.0, 10.5, 11.0))), (10.625, Up));
+        assert_eq!(round(vstop.next(&bar(11.0, 9.0, 9.5))), (11.219, Down));
+        assert_eq!(round(vstop.next(&bar(10.0, 8.0, 8.5))), (10.359, Down));
+        assert_eq!(round(vstop.next(&bar(9.5, 8.0, 9.0))), (10.18, Down));
+        assert_eq!(round(vstop.next(&bar(11.0, 9.0, 10.5))), (8.66, Up));
+        assert_eq!(round(vstop.next(&bar(12.0, 10.0, 11.8))), (9.88, Up));
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vstop = VolatilityStop::new(3, 2.0).unwrap();
+
+        // ATR of a single value is 0
+        assert_eq!(round(vstop.next(10.0)), (10.0, Up));
+        assert_eq!(round(vstop.next(12.0)), (10.0, Up));
+        assert_eq!(round(vstop.next(11.0)), (10.0, Up));
+        assert_eq!(round(vstop.next(9.0)), (12.0, Down));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vstop = VolatilityStop::new(3, 1.0).unwrap();
+
+        vstop.next(&bar(10.5, 9.5, 10.0));
+        vstop.next(&bar(11.0, 9.0, 9.5));
+        vstop.next(&bar(10.0, 8.0, 8.5));
+
+        vstop.reset();
+
+        assert_eq!(round(vstop.next(&bar(10.5, 9.5, 10.0))), (9.0, Up));
+    }
+
+    #[test]
+    fn test_default() {
+        VolatilityStop::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VolatilityStop::new(20, 2.5).unwrap();
+        assert_eq!(format!("{}", indicator), "VSTOP(20, 2.5)");
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Current, IsReady, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Weighted moving average (WMA).
+///
+/// A moving average that assigns linearly decreasing weights to older values:
+/// the most recent value has weight _period_, the oldest one has weight 1.
+///
+/// # Formula
+///
+/// WMA<sub>t</sub> = (n * p<sub>t</sub> + (n - 1) * p<sub>t-1</sub> + ... + 1 * p<sub>t-n+1</sub>) / (n * (n + 1) / 2)
+///
+/// Where:
+///
+/// * _WMA<sub>t</sub>_ - value of weighted moving average at a point of time _t_
+/// * _n_ - number of periods
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// While less than _period_ values were given, _n_ is the number of the given values.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WeightedMovingAverage;
+/// use ta::Next;
+///
+/// let mut wma = WeightedMovingAverage::new(3).unwrap();
+/// assert_eq!(wma.next(1.0), 1.0);
+/// assert_eq!(wma.next(4.0), 3.0);
+/// assert_eq!(wma.next(7.0), 5.0);
+/// assert_eq!(wma.next(1.0), 3.5);
+/// ```
+///
+/// # Links
+///
+/// * [Weighted moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average)
+///
+#[doc(alias = "WMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WeightedMovingAverage {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    weighted_sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl WeightedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                weighted_sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for WeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for WeightedMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl IsReady for WeightedMovingAverage {
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl Next<f64> for WeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+            self.weighted_sum += self.count as f64 * input;
+            self.sum += input;
+        } else {
+            // Every value loses one unit of weight, the oldest one drops out
+            self.weighted_sum += self.period as f64 * input - self.sum;
+            self.sum += input - old_val;
+        }
+
+        let count = self.count as f64;
+        self.weighted_sum / (count * (count + 1.0) / 2.0)
+    }
+}
+
+impl<T: Close> Next<&T> for WeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for WeightedMovingAverage {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.weighted_sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Current for WeightedMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.count {
+            0 => None,
+            count => {
+                let count = count as f64;
+                Some(self.weighted_sum / (count * (count + 1.0) / 2.0))
+            }
+        }
+    }
+}
+
+impl Default for WeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for WeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(WeightedMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(WeightedMovingAverage::new(0).is_err());
+        assert!(WeightedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(round(wma.next(1.0)), 1.0);
+        assert_eq!(round(wma.next(2.0)), 1.667);
+        assert_eq!(round(wma.next(3.0)), 2.333);
+        assert_eq!(round(wma.next(4.0)), 3.333);
+        assert_eq!(round(wma.next(5.0)), 4.333);
+        assert_eq!(round(wma.next(10.0)), 7.333);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(wma.next(&bar(1.0)), 1.0);
+        assert_eq!(wma.next(&bar(4.0)), 3.0);
+        assert_eq!(wma.next(&bar(7.0)), 5.0);
+        assert_eq!(wma.next(&bar(1.0)), 3.5);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut wma = WeightedMovingAverage::new(2).unwrap();
+        assert_eq!(wma.current(), None);
+
+        for &input in &[4.0, 5.0, 9.0] {
+            let output = wma.next(input);
+            assert_eq!(wma.current(), Some(output));
+        }
+
+        wma.reset();
+        assert_eq!(wma.current(), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(wma.next(1.0), 1.0);
+        assert_eq!(wma.next(4.0), 3.0);
+
+        wma.reset();
+        assert_eq!(wma.next(99.0), 99.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WeightedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let wma = WeightedMovingAverage::new(5).unwrap();
+        assert_eq!(format!("{}", wma), "WMA(5)");
+    }
+}
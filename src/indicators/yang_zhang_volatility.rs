@@ -0,0 +1,178 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::rolling_moments::RollingMoments;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Yang-Zhang volatility.
+///
+/// An OHLC volatility estimator developed by Dennis Yang and Qiang Zhang. It combines the
+/// overnight (close to open) volatility, the open to close volatility and the Rogers-Satchell
+/// intraday estimator, which makes it independent of both drift and opening jumps.
+///
+/// # Formula
+///
+/// σ = sqrt(σ<sub>o</sub><sup>2</sup> + k * σ<sub>c</sub><sup>2</sup> + (1 - k) * σ<sub>rs</sub><sup>2</sup>)
+///
+/// Where:
+///
+/// * _σ<sub>o</sub><sup>2</sup>_ - sample variance of ln(Open<sub>t</sub> / Close<sub>t-1</sub>)
+/// * _σ<sub>c</sub><sup>2</sup>_ - sample variance of ln(Close / Open)
+/// * _σ<sub>rs</sub><sup>2</sup>_ - mean of ln(High / Close) * ln(High / Open) + ln(Low / Close) * ln(Low / Open)
+/// * _k_ = 0.34 / (1.34 + (_period_ + 1) / (_period_ - 1))
+///
+/// The overnight return of the first bar is 0, as there is no previous close.
+/// The result is the volatility per bar, not annualized. Prices are expected to be positive.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::YangZhangVolatility;
+/// use ta::{Next, DataItem};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.5).volume(1.0).build().unwrap();
+///
+/// let mut yz = YangZhangVolatility::new(3).unwrap();
+/// assert_eq!((yz.next(&bar) * 10000.0).round() / 10000.0, 0.1363);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility estimators, Portfolio Optimizer](https://portfoliooptimizer.io/blog/range-based-volatility-estimators-overview-and-examples-of-usage/)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct YangZhangVolatility {
+    k: f64,
+    overnight: RollingMoments,
+    open_close: RollingMoments,
+    rogers_satchell: Sma,
+    prev_close: Option<f64>,
+}
+
+impl YangZhangVolatility {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let n = period as f64;
+        Ok(Self {
+            k: 0.34 / (1.34 + (n + 1.0) / (n - 1.0)),
+            overnight: RollingMoments::new(period),
+            open_close: RollingMoments::new(period),
+            rogers_satchell: Sma::new(period)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl Period for YangZhangVolatility {
+    fn period(&self) -> usize {
+        self.rogers_satchell.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for YangZhangVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (open, high, low, close) = (input.open(), input.high(), input.low(), input.close());
+        let prev_close = self.prev_close.unwrap_or(open);
+        self.prev_close = Some(close);
+
+        self.overnight.next((open / prev_close).ln());
+        self.open_close.next((close / open).ln());
+        let rs = (high / close).ln() * (high / open).ln() + (low / close).ln() * (low / open).ln();
+        let rs_variance = self.rogers_satchell.next(rs);
+
+        let variance = self.overnight.sample_variance()
+            + self.k * self.open_close.sample_variance()
+            + (1.0 - self.k) * rs_variance;
+        variance.max(0.0).sqrt()
+    }
+}
+
+impl Reset for YangZhangVolatility {
+    fn reset(&mut self) {
+        self.overnight.reset();
+        self.open_close.reset();
+        self.rogers_satchell.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for YangZhangVolatility {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for YangZhangVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "YZ({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(num: f64) -> f64 {
+        (num * 10000.0).round() / 10000.0
+    }
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(YangZhangVolatility::new(0).is_err());
+        assert!(YangZhangVolatility::new(1).is_err());
+        assert!(YangZhangVolatility::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut yz = YangZhangVolatility::new(3).unwrap();
+
+        assert_eq!(round(yz.next(&bar(10.0, 11.0, 9.0, 10.5))), 0.1363);
+        assert_eq!(round(yz.next(&bar(10.6, 11.5, 10.2, 11.0))), 0.1108);
+        assert_eq!(round(yz.next(&bar(11.2, 12.0, 10.8, 11.5))), 0.099);
+        assert_eq!(round(yz.next(&bar(11.3, 11.6, 10.5, 10.8))), 0.0724);
+        assert_eq!(round(yz.next(&bar(10.7, 11.0, 10.0, 10.2))), 0.0655);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut yz = YangZhangVolatility::new(3).unwrap();
+
+        assert_eq!(round(yz.next(&bar(10.0, 11.0, 9.0, 10.5))), 0.1363);
+        yz.next(&bar(10.6, 11.5, 10.2, 11.0));
+
+        yz.reset();
+
+        assert_eq!(round(yz.next(&bar(10.0, 11.0, 9.0, 10.5))), 0.1363);
+    }
+
+    #[test]
+    fn test_default() {
+        YangZhangVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = YangZhangVolatility::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "YZ(20)");
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Z-Score.
+///
+/// Measures how many standard deviations the current value is away from the mean
+/// of the last _period_ values. It is commonly used to find mean reversion entries
+/// and to normalize other series.
+///
+/// # Formula
+///
+/// Z = (p - μ) / σ
+///
+/// Where:
+///
+/// * _p_ - current input value
+/// * _μ_ - mean of the last _period_ values
+/// * _σ_ - [standard deviation](struct.StandardDeviation.html) of the last _period_ values
+///
+/// When the standard deviation is 0, Z-Score is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ZScore;
+/// use ta::Next;
+///
+/// let mut z = ZScore::new(3).unwrap();
+/// assert_eq!(z.next(10.0), 0.0);
+/// assert_eq!(z.next(20.0), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Standard score, Wikipedia](https://en.wikipedia.org/wiki/Standard_score)
+///
+#[doc(alias = "ZSCORE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZScore {
+    sd: Sd,
+}
+
+impl ZScore {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sd: Sd::new(period)?,
+        })
+    }
+}
+
+impl Period for ZScore {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Next<f64> for ZScore {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let sd = self.sd.next(input);
+        if sd == 0.0 {
+            0.0
+        } else {
+            (input - self.sd.mean()) / sd
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for ZScore {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ZScore {
+    fn reset(&mut self) {
+        self.sd.reset();
+    }
+}
+
+impl Default for ZScore {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ZScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZSCORE({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ZScore);
+
+    #[test]
+    fn test_new() {
+        assert!(ZScore::new(0).is_err());
+        assert!(ZScore::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut z = ZScore::new(4).unwrap();
+
+        assert_eq!(z.next(10.0), 0.0);
+        assert_eq!(z.next(20.0), 1.0);
+        assert_eq!(round(z.next(30.0)), 1.225);
+        assert_eq!(round(z.next(20.0)), 0.0);
+        assert_eq!(round(z.next(10.0)), -1.414);
+        assert_eq!(round(z.next(100.0)), 1.697);
+    }
+
+    #[test]
+    fn test_next_same_values() {
+        let mut z = ZScore::new(3).unwrap();
+
+        assert_eq!(z.next(4.2), 0.0);
+        assert_eq!(z.next(4.2), 0.0);
+        assert_eq!(z.next(4.2), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut z = ZScore::new(4).unwrap();
+
+        assert_eq!(z.next(&bar(10.0)), 0.0);
+        assert_eq!(z.next(&bar(20.0)), 1.0);
+        assert_eq!(round(z.next(&bar(30.0))), 1.225);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut z = ZScore::new(4).unwrap();
+
+        assert_eq!(z.next(10.0), 0.0);
+        assert_eq!(z.next(20.0), 1.0);
+
+        z.reset();
+
+        assert_eq!(z.next(20.0), 0.0);
+        assert_eq!(z.next(10.0), -1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ZScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ZScore::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "ZSCORE(20)");
+    }
+}
@@ -0,0 +1,482 @@
+//! Loading of OHLCV bars from CSV files.
+//!
+//! Requires the `csv` feature.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+use crate::errors::{DataItemError, TaError};
+use crate::DataItem;
+
+/// Column of a CSV file, given by its header name or its index.
+///
+/// Header names are matched ignoring case and surrounding whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+/// Format of the timestamps of a CSV file.
+///
+/// Requires the `chrono` feature.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// RFC 3339 date and time, e.g. `2021-06-27T10:00:00Z`.
+    Rfc3339,
+    /// Seconds since the Unix epoch.
+    UnixSeconds,
+    /// Milliseconds since the Unix epoch.
+    UnixMillis,
+    /// [strftime](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) format of a
+    /// date and time, or of a date only, in UTC, e.g. `%Y-%m-%d %H:%M:%S` or `%Y-%m-%d`.
+    Custom(String),
+}
+
+#[cfg(feature = "chrono")]
+impl TimestampFormat {
+    fn parse(&self, s: &str) -> Option<DateTime<Utc>> {
+        match self {
+            TimestampFormat::Rfc3339 => DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|time| time.with_timezone(&Utc)),
+            TimestampFormat::UnixSeconds => DateTime::from_timestamp(s.parse().ok()?, 0),
+            TimestampFormat::UnixMillis => DateTime::from_timestamp_millis(s.parse().ok()?),
+            TimestampFormat::Custom(format) => NaiveDateTime::parse_from_str(s, format)
+                .or_else(|_| {
+                    NaiveDate::parse_from_str(s, format)
+                        .map(|date| date.and_time(Default::default()))
+                })
+                .ok()
+                .map(|time| time.and_utc()),
+        }
+    }
+}
+
+/// Error of a [CsvLoader](struct.CsvLoader.html).
+#[derive(Debug)]
+pub enum CsvError {
+    /// The file could not be read or is not valid CSV.
+    Csv(::csv::Error),
+    /// The named column is not in the header.
+    MissingColumn(String),
+    /// The record at the given line is not a valid data item.
+    InvalidRecord { line: u64, error: TaError },
+    /// The timestamp of the record at the given line does not have the expected format.
+    #[cfg(feature = "chrono")]
+    InvalidTimestamp { line: u64 },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match *self {
+            CsvError::Csv(ref err) => write!(f, "{}", err),
+            CsvError::MissingColumn(ref name) => write!(f, "column {} is missing", name),
+            CsvError::InvalidRecord { line, ref error } => {
+                write!(f, "line {}: {}", line, error)
+            }
+            #[cfg(feature = "chrono")]
+            CsvError::InvalidTimestamp { line } => write!(f, "line {}: invalid timestamp", line),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CsvError::Csv(ref err) => Some(err),
+            CsvError::MissingColumn(_) => None,
+            CsvError::InvalidRecord { ref error, .. } => Some(error),
+            #[cfg(feature = "chrono")]
+            CsvError::InvalidTimestamp { .. } => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(err: ::csv::Error) -> Self {
+        CsvError::Csv(err)
+    }
+}
+
+/// Loader of OHLCV bars from CSV files.
+///
+/// By default, the file has a header and the bars are read from the `open`, `high`, `low`,
+/// `close` and `volume` columns, any other column being ignored. Every column can be mapped to
+/// another header name, or to an index for files without a header. With the `chrono` feature,
+/// the timestamps of the bars can be read from a column in a given
+/// [format](enum.TimestampFormat.html).
+///
+/// Every record is validated as by [DataItemBuilder::build](../../struct.DataItemBuilder.html#method.build),
+/// or [build_lenient](../../struct.DataItemBuilder.html#method.build_lenient) with
+/// [lenient](#method.lenient), the first invalid record failing the load with its line.
+///
+/// # Example
+///
+/// ```
+/// use ta::io::csv::CsvLoader;
+/// use ta::{Close, High};
+///
+/// let data = "\
+/// Date,Open,High,Low,Close,Adj Close,Volume
+/// 2021-06-25,10.0,12.0,9.5,11.0,11.0,1200
+/// 2021-06-28,11.0,11.5,10.0,10.5,10.5,800
+/// ";
+///
+/// let bars = CsvLoader::new().load(data.as_bytes()).unwrap();
+/// assert_eq!(bars.len(), 2);
+/// assert_eq!(bars[0].high(), 12.0);
+///
+/// // A file without header, with the timestamps in the first column
+/// let bars = CsvLoader::new()
+///     .has_headers(false)
+///     .delimiter(b';')
+///     .open(1)
+///     .high(2)
+///     .low(3)
+///     .close(4)
+///     .volume(5)
+///     .load("1624838400000;10.0;12.0;9.5;11.0;1200".as_bytes())
+///     .unwrap();
+/// assert_eq!(bars[0].close(), 11.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvLoader {
+    has_headers: bool,
+    delimiter: u8,
+    lenient: Option<f64>,
+    columns: [Column; 5],
+    #[cfg(feature = "chrono")]
+    timestamp: Option<(Column, TimestampFormat)>,
+}
+
+const FIELDS: [&str; 5] = ["open", "high", "low", "close", "volume"];
+
+impl CsvLoader {
+    pub fn new() -> Self {
+        Self {
+            has_headers: true,
+            delimiter: b',',
+            lenient: None,
+            columns: FIELDS.map(Column::from),
+            #[cfg(feature = "chrono")]
+            timestamp: None,
+        }
+    }
+
+    /// Whether the first record is a header, `true` by default.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Delimiter of the fields, `,` by default.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Relative tolerance up to which the high and low of the bars are widened to include
+    /// their open and close, `None` by default. See
+    /// [build_lenient](../../struct.DataItemBuilder.html#method.build_lenient).
+    pub fn lenient(mut self, tolerance: Option<f64>) -> Self {
+        self.lenient = tolerance;
+        self
+    }
+
+    pub fn open<C: Into<Column>>(mut self, column: C) -> Self {
+        self.columns[0] = column.into();
+        self
+    }
+
+    pub fn high<C: Into<Column>>(mut self, column: C) -> Self {
+        self.columns[1] = column.into();
+        self
+    }
+
+    pub fn low<C: Into<Column>>(mut self, column: C) -> Self {
+        self.columns[2] = column.into();
+        self
+    }
+
+    pub fn close<C: Into<Column>>(mut self, column: C) -> Self {
+        self.columns[3] = column.into();
+        self
+    }
+
+    pub fn volume<C: Into<Column>>(mut self, column: C) -> Self {
+        self.columns[4] = column.into();
+        self
+    }
+
+    /// Reads the timestamps of the bars from the column, in the given format.
+    #[cfg(feature = "chrono")]
+    pub fn timestamp<C: Into<Column>>(mut self, column: C, format: TimestampFormat) -> Self {
+        self.timestamp = Some((column.into(), format));
+        self
+    }
+
+    /// Loads the bars of the CSV file at the path.
+    pub fn load_path<P: AsRef<Path>>(&self, path: P) -> Result<Vec<DataItem>, CsvError> {
+        let reader = self.reader_builder().from_path(path)?;
+        self.read(reader)
+    }
+
+    /// Loads the bars of the CSV data of the reader.
+    pub fn load<R: io::Read>(&self, reader: R) -> Result<Vec<DataItem>, CsvError> {
+        self.read(self.reader_builder().from_reader(reader))
+    }
+
+    fn reader_builder(&self) -> ::csv::ReaderBuilder {
+        let mut builder = ::csv::ReaderBuilder::new();
+        builder
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .flexible(true);
+        builder
+    }
+
+    fn read<R: io::Read>(&self, mut reader: ::csv::Reader<R>) -> Result<Vec<DataItem>, CsvError> {
+        let headers = if self.has_headers {
+            Some(reader.headers()?.clone())
+        } else {
+            None
+        };
+        let index = |column: &Column| match column {
+            Column::Index(index) => Ok(*index),
+            Column::Name(name) => headers
+                .iter()
+                .flatten()
+                .position(|header| header.trim().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| CsvError::MissingColumn(name.clone())),
+        };
+
+        let mut indices = [0; 5];
+        for (index_of, column) in indices.iter_mut().zip(self.columns.iter()) {
+            *index_of = index(column)?;
+        }
+        #[cfg(feature = "chrono")]
+        let timestamp = match self.timestamp {
+            Some((ref column, ref format)) => Some((index(column)?, format)),
+            None => None,
+        };
+
+        let mut bars = vec![];
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map_or(0, |position| position.line());
+
+            let mut values = [0.0; 5];
+            for ((value, &index), field) in values.iter_mut().zip(indices.iter()).zip(FIELDS) {
+                *value = parse_field(&record, index, field)
+                    .map_err(|error| CsvError::InvalidRecord { line, error })?;
+            }
+            let [open, high, low, close, volume] = values;
+            let builder = DataItem::builder()
+                .open(open)
+                .high(high)
+                .low(low)
+                .close(close)
+                .volume(volume);
+
+            #[cfg(feature = "chrono")]
+            let builder = match timestamp {
+                Some((index, format)) => {
+                    let time = record
+                        .get(index)
+                        .and_then(|field| format.parse(field.trim()))
+                        .ok_or(CsvError::InvalidTimestamp { line })?;
+                    builder.timestamp(time)
+                }
+                None => builder,
+            };
+
+            let bar = match self.lenient {
+                Some(tolerance) => builder.build_lenient(tolerance),
+                None => builder.build(),
+            };
+            bars.push(bar.map_err(|error| CsvError::InvalidRecord { line, error })?);
+        }
+        Ok(bars)
+    }
+}
+
+impl Default for CsvLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse_field(
+    record: &::csv::StringRecord,
+    index: usize,
+    field: &'static str,
+) -> Result<f64, TaError> {
+    record
+        .get(index)
+        .ok_or(TaError::DataItemIncomplete)?
+        .trim()
+        .parse()
+        .map_err(|_| TaError::DataItemInvalid(DataItemError::NotANumber(field)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Close, High, Open, Volume};
+
+    const DATA: &str = "\
+date,open,high,low,close,volume
+2021-06-25,10.0,12.0,9.5,11.0,1200
+2021-06-28,11.0,11.5,10.0,10.5,800
+";
+
+    #[test]
+    fn test_load() {
+        let bars = CsvLoader::new().load(DATA.as_bytes()).unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].open(), 10.0);
+        assert_eq!(bars[1].close(), 10.5);
+        assert_eq!(bars[1].volume(), 800.0);
+    }
+
+    #[test]
+    fn test_load_mapped_columns() {
+        let data = "Time,C,O,H,L,Vol\n1,11.0,10.0,12.0,9.5,1200\n";
+        let bars = CsvLoader::new()
+            .open("o")
+            .high("H")
+            .low("L")
+            .close("C")
+            .volume(5)
+            .load(data.as_bytes())
+            .unwrap();
+        assert_eq!(bars[0].open(), 10.0);
+        assert_eq!(bars[0].close(), 11.0);
+
+        let bars = CsvLoader::new()
+            .has_headers(false)
+            .delimiter(b';')
+            .open(2)
+            .high(3)
+            .low(4)
+            .close(1)
+            .volume(5)
+            .load("1;11.0;10.0;12.0;9.5;1200\n".as_bytes())
+            .unwrap();
+        assert_eq!(bars[0].open(), 10.0);
+        assert_eq!(bars[0].volume(), 1200.0);
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let missing = CsvLoader::new().close("last").load(DATA.as_bytes());
+        assert!(matches!(missing, Err(CsvError::MissingColumn(name)) if name == "last"));
+
+        let named = CsvLoader::new()
+            .has_headers(false)
+            .load("10.0,12.0,9.5,11.0,1200\n".as_bytes());
+        assert!(matches!(named, Err(CsvError::MissingColumn(_))));
+
+        let data = "open,high,low,close,volume\n10,12,9,11,100\n10,12,9,x,100\n";
+        let not_a_number = CsvLoader::new().load(data.as_bytes());
+        assert!(matches!(
+            not_a_number,
+            Err(CsvError::InvalidRecord {
+                line: 3,
+                error: TaError::DataItemInvalid(DataItemError::NotANumber("close"))
+            })
+        ));
+
+        let data = "open,high,low,close,volume\n10,12,9\n";
+        let incomplete = CsvLoader::new().load(data.as_bytes());
+        assert!(matches!(
+            incomplete,
+            Err(CsvError::InvalidRecord {
+                line: 2,
+                error: TaError::DataItemIncomplete
+            })
+        ));
+    }
+
+    #[test]
+    fn test_load_lenient() {
+        let data = "open,high,low,close,volume\n13,12,9,11,100\n";
+        let strict = CsvLoader::new().load(data.as_bytes());
+        assert!(matches!(
+            strict,
+            Err(CsvError::InvalidRecord {
+                error: TaError::DataItemInvalid(DataItemError::OpenOutOfRange),
+                ..
+            })
+        ));
+
+        let bars = CsvLoader::new()
+            .lenient(Some(0.1))
+            .load(data.as_bytes())
+            .unwrap();
+        assert_eq!(bars[0].high(), 13.0);
+
+        let tight = CsvLoader::new().lenient(Some(0.01)).load(data.as_bytes());
+        assert!(matches!(
+            tight,
+            Err(CsvError::InvalidRecord {
+                error: TaError::DataItemInvalid(DataItemError::OpenOutOfRange),
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_load_timestamp() {
+        use crate::Timestamp;
+        use chrono::TimeZone;
+
+        let bars = CsvLoader::new()
+            .timestamp("date", TimestampFormat::Custom("%Y-%m-%d".to_string()))
+            .load(DATA.as_bytes())
+            .unwrap();
+        let time = Utc.with_ymd_and_hms(2021, 6, 28, 0, 0, 0).unwrap();
+        assert_eq!(bars[1].timestamp(), Some(time));
+
+        let formats = [
+            (TimestampFormat::Rfc3339, "2021-06-28T02:00:00+02:00"),
+            (TimestampFormat::UnixSeconds, "1624838400"),
+            (TimestampFormat::UnixMillis, "1624838400000"),
+            (
+                TimestampFormat::Custom("%Y-%m-%d %H:%M".to_string()),
+                "2021-06-28 00:00",
+            ),
+        ];
+        for (format, field) in formats {
+            assert_eq!(format.parse(field), Some(time));
+        }
+
+        let invalid = CsvLoader::new()
+            .timestamp("date", TimestampFormat::UnixSeconds)
+            .load(DATA.as_bytes());
+        assert!(matches!(
+            invalid,
+            Err(CsvError::InvalidTimestamp { line: 2 })
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+//! Export of indicator outputs over bars to tidy CSV or JSON tables.
+//!
+//! Requires the `export` feature.
+
+use std::fmt::Display;
+use std::io::Write;
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+#[cfg(feature = "chrono")]
+use crate::Timestamp;
+use crate::{DataItem, Next, OutputInfo, OutputValues};
+
+// Outputs of a field of an indicator over the bars
+#[derive(Debug, Clone)]
+struct Column {
+    label: String,
+    values: Vec<f64>,
+}
+
+/// Table of the outputs of indicators over bars, with a row per bar and a column per output
+/// field, written to CSV or JSON, e.g. to diff the outputs against other platforms or to load
+/// them into notebooks.
+///
+/// The first column is the `timestamp` of the bars in RFC 3339 format, empty when the bar has
+/// none, or the `index` of the bars without the `chrono` feature. The columns of the outputs
+/// are labelled by the display of the indicator, followed by the name of the field for
+/// indicators with several fields, e.g. `BB(20, 2).upper`, as given by their
+/// [OutputInfo](../../trait.OutputInfo.html). NaN values are written as empty fields in CSV and
+/// as `null` in JSON.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{BollingerBands, SimpleMovingAverage};
+/// use ta::io::export::Exporter;
+/// use ta::DataItem;
+///
+/// let bars: Vec<DataItem> = [10.0, 11.0, 12.0]
+///     .iter()
+///     .map(|&close| {
+///         DataItem::builder()
+///             .open(close).high(close).low(close).close(close).volume(100.0)
+///             .build()
+///             .unwrap()
+///     })
+///     .collect();
+///
+/// let exporter = Exporter::new(&bars)
+///     .indicator(SimpleMovingAverage::new(2).unwrap())
+///     .indicator(BollingerBands::new(2, 2.0).unwrap());
+///
+/// let mut csv = Vec::new();
+/// exporter.write_csv(&mut csv).unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// // Labels holding commas are quoted
+/// assert!(csv.contains("SMA(2),\"BB(2, 2).average\",\"BB(2, 2).upper\",\"BB(2, 2).lower\""));
+///
+/// let mut json = Vec::new();
+/// exporter.write_json(&mut json).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Exporter<'a> {
+    bars: &'a [DataItem],
+    columns: Vec<Column>,
+}
+
+impl<'a> Exporter<'a> {
+    pub fn new(bars: &'a [DataItem]) -> Self {
+        Self {
+            bars,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Runs the indicator over the bars and adds a column per field of its output.
+    pub fn indicator<I>(mut self, mut indicator: I) -> Self
+    where
+        I: for<'b> Next<&'b DataItem> + OutputInfo + Display,
+        for<'b> <I as Next<&'b DataItem>>::Output: OutputValues,
+    {
+        let label = indicator.to_string();
+        let mut columns: Vec<Column> = match indicator.output_fields() {
+            [_] => vec![label],
+            fields => fields
+                .iter()
+                .map(|field| format!("{}.{}", label, field))
+                .collect(),
+        }
+        .into_iter()
+        .map(|label| Column {
+            label,
+            values: Vec::with_capacity(self.bars.len()),
+        })
+        .collect();
+
+        for bar in self.bars {
+            let output = indicator.next(bar).output_values();
+            for (column, value) in columns.iter_mut().zip(output) {
+                column.values.push(value);
+            }
+        }
+
+        self.columns.extend(columns);
+        self
+    }
+
+    /// Returns the labels of the columns, starting with `timestamp` or `index`.
+    pub fn headers(&self) -> Vec<&str> {
+        let mut headers = vec![KEY];
+        headers.extend(self.columns.iter().map(|column| column.label.as_str()));
+        headers
+    }
+
+    /// Writes the table as CSV, with a header row.
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), ::csv::Error> {
+        let mut writer = ::csv::Writer::from_writer(writer);
+        writer.write_record(self.headers())?;
+
+        for (row, bar) in self.bars.iter().enumerate() {
+            let mut record = vec![key(row, bar).unwrap_or_default()];
+            record.extend(self.columns.iter().map(|column| {
+                let value = column.values[row];
+                if value.is_nan() {
+                    String::new()
+                } else {
+                    value.to_string()
+                }
+            }));
+            writer.write_record(&record)?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the table as a JSON array of objects, one per row, keyed by the labels of the
+    /// columns.
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<(), serde_json::Error> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Serialize for Exporter<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut rows = serializer.serialize_seq(Some(self.bars.len()))?;
+        for row in 0..self.bars.len() {
+            rows.serialize_element(&Row {
+                exporter: self,
+                row,
+            })?;
+        }
+        rows.end()
+    }
+}
+
+// Row of an exporter, serialized as an object
+struct Row<'a> {
+    exporter: &'a Exporter<'a>,
+    row: usize,
+}
+
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let columns = &self.exporter.columns;
+        let mut map = serializer.serialize_map(Some(columns.len() + 1))?;
+
+        #[cfg(feature = "chrono")]
+        map.serialize_entry(KEY, &key(self.row, &self.exporter.bars[self.row]))?;
+        #[cfg(not(feature = "chrono"))]
+        map.serialize_entry(KEY, &self.row)?;
+
+        for column in columns {
+            let value = column.values[self.row];
+            map.serialize_entry(&column.label, &Some(value).filter(|value| !value.is_nan()))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "chrono")]
+const KEY: &str = "timestamp";
+#[cfg(not(feature = "chrono"))]
+const KEY: &str = "index";
+
+// Value of the first column for the bar of the row
+#[cfg(feature = "chrono")]
+fn key(_row: usize, bar: &DataItem) -> Option<String> {
+    bar.timestamp().map(|timestamp| timestamp.to_rfc3339())
+}
+
+#[cfg(not(feature = "chrono"))]
+fn key(row: usize, _bar: &DataItem) -> Option<String> {
+    Some(row.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        MovingAverageConvergenceDivergence as Macd, PivotDetector, SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+
+    fn bars() -> Vec<DataItem> {
+        [2.0, 4.0, 3.0].iter().map(|&close| item(close)).collect()
+    }
+
+    #[test]
+    fn test_headers() {
+        let bars = bars();
+        let exporter = Exporter::new(&bars)
+            .indicator(Sma::new(2).unwrap())
+            .indicator(Macd::new(3, 6, 4).unwrap());
+
+        assert_eq!(
+            exporter.headers(),
+            [
+                KEY,
+                "SMA(2)",
+                "MACD(3, 6, 4).macd",
+                "MACD(3, 6, 4).signal",
+                "MACD(3, 6, 4).histogram"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let bars = bars();
+        let mut csv = Vec::new();
+        Exporter::new(&bars)
+            .indicator(Sma::new(2).unwrap())
+            .indicator(PivotDetector::new(1, 1).unwrap())
+            .write_csv(&mut csv)
+            .unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            format!("{},SMA(2),\"PIVOTS(1, 1).high\",\"PIVOTS(1, 1).low\"", KEY)
+        );
+        assert!(lines[1].ends_with(",2,,"));
+        assert!(lines[3].ends_with(",3.5,4,"));
+    }
+
+    #[test]
+    fn test_write_json() {
+        let bars = bars();
+        let mut json = Vec::new();
+        Exporter::new(&bars)
+            .indicator(Sma::new(2).unwrap())
+            .indicator(PivotDetector::new(1, 1).unwrap())
+            .write_json(&mut json)
+            .unwrap();
+
+        let rows: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(rows.as_array().unwrap().len(), 3);
+        assert_eq!(rows[1]["SMA(2)"], 3.0);
+        assert_eq!(rows[2]["PIVOTS(1, 1).high"], 4.0);
+        assert!(rows[2]["PIVOTS(1, 1).low"].is_null());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamps() {
+        use chrono::{TimeZone, Utc};
+
+        let timestamp = Utc.with_ymd_and_hms(2021, 6, 27, 10, 0, 0).unwrap();
+        let bars = vec![
+            DataItem::builder()
+                .open(2.0)
+                .high(2.0)
+                .low(2.0)
+                .close(2.0)
+                .volume(100.0)
+                .timestamp(timestamp)
+                .build()
+                .unwrap(),
+            item(3.0),
+        ];
+
+        let mut csv = Vec::new();
+        Exporter::new(&bars)
+            .indicator(Sma::new(2).unwrap())
+            .write_csv(&mut csv)
+            .unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv, "timestamp,SMA(2)\n2021-06-27T10:00:00+00:00,2\n,2.5\n");
+    }
+}
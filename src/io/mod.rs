@@ -0,0 +1,8 @@
+//! Reading and writing of bars and indicator outputs in files.
+
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "parquet")]
+pub mod parquet;
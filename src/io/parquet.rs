@@ -0,0 +1,251 @@
+//! Reading of OHLCV bars from Parquet files and writing of indicator outputs to them.
+//!
+//! Requires the `parquet` feature.
+
+use std::io::Write;
+use std::sync::Arc;
+use std::vec;
+
+use ::arrow::array::{Array, ArrayRef, Float64Array};
+use ::arrow::datatypes::{DataType, Field, Schema};
+use ::arrow::record_batch::RecordBatch;
+#[cfg(feature = "chrono")]
+use ::arrow::{array::TimestampMillisecondArray, compute::cast, datatypes::TimeUnit};
+use ::parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use ::parquet::arrow::ArrowWriter;
+use ::parquet::errors::ParquetError;
+use ::parquet::file::reader::ChunkReader;
+
+use crate::arrow::ohlcv_columns;
+use crate::errors::TaError;
+use crate::DataItem;
+
+/// Returns an iterator over the bars of the Parquet file.
+///
+/// The bars are read one record batch at a time from the `open`, `high`, `low`, `close` and
+/// `volume` columns, cast to `Float64`, only the `close` column being required: the other
+/// prices default to the close, and the volume to 0. With the `chrono` feature, the timestamps
+/// of the bars are read from the `timestamp` column, if any, holding either Arrow timestamps
+/// or milliseconds since the Unix epoch.
+///
+/// Every bar is validated as by [DataItemBuilder::build](../../struct.DataItemBuilder.html#method.build),
+/// an invalid bar or a null value giving an `External` error wrapping the
+/// [TaError](../../errors/enum.TaError.html).
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::io::parquet::{read_bars, write_series};
+/// use ta::Next;
+///
+/// let mut sma = SimpleMovingAverage::new(20).unwrap();
+/// let outputs = read_bars(File::open("candles.parquet").unwrap())
+///     .unwrap()
+///     .map(|bar| bar.map(|bar| sma.next(&bar)))
+///     .collect::<Result<Vec<f64>, _>>()
+///     .unwrap();
+///
+/// write_series(File::create("sma.parquet").unwrap(), &[("SMA(20)", &outputs)]).unwrap();
+/// ```
+pub fn read_bars<R: ChunkReader + 'static>(reader: R) -> Result<ParquetBars, ParquetError> {
+    let batches = ParquetRecordBatchReaderBuilder::try_new(reader)?.build()?;
+    Ok(ParquetBars {
+        batches,
+        bars: Vec::new().into_iter(),
+    })
+}
+
+/// Iterator over the bars of a Parquet file, returned by [read_bars](fn.read_bars.html).
+pub struct ParquetBars {
+    batches: ParquetRecordBatchReader,
+    bars: vec::IntoIter<Result<DataItem, ParquetError>>,
+}
+
+impl Iterator for ParquetBars {
+    type Item = Result<DataItem, ParquetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(bar) = self.bars.next() {
+                return Some(bar);
+            }
+            let bars = match self.batches.next()? {
+                Ok(batch) => batch_bars(&batch),
+                Err(err) => Err(err.into()),
+            };
+            match bars {
+                Ok(bars) => self.bars = bars.into_iter(),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+// Bars of a record batch
+fn batch_bars(batch: &RecordBatch) -> Result<Vec<Result<DataItem, ParquetError>>, ParquetError> {
+    let columns = ohlcv_columns(batch)?;
+    #[cfg(feature = "chrono")]
+    let timestamps = timestamp_column(batch)?;
+
+    let bars = (0..batch.num_rows())
+        .map(|row| {
+            if columns.iter().any(|column| column.is_null(row)) {
+                return Err(external(TaError::DataItemIncomplete));
+            }
+            let [open, high, low, close, volume] = columns.each_ref().map(|c| c.value(row));
+            let builder = DataItem::builder()
+                .open(open)
+                .high(high)
+                .low(low)
+                .close(close)
+                .volume(volume);
+
+            #[cfg(feature = "chrono")]
+            let builder = match timestamps {
+                Some(ref timestamps) if timestamps.is_valid(row) => builder.timestamp(
+                    chrono::DateTime::from_timestamp_millis(timestamps.value(row))
+                        .ok_or_else(|| external(TaError::InvalidInput))?,
+                ),
+                _ => builder,
+            };
+
+            builder.build().map_err(external)
+        })
+        .collect();
+    Ok(bars)
+}
+
+// Timestamp column of the batch in milliseconds, if any
+#[cfg(feature = "chrono")]
+fn timestamp_column(
+    batch: &RecordBatch,
+) -> Result<Option<TimestampMillisecondArray>, ParquetError> {
+    let Some(column) = batch.column_by_name("timestamp") else {
+        return Ok(None);
+    };
+    let column = cast(column, &DataType::Timestamp(TimeUnit::Millisecond, None))?;
+    Ok(column
+        .as_any()
+        .downcast_ref::<TimestampMillisecondArray>()
+        .cloned())
+}
+
+fn external(err: TaError) -> ParquetError {
+    ParquetError::External(Box::new(err))
+}
+
+/// Writes the named series, e.g. the outputs of indicators, as the `Float64` columns of a
+/// Parquet file.
+///
+/// All the series must have the same length.
+pub fn write_series<W: Write + Send>(
+    writer: W,
+    series: &[(&str, &[f64])],
+) -> Result<(), ParquetError> {
+    let fields: Vec<Field> = series
+        .iter()
+        .map(|(name, _)| Field::new(*name, DataType::Float64, false))
+        .collect();
+    let columns: Vec<ArrayRef> = series
+        .iter()
+        .map(|(_, values)| Arc::new(Float64Array::from(values.to_vec())) as ArrayRef)
+        .collect();
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Close, High, Volume};
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+
+    // Path of a temporary file, removed when dropped
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let name = format!("ta-{}-{}.parquet", name, std::process::id());
+            Self(std::env::temp_dir().join(name))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_write_read() {
+        let file = TempFile::new("write-read");
+        let series: [(&str, &[f64]); 4] = [
+            ("high", &[12.0, 11.5]),
+            ("low", &[9.5, 10.0]),
+            ("close", &[11.0, 10.5]),
+            ("volume", &[1200.0, 800.0]),
+        ];
+        write_series(File::create(&file.0).unwrap(), &series).unwrap();
+
+        let bars = read_bars(File::open(&file.0).unwrap())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].high(), 12.0);
+        assert_eq!(bars[1].close(), 10.5);
+        assert_eq!(bars[1].volume(), 800.0);
+    }
+
+    #[test]
+    fn test_read_invalid() {
+        let file = TempFile::new("read-invalid");
+        let series: [(&str, &[f64]); 3] = [
+            ("high", &[12.0, 11.5]),
+            ("low", &[9.5, 12.0]),
+            ("close", &[11.0, 10.5]),
+        ];
+        write_series(File::create(&file.0).unwrap(), &series).unwrap();
+
+        let mut bars = read_bars(File::open(&file.0).unwrap()).unwrap();
+        assert!(bars.next().unwrap().is_ok());
+        assert!(matches!(bars.next(), Some(Err(ParquetError::External(_)))));
+        assert!(bars.next().is_none());
+
+        let file = TempFile::new("read-without-close");
+        write_series(File::create(&file.0).unwrap(), &[("open", &[1.0])]).unwrap();
+        let mut bars = read_bars(File::open(&file.0).unwrap()).unwrap();
+        assert!(bars.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_write_invalid() {
+        let series: [(&str, &[f64]); 2] = [("a", &[1.0, 2.0]), ("b", &[1.0])];
+        assert!(write_series(Vec::new(), &series).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_read_timestamp() {
+        use crate::Timestamp;
+
+        let file = TempFile::new("read-timestamp");
+        let series: [(&str, &[f64]); 2] = [("timestamp", &[1624838400000.0]), ("close", &[11.0])];
+        write_series(File::create(&file.0).unwrap(), &series).unwrap();
+
+        let bar = read_bars(File::open(&file.0).unwrap())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let time = chrono::DateTime::from_timestamp(1624838400, 0).unwrap();
+        assert_eq!(bar.timestamp(), Some(time));
+    }
+}
@@ -10,8 +10,14 @@
 //! Since `Next<T>` is a generic trait, most of the indicators can work with both input types: `f64` and more complex
 //! structures like [DataItem](struct.DataItem.html).
 //!
+//! Without the default `std` feature, the crate is `no_std` but still needs `alloc`, and only
+//! provides the traits, [DataItem](struct.DataItem.html) and the [numeric](numeric/index.html)
+//! indicators. The `f64` indicators and the rest of the library require `std`.
+//!
 //! # Example
 //! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
 //! use ta::indicators::ExponentialMovingAverage;
 //! use ta::Next;
 //!
@@ -22,6 +28,9 @@
 //! assert_eq!(ema.next(5.0), 3.5);
 //! assert_eq!(ema.next(1.0), 2.25);
 //! assert_eq!(ema.next(6.25), 4.25);
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
 //!
 //! # List of indicators
@@ -29,6 +38,16 @@
 //! * Trend
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
+//!   * [Kaufman's Adaptive Moving Average (KAMA)](indicators/struct.KaufmanAdaptiveMovingAverage.html)
+//!   * [Weighted Moving Average (WMA)](indicators/struct.WeightedMovingAverage.html)
+//!   * [McGinley Dynamic (MD)](indicators/struct.McGinleyDynamic.html)
+//!   * [Tillson T3 Moving Average (T3)](indicators/struct.T3MovingAverage.html)
+//!   * [Smoothed Moving Average (SMMA)](indicators/struct.SmoothedMovingAverage.html)
+//!   * [Williams Alligator](indicators/struct.Alligator.html)
+//!   * [Linear Regression (LINREG)](indicators/struct.LinearRegression.html)
+//!   * [Linear Regression Channel (LRC)](indicators/struct.LinearRegressionChannel.html)
+//!   * [Kalman Filter (KALMAN)](indicators/struct.KalmanFilter.html)
+//!   * [Savitzky-Golay Filter (SG)](indicators/struct.SavitzkyGolay.html)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
@@ -37,6 +56,14 @@
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Stochastic RSI (StochRSI)](indicators/struct.StochasticRsi.html)
+//!   * [Aroon](indicators/struct.Aroon.html)
+//!   * [Ultimate Oscillator (UO)](indicators/struct.UltimateOscillator.html)
+//!   * [Awesome Oscillator (AO)](indicators/struct.AwesomeOscillator.html)
+//!   * [Accelerator Oscillator (AC)](indicators/struct.AcceleratorOscillator.html)
+//!   * [Coppock Curve](indicators/struct.CoppockCurve.html)
+//!   * [Stochastic Momentum Index (SMI)](indicators/struct.StochasticMomentumIndex.html)
+//!   * [Momentum (MOM)](indicators/struct.Momentum.html)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
@@ -50,18 +77,155 @@
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Accumulation/Distribution Line (A/D)](indicators/struct.AccumulationDistribution.html)
+//!   * [Rolling R-squared (RSQ)](indicators/struct.RollingRSquared.html)
+//!   * [Z-Score](indicators/struct.ZScore.html)
+//!   * [Percent Rank (PR)](indicators/struct.PercentRank.html)
+//!   * [Rolling Median](indicators/struct.RollingMedian.html)
+//!   * [Rolling Percentile](indicators/struct.RollingPercentile.html)
+//!   * [Rolling Skewness (SKEW)](indicators/struct.RollingSkewness.html)
+//!   * [Rolling Kurtosis (KURT)](indicators/struct.RollingKurtosis.html)
+//!   * [Parkinson Volatility](indicators/struct.ParkinsonVolatility.html)
+//!   * [Garman-Klass Volatility](indicators/struct.GarmanKlassVolatility.html)
+//!   * [Yang-Zhang Volatility](indicators/struct.YangZhangVolatility.html)
+//!   * [Normalized Average True Range (NATR)](indicators/struct.NormalizedAverageTrueRange.html)
+//!   * [Chande Kroll Stop (CKS)](indicators/struct.ChandeKrollStop.html)
+//!   * [Volatility Stop (VSTOP)](indicators/struct.VolatilityStop.html)
+//!   * [Acceleration Bands (ABANDS)](indicators/struct.AccelerationBands.html)
+//!   * [Midpoint Price (MIDPRICE)](indicators/struct.MidPrice.html)
+//!   * [Median Price (MEDPRICE)](indicators/struct.MedianPrice.html)
+//!   * [Time Weighted Average Price (TWAP)](indicators/struct.TimeWeightedAveragePrice.html)
+//!   * [Spread Z-Score (SPREADZ)](indicators/struct.SpreadZScore.html)
+//!   * [Fractal Dimension Index (FDI)](indicators/struct.FractalDimensionIndex.html)
+//!   * [Sample Entropy (SAMPEN)](indicators/struct.SampleEntropy.html)
+//!   * [Drawdown (DD)](indicators/struct.Drawdown.html)
+//!   * [Rolling Sortino Ratio (SORTINO)](indicators/struct.RollingSortino.html)
+//!   * [Equity Statistics](indicators/struct.EquityStats.html)
+//!   * [Simple Returns](indicators/struct.SimpleReturns.html)
+//!   * [Log Returns](indicators/struct.LogReturns.html)
+//!   * [Cumulative Sum (CUMSUM)](indicators/struct.CumulativeSum.html)
+//!   * [Lag](indicators/struct.Lag.html)
+//!   * [Cross](indicators/struct.Cross.html)
+//!   * [Pivot Detector](indicators/struct.PivotDetector.html)
+//!   * [Divergence](indicators/struct.Divergence.html)
+//!   * [Trend Lines](indicators/struct.TrendLines.html)
+//!
+//! # List of bar transformers
+//!
+//! * [Heikin-Ashi](bars/struct.HeikinAshi.html)
+//! * [Kagi](bars/struct.KagiBuilder.html)
+//! * [Point & Figure](bars/struct.PointAndFigureBuilder.html)
+//! * [Range Bars](bars/struct.RangeBars.html)
+//! * [Tick Bars](bars/struct.TickBars.html)
+//! * [Volume Bars](bars/struct.VolumeBars.html)
+//! * [Time Bars](bars/struct.BarAggregator.html)
+//! * [Timeframe Resampler](bars/struct.Resampler.html)
+//!
+//! # List of profiles
+//!
+//! * [Volume Profile (VP)](profile/struct.VolumeProfile.html)
+//! * [Market Profile (MP)](profile/struct.MarketProfile.html)
 //!
+//! # List of chart patterns
+//!
+//! * [Head and Shoulders, Inverse Head and Shoulders](chart_patterns/enum.PatternKind.html)
+//! * [Double Top, Double Bottom](chart_patterns/enum.PatternKind.html)
+//! * [Ascending, Descending and Symmetrical Triangles](chart_patterns/enum.PatternKind.html)
+//! * [Bull Flag, Bear Flag](chart_patterns/enum.PatternKind.html)
+//!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
+#[cfg(feature = "std")]
 mod helpers;
 
 pub mod errors;
+#[cfg(feature = "std")]
 pub mod indicators;
 
+#[cfg(feature = "std")]
+pub mod compose;
+pub mod numeric;
+
+#[cfg(feature = "std")]
+pub mod bars;
+#[cfg(feature = "std")]
+pub mod profile;
+
+#[cfg(feature = "std")]
+pub mod chart_patterns;
+#[cfg(feature = "std")]
+pub mod expr;
+
+#[cfg(feature = "std")]
+pub mod strategy;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "std")]
+pub mod backtest;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+pub mod io;
+#[cfg(feature = "std")]
+pub mod live;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "std")]
+pub mod optimize;
+#[cfg(feature = "std")]
+pub mod performance;
+#[cfg(feature = "plot")]
+pub mod plot;
+#[cfg(feature = "polars")]
+pub mod polars;
+#[cfg(feature = "std")]
+pub mod position;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "reference")]
+pub mod reference;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod replay;
+#[cfg(feature = "std")]
+pub mod series;
+#[cfg(feature = "std")]
+pub mod sizing;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod talib;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 mod traits;
 pub use crate::traits::*;
 
+#[cfg(feature = "std")]
+mod signal;
+#[cfg(feature = "std")]
+pub use crate::signal::{Action, Signal};
+
+#[cfg(feature = "std")]
+mod indicator_set;
+#[cfg(feature = "std")]
+pub use crate::indicator_set::{IndicatorSet, IndicatorValues};
+
+#[cfg(feature = "std")]
+mod symbol_manager;
+#[cfg(feature = "std")]
+pub use crate::symbol_manager::SymbolManager;
+
+#[cfg(feature = "std")]
+mod dyn_next;
+#[cfg(feature = "std")]
+pub use crate::dyn_next::{BoxedIndicator, DynNext};
+
 mod data_item;
 pub use crate::data_item::DataItem;
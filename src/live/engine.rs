@@ -0,0 +1,253 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Arc;
+
+use crate::errors::{Result, TaError};
+use crate::live::{Backpressure, Output, Subscription, Update};
+use crate::registry::{DynIndicator, IndicatorRegistry};
+use crate::{DataItem, Next};
+
+struct Subscriber {
+    sender: SyncSender<Arc<Update>>,
+    backpressure: Backpressure,
+    dropped: Arc<AtomicU64>,
+}
+
+/// Engine computing indicators on live bars and fanning their outputs out to subscribers.
+///
+/// The indicators are given by their [specifications](../registry/struct.IndicatorSpec.html),
+/// e.g. `"rsi(14)"`. Every bar, read from a channel by `run` or given to `push`, is fed to all of
+/// the indicators, and the [update](struct.Update.html) with their outputs is shared by all of
+/// the subscribers, through channels of a bounded capacity. When the channel of a subscriber is
+/// full, the engine applies the [backpressure](enum.Backpressure.html) policy of the
+/// subscription. Subscribers which dropped their subscription are removed.
+///
+/// The indicators are not required to be `Send`, so the engine runs on the thread which created
+/// it, while the subscriptions and the sender of the bars can be moved to other threads.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::mpsc;
+/// use std::thread;
+/// use ta::live::{Backpressure, LiveEngine};
+/// use ta::DataItem;
+///
+/// let mut engine = LiveEngine::new(&["sma(2)", "max(3)"]).unwrap();
+/// let subscription = engine.subscribe(16, Backpressure::Block).unwrap();
+///
+/// let subscriber = thread::spawn(move || {
+///     subscription
+///         .iter()
+///         .map(|update| update.get("SMA(2)").unwrap()[0])
+///         .collect::<Vec<f64>>()
+/// });
+///
+/// let (sender, bars) = mpsc::channel();
+/// let feed = thread::spawn(move || {
+///     for close in [1.0, 2.0, 3.0] {
+///         let bar = DataItem::builder()
+///             .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap();
+///         sender.send(bar).unwrap();
+///     }
+/// });
+///
+/// // Runs until the feed stops, then closes the subscriptions
+/// engine.run(bars);
+/// feed.join().unwrap();
+/// drop(engine);
+///
+/// assert_eq!(subscriber.join().unwrap(), [1.0, 1.5, 2.5]);
+/// ```
+pub struct LiveEngine {
+    indicators: Vec<DynIndicator>,
+    subscribers: Vec<Subscriber>,
+    sequence: u64,
+}
+
+impl LiveEngine {
+    /// Creates the indicators of the specifications with the built-in
+    /// [registry](../registry/struct.IndicatorRegistry.html).
+    pub fn new(specs: &[&str]) -> Result<Self> {
+        Self::with_registry(&IndicatorRegistry::new(), specs)
+    }
+
+    /// Creates the indicators of the specifications with the given registry.
+    pub fn with_registry(registry: &IndicatorRegistry, specs: &[&str]) -> Result<Self> {
+        let indicators = specs
+            .iter()
+            .map(|spec| registry.create(spec))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            indicators,
+            subscribers: vec![],
+            sequence: 0,
+        })
+    }
+
+    /// Subscribes to the updates, through a channel holding up to _capacity_ updates.
+    pub fn subscribe(
+        &mut self,
+        capacity: usize,
+        backpressure: Backpressure,
+    ) -> Result<Subscription> {
+        if capacity == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let dropped = Arc::new(AtomicU64::new(0));
+        self.subscribers.push(Subscriber {
+            sender,
+            backpressure,
+            dropped: dropped.clone(),
+        });
+        Ok(Subscription::new(receiver, dropped))
+    }
+
+    /// Returns the number of subscribers, as of the last update.
+    pub fn subscribers(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Computes the indicators on the bar and sends the update to the subscribers.
+    pub fn push(&mut self, bar: DataItem) {
+        let outputs = self
+            .indicators
+            .iter_mut()
+            .map(|indicator| Output {
+                label: indicator.to_string(),
+                values: indicator.next(&bar),
+            })
+            .collect();
+        let update = Arc::new(Update {
+            sequence: self.sequence,
+            bar,
+            outputs,
+        });
+        self.sequence += 1;
+
+        self.subscribers.retain(|subscriber| {
+            let update = update.clone();
+            match subscriber.backpressure {
+                Backpressure::Block => subscriber.sender.send(update).is_ok(),
+                Backpressure::DropNewest => match subscriber.sender.try_send(update) {
+                    Ok(()) => true,
+                    Err(TrySendError::Full(_)) => {
+                        subscriber.dropped.fetch_add(1, Ordering::Relaxed);
+                        true
+                    }
+                    Err(TrySendError::Disconnected(_)) => false,
+                },
+                Backpressure::Unsubscribe => subscriber.sender.try_send(update).is_ok(),
+            }
+        });
+    }
+
+    /// Pushes the bars of the channel until all of its senders are dropped.
+    pub fn run(&mut self, bars: Receiver<DataItem>) {
+        for bar in bars {
+            self.push(bar);
+        }
+    }
+}
+
+impl fmt::Debug for LiveEngine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LiveEngine")
+            .field("indicators", &self.indicators)
+            .field("subscribers", &self.subscribers.len())
+            .field("sequence", &self.sequence)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use std::sync::mpsc::TryRecvError;
+    use std::thread;
+
+    #[test]
+    fn test_new() {
+        assert!(LiveEngine::new(&["sma(2)", "bb(20, 2)"]).is_ok());
+        assert!(LiveEngine::new(&["foo(2)"]).is_err());
+
+        let mut engine = LiveEngine::new(&[]).unwrap();
+        assert!(engine.subscribe(0, Backpressure::Block).is_err());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut engine = LiveEngine::new(&["sma(2)", "macd(3, 6, 4)"]).unwrap();
+        let first = engine.subscribe(4, Backpressure::Block).unwrap();
+        let second = engine.subscribe(4, Backpressure::Block).unwrap();
+
+        engine.push(item(2.0));
+        engine.push(item(4.0));
+
+        for subscription in [&first, &second] {
+            let update = subscription.recv().unwrap();
+            assert_eq!(update.sequence, 0);
+            assert_eq!(update.get("SMA(2)"), Some(&[2.0][..]));
+
+            let update = subscription.recv().unwrap();
+            assert_eq!(update.sequence, 1);
+            assert_eq!(update.bar.close, 4.0);
+            assert_eq!(update.get("SMA(2)"), Some(&[3.0][..]));
+            assert_eq!(update.outputs[1].label, "MACD(3, 6, 4)");
+            assert_eq!(update.outputs[1].values.len(), 3);
+            assert_eq!(update.get("RSI(14)"), None);
+        }
+    }
+
+    #[test]
+    fn test_backpressure() {
+        let mut engine = LiveEngine::new(&["sma(2)"]).unwrap();
+        let dropping = engine.subscribe(1, Backpressure::DropNewest).unwrap();
+        let unsubscribed = engine.subscribe(1, Backpressure::Unsubscribe).unwrap();
+        let gone = engine.subscribe(1, Backpressure::DropNewest).unwrap();
+        drop(gone);
+
+        engine.push(item(2.0));
+        engine.push(item(4.0));
+        engine.push(item(6.0));
+        assert_eq!(engine.subscribers(), 1);
+
+        assert_eq!(dropping.dropped(), 2);
+        assert_eq!(dropping.recv().unwrap().sequence, 0);
+        engine.push(item(8.0));
+        assert_eq!(dropping.recv().unwrap().sequence, 3);
+
+        assert_eq!(unsubscribed.recv().unwrap().sequence, 0);
+        assert!(matches!(
+            unsubscribed.try_recv(),
+            Err(TryRecvError::Disconnected)
+        ));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut engine = LiveEngine::new(&["sma(2)"]).unwrap();
+        let subscription = engine.subscribe(1, Backpressure::Block).unwrap();
+        let subscriber = thread::spawn(move || {
+            let values: Vec<f64> = subscription
+                .iter()
+                .map(|update| update.outputs[0].values[0])
+                .collect();
+            values
+        });
+
+        let (sender, bars) = mpsc::channel();
+        for close in [2.0, 4.0, 6.0] {
+            sender.send(item(close)).unwrap();
+        }
+        drop(sender);
+
+        engine.run(bars);
+        drop(engine);
+        assert_eq!(subscriber.join().unwrap(), [2.0, 3.0, 5.0]);
+    }
+}
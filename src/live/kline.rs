@@ -0,0 +1,281 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+
+use crate::errors::Result;
+use crate::{DataItem, Next, Reset, TryNext, UpdateLast};
+
+/// Candlestick of an exchange feed, sent again with revised values until it closes.
+///
+/// Requires the `kline` feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kline {
+    pub symbol: String,
+    /// Unix milliseconds of the start of the kline.
+    pub start: i64,
+    /// Unix milliseconds of the end of the kline.
+    pub end: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    /// Whether the kline is final, so that it will not be revised anymore.
+    pub closed: bool,
+}
+
+impl Kline {
+    /// Parses a kline message of the Binance websocket API, from either a raw stream, e.g.
+    /// `btcusdt@kline_1m`, or a combined stream.
+    pub fn from_binance_json(message: &str) -> serde_json::Result<Self> {
+        let event = match serde_json::from_str(message)? {
+            BinanceMessage::Combined { data } => data,
+            BinanceMessage::Raw(event) => event,
+        };
+        let kline = event.kline;
+        Ok(Self {
+            symbol: event.symbol,
+            start: kline.start,
+            end: kline.end,
+            open: kline.open,
+            high: kline.high,
+            low: kline.low,
+            close: kline.close,
+            volume: kline.volume,
+            closed: kline.closed,
+        })
+    }
+
+    /// Returns the bar of the kline, validated as by
+    /// [DataItemBuilder::build](../struct.DataItemBuilder.html#method.build). With the `chrono`
+    /// feature, the timestamp of the bar is the start of the kline.
+    pub fn bar(&self) -> Result<DataItem> {
+        let builder = DataItem::builder()
+            .open(self.open)
+            .high(self.high)
+            .low(self.low)
+            .close(self.close)
+            .volume(self.volume);
+
+        #[cfg(feature = "chrono")]
+        let builder = match chrono::DateTime::from_timestamp_millis(self.start) {
+            Some(timestamp) => builder.timestamp(timestamp),
+            None => builder,
+        };
+
+        builder.build()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BinanceMessage {
+    Combined { data: BinanceEvent },
+    Raw(BinanceEvent),
+}
+
+#[derive(Deserialize)]
+struct BinanceEvent {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "k")]
+    kline: BinanceKline,
+}
+
+// Binance sends the prices and volumes as strings
+#[derive(Deserialize)]
+struct BinanceKline {
+    #[serde(rename = "t")]
+    start: i64,
+    #[serde(rename = "T")]
+    end: i64,
+    #[serde(rename = "o", deserialize_with = "number")]
+    open: f64,
+    #[serde(rename = "h", deserialize_with = "number")]
+    high: f64,
+    #[serde(rename = "l", deserialize_with = "number")]
+    low: f64,
+    #[serde(rename = "c", deserialize_with = "number")]
+    close: f64,
+    #[serde(rename = "v", deserialize_with = "number")]
+    volume: f64,
+    #[serde(rename = "x")]
+    closed: bool,
+}
+
+fn number<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<f64, D::Error> {
+    let value = <&str>::deserialize(deserializer)?;
+    value.parse().map_err(D::Error::custom)
+}
+
+/// Indicator fed with the klines of a feed.
+///
+/// The first kline of a period is given to `next`, and its revisions to
+/// [update_last](../trait.UpdateLast.html), so the output follows the kline in progress
+/// without counting it more than once. Indicators which do not implement `UpdateLast` can be
+/// wrapped in an [Undoable](../compose/struct.Undoable.html). A kline whose bar is invalid is
+/// not consumed.
+///
+/// Requires the `kline` feature.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::live::{Kline, KlineIndicator};
+/// use ta::TryNext;
+///
+/// let message = |start, close, closed| {
+///     format!(
+///         r#"{{"e":"kline","s":"BTCUSDT","k":{{"t":{},"T":{},"o":"{c}","h":"{c}","l":"{c}","c":"{c}","v":"1.5","x":{}}}}}"#,
+///         start, start + 59_999, closed, c = close
+///     )
+/// };
+/// let mut sma = KlineIndicator::new(Sma::new(2).unwrap());
+///
+/// let kline = Kline::from_binance_json(&message(0, 10.0, true)).unwrap();
+/// assert_eq!(sma.try_next(&kline).unwrap(), 10.0);
+///
+/// // The kline in progress is revised
+/// let kline = Kline::from_binance_json(&message(60_000, 11.0, false)).unwrap();
+/// assert_eq!(sma.try_next(&kline).unwrap(), 10.5);
+/// let kline = Kline::from_binance_json(&message(60_000, 12.0, true)).unwrap();
+/// assert_eq!(sma.try_next(&kline).unwrap(), 11.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KlineIndicator<I> {
+    indicator: I,
+    start: Option<i64>,
+}
+
+impl<I> KlineIndicator<I> {
+    pub fn new(indicator: I) -> Self {
+        Self {
+            indicator,
+            start: None,
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I, O> TryNext<&Kline> for KlineIndicator<I>
+where
+    I: for<'a> UpdateLast<&'a DataItem> + for<'a> Next<&'a DataItem, Output = O>,
+{
+    type Output = O;
+
+    fn try_next(&mut self, kline: &Kline) -> Result<O> {
+        let bar = kline.bar()?;
+        let output = if self.start == Some(kline.start) {
+            self.indicator.update_last(&bar)
+        } else {
+            self.indicator.next(&bar)
+        };
+        self.start = Some(kline.start);
+        Ok(output)
+    }
+}
+
+impl<I: Reset> Reset for KlineIndicator<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.start = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compose::Undoable;
+    use crate::indicators::{AverageTrueRange, OnBalanceVolume, SimpleMovingAverage as Sma};
+
+    const MESSAGE: &str = r#"{
+        "e": "kline", "E": 1672515782136, "s": "BNBBTC",
+        "k": {
+            "t": 1672515780000, "T": 1672515839999, "s": "BNBBTC", "i": "1m",
+            "f": 100, "L": 200, "o": "0.0010", "c": "0.0020", "h": "0.0025", "l": "0.0015",
+            "v": "1000", "n": 100, "x": false, "q": "1.0000", "V": "500", "Q": "0.500", "B": "123456"
+        }
+    }"#;
+
+    fn kline(start: i64, close: f64, closed: bool) -> Kline {
+        Kline {
+            symbol: "BTCUSDT".to_string(),
+            start,
+            end: start + 59_999,
+            open: close,
+            high: close + 1.0,
+            low: close - 1.0,
+            close,
+            volume: 1.0,
+            closed,
+        }
+    }
+
+    #[test]
+    fn test_from_binance_json() {
+        let kline = Kline::from_binance_json(MESSAGE).unwrap();
+        assert_eq!(kline.symbol, "BNBBTC");
+        assert_eq!((kline.start, kline.end), (1672515780000, 1672515839999));
+        assert_eq!((kline.open, kline.high), (0.001, 0.0025));
+        assert_eq!((kline.low, kline.close), (0.0015, 0.002));
+        assert_eq!(kline.volume, 1000.0);
+        assert!(!kline.closed);
+
+        let combined = format!(r#"{{"stream":"bnbbtc@kline_1m","data":{}}}"#, MESSAGE);
+        assert_eq!(Kline::from_binance_json(&combined).unwrap(), kline);
+
+        assert!(Kline::from_binance_json("{}").is_err());
+        assert!(Kline::from_binance_json(&MESSAGE.replace("\"0.0010\"", "\"x\"")).is_err());
+    }
+
+    #[test]
+    fn test_bar() {
+        // The open is outside of the high-low range
+        assert!(Kline::from_binance_json(MESSAGE).unwrap().bar().is_err());
+
+        let bar = kline(0, 10.0, true).bar().unwrap();
+        assert_eq!((bar.high, bar.low, bar.volume), (11.0, 9.0, 1.0));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_bar_timestamp() {
+        use crate::Timestamp;
+
+        let bar = kline(60_000, 10.0, true).bar().unwrap();
+        assert_eq!(bar.timestamp(), chrono::DateTime::from_timestamp(60, 0));
+    }
+
+    #[test]
+    fn test_try_next() {
+        let mut atr = KlineIndicator::new(AverageTrueRange::new(3).unwrap());
+        assert_eq!(atr.try_next(&kline(0, 10.0, true)).unwrap(), 2.0);
+        assert_eq!(atr.try_next(&kline(60_000, 12.0, false)).unwrap(), 2.5);
+        assert_eq!(atr.try_next(&kline(60_000, 10.0, true)).unwrap(), 2.0);
+
+        let mut invalid = kline(120_000, 10.0, false);
+        invalid.high = 5.0;
+        assert!(atr.try_next(&invalid).is_err());
+        assert_eq!(atr.try_next(&kline(120_000, 10.0, false)).unwrap(), 2.0);
+
+        atr.reset();
+        assert_eq!(atr.try_next(&kline(120_000, 10.0, false)).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_undoable() {
+        let mut obv = KlineIndicator::new(Undoable::new(OnBalanceVolume::new()));
+        assert_eq!(obv.try_next(&kline(0, 10.0, true)).unwrap(), 1.0);
+        assert_eq!(obv.try_next(&kline(60_000, 11.0, false)).unwrap(), 2.0);
+        assert_eq!(obv.try_next(&kline(60_000, 9.0, false)).unwrap(), 0.0);
+
+        let mut sma = KlineIndicator::new(Sma::new(2).unwrap());
+        sma.try_next(&kline(0, 10.0, true)).unwrap();
+        sma.try_next(&kline(60_000, 20.0, false)).unwrap();
+        assert_eq!(sma.indicator().to_string(), "SMA(2)");
+    }
+}
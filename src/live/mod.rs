@@ -0,0 +1,22 @@
+//! Real-time computation of indicators fanned out to subscribers.
+//!
+//! A [LiveEngine](struct.LiveEngine.html) reads bars from a channel, computes a set of
+//! indicators of the [registry](../registry/index.html) on every bar and sends the
+//! [update](struct.Update.html) to every [subscription](struct.Subscription.html). The channels
+//! of the subscriptions are bounded, and a [backpressure](enum.Backpressure.html) policy tells
+//! what to do when a subscriber lags behind.
+//!
+//! With the `kline` feature, [Kline](struct.Kline.html) parses the candlesticks of exchange
+//! feeds, and [KlineIndicator](struct.KlineIndicator.html) feeds them to indicators, revising
+//! the kline in progress.
+
+mod engine;
+pub use self::engine::LiveEngine;
+
+mod subscription;
+pub use self::subscription::{Backpressure, Output, Subscription, Update};
+
+#[cfg(feature = "kline")]
+mod kline;
+#[cfg(feature = "kline")]
+pub use self::kline::{Kline, KlineIndicator};
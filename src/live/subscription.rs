@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvError, RecvTimeoutError, TryRecvError};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::DataItem;
+
+/// Outputs of the indicators of a [LiveEngine](struct.LiveEngine.html) for a bar.
+#[derive(Debug, Clone)]
+pub struct Update {
+    /// Index of the bar, counted from 0 since the engine was created.
+    pub sequence: u64,
+    pub bar: DataItem,
+    /// Outputs of the indicators, in the order of their specifications.
+    pub outputs: Vec<Output>,
+}
+
+impl Update {
+    /// Returns the values of the indicator with the label, e.g. `"RSI(14)"`.
+    pub fn get(&self, label: &str) -> Option<&[f64]> {
+        self.outputs
+            .iter()
+            .find(|output| output.label == label)
+            .map(|output| output.values.as_slice())
+    }
+}
+
+/// Output of an indicator, with a value for every field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    pub label: String,
+    pub values: Vec<f64>,
+}
+
+/// What a [LiveEngine](struct.LiveEngine.html) does when the channel of a subscriber is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Waits for room in the channel, slowing the engine and every other subscriber down to the
+    /// pace of the subscriber.
+    Block,
+    /// Drops the update for the subscriber, which can count the updates it missed.
+    DropNewest,
+    /// Unsubscribes the subscriber, whose channel is closed once emptied.
+    Unsubscribe,
+}
+
+/// Receiving end of the updates of a [LiveEngine](struct.LiveEngine.html).
+///
+/// The subscription can be sent to another thread. Dropping it unsubscribes.
+#[derive(Debug)]
+pub struct Subscription {
+    receiver: Receiver<Arc<Update>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl Subscription {
+    pub(super) fn new(receiver: Receiver<Arc<Update>>, dropped: Arc<AtomicU64>) -> Self {
+        Self { receiver, dropped }
+    }
+
+    /// Waits for the next update, failing once the engine stopped and the channel is empty.
+    pub fn recv(&self) -> Result<Arc<Update>, RecvError> {
+        self.receiver.recv()
+    }
+
+    pub fn try_recv(&self) -> Result<Arc<Update>, TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Arc<Update>, RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+
+    /// Returns an iterator waiting for the updates until the engine stopped.
+    pub fn iter(&self) -> mpsc::Iter<'_, Arc<Update>> {
+        self.receiver.iter()
+    }
+
+    /// Returns the number of updates dropped because the channel was full, with the
+    /// [DropNewest](enum.Backpressure.html#variant.DropNewest) policy.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<'a> IntoIterator for &'a Subscription {
+    type Item = Arc<Update>;
+    type IntoIter = mpsc::Iter<'a, Arc<Update>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
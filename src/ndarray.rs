@@ -0,0 +1,121 @@
+//! Integration with [ndarray](https://docs.rs/ndarray) arrays.
+//!
+//! Runs any indicator over one dimensional arrays, either of single values, e.g. closes, or of
+//! the separate open, high, low, close and volume columns of bars, and returns an array of
+//! the outputs. Requires the `ndarray` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::array;
+//! use ta::indicators::{AverageTrueRange as Atr, SimpleMovingAverage as Sma};
+//! use ta::ndarray::{next_array, next_ohlcv_arrays};
+//!
+//! let close = array![10.0, 11.0, 12.0, 10.5];
+//! let sma = next_array(&mut Sma::new(2).unwrap(), close.view());
+//! assert_eq!(sma, array![10.0, 10.5, 11.5, 11.25]);
+//!
+//! let high = array![10.5, 11.5, 12.5, 12.0];
+//! let low = array![9.5, 10.5, 11.5, 10.0];
+//! let volume = array![100.0, 120.0, 90.0, 150.0];
+//! let atr = next_ohlcv_arrays(
+//!     &mut Atr::new(3).unwrap(),
+//!     close.view(), high.view(), low.view(), close.view(), volume.view(),
+//! ).unwrap();
+//! assert_eq!(atr.len(), 4);
+//! ```
+
+use ::ndarray::{Array1, ArrayView1};
+
+use crate::errors::{Result, TaError};
+use crate::{DataItem, Next};
+
+/// Feeds every value of the array to the indicator and returns the outputs.
+pub fn next_array<I: Next<f64>>(indicator: &mut I, input: ArrayView1<f64>) -> Array1<I::Output> {
+    input.iter().map(|&value| indicator.next(value)).collect()
+}
+
+/// Feeds the bars given as separate columns to the indicator and returns the outputs.
+///
+/// Returns an `InvalidParameter` error when the columns have different lengths. The bars are
+/// not validated, as the columns usually come from trusted data.
+pub fn next_ohlcv_arrays<I, O>(
+    indicator: &mut I,
+    open: ArrayView1<f64>,
+    high: ArrayView1<f64>,
+    low: ArrayView1<f64>,
+    close: ArrayView1<f64>,
+    volume: ArrayView1<f64>,
+) -> Result<Array1<O>>
+where
+    I: for<'a> Next<&'a DataItem, Output = O>,
+{
+    let len = open.len();
+    if [high.len(), low.len(), close.len(), volume.len()]
+        .iter()
+        .any(|&other| other != len)
+    {
+        return Err(TaError::InvalidParameter);
+    }
+
+    Ok((0..len)
+        .map(|i| {
+            let bar = DataItem::unchecked(open[i], high[i], low[i], close[i], volume[i]);
+            indicator.next(&bar)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        MovingAverageConvergenceDivergence as Macd, OnBalanceVolume as Obv,
+        SimpleMovingAverage as Sma,
+    };
+    use ::ndarray::{array, Array1};
+
+    #[test]
+    fn test_next_array() {
+        let input = array![4.0, 5.0, 6.0, 6.0, 6.0, 2.0];
+        let sma = next_array(&mut Sma::new(4).unwrap(), input.view());
+        assert_eq!(sma, array![4.0, 4.5, 5.0, 5.25, 5.75, 5.0]);
+
+        let macd = next_array(
+            &mut Macd::new(3, 6, 4).unwrap(),
+            input.slice(::ndarray::s![..2]),
+        );
+        assert_eq!(macd.len(), 2);
+        assert_eq!(macd[0].macd, 0.0);
+
+        let empty: Array1<f64> = array![];
+        assert!(next_array(&mut Sma::new(4).unwrap(), empty.view()).is_empty());
+    }
+
+    #[test]
+    fn test_next_ohlcv_arrays() {
+        let prices = array![2.0, 3.0, 1.0];
+        let volume = array![100.0, 200.0, 50.0];
+        let obv = next_ohlcv_arrays(
+            &mut Obv::new(),
+            prices.view(),
+            prices.view(),
+            prices.view(),
+            prices.view(),
+            volume.view(),
+        )
+        .unwrap();
+        assert_eq!(obv, array![100.0, 300.0, 250.0]);
+
+        let short = array![2.0];
+        assert!(next_ohlcv_arrays(
+            &mut Obv::new(),
+            prices.view(),
+            prices.view(),
+            short.view(),
+            prices.view(),
+            volume.view(),
+        )
+        .is_err());
+    }
+}
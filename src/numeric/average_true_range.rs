@@ -0,0 +1,150 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::numeric::{ExponentialMovingAverage, Number, TrueRange};
+use crate::{Current, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average true range (ATR) over a generic [number](trait.Number.html) type.
+///
+/// Same as the [f64 ATR](../indicators/struct.AverageTrueRange.html), taking bars as
+/// `(high, low, close)` tuples, or close prices only.
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::AverageTrueRange;
+/// use ta::Next;
+///
+/// let mut atr = AverageTrueRange::new(3).unwrap();
+/// assert_eq!(atr.next((10.0, 7.5, 9.0)), 2.5);
+/// assert_eq!(atr.next((11.0, 9.0, 9.5)), 2.25);
+/// assert_eq!(atr.next((9.0, 5.0, 8.0)), 3.375);
+/// ```
+#[doc(alias = "ATR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageTrueRange<N> {
+    true_range: TrueRange<N>,
+    ema: ExponentialMovingAverage<N>,
+}
+
+impl<N: Number> AverageTrueRange<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            true_range: TrueRange::new(),
+            ema: ExponentialMovingAverage::new(period)?,
+        })
+    }
+}
+
+impl<N> Period for AverageTrueRange<N> {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl<N> Lookback for AverageTrueRange<N> {
+    fn lookback(&self) -> usize {
+        self.true_range.lookback() + self.ema.lookback()
+    }
+}
+
+impl<N: Number> Next<N> for AverageTrueRange<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        self.ema.next(self.true_range.next(input))
+    }
+}
+
+impl<N: Number> Next<(N, N, N)> for AverageTrueRange<N> {
+    type Output = N;
+
+    fn next(&mut self, input: (N, N, N)) -> Self::Output {
+        self.ema.next(self.true_range.next(input))
+    }
+}
+
+impl<N: Copy> Current for AverageTrueRange<N> {
+    type Output = N;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.ema.current()
+    }
+}
+
+impl<N> Reset for AverageTrueRange<N> {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.ema.reset();
+    }
+}
+
+impl<N: Number> Default for AverageTrueRange<N> {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<N> fmt::Display for AverageTrueRange<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ATR({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Atr = AverageTrueRange<f64>;
+
+    #[test]
+    fn test_new() {
+        assert!(Atr::new(0).is_err());
+        assert!(Atr::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut atr = Atr::new(3).unwrap();
+        assert_eq!(atr.current(), None);
+        assert_eq!(atr.next((10.0, 7.5, 9.0)), 2.5);
+        assert_eq!(atr.next((11.0, 9.0, 9.5)), 2.25);
+        assert_eq!(atr.next((9.0, 5.0, 8.0)), 3.375);
+        assert_eq!(atr.current(), Some(3.375));
+        assert_eq!(atr.lookback(), 3);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut atr = AverageTrueRange::new(3).unwrap();
+        assert_eq!(atr.next(Decimal::new(10, 0)), Decimal::ZERO);
+        assert_eq!(atr.next(Decimal::new(11, 0)), Decimal::new(5, 1));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut atr = Atr::new(9).unwrap();
+        atr.next((10.0, 7.5, 9.0));
+        atr.next((11.0, 9.0, 9.5));
+
+        atr.reset();
+        assert_eq!(atr.next((10.0, 7.5, 9.0)), 2.5);
+    }
+
+    #[test]
+    fn test_default() {
+        Atr::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Atr::new(8).unwrap();
+        assert_eq!(format!("{}", indicator), "ATR(8)");
+    }
+}
@@ -0,0 +1,155 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::numeric::Number;
+use crate::{Current, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Exponential moving average (EMA) over a generic [number](trait.Number.html) type.
+///
+/// Same as the [f64 EMA](../indicators/struct.ExponentialMovingAverage.html), with the
+/// smoothing factor `2 / (period + 1)` rounded to the precision of the number type.
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::ExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut ema = ExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(ema.next(2.0), 2.0);
+/// assert_eq!(ema.next(5.0), 3.5);
+/// assert_eq!(ema.next(1.0), 2.25);
+/// ```
+#[doc(alias = "EMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ExponentialMovingAverage<N> {
+    period: usize,
+    k: N,
+    current: Option<N>,
+}
+
+impl<N: Number> ExponentialMovingAverage<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                k: N::from_usize(2) / N::from_usize(period + 1),
+                current: None,
+            }),
+        }
+    }
+}
+
+impl<N> Period for ExponentialMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N> Lookback for ExponentialMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl<N: Number> Next<N> for ExponentialMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let current = match self.current {
+            Some(current) => (input - current) * self.k + current,
+            None => input,
+        };
+        self.current = Some(current);
+        current
+    }
+}
+
+impl<N: Copy> Current for ExponentialMovingAverage<N> {
+    type Output = N;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+
+impl<N> Reset for ExponentialMovingAverage<N> {
+    fn reset(&mut self) {
+        self.current = None;
+    }
+}
+
+impl<N: Number> Default for ExponentialMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<N> fmt::Display for ExponentialMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Ema = ExponentialMovingAverage<f64>;
+
+    #[test]
+    fn test_new() {
+        assert!(Ema::new(0).is_err());
+        assert!(Ema::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ema = Ema::new(3).unwrap();
+        assert_eq!(ema.current(), None);
+        assert_eq!(ema.next(2.0), 2.0);
+        assert_eq!(ema.next(5.0), 3.5);
+        assert_eq!(ema.next(1.0), 2.25);
+        assert_eq!(ema.next(6.25), 4.25);
+        assert_eq!(round(ema.next(4.5)), 4.375);
+        assert_eq!(ema.current(), Some(4.375));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.next(Decimal::new(2, 0)), Decimal::new(2, 0));
+        assert_eq!(ema.next(Decimal::new(5, 0)), Decimal::new(35, 1));
+        assert_eq!(ema.next(Decimal::new(1, 0)), Decimal::new(225, 2));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ema = Ema::new(5).unwrap();
+        assert_eq!(ema.next(4.0), 4.0);
+        ema.next(10.0);
+
+        ema.reset();
+        assert_eq!(ema.current(), None);
+        assert_eq!(ema.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Ema::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ema = Ema::new(7).unwrap();
+        assert_eq!(format!("{}", ema), "EMA(7)");
+    }
+}
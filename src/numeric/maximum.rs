@@ -0,0 +1,159 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::numeric::Number;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the highest value of the last `period` inputs, over a generic
+/// [number](trait.Number.html) type.
+///
+/// Same as the [f64 Maximum](../indicators/struct.Maximum.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::Maximum;
+/// use ta::Next;
+///
+/// let mut max = Maximum::new(2).unwrap();
+/// assert_eq!(max.next(7.0), 7.0);
+/// assert_eq!(max.next(5.0), 7.0);
+/// assert_eq!(max.next(6.0), 6.0);
+/// ```
+#[doc(alias = "MAX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Maximum<N> {
+    period: usize,
+    deque: VecDeque<N>,
+}
+
+impl<N> Maximum<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                deque: VecDeque::with_capacity(period),
+            }),
+        }
+    }
+}
+
+impl<N> Period for Maximum<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N> Lookback for Maximum<N> {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl<N: Number> Next<N> for Maximum<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        if self.deque.len() == self.period {
+            self.deque.pop_front();
+        }
+        self.deque.push_back(input);
+
+        self.deque
+            .iter()
+            .fold(input, |max, value| Number::max(max, *value))
+    }
+}
+
+impl<N> Reset for Maximum<N> {
+    fn reset(&mut self) {
+        self.deque.clear();
+    }
+}
+
+impl<N> Default for Maximum<N> {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<N> fmt::Display for Maximum<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAX({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Max = Maximum<f64>;
+
+    #[test]
+    fn test_new() {
+        assert!(Max::new(0).is_err());
+        assert!(Max::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut max = Max::new(3).unwrap();
+        assert_eq!(max.next(4.0), 4.0);
+        assert_eq!(max.next(1.2), 4.0);
+        assert_eq!(max.next(5.0), 5.0);
+        assert_eq!(max.next(3.0), 5.0);
+        assert_eq!(max.next(4.0), 5.0);
+        assert_eq!(max.next(0.0), 4.0);
+        assert_eq!(max.next(-1.0), 4.0);
+        assert_eq!(max.next(-2.0), 0.0);
+        assert_eq!(max.next(-1.5), -1.0);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut max = Maximum::new(2).unwrap();
+        assert_eq!(max.next(Decimal::new(101, 2)), Decimal::new(101, 2));
+        assert_eq!(max.next(Decimal::ONE), Decimal::new(101, 2));
+        assert_eq!(max.next(Decimal::new(99, 2)), Decimal::ONE);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_next_fixed() {
+        use fixed::types::I16F16;
+
+        let mut max = Maximum::new(2).unwrap();
+        assert_eq!(max.next(I16F16::from_num(1.5)), I16F16::from_num(1.5));
+        assert_eq!(max.next(I16F16::ONE), I16F16::from_num(1.5));
+        assert_eq!(max.next(I16F16::ZERO), I16F16::ONE);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut max = Max::new(100).unwrap();
+        assert_eq!(max.next(4.0), 4.0);
+        assert_eq!(max.next(10.0), 10.0);
+
+        max.reset();
+        assert_eq!(max.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Max::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Max::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "MAX(7)");
+    }
+}
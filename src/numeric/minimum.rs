@@ -0,0 +1,149 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::numeric::Number;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the lowest value of the last `period` inputs, over a generic
+/// [number](trait.Number.html) type.
+///
+/// Same as the [f64 Minimum](../indicators/struct.Minimum.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::Minimum;
+/// use ta::Next;
+///
+/// let mut min = Minimum::new(2).unwrap();
+/// assert_eq!(min.next(7.0), 7.0);
+/// assert_eq!(min.next(5.0), 5.0);
+/// assert_eq!(min.next(6.0), 5.0);
+/// ```
+#[doc(alias = "MIN")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Minimum<N> {
+    period: usize,
+    deque: VecDeque<N>,
+}
+
+impl<N> Minimum<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                deque: VecDeque::with_capacity(period),
+            }),
+        }
+    }
+}
+
+impl<N> Period for Minimum<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N> Lookback for Minimum<N> {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl<N: Number> Next<N> for Minimum<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        if self.deque.len() == self.period {
+            self.deque.pop_front();
+        }
+        self.deque.push_back(input);
+
+        self.deque
+            .iter()
+            .fold(input, |min, value| Number::min(min, *value))
+    }
+}
+
+impl<N> Reset for Minimum<N> {
+    fn reset(&mut self) {
+        self.deque.clear();
+    }
+}
+
+impl<N> Default for Minimum<N> {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<N> fmt::Display for Minimum<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MIN({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Min = Minimum<f64>;
+
+    #[test]
+    fn test_new() {
+        assert!(Min::new(0).is_err());
+        assert!(Min::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut min = Min::new(3).unwrap();
+        assert_eq!(min.next(4.0), 4.0);
+        assert_eq!(min.next(1.2), 1.2);
+        assert_eq!(min.next(5.0), 1.2);
+        assert_eq!(min.next(3.0), 1.2);
+        assert_eq!(min.next(4.0), 3.0);
+        assert_eq!(min.next(6.0), 3.0);
+        assert_eq!(min.next(7.0), 4.0);
+        assert_eq!(min.next(8.0), 6.0);
+        assert_eq!(min.next(-9.0), -9.0);
+        assert_eq!(min.next(0.0), -9.0);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut min = Minimum::new(2).unwrap();
+        assert_eq!(min.next(Decimal::new(99, 2)), Decimal::new(99, 2));
+        assert_eq!(min.next(Decimal::ONE), Decimal::new(99, 2));
+        assert_eq!(min.next(Decimal::new(101, 2)), Decimal::ONE);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut min = Min::new(10).unwrap();
+        assert_eq!(min.next(5.0), 5.0);
+        assert_eq!(min.next(7.0), 5.0);
+
+        min.reset();
+        assert_eq!(min.next(8.0), 8.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Min::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Min::new(10).unwrap();
+        assert_eq!(format!("{}", indicator), "MIN(10)");
+    }
+}
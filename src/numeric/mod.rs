@@ -0,0 +1,42 @@
+//! Indicators over generic number types.
+//!
+//! The indicators of this module compute with any [Number](trait.Number.html) type instead of
+//! `f64`, e.g. with `rust_decimal::Decimal` in domains where prices must be exact, with the
+//! `rust_decimal` feature, or with fixed-point numbers like `fixed::types::I64F64` on targets
+//! without a floating-point unit, with the `fixed` feature. Bars are given as
+//! `(high, low, close)` tuples.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::numeric::SimpleMovingAverage;
+//! use ta::Next;
+//!
+//! let mut sma = SimpleMovingAverage::new(2).unwrap();
+//! assert_eq!(sma.next(1.0), 1.0);
+//! assert_eq!(sma.next(2.0), 1.5);
+//! ```
+
+mod number;
+pub use self::number::Number;
+
+mod simple_moving_average;
+pub use self::simple_moving_average::SimpleMovingAverage;
+
+mod exponential_moving_average;
+pub use self::exponential_moving_average::ExponentialMovingAverage;
+
+mod rate_of_change;
+pub use self::rate_of_change::RateOfChange;
+
+mod maximum;
+pub use self::maximum::Maximum;
+
+mod minimum;
+pub use self::minimum::Minimum;
+
+mod true_range;
+pub use self::true_range::TrueRange;
+
+mod average_true_range;
+pub use self::average_true_range::AverageTrueRange;
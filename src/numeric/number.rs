@@ -0,0 +1,130 @@
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Sub};
+
+#[cfg(feature = "fixed")]
+use fixed::types::extra::{LeEqU128, LeEqU32, LeEqU64};
+#[cfg(feature = "fixed")]
+use fixed::{FixedI128, FixedI32, FixedI64};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+/// Number type the indicators of this module compute with.
+///
+/// Implemented for `f64`, for `rust_decimal::Decimal` with the `rust_decimal` feature, and for
+/// the signed fixed-point numbers of the `fixed` crate, e.g. `I64F64`, with the `fixed`
+/// feature. Fixed-point numbers suit targets without a floating-point unit, as long as the
+/// values and the sums of the windows fit their integer bits.
+pub trait Number:
+    Copy
+    + PartialOrd
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn from_usize(n: usize) -> Self;
+
+    fn abs(self) -> Self {
+        if self < Self::zero() {
+            Self::zero() - self
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl Number for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn from_usize(n: usize) -> Self {
+        n as f64
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Number for Decimal {
+    fn zero() -> Self {
+        Decimal::ZERO
+    }
+
+    fn from_usize(n: usize) -> Self {
+        Decimal::from(n)
+    }
+}
+
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed_number {
+    ($fixed:ident, $bound:ident) => {
+        impl<Frac: $bound> Number for $fixed<Frac> {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn from_usize(n: usize) -> Self {
+                Self::from_num(n)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed_number!(FixedI32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_number!(FixedI64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_number!(FixedI128, LeEqU128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64() {
+        assert_eq!(Number::abs(-2.5), 2.5);
+        assert_eq!(Number::max(1.0, 2.0), 2.0);
+        assert_eq!(Number::min(1.0, 2.0), 1.0);
+        assert_eq!(f64::from_usize(3), 3.0);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal() {
+        let tenth = Decimal::new(1, 1);
+        assert_eq!(Number::abs(Decimal::ZERO - tenth), tenth);
+        assert_eq!(Number::max(tenth, Decimal::ONE), Decimal::ONE);
+        assert_eq!(tenth + tenth + tenth, Decimal::new(3, 1));
+        assert_eq!(Decimal::from_usize(3), Decimal::new(3, 0));
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_fixed() {
+        use fixed::types::I64F64;
+
+        let half = I64F64::from_num(0.5);
+        assert_eq!(Number::abs(I64F64::ZERO - half), half);
+        assert_eq!(Number::min(half, I64F64::ONE), half);
+        assert_eq!(I64F64::from_usize(3), I64F64::from_num(3));
+    }
+}
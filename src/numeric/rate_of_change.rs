@@ -0,0 +1,161 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::numeric::Number;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rate of change (ROC) over a generic [number](trait.Number.html) type.
+///
+/// Same as the [f64 ROC](../indicators/struct.RateOfChange.html), in percent, except that
+/// the output is `None` when the price `period` inputs back is 0, since dividing a
+/// `Decimal` by 0 panics.
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::RateOfChange;
+/// use ta::Next;
+///
+/// let mut roc = RateOfChange::new(2).unwrap();
+/// assert_eq!(roc.next(10.0), Some(0.0));
+/// assert_eq!(roc.next(9.0), Some(-10.0));
+/// assert_eq!(roc.next(12.0), Some(20.0));
+/// ```
+#[doc(alias = "ROC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RateOfChange<N> {
+    period: usize,
+    deque: VecDeque<N>,
+}
+
+impl<N> RateOfChange<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                deque: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+}
+
+impl<N> Period for RateOfChange<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N> Lookback for RateOfChange<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Number> Next<N> for RateOfChange<N> {
+    type Output = Option<N>;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        // The first input is the reference until the window is full
+        let previous = self.deque.front().copied().unwrap_or(input);
+        self.deque.push_back(input);
+        if self.deque.len() > self.period {
+            self.deque.pop_front();
+        }
+
+        if previous == N::zero() {
+            None
+        } else {
+            Some((input - previous) / previous * N::from_usize(100))
+        }
+    }
+}
+
+impl<N> Reset for RateOfChange<N> {
+    fn reset(&mut self) {
+        self.deque.clear();
+    }
+}
+
+impl<N> Default for RateOfChange<N> {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<N> fmt::Display for RateOfChange<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ROC({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Roc = RateOfChange<f64>;
+
+    #[test]
+    fn test_new() {
+        assert!(Roc::new(0).is_err());
+        assert!(Roc::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut roc = Roc::new(3).unwrap();
+        assert_eq!(roc.next(10.0), Some(0.0));
+        assert_eq!(roc.next(10.4).map(round), Some(4.0));
+        assert_eq!(roc.next(10.57).map(round), Some(5.7));
+        assert_eq!(roc.next(10.8).map(round), Some(8.0));
+        assert_eq!(roc.next(10.9).map(round), Some(4.808));
+        assert_eq!(roc.next(10.0).map(round), Some(-5.393));
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut roc = Roc::new(1).unwrap();
+        assert_eq!(roc.next(0.0), None);
+        assert_eq!(roc.next(2.0), None);
+        assert_eq!(roc.next(3.0), Some(50.0));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut roc = RateOfChange::new(1).unwrap();
+        assert_eq!(roc.next(Decimal::new(3, 1)), Some(Decimal::ZERO));
+        assert_eq!(roc.next(Decimal::new(6, 1)), Some(Decimal::new(100, 0)));
+        assert_eq!(roc.next(Decimal::ZERO), Some(Decimal::new(-100, 0)));
+        assert_eq!(roc.next(Decimal::ONE), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut roc = Roc::new(3).unwrap();
+        roc.next(12.3);
+        roc.next(15.0);
+
+        roc.reset();
+        assert_eq!(roc.next(13.0), Some(0.0));
+        assert_eq!(roc.next(14.3).map(round), Some(10.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Roc::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let roc = Roc::new(16).unwrap();
+        assert_eq!(format!("{}", roc), "ROC(16)");
+    }
+}
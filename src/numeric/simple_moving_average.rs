@@ -0,0 +1,180 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::numeric::Number;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Simple moving average (SMA) over a generic [number](trait.Number.html) type.
+///
+/// Same as the [f64 SMA](../indicators/struct.SimpleMovingAverage.html). The running sum
+/// of a `Decimal` is exact, so it does not drift away from the sum of the window.
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::SimpleMovingAverage;
+/// use ta::Next;
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+/// assert_eq!(sma.next(10.0), 10.0);
+/// assert_eq!(sma.next(11.0), 10.5);
+/// assert_eq!(sma.next(12.0), 11.0);
+/// assert_eq!(sma.next(13.0), 12.0);
+/// ```
+#[doc(alias = "SMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SimpleMovingAverage<N> {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: N,
+    deque: Box<[N]>,
+}
+
+impl<N: Number> SimpleMovingAverage<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl<N> Period for SimpleMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N> Lookback for SimpleMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl<N: Number> Next<N> for SimpleMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        self.sum = self.sum - old_val + input;
+        self.sum / N::from_usize(self.count)
+    }
+}
+
+impl<N: Number> Reset for SimpleMovingAverage<N> {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = N::zero();
+        for i in 0..self.period {
+            self.deque[i] = N::zero();
+        }
+    }
+}
+
+impl<N: Number> Default for SimpleMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<N> fmt::Display for SimpleMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sma = SimpleMovingAverage<f64>;
+
+    #[test]
+    fn test_new() {
+        assert!(Sma::new(0).is_err());
+        assert!(Sma::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = Sma::new(4).unwrap();
+        assert_eq!(sma.next(4.0), 4.0);
+        assert_eq!(sma.next(5.0), 4.5);
+        assert_eq!(sma.next(6.0), 5.0);
+        assert_eq!(sma.next(6.0), 5.25);
+        assert_eq!(sma.next(6.0), 5.75);
+        assert_eq!(sma.next(2.0), 5.0);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        assert_eq!(sma.next(Decimal::new(1, 1)), Decimal::new(1, 1));
+        assert_eq!(sma.next(Decimal::new(2, 1)), Decimal::new(15, 2));
+        for _ in 0..1000 {
+            sma.next(Decimal::new(7, 1));
+            sma.next(Decimal::new(1, 1));
+        }
+        assert_eq!(sma.next(Decimal::new(2, 1)), Decimal::new(15, 2));
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_next_fixed() {
+        use fixed::types::I32F32;
+
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        assert_eq!(sma.next(I32F32::from_num(4)), I32F32::from_num(4));
+        assert_eq!(sma.next(I32F32::from_num(5)), I32F32::from_num(4.5));
+        assert_eq!(sma.next(I32F32::from_num(7)), I32F32::from_num(6));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = Sma::new(4).unwrap();
+        assert_eq!(sma.next(4.0), 4.0);
+        assert_eq!(sma.next(5.0), 4.5);
+
+        sma.reset();
+        assert_eq!(sma.next(99.0), 99.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Sma::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = Sma::new(5).unwrap();
+        assert_eq!(format!("{}", sma), "SMA(5)");
+    }
+}
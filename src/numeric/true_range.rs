@@ -0,0 +1,146 @@
+use core::fmt;
+
+use crate::numeric::Number;
+use crate::{Lookback, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// True range over a generic [number](trait.Number.html) type.
+///
+/// Same as the [f64 true range](../indicators/struct.TrueRange.html), taking bars as
+/// `(high, low, close)` tuples, or close prices only.
+///
+/// # Example
+///
+/// ```
+/// use ta::numeric::TrueRange;
+/// use ta::Next;
+///
+/// let mut tr = TrueRange::new();
+/// assert_eq!(tr.next((10.0, 7.5, 9.0)), 2.5);
+/// assert_eq!(tr.next((11.0, 9.0, 9.5)), 2.0);
+/// assert_eq!(tr.next((9.0, 5.0, 8.0)), 4.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TrueRange<N> {
+    prev_close: Option<N>,
+}
+
+impl<N> TrueRange<N> {
+    pub fn new() -> Self {
+        Self { prev_close: None }
+    }
+}
+
+impl<N> Lookback for TrueRange<N> {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl<N: Number> Next<N> for TrueRange<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let distance = match self.prev_close {
+            Some(prev) => (input - prev).abs(),
+            None => N::zero(),
+        };
+        self.prev_close = Some(input);
+        distance
+    }
+}
+
+impl<N: Number> Next<(N, N, N)> for TrueRange<N> {
+    type Output = N;
+
+    fn next(&mut self, (high, low, close): (N, N, N)) -> Self::Output {
+        let max_dist = match self.prev_close {
+            Some(prev_close) => high.max(prev_close) - low.min(prev_close),
+            None => high - low,
+        };
+        self.prev_close = Some(close);
+        max_dist
+    }
+}
+
+impl<N> Reset for TrueRange<N> {
+    fn reset(&mut self) {
+        self.prev_close = None;
+    }
+}
+
+impl<N> Default for TrueRange<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> fmt::Display for TrueRange<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRUE_RANGE()")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Tr = TrueRange<f64>;
+
+    #[test]
+    fn test_next_f64() {
+        let mut tr = Tr::new();
+        assert_eq!(round(tr.next(2.5)), 0.0);
+        assert_eq!(round(tr.next(3.6)), 1.1);
+        assert_eq!(round(tr.next(3.3)), 0.3);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut tr = Tr::new();
+        assert_eq!(tr.next((10.0, 7.5, 9.0)), 2.5);
+        assert_eq!(tr.next((11.0, 9.0, 9.5)), 2.0);
+        assert_eq!(tr.next((9.0, 5.0, 8.0)), 4.5);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_next_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut tr = TrueRange::new();
+        let bar = |high, low, close| {
+            (
+                Decimal::new(high, 1),
+                Decimal::new(low, 1),
+                Decimal::new(close, 1),
+            )
+        };
+        assert_eq!(tr.next(bar(3, 1, 2)), Decimal::new(2, 1));
+        assert_eq!(tr.next(bar(7, 6, 7)), Decimal::new(5, 1));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tr = Tr::new();
+        tr.next((10.0, 7.5, 9.0));
+        tr.next((11.0, 9.0, 9.5));
+
+        tr.reset();
+        assert_eq!(tr.next((12.0, 9.0, 11.0)), 3.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Tr::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Tr::new();
+        assert_eq!(format!("{}", indicator), "TRUE_RANGE()");
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Grid of parameters.
+///
+/// Every parameter has a name and a list of values. The grid contains a
+/// [parameter set](struct.ParameterSet.html) for every combination of the values, the values
+/// of the last added parameter changing first.
+///
+/// # Example
+///
+/// ```
+/// use ta::optimize::ParameterGrid;
+///
+/// let grid = ParameterGrid::new()
+///     .add("fast", &[5.0, 10.0]).unwrap()
+///     .add("slow", &[20.0, 50.0, 100.0]).unwrap();
+///
+/// let sets = grid.sets();
+/// assert_eq!(sets.len(), 6);
+/// assert_eq!(sets[1].get("fast"), Some(5.0));
+/// assert_eq!(sets[1].get("slow"), Some(50.0));
+/// assert_eq!(format!("{}", sets[1]), "fast=5, slow=50");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct ParameterGrid {
+    parameters: Vec<(String, Vec<f64>)>,
+}
+
+/// Values of the parameters of a [grid](struct.ParameterGrid.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterSet {
+    values: Vec<(String, f64)>,
+}
+
+impl ParameterGrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a parameter. Returns an error when there are no _values_ or the _name_ is already
+    /// used by another parameter.
+    pub fn add(mut self, name: &str, values: &[f64]) -> Result<Self> {
+        if values.is_empty() || self.parameters.iter().any(|(other, _)| other == name) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.parameters.push((name.to_string(), values.to_vec()));
+        Ok(self)
+    }
+
+    /// Returns the number of parameter sets.
+    pub fn len(&self) -> usize {
+        self.parameters
+            .iter()
+            .map(|(_, values)| values.len())
+            .product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns all the parameter sets of the grid.
+    pub fn sets(&self) -> Vec<ParameterSet> {
+        let mut sets = vec![ParameterSet { values: vec![] }];
+
+        for (name, values) in &self.parameters {
+            sets = sets
+                .iter()
+                .flat_map(|set| {
+                    values.iter().map(move |&value| {
+                        let mut set = set.clone();
+                        set.values.push((name.clone(), value));
+                        set
+                    })
+                })
+                .collect();
+        }
+
+        sets
+    }
+}
+
+impl ParameterSet {
+    /// Returns the value of the parameter named _name_.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Returns the names and the values of the parameters in the order they were added to
+    /// the grid.
+    pub fn values(&self) -> &[(String, f64)] {
+        &self.values
+    }
+}
+
+impl fmt::Display for ParameterSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, (name, value)) in self.values.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert!(ParameterGrid::new().add("period", &[]).is_err());
+        assert!(ParameterGrid::new()
+            .add("period", &[10.0])
+            .unwrap()
+            .add("period", &[20.0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_sets() {
+        let empty = ParameterGrid::new();
+        assert_eq!(empty.len(), 1);
+        assert_eq!(empty.sets(), vec![ParameterSet { values: vec![] }]);
+
+        let grid = ParameterGrid::new()
+            .add("period", &[10.0, 20.0])
+            .unwrap()
+            .add("multiplier", &[1.5, 2.0])
+            .unwrap();
+        assert_eq!(grid.len(), 4);
+        assert!(!grid.is_empty());
+
+        let sets: Vec<_> = grid
+            .sets()
+            .iter()
+            .map(|set| (set.get("period").unwrap(), set.get("multiplier").unwrap()))
+            .collect();
+        assert_eq!(
+            sets,
+            vec![(10.0, 1.5), (10.0, 2.0), (20.0, 1.5), (20.0, 2.0)]
+        );
+        assert_eq!(grid.sets()[0].get("shift"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = ParameterGrid::new()
+            .add("period", &[14.0])
+            .unwrap()
+            .add("level", &[30.5])
+            .unwrap();
+        assert_eq!(format!("{}", grid.sets()[0]), "period=14, level=30.5");
+    }
+}
@@ -0,0 +1,11 @@
+//! Optimization of strategy parameters.
+//!
+//! An [Optimizer](struct.Optimizer.html) backtests a strategy for every set of parameters of
+//! a [ParameterGrid](struct.ParameterGrid.html), or of any other list, and ranks them by a
+//! user supplied score.
+
+mod grid;
+pub use self::grid::{ParameterGrid, ParameterSet};
+
+mod optimizer;
+pub use self::optimizer::{OptimizationResult, Optimizer};
@@ -0,0 +1,232 @@
+use std::cmp::Ordering;
+
+use crate::backtest::{BacktestReport, Backtester};
+use crate::{Action, Close, High, Low, Next, Open};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Optimizer of strategy parameters.
+///
+/// Backtests the strategy created by a factory for every parameter set, e.g. the sets of a
+/// [ParameterGrid](struct.ParameterGrid.html), and ranks the parameter sets by the score
+/// given by a user supplied metric to their backtests.
+///
+/// With the `rayon` feature, the parameter sets can be backtested in parallel with
+/// `par_run`.
+///
+/// # Example
+///
+/// ```
+/// use ta::backtest::Backtester;
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::optimize::{Optimizer, ParameterGrid};
+/// use ta::performance::PerformanceReport;
+/// use ta::strategy::{Strategy, Threshold};
+/// use ta::DataItem;
+///
+/// let bars: Vec<DataItem> = [10.0, 10.0, 12.0, 12.0, 11.0, 9.0]
+///     .iter()
+///     .map(|&close| {
+///         DataItem::builder()
+///             .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+///     })
+///     .collect();
+///
+/// let grid = ParameterGrid::new()
+///     .add("buy", &[9.5, 10.5]).unwrap()
+///     .add("sell", &[11.5, 12.5]).unwrap();
+///
+/// let optimizer = Optimizer::new(Backtester::new(1000.0).unwrap());
+/// let results = optimizer.run(
+///     &bars,
+///     &grid.sets(),
+///     |set| {
+///         Strategy::new(
+///             Threshold::below(Sma::new(1).unwrap(), set.get("buy").unwrap()),
+///             Threshold::above(Sma::new(1).unwrap(), set.get("sell").unwrap()),
+///         )
+///     },
+///     |report| PerformanceReport::from(report).net_profit,
+/// );
+///
+/// assert_eq!(format!("{}", results[0].params), "buy=10.5, sell=11.5");
+/// assert_eq!(results[0].score, 200.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Optimizer {
+    backtester: Backtester,
+}
+
+/// Backtest of a parameter set by an [Optimizer](struct.Optimizer.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizationResult<P> {
+    pub params: P,
+    pub score: f64,
+    pub report: BacktestReport,
+}
+
+// Highest scores first, NaN scores last
+fn by_score<P>(a: &OptimizationResult<P>, b: &OptimizationResult<P>) -> Ordering {
+    a.score
+        .is_nan()
+        .cmp(&b.score.is_nan())
+        .then(b.score.total_cmp(&a.score))
+}
+
+impl Optimizer {
+    pub fn new(backtester: Backtester) -> Self {
+        Self { backtester }
+    }
+
+    pub fn backtester(&self) -> &Backtester {
+        &self.backtester
+    }
+
+    fn evaluate<P, F, S, M, T>(
+        &self,
+        bars: &[T],
+        params: &P,
+        factory: &F,
+        score: &M,
+    ) -> OptimizationResult<P>
+    where
+        P: Clone,
+        F: Fn(&P) -> S,
+        S: for<'a> Next<&'a T, Output = Action>,
+        M: Fn(&BacktestReport) -> f64,
+        T: Open + High + Low + Close,
+    {
+        let report = self.backtester.run(&mut factory(params), bars);
+        OptimizationResult {
+            params: params.clone(),
+            score: score(&report),
+            report,
+        }
+    }
+
+    /// Backtests the strategies created by the _factory_ for every parameter set of _params_
+    /// over the _bars_.
+    ///
+    /// Returns the results sorted by descending _score_, with the NaN scores last. Parameter
+    /// sets with equal scores keep their order.
+    pub fn run<P, F, S, M, T>(
+        &self,
+        bars: &[T],
+        params: &[P],
+        factory: F,
+        score: M,
+    ) -> Vec<OptimizationResult<P>>
+    where
+        P: Clone,
+        F: Fn(&P) -> S,
+        S: for<'a> Next<&'a T, Output = Action>,
+        M: Fn(&BacktestReport) -> f64,
+        T: Open + High + Low + Close,
+    {
+        let mut results: Vec<_> = params
+            .iter()
+            .map(|set| self.evaluate(bars, set, &factory, &score))
+            .collect();
+        results.sort_by(by_score);
+        results
+    }
+
+    /// Same as `run`, backtesting the parameter sets in parallel.
+    #[cfg(feature = "rayon")]
+    pub fn par_run<P, F, S, M, T>(
+        &self,
+        bars: &[T],
+        params: &[P],
+        factory: F,
+        score: M,
+    ) -> Vec<OptimizationResult<P>>
+    where
+        P: Clone + Send + Sync,
+        F: Fn(&P) -> S + Sync,
+        S: for<'a> Next<&'a T, Output = Action>,
+        M: Fn(&BacktestReport) -> f64 + Sync,
+        T: Open + High + Low + Close + Sync,
+    {
+        let mut results: Vec<_> = params
+            .par_iter()
+            .map(|set| self.evaluate(bars, set, &factory, &score))
+            .collect();
+        results.sort_by(by_score);
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::strategy::{Strategy, Threshold};
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    fn bars() -> Vec<DataItem> {
+        [10.0, 10.0, 12.0, 12.0, 11.0, 9.0]
+            .iter()
+            .map(|&close| item(close))
+            .collect()
+    }
+
+    // Buys below the level and sells above 11.5
+    fn factory(level: &f64) -> impl for<'a> Next<&'a DataItem, Output = Action> {
+        Strategy::new(
+            Threshold::below(Sma::new(1).unwrap(), *level),
+            Threshold::above(Sma::new(1).unwrap(), 11.5),
+        )
+    }
+
+    fn final_equity(report: &BacktestReport) -> f64 {
+        report.final_equity().unwrap()
+    }
+
+    fn scores<P>(results: &[OptimizationResult<P>]) -> Vec<f64> {
+        results.iter().map(|result| result.score).collect()
+    }
+
+    #[test]
+    fn test_run() {
+        let optimizer = Optimizer::new(Backtester::new(1000.0).unwrap());
+        let results = optimizer.run(&bars(), &[9.0, 10.5, 9.5], factory, final_equity);
+
+        let params: Vec<_> = results.iter().map(|result| result.params).collect();
+        assert_eq!(params, vec![10.5, 9.0, 9.5]);
+        assert_eq!(scores(&results), vec![1200.0, 1000.0, 1000.0]);
+        assert_eq!(results[0].report.trades.len(), 1);
+    }
+
+    #[test]
+    fn test_run_nan_score() {
+        let optimizer = Optimizer::new(Backtester::new(1000.0).unwrap());
+        let results = optimizer.run(&bars(), &[9.0, 10.5], factory, |report| {
+            if report.trades.is_empty() {
+                f64::NAN
+            } else {
+                -final_equity(report)
+            }
+        });
+
+        assert_eq!(results[0].params, 10.5);
+        assert_eq!(results[0].score, -1200.0);
+        assert!(results[1].score.is_nan());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_run() {
+        let optimizer = Optimizer::new(Backtester::new(1000.0).unwrap());
+        let levels = [9.0, 10.5, 9.5];
+
+        assert_eq!(
+            optimizer.par_run(&bars(), &levels, factory, final_equity),
+            optimizer.run(&bars(), &levels, factory, final_equity)
+        );
+    }
+}
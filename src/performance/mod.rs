@@ -0,0 +1,12 @@
+//! Performance statistics of backtested strategies.
+//!
+//! A [PerformanceReport](struct.PerformanceReport.html) summarizes the trades and the equity
+//! curve produced by a [Backtester](../backtest/struct.Backtester.html), and a
+//! [MonteCarlo](struct.MonteCarlo.html) simulation resamples the trades to estimate the
+//! distributions of the final equity and of the maximum drawdown.
+
+mod report;
+pub use self::report::PerformanceReport;
+
+mod montecarlo;
+pub use self::montecarlo::{Distribution, MonteCarlo, MonteCarloReport, Resampling};
@@ -0,0 +1,331 @@
+use crate::backtest::Trade;
+use crate::errors::{Result, TaError};
+use crate::indicators::Drawdown;
+use crate::Next;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the trades are drawn by a [Monte Carlo simulation](struct.MonteCarlo.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Resampling {
+    /// Draws as many trades as there are, with replacement.
+    #[default]
+    Bootstrap,
+    /// Shuffles the trades. The final equity is the same for every order, only the drawdown
+    /// changes.
+    Permutation,
+}
+
+/// Monte Carlo simulation of the trades of a backtest.
+///
+/// Every simulation draws a new sequence of trades from the trades of a backtest and
+/// compounds their returns from the initial equity. The final equity and the maximum drawdown
+/// of the simulated equity curves are returned as [distributions](struct.Distribution.html),
+/// giving confidence intervals of the results of a strategy that do not depend on the order
+/// of its trades.
+///
+/// The trades are drawn by a pseudorandom generator, so simulations with the same _seed_
+/// give the same results.
+///
+/// # Parameters
+///
+/// * _simulations_ - number of simulations (integer greater than 0)
+/// * _resampling_ - how the trades are drawn
+/// * _seed_ - seed of the pseudorandom generator
+///
+/// # Example
+///
+/// ```
+/// use ta::backtest::Trade;
+/// use ta::performance::{MonteCarlo, Resampling};
+///
+/// let trade = |profit| Trade {
+///     entry_index: 0,
+///     entry_price: 10.0,
+///     exit_index: 1,
+///     exit_price: 10.0 + profit / 100.0,
+///     quantity: 100.0,
+///     commission: 0.0,
+///     profit,
+/// };
+/// let trades = vec![trade(100.0), trade(-100.0), trade(-100.0)];
+///
+/// let report = MonteCarlo::new(100, Resampling::Permutation, 42)
+///     .unwrap()
+///     .run(&trades, 1000.0);
+///
+/// assert_eq!(report.final_equity.min().round(), 891.0);
+/// assert_eq!(report.final_equity.max().round(), 891.0);
+/// assert!(report.max_drawdown.percentile(95.0) <= 19.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MonteCarlo {
+    simulations: usize,
+    resampling: Resampling,
+    seed: u64,
+}
+
+/// Result of a [Monte Carlo simulation](struct.MonteCarlo.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloReport {
+    /// Equity after the last trade.
+    pub final_equity: Distribution,
+    /// Maximum drawdown in percent.
+    pub max_drawdown: Distribution,
+}
+
+/// Distribution of the results of a [Monte Carlo simulation](struct.MonteCarlo.html).
+///
+/// Percentiles are interpolated linearly between the closest values, as by
+/// [RollingPercentile](../indicators/struct.RollingPercentile.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    // Sorted in ascending order
+    values: Vec<f64>,
+}
+
+impl Distribution {
+    /// Creates the distribution of the _values_. Returns an error when there are no values.
+    pub fn new(values: Vec<f64>) -> Result<Self> {
+        if values.is_empty() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self::sorted(values))
+    }
+
+    fn sorted(mut values: Vec<f64>) -> Self {
+        values.sort_by(f64::total_cmp);
+        Self { values }
+    }
+
+    /// Returns the values in ascending order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn min(&self) -> f64 {
+        self.values[0]
+    }
+
+    pub fn max(&self) -> f64 {
+        self.values[self.values.len() - 1]
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.values.iter().sum::<f64>() / self.values.len() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// Returns the _percentile_ of the values. The percentile is clamped to the range of 0..100.
+    pub fn percentile(&self, percentile: f64) -> f64 {
+        let rank = percentile.clamp(0.0, 100.0) / 100.0 * (self.values.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+
+        self.values[lower] + (self.values[upper] - self.values[lower]) * (rank - lower as f64)
+    }
+
+    /// Returns the lower and the upper bounds of the central interval containing _confidence_
+    /// percent of the values, e.g. the 5th and the 95th percentiles for a confidence of 90.
+    pub fn confidence_interval(&self, confidence: f64) -> (f64, f64) {
+        let tail = (100.0 - confidence) / 2.0;
+        (self.percentile(tail), self.percentile(100.0 - tail))
+    }
+}
+
+// SplitMix64 pseudorandom generator
+#[derive(Debug, Clone)]
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Returns an index in the range of 0..len
+    fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+impl MonteCarlo {
+    pub fn new(simulations: usize, resampling: Resampling, seed: u64) -> Result<Self> {
+        if simulations == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            simulations,
+            resampling,
+            seed,
+        })
+    }
+
+    pub fn simulations(&self) -> usize {
+        self.simulations
+    }
+
+    pub fn resampling(&self) -> Resampling {
+        self.resampling
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Runs the simulations of the _trades_, starting with the _initial_equity_.
+    pub fn run(&self, trades: &[Trade], initial_equity: f64) -> MonteCarloReport {
+        let returns: Vec<f64> = trades.iter().map(|trade| trade.return_pct()).collect();
+        let mut random = Random::new(self.seed);
+        let mut sample = returns.clone();
+        let mut final_equity = Vec::with_capacity(self.simulations);
+        let mut max_drawdown = Vec::with_capacity(self.simulations);
+
+        for _ in 0..self.simulations {
+            match self.resampling {
+                Resampling::Bootstrap => {
+                    for value in sample.iter_mut() {
+                        *value = returns[random.index(returns.len())];
+                    }
+                }
+                // Fisher-Yates shuffle
+                Resampling::Permutation => {
+                    for i in (1..sample.len()).rev() {
+                        sample.swap(i, random.index(i + 1));
+                    }
+                }
+            }
+
+            let mut drawdown = Drawdown::new();
+            let mut equity = initial_equity;
+            let mut out = drawdown.next(equity);
+            for return_pct in &sample {
+                equity *= 1.0 + return_pct / 100.0;
+                out = drawdown.next(equity);
+            }
+
+            final_equity.push(equity);
+            max_drawdown.push(out.max_drawdown);
+        }
+
+        MonteCarloReport {
+            final_equity: Distribution::sorted(final_equity),
+            max_drawdown: Distribution::sorted(max_drawdown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn trade(return_pct: f64) -> Trade {
+        Trade {
+            entry_index: 0,
+            entry_price: 10.0,
+            exit_index: 1,
+            exit_price: 10.0 + return_pct / 10.0,
+            quantity: 10.0,
+            commission: 0.0,
+            profit: return_pct,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(MonteCarlo::new(0, Resampling::Bootstrap, 1).is_err());
+
+        let monte_carlo = MonteCarlo::new(500, Resampling::Permutation, 7).unwrap();
+        assert_eq!(monte_carlo.simulations(), 500);
+        assert_eq!(monte_carlo.resampling(), Resampling::Permutation);
+        assert_eq!(monte_carlo.seed(), 7);
+    }
+
+    #[test]
+    fn test_run_permutation() {
+        let trades = vec![trade(10.0), trade(-10.0), trade(-10.0)];
+        let report = MonteCarlo::new(200, Resampling::Permutation, 1)
+            .unwrap()
+            .run(&trades, 1000.0);
+
+        assert_eq!(report.final_equity.values().len(), 200);
+        assert_eq!(round(report.final_equity.min()), 891.0);
+        assert_eq!(round(report.final_equity.max()), 891.0);
+
+        // Down, up, down is the only order with a smaller drawdown
+        assert_eq!(round(report.max_drawdown.min()), 10.9);
+        assert_eq!(round(report.max_drawdown.max()), 19.0);
+    }
+
+    #[test]
+    fn test_run_bootstrap() {
+        let trades = vec![trade(10.0), trade(-10.0)];
+        let report = MonteCarlo::new(200, Resampling::Bootstrap, 1)
+            .unwrap()
+            .run(&trades, 1000.0);
+
+        assert_eq!(round(report.final_equity.min()), 810.0);
+        assert_eq!(round(report.final_equity.max()), 1210.0);
+        assert_eq!(report.max_drawdown.min(), 0.0);
+        assert_eq!(round(report.max_drawdown.max()), 19.0);
+    }
+
+    #[test]
+    fn test_run_seed() {
+        let trades = vec![trade(10.0), trade(-5.0), trade(3.0), trade(-8.0)];
+        let run = |seed| {
+            MonteCarlo::new(50, Resampling::Bootstrap, seed)
+                .unwrap()
+                .run(&trades, 1000.0)
+        };
+
+        assert_eq!(run(3), run(3));
+        assert_ne!(run(3), run(4));
+    }
+
+    #[test]
+    fn test_run_no_trades() {
+        let report = MonteCarlo::new(10, Resampling::Bootstrap, 1)
+            .unwrap()
+            .run(&[], 1000.0);
+
+        assert_eq!(report.final_equity.min(), 1000.0);
+        assert_eq!(report.final_equity.max(), 1000.0);
+        assert_eq!(report.max_drawdown.max(), 0.0);
+    }
+
+    #[test]
+    fn test_distribution() {
+        assert!(Distribution::new(vec![]).is_err());
+
+        let values: Vec<f64> = (0..=100).rev().map(|value| value as f64).collect();
+        let distribution = Distribution::new(values).unwrap();
+
+        assert_eq!(distribution.values()[0], 0.0);
+        assert_eq!(distribution.min(), 0.0);
+        assert_eq!(distribution.max(), 100.0);
+        assert_eq!(distribution.mean(), 50.0);
+        assert_eq!(distribution.median(), 50.0);
+        assert_eq!(distribution.percentile(2.5), 2.5);
+        assert_eq!(distribution.percentile(150.0), 100.0);
+        assert_eq!(distribution.confidence_interval(90.0), (5.0, 95.0));
+    }
+}
@@ -0,0 +1,290 @@
+use crate::backtest::{BacktestReport, Trade};
+use crate::indicators::Drawdown;
+use crate::Next;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Performance statistics of a list of trades and an equity curve.
+///
+/// Trades with a positive profit are winning, trades with a negative profit are losing, and
+/// trades without profit are neither, and break both winning and losing streaks. Ratios which
+/// are undefined without trades are 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::backtest::Backtester;
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::performance::PerformanceReport;
+/// use ta::strategy::{Strategy, Threshold};
+/// use ta::DataItem;
+///
+/// let bar = |close| {
+///     DataItem::builder()
+///         .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+/// };
+/// let bars: Vec<_> = [10.0, 10.0, 12.0, 12.0, 10.0, 10.0, 8.0, 8.0]
+///     .iter()
+///     .map(|&close| bar(close))
+///     .collect();
+///
+/// let mut strategy = Strategy::new(
+///     Threshold::below(Sma::new(1).unwrap(), 11.0),
+///     Threshold::above(Sma::new(1).unwrap(), 11.0),
+/// );
+/// let backtest = Backtester::new(1000.0).unwrap().run(&mut strategy, &bars);
+/// let report = PerformanceReport::from(&backtest);
+///
+/// assert_eq!(report.total_trades, 1);
+/// assert_eq!(report.win_rate, 100.0);
+/// assert_eq!(report.net_profit, 200.0);
+/// // The position entered on the sixth bar is still open at the end
+/// assert_eq!(report.exposure, 62.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceReport {
+    pub total_trades: usize,
+    pub winning_trades: usize,
+    pub losing_trades: usize,
+    /// Percentage of the winning trades.
+    pub win_rate: f64,
+    /// Sum of the profits of the winning trades.
+    pub gross_profit: f64,
+    /// Sum of the losses of the losing trades, as a positive number.
+    pub gross_loss: f64,
+    pub net_profit: f64,
+    /// Gross profit divided by gross loss, infinite when there is a profit and no loss.
+    pub profit_factor: f64,
+    /// Net profit per trade.
+    pub average_trade: f64,
+    pub average_win: f64,
+    /// Average loss of the losing trades, as a positive number.
+    pub average_loss: f64,
+    /// Average trade in multiples of the average loss.
+    pub expectancy: f64,
+    /// Maximum drawdown of the equity curve in percent.
+    pub max_drawdown: f64,
+    /// Percentage of the bars of the equity curve with an open position, counting the bars
+    /// from the entry of every trade to the bar before its exit, and from the entry of a
+    /// position still open at the end to the last bar.
+    pub exposure: f64,
+    pub max_consecutive_wins: usize,
+    pub max_consecutive_losses: usize,
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+impl PerformanceReport {
+    pub fn new(trades: &[Trade], equity: &[f64]) -> Self {
+        Self::with_open_position(trades, equity, None)
+    }
+
+    /// Returns the report of the trades and the equity curve of a backtest ending with a
+    /// position still open, entered on the bar at the given index.
+    pub fn with_open_position(
+        trades: &[Trade],
+        equity: &[f64],
+        position_entry_index: Option<usize>,
+    ) -> Self {
+        let mut winning_trades = 0;
+        let mut losing_trades = 0;
+        let mut gross_profit = 0.0;
+        let mut gross_loss = 0.0;
+        let mut wins_streak = 0;
+        let mut losses_streak = 0;
+        let mut max_consecutive_wins = 0;
+        let mut max_consecutive_losses = 0;
+        let mut bars_in_market = 0;
+
+        for trade in trades {
+            if trade.profit > 0.0 {
+                winning_trades += 1;
+                gross_profit += trade.profit;
+                wins_streak += 1;
+                losses_streak = 0;
+            } else if trade.profit < 0.0 {
+                losing_trades += 1;
+                gross_loss -= trade.profit;
+                losses_streak += 1;
+                wins_streak = 0;
+            } else {
+                wins_streak = 0;
+                losses_streak = 0;
+            }
+            max_consecutive_wins = max_consecutive_wins.max(wins_streak);
+            max_consecutive_losses = max_consecutive_losses.max(losses_streak);
+            bars_in_market += trade.exit_index.saturating_sub(trade.entry_index);
+        }
+        if let Some(entry_index) = position_entry_index {
+            bars_in_market += equity.len().saturating_sub(entry_index);
+        }
+
+        let total_trades = trades.len();
+        let net_profit = gross_profit - gross_loss;
+        let average_trade = ratio(net_profit, total_trades as f64);
+        let average_loss = ratio(gross_loss, losing_trades as f64);
+
+        let profit_factor = if gross_loss == 0.0 && gross_profit > 0.0 {
+            f64::INFINITY
+        } else {
+            ratio(gross_profit, gross_loss)
+        };
+
+        let mut drawdown = Drawdown::new();
+        let max_drawdown = equity
+            .iter()
+            .map(|&value| drawdown.next(value).max_drawdown)
+            .last()
+            .unwrap_or(0.0);
+
+        Self {
+            total_trades,
+            winning_trades,
+            losing_trades,
+            win_rate: ratio(winning_trades as f64, total_trades as f64) * 100.0,
+            gross_profit,
+            gross_loss,
+            net_profit,
+            profit_factor,
+            average_trade,
+            average_win: ratio(gross_profit, winning_trades as f64),
+            average_loss,
+            expectancy: ratio(average_trade, average_loss),
+            max_drawdown,
+            exposure: ratio(bars_in_market as f64, equity.len() as f64) * 100.0,
+            max_consecutive_wins,
+            max_consecutive_losses,
+        }
+    }
+}
+
+impl From<&BacktestReport> for PerformanceReport {
+    fn from(report: &BacktestReport) -> Self {
+        Self::with_open_position(&report.trades, &report.equity, report.position_entry_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn trade(entry_index: usize, exit_index: usize, profit: f64) -> Trade {
+        Trade {
+            entry_index,
+            entry_price: 10.0,
+            exit_index,
+            exit_price: 10.0 + profit / 10.0,
+            quantity: 10.0,
+            commission: 0.0,
+            profit,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let trades = vec![
+            trade(0, 2, 100.0),
+            trade(3, 5, 30.0),
+            trade(6, 7, -50.0),
+            trade(8, 9, 0.0),
+            trade(10, 12, -20.0),
+            trade(13, 14, -10.0),
+            trade(15, 18, 60.0),
+        ];
+        let mut equity = vec![1000.0; 20];
+        equity[5] = 1100.0;
+        equity[6] = 990.0;
+
+        let report = PerformanceReport::new(&trades, &equity);
+
+        assert_eq!(report.total_trades, 7);
+        assert_eq!(report.winning_trades, 3);
+        assert_eq!(report.losing_trades, 3);
+        assert_eq!(round(report.win_rate), 42.857);
+        assert_eq!(report.gross_profit, 190.0);
+        assert_eq!(report.gross_loss, 80.0);
+        assert_eq!(report.net_profit, 110.0);
+        assert_eq!(report.profit_factor, 2.375);
+        assert_eq!(round(report.average_trade), 15.714);
+        assert_eq!(round(report.average_win), 63.333);
+        assert_eq!(round(report.average_loss), 26.667);
+        assert_eq!(round(report.expectancy), 0.589);
+        assert_eq!(round(report.max_drawdown), 10.0);
+        assert_eq!(report.exposure, 60.0);
+        assert_eq!(report.max_consecutive_wins, 2);
+        assert_eq!(report.max_consecutive_losses, 2);
+    }
+
+    #[test]
+    fn test_new_without_losses() {
+        let report = PerformanceReport::new(&[trade(0, 1, 10.0)], &[1000.0, 1010.0]);
+
+        assert_eq!(report.win_rate, 100.0);
+        assert_eq!(report.profit_factor, f64::INFINITY);
+        assert_eq!(report.average_loss, 0.0);
+        assert_eq!(report.expectancy, 0.0);
+        assert_eq!(report.max_drawdown, 0.0);
+        assert_eq!(report.exposure, 50.0);
+    }
+
+    #[test]
+    fn test_new_empty() {
+        let report = PerformanceReport::new(&[], &[]);
+
+        assert_eq!(report.total_trades, 0);
+        assert_eq!(report.win_rate, 0.0);
+        assert_eq!(report.profit_factor, 0.0);
+        assert_eq!(report.average_trade, 0.0);
+        assert_eq!(report.max_drawdown, 0.0);
+        assert_eq!(report.exposure, 0.0);
+        assert_eq!(report.max_consecutive_wins, 0);
+    }
+
+    #[test]
+    fn test_from_backtest_report() {
+        let backtest = BacktestReport {
+            trades: vec![trade(1, 3, -20.0)],
+            equity: vec![1000.0, 1000.0, 990.0, 980.0],
+            cash: 980.0,
+            position: 0.0,
+            position_entry_index: None,
+        };
+
+        let report = PerformanceReport::from(&backtest);
+
+        assert_eq!(report.losing_trades, 1);
+        assert_eq!(report.expectancy, -1.0);
+        assert_eq!(report.max_drawdown, 2.0);
+        assert_eq!(report.exposure, 50.0);
+    }
+
+    #[test]
+    fn test_from_backtest_report_open_position() {
+        let backtest = BacktestReport {
+            trades: vec![trade(0, 2, 10.0)],
+            equity: vec![1000.0, 1000.0, 1010.0, 1010.0, 1020.0, 1030.0],
+            cash: 0.0,
+            position: 100.0,
+            position_entry_index: Some(4),
+        };
+
+        let report = PerformanceReport::from(&backtest);
+
+        assert_eq!(report.total_trades, 1);
+        assert_eq!(round(report.exposure), 66.667);
+    }
+
+    #[test]
+    fn test_new_inconsistent_trade() {
+        let report = PerformanceReport::new(&[trade(3, 1, 10.0)], &[1000.0; 4]);
+        assert_eq!(report.exposure, 0.0);
+    }
+}
@@ -0,0 +1,431 @@
+//! Rendering of candles and indicator outputs to PNG or SVG charts, for quick visual checks of
+//! the behavior of indicators.
+//!
+//! Requires the `plot` feature.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+use std::path::Path;
+
+use plotters::coord::types::RangedCoordf64;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+use crate::{Close, High, Low, Next, Open, OutputInfo, OutputValues, Pane};
+
+/// Error rendering a [Plot](struct.Plot.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlotError {
+    /// The plot has no bar.
+    NoBars,
+    /// Error of the drawing backend, e.g. when writing the file.
+    Drawing(String),
+}
+
+impl Display for PlotError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PlotError::NoBars => write!(f, "no bars to plot"),
+            PlotError::Drawing(ref message) => write!(f, "drawing error: {}", message),
+        }
+    }
+}
+
+impl Error for PlotError {}
+
+impl<E: Error + Send + Sync> From<DrawingAreaErrorKind<E>> for PlotError {
+    fn from(err: DrawingAreaErrorKind<E>) -> Self {
+        PlotError::Drawing(err.to_string())
+    }
+}
+
+// Outputs of an indicator over the bars of a plot, one row per field
+#[derive(Debug, Clone)]
+struct Series {
+    label: String,
+    fields: Vec<&'static str>,
+    range: Option<(f64, f64)>,
+    pane: Pane,
+    values: Vec<Vec<f64>>,
+}
+
+impl Series {
+    fn field_label(&self, field: usize) -> String {
+        if self.fields.len() == 1 {
+            self.label.clone()
+        } else {
+            format!("{}.{}", self.label, self.fields[field])
+        }
+    }
+
+    fn bounds(&self) -> Option<(f64, f64)> {
+        bounds(self.values.iter().flatten().copied())
+    }
+}
+
+/// Chart of candles with the outputs of indicators.
+///
+/// Every indicator is run over the bars when added. Its [OutputInfo](../trait.OutputInfo.html)
+/// tells how to draw it: overlays, like moving averages and bands, are drawn over the candles,
+/// while the other indicators, like oscillators, get a pane of their own below the candles,
+/// scaled to their typical range when they have one. Every field of the output is drawn as a
+/// line, NaN values leaving gaps.
+///
+/// PNG files are drawn with the fonts of the system, found with fontconfig on Linux.
+///
+/// # Example
+///
+/// ```no_run
+/// use ta::indicators::{BollingerBands, RelativeStrengthIndex};
+/// use ta::plot::Plot;
+/// use ta::DataItem;
+///
+/// let bars: Vec<DataItem> = (0..100)
+///     .map(|i| {
+///         let close = 100.0 + (i as f64 / 5.0).sin() * 10.0;
+///         DataItem::builder()
+///             .open(close - 1.0).high(close + 2.0).low(close - 2.0).close(close).volume(1000.0)
+///             .build()
+///             .unwrap()
+///     })
+///     .collect();
+///
+/// Plot::new(&bars)
+///     .title("Sine")
+///     .indicator(BollingerBands::new(20, 2.0).unwrap())
+///     .indicator(RelativeStrengthIndex::new(14).unwrap())
+///     .save_png("sine.png")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Plot<'a, T> {
+    bars: &'a [T],
+    title: Option<String>,
+    size: (u32, u32),
+    series: Vec<Series>,
+}
+
+impl<'a, T: Open + High + Low + Close> Plot<'a, T> {
+    /// Returns a plot of the bars, of 1024 by 768 pixels.
+    pub fn new(bars: &'a [T]) -> Self {
+        Self {
+            bars,
+            title: None,
+            size: (1024, 768),
+            series: Vec::new(),
+        }
+    }
+
+    /// Sets the title drawn above the candles.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the width and height of the chart, in pixels.
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Runs the indicator over the bars and adds its outputs to the plot, labelled by the
+    /// display of the indicator.
+    pub fn indicator<I>(mut self, mut indicator: I) -> Self
+    where
+        I: for<'b> Next<&'b T> + OutputInfo + Display,
+        for<'b> <I as Next<&'b T>>::Output: OutputValues,
+    {
+        let fields = indicator.output_fields().to_vec();
+        let mut values = vec![Vec::with_capacity(self.bars.len()); fields.len()];
+        for bar in self.bars {
+            let output = indicator.next(bar).output_values();
+            for (column, value) in values.iter_mut().zip(output) {
+                column.push(value);
+            }
+        }
+
+        self.series.push(Series {
+            label: indicator.to_string(),
+            fields,
+            range: indicator.output_range(),
+            pane: indicator.pane(),
+            values,
+        });
+        self
+    }
+
+    /// Renders the plot to a PNG file.
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), PlotError> {
+        self.draw(BitMapBackend::new(path.as_ref(), self.size).into_drawing_area())
+    }
+
+    /// Renders the plot to an SVG file.
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), PlotError> {
+        self.draw(SVGBackend::new(path.as_ref(), self.size).into_drawing_area())
+    }
+
+    /// Renders the plot to an SVG document.
+    pub fn to_svg(&self) -> Result<String, PlotError> {
+        let mut svg = String::new();
+        self.draw(SVGBackend::with_string(&mut svg, self.size).into_drawing_area())?;
+        Ok(svg)
+    }
+
+    /// Renders the plot on a drawing area of any plotters backend.
+    pub fn draw<DB: DrawingBackend>(&self, root: DrawingArea<DB, Shift>) -> Result<(), PlotError>
+    where
+        DB::ErrorType: 'static,
+    {
+        if self.bars.is_empty() {
+            return Err(PlotError::NoBars);
+        }
+        root.fill(&WHITE)?;
+
+        let separate: Vec<&Series> = self
+            .series
+            .iter()
+            .filter(|series| series.pane == Pane::Separate)
+            .collect();
+        let (price_area, lower_area) = if separate.is_empty() {
+            (root.clone(), None)
+        } else {
+            let height = root.dim_in_pixel().1 * 3 / 5;
+            let (upper, lower) = root.split_vertically(height);
+            (upper, Some(lower))
+        };
+
+        self.draw_prices(&price_area)?;
+        if let Some(lower_area) = lower_area {
+            let areas = lower_area.split_evenly((separate.len(), 1));
+            for (series, area) in separate.iter().zip(areas.iter()) {
+                draw_pane(area, series)?;
+            }
+        }
+
+        root.present()?;
+        Ok(())
+    }
+
+    fn x_range(&self) -> Range<f64> {
+        -0.5..self.bars.len() as f64 - 0.5
+    }
+
+    fn draw_prices<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), PlotError>
+    where
+        DB::ErrorType: 'static,
+    {
+        let overlays: Vec<&Series> = self
+            .series
+            .iter()
+            .filter(|series| series.pane == Pane::Overlay)
+            .collect();
+        let prices = self.bars.iter().flat_map(|bar| vec![bar.low(), bar.high()]);
+        let overlay_values = overlays.iter().filter_map(|series| series.bounds());
+        let (min, max) = bounds(prices.chain(overlay_values.flat_map(|(min, max)| vec![min, max])))
+            .unwrap_or((0.0, 1.0));
+
+        let mut builder = ChartBuilder::on(area);
+        if let Some(title) = &self.title {
+            builder.caption(title, ("sans-serif", 20));
+        }
+        let mut chart = builder
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(60)
+            .build_cartesian_2d(self.x_range(), padded(min, max))?;
+        chart.configure_mesh().disable_x_mesh().draw()?;
+
+        let width = (area.dim_in_pixel().0 as f64 / self.bars.len() as f64 * 0.6).max(1.0) as u32;
+        chart.draw_series(self.bars.iter().enumerate().map(|(index, bar)| {
+            CandleStick::new(
+                index as f64,
+                bar.open(),
+                bar.high(),
+                bar.low(),
+                bar.close(),
+                GREEN.filled(),
+                RED.filled(),
+                width,
+            )
+        }))?;
+
+        let mut color = 0;
+        for series in overlays {
+            for field in 0..series.fields.len() {
+                draw_line(&mut chart, series, field, Palette99::pick(color))?;
+                color += 1;
+            }
+        }
+        if color > 0 {
+            draw_legend(&mut chart)?;
+        }
+        Ok(())
+    }
+}
+
+fn draw_pane<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    series: &Series,
+) -> Result<(), PlotError>
+where
+    DB::ErrorType: 'static,
+{
+    let len = series.values.first().map_or(0, |values| values.len());
+    let (min, max) = series
+        .range
+        .or_else(|| series.bounds())
+        .unwrap_or((0.0, 1.0));
+
+    let mut chart = ChartBuilder::on(area)
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(60)
+        .build_cartesian_2d(-0.5..len as f64 - 0.5, padded(min, max))?;
+    chart.configure_mesh().disable_x_mesh().draw()?;
+
+    for field in 0..series.fields.len() {
+        draw_line(&mut chart, series, field, Palette99::pick(field))?;
+    }
+    draw_legend(&mut chart)
+}
+
+type Chart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
+// Draws a field of the series as lines between the finite values
+fn draw_line<'a, DB: DrawingBackend + 'a>(
+    chart: &mut Chart<'a, DB>,
+    series: &Series,
+    field: usize,
+    color: PaletteColor<Palette99>,
+) -> Result<(), PlotError>
+where
+    DB::ErrorType: 'static,
+{
+    let segments = series.values[field]
+        .split(|value| !value.is_finite())
+        .scan(0, |start, segment| {
+            let points = (*start..)
+                .zip(segment)
+                .map(|(index, &value)| (index as f64, value))
+                .collect::<Vec<_>>();
+            *start += segment.len() + 1;
+            Some(points)
+        })
+        .filter(|points| !points.is_empty())
+        .collect::<Vec<_>>();
+
+    let style = color.stroke_width(2);
+    for (index, points) in segments.into_iter().enumerate() {
+        let drawn = chart.draw_series(LineSeries::new(points, style))?;
+        if index == 0 {
+            drawn
+                .label(series.field_label(field))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+    }
+    Ok(())
+}
+
+fn draw_legend<'a, DB: DrawingBackend + 'a>(chart: &mut Chart<'a, DB>) -> Result<(), PlotError>
+where
+    DB::ErrorType: 'static,
+{
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+    Ok(())
+}
+
+// Smallest and largest finite values, if any
+fn bounds<I: Iterator<Item = f64>>(values: I) -> Option<(f64, f64)> {
+    values
+        .filter(|value| value.is_finite())
+        .fold(None, |bounds, value| match bounds {
+            None => Some((value, value)),
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+        })
+}
+
+// Range of the values with a margin of 5%, and of 1 around a single value
+fn padded(min: f64, max: f64) -> Range<f64> {
+    let margin = if max > min { (max - min) * 0.05 } else { 1.0 };
+    min - margin..max + margin
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        BollingerBands, MovingAverageConvergenceDivergence as Macd, RelativeStrengthIndex as Rsi,
+        SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+
+    fn bars() -> Vec<Bar> {
+        (0..40)
+            .map(|i| {
+                let close = 10.0 + (i as f64 / 4.0).sin();
+                Bar::new()
+                    .open(close - 0.2)
+                    .high(close + 0.5)
+                    .low(close - 0.5)
+                    .close(close)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_indicator() {
+        let bars = bars();
+        let plot = Plot::new(&bars)
+            .indicator(Sma::new(3).unwrap())
+            .indicator(Macd::new(3, 6, 4).unwrap());
+
+        assert_eq!(plot.series.len(), 2);
+        assert_eq!(plot.series[0].label, "SMA(3)");
+        assert_eq!(plot.series[0].pane, Pane::Overlay);
+        assert_eq!(plot.series[0].values[0].len(), 40);
+        assert_eq!(plot.series[1].values.len(), 3);
+        assert_eq!(plot.series[1].field_label(2), "MACD(3, 6, 4).histogram");
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let bars = bars();
+        let svg = Plot::new(&bars)
+            .title("Sine")
+            .size(640, 480)
+            .indicator(BollingerBands::new(5, 2.0).unwrap())
+            .indicator(Rsi::new(5).unwrap())
+            .to_svg()
+            .unwrap();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Sine"));
+        assert!(svg.contains("BB(5, 2).upper"));
+        assert!(svg.contains("RSI(5)"));
+    }
+
+    #[test]
+    fn test_no_bars() {
+        let bars: Vec<Bar> = vec![];
+        assert_eq!(Plot::new(&bars).to_svg(), Err(PlotError::NoBars));
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(
+            bounds(vec![2.0, f64::NAN, -1.0, 3.0].into_iter()),
+            Some((-1.0, 3.0))
+        );
+        assert_eq!(bounds(vec![f64::NAN].into_iter()), None);
+        assert_eq!(padded(1.0, 1.0), 0.0..2.0);
+    }
+}
@@ -0,0 +1,166 @@
+//! Integration with [polars](https://docs.rs/polars) series and data frames.
+//!
+//! [next_series](fn.next_series.html) runs an indicator over a series of values, e.g. the
+//! closes, and [compute_frame](fn.compute_frame.html) runs an indicator given by its
+//! [specification](../registry/struct.IndicatorSpec.html), e.g. `"rsi(14)"`, over the bars of
+//! a data frame. Null values are skipped, giving null outputs. The functions fit in the
+//! `map` of a lazy expression. Requires the `polars` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use polars::prelude::*;
+//! use ta::indicators::SimpleMovingAverage as Sma;
+//! use ta::polars::{compute_frame, next_series};
+//!
+//! let close = Series::new("close".into(), &[Some(10.0), Some(11.0), None, Some(12.0)]);
+//! let sma = next_series(&mut Sma::new(2).unwrap(), &close).unwrap();
+//! assert_eq!(sma.name().as_str(), "SMA(2)");
+//! assert_eq!(sma.f64().unwrap().get(2), None);
+//! assert_eq!(sma.f64().unwrap().get(3), Some(11.5));
+//!
+//! let df = DataFrame::new(vec![close.into()]).unwrap();
+//! let macd = compute_frame(&df, "macd(3, 6, 4)").unwrap();
+//! assert_eq!(macd.get_column_names(), ["MACD(3, 6, 4).macd", "MACD(3, 6, 4).signal",
+//!     "MACD(3, 6, 4).histogram"]);
+//! ```
+
+use std::fmt;
+
+use ::polars::prelude::*;
+
+use crate::registry::IndicatorRegistry;
+use crate::{DataItem, Next};
+
+/// Feeds every value of the series, cast to `f64`, to the indicator and returns the outputs
+/// as a series named after the indicator.
+pub fn next_series<I>(indicator: &mut I, series: &Series) -> PolarsResult<Series>
+where
+    I: Next<f64, Output = f64> + fmt::Display,
+{
+    let values = series.cast(&DataType::Float64)?;
+    let outputs: Float64Chunked = values
+        .f64()?
+        .into_iter()
+        .map(|value| value.map(|value| indicator.next(value)))
+        .collect();
+    Ok(outputs
+        .with_name(indicator.to_string().into())
+        .into_series())
+}
+
+/// Runs the indicator of the specification over the bars of the data frame and returns a data
+/// frame with a column for every field of the indicator.
+///
+/// The bars are read from the `open`, `high`, `low`, `close` and `volume` columns, only the
+/// `close` column being required: the other prices default to the close, and the volume to 0.
+/// The columns are named after the indicator, with the name of the field for indicators with
+/// several fields, e.g. `"BB(20, 2).upper"`.
+pub fn compute_frame(df: &DataFrame, spec: &str) -> PolarsResult<DataFrame> {
+    let mut indicator = IndicatorRegistry::new()
+        .create(spec)
+        .map_err(|err| polars_err!(ComputeError: "invalid indicator {}: {}", spec, err))?;
+
+    let close = float_column(df, "close")?
+        .ok_or_else(|| polars_err!(ColumnNotFound: "close column is required"))?;
+    let column = |name| -> PolarsResult<Float64Chunked> {
+        Ok(float_column(df, name)?.unwrap_or_else(|| close.clone()))
+    };
+    let (open, high, low) = (column("open")?, column("high")?, column("low")?);
+    let volume = float_column(df, "volume")?
+        .unwrap_or_else(|| Float64Chunked::full("volume".into(), 0.0, close.len()));
+
+    let mut outputs = vec![Vec::with_capacity(close.len()); indicator.fields().len()];
+    for i in 0..close.len() {
+        let bar = match (
+            open.get(i),
+            high.get(i),
+            low.get(i),
+            close.get(i),
+            volume.get(i),
+        ) {
+            (Some(open), Some(high), Some(low), Some(close), Some(volume)) => {
+                Some(DataItem::unchecked(open, high, low, close, volume))
+            }
+            _ => None,
+        };
+        match bar {
+            Some(bar) => {
+                for (output, value) in outputs.iter_mut().zip(indicator.next(&bar)) {
+                    output.push(Some(value));
+                }
+            }
+            None => outputs.iter_mut().for_each(|output| output.push(None)),
+        }
+    }
+
+    let columns = indicator
+        .field_labels()
+        .into_iter()
+        .zip(outputs)
+        .map(|(label, values)| Column::new(label.into(), values))
+        .collect();
+    DataFrame::new(columns)
+}
+
+// Column of the data frame cast to f64, if any
+fn float_column(df: &DataFrame, name: &str) -> PolarsResult<Option<Float64Chunked>> {
+    df.column(name)
+        .ok()
+        .map(|column| Ok(column.cast(&DataType::Float64)?.f64()?.clone()))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RelativeStrengthIndex as Rsi;
+
+    fn values(series: &Series) -> Vec<Option<f64>> {
+        series.f64().unwrap().into_iter().collect()
+    }
+
+    #[test]
+    fn test_next_series() {
+        let close = Series::new("close".into(), &[10, 12, 11]);
+        let rsi = next_series(&mut Rsi::new(3).unwrap(), &close).unwrap();
+        assert_eq!(rsi.name().as_str(), "RSI(3)");
+        assert_eq!(rsi.len(), 3);
+        assert_eq!(rsi.f64().unwrap().get(0), Some(50.0));
+
+        let close = Series::new("close".into(), &[Some(10.0), None]);
+        let rsi = next_series(&mut Rsi::new(3).unwrap(), &close).unwrap();
+        assert_eq!(values(&rsi), [Some(50.0), None]);
+    }
+
+    #[test]
+    fn test_compute_frame() {
+        let df = df! {
+            "high" => [11.0, 12.0, 13.0],
+            "low" => [9.0, 9.0, 10.0],
+            "close" => [Some(10.0), None, Some(12.0)],
+        }
+        .unwrap();
+
+        let atr = compute_frame(&df, "atr(3)").unwrap();
+        assert_eq!(atr.get_column_names(), ["ATR(3)"]);
+        let atr = atr.column("ATR(3)").unwrap().as_materialized_series();
+        assert_eq!(values(atr), [Some(2.0), None, Some(2.5)]);
+
+        let bb = compute_frame(&df, "bb(2, 1)").unwrap();
+        assert_eq!(
+            bb.get_column_names(),
+            ["BB(2, 1).average", "BB(2, 1).upper", "BB(2, 1).lower"]
+        );
+    }
+
+    #[test]
+    fn test_compute_frame_invalid() {
+        let df = df! { "close" => [10.0, 11.0] }.unwrap();
+        assert!(compute_frame(&df, "foo(3)").is_err());
+        assert!(compute_frame(&df, "sma(0)").is_err());
+
+        let df = df! { "open" => [10.0, 11.0] }.unwrap();
+        assert!(compute_frame(&df, "sma(3)").is_err());
+    }
+}
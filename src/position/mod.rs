@@ -0,0 +1,10 @@
+//! Management of open positions.
+//!
+//! The [TrailingStopManager](struct.TrailingStopManager.html) keeps the trailing stop of an
+//! open position up to date with an ATR based indicator, and reports when it is hit.
+
+mod trailing_stop;
+pub use self::trailing_stop::TrailingStop;
+
+mod trailing_stop_manager;
+pub use self::trailing_stop_manager::{PositionSide, StopEvent, TrailingStopManager};
@@ -0,0 +1,25 @@
+use crate::indicators::{ChandelierExit, VolatilityStop};
+use crate::{Close, High, Low, Next, Reset};
+
+/// Indicator providing trailing stop levels, used by
+/// [TrailingStopManager](struct.TrailingStopManager.html).
+pub trait TrailingStop: Reset {
+    /// Consumes a bar and returns the stop levels of a long and a short position.
+    fn next_stops<T: High + Low + Close>(&mut self, bar: &T) -> (f64, f64);
+}
+
+impl TrailingStop for ChandelierExit {
+    fn next_stops<T: High + Low + Close>(&mut self, bar: &T) -> (f64, f64) {
+        let out = self.next(bar);
+        (out.long, out.short)
+    }
+}
+
+/// The single stop level is used for both sides. While it is on the wrong side of the price,
+/// i.e. the trend is against the position, the position is closed on the next bar.
+impl TrailingStop for VolatilityStop {
+    fn next_stops<T: High + Low + Close>(&mut self, bar: &T) -> (f64, f64) {
+        let out = self.next(bar);
+        (out.stop, out.stop)
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+
+use crate::position::TrailingStop;
+use crate::{Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Side of a position.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+/// Hit of the stop of a position managed by
+/// [TrailingStopManager](struct.TrailingStopManager.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StopEvent {
+    pub side: PositionSide,
+    pub entry_price: f64,
+    /// Stop level which was hit.
+    pub stop_price: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct OpenPosition {
+    side: PositionSide,
+    entry_price: f64,
+    stop: Option<f64>,
+}
+
+/// Trailing stop manager.
+///
+/// Tracks an open position and its trailing stop, computed by a
+/// [Chandelier Exit](../indicators/struct.ChandelierExit.html) or a
+/// [Volatility Stop](../indicators/struct.VolatilityStop.html). The stop only moves in the
+/// favorable direction: up for long positions and down for short ones.
+///
+/// Every bar is given to the indicator, whether a position is open or not, so the stop is
+/// available as soon as a position is opened. For every bar, the manager first checks
+/// whether the low (long positions) or the high (short positions) reached the stop set on the
+/// previous bars, in which case the position is closed and a [StopEvent](struct.StopEvent.html)
+/// is returned. Otherwise the stop is ratcheted with the new level of the indicator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolatilityStop;
+/// use ta::position::{PositionSide, TrailingStopManager};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high, low, close| {
+///     DataItem::builder()
+///         .open(close).high(high).low(low).close(close).volume(0.0).build().unwrap()
+/// };
+///
+/// let mut manager = TrailingStopManager::new(VolatilityStop::new(3, 1.0).unwrap());
+///
+/// assert_eq!(manager.next(&bar(10.5, 9.5, 10.0)), None);
+/// manager.open(PositionSide::Long, 10.0);
+/// assert_eq!(manager.stop(), Some(9.0));
+///
+/// let event = manager.next(&bar(10.0, 8.5, 8.8)).unwrap();
+/// assert_eq!(event.stop_price, 9.0);
+/// assert!(!manager.is_open());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TrailingStopManager<S> {
+    trailing_stop: S,
+    // Stop levels of a long and a short position after the last bar
+    last_stops: Option<(f64, f64)>,
+    position: Option<OpenPosition>,
+}
+
+impl<S: TrailingStop> TrailingStopManager<S> {
+    pub fn new(trailing_stop: S) -> Self {
+        Self {
+            trailing_stop,
+            last_stops: None,
+            position: None,
+        }
+    }
+
+    /// Opens a position, replacing the current one. The stop is set to the level of the
+    /// indicator after the last bar, or after the next bar if none was given yet.
+    pub fn open(&mut self, side: PositionSide, entry_price: f64) {
+        self.position = Some(OpenPosition {
+            side,
+            entry_price,
+            stop: self.last_stops.map(|(long, short)| match side {
+                PositionSide::Long => long,
+                PositionSide::Short => short,
+            }),
+        });
+    }
+
+    /// Closes the current position, e.g. on an exit signal of a strategy.
+    pub fn close(&mut self) {
+        self.position = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+
+    pub fn side(&self) -> Option<PositionSide> {
+        self.position.map(|position| position.side)
+    }
+
+    pub fn entry_price(&self) -> Option<f64> {
+        self.position.map(|position| position.entry_price)
+    }
+
+    /// Returns the current stop of the open position.
+    pub fn stop(&self) -> Option<f64> {
+        self.position.and_then(|position| position.stop)
+    }
+}
+
+impl<S: TrailingStop, T: High + Low + Close> Next<&T> for TrailingStopManager<S> {
+    type Output = Option<StopEvent>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (long, short) = self.trailing_stop.next_stops(input);
+        self.last_stops = Some((long, short));
+
+        let position = self.position.as_mut()?;
+
+        if let Some(stop) = position.stop {
+            let is_hit = match position.side {
+                PositionSide::Long => input.low() <= stop,
+                PositionSide::Short => input.high() >= stop,
+            };
+            if is_hit {
+                let event = StopEvent {
+                    side: position.side,
+                    entry_price: position.entry_price,
+                    stop_price: stop,
+                };
+                self.position = None;
+                return Some(event);
+            }
+        }
+
+        position.stop = Some(match (position.side, position.stop) {
+            (PositionSide::Long, Some(stop)) => stop.max(long),
+            (PositionSide::Short, Some(stop)) => stop.min(short),
+            (PositionSide::Long, None) => long,
+            (PositionSide::Short, None) => short,
+        });
+        None
+    }
+}
+
+impl<S: TrailingStop> Reset for TrailingStopManager<S> {
+    fn reset(&mut self) {
+        self.trailing_stop.reset();
+        self.last_stops = None;
+        self.position = None;
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for TrailingStopManager<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRAILING_STOP({})", self.trailing_stop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ChandelierExit;
+    use crate::test_helper::*;
+
+    // Returns the given stop levels, one pair per bar
+    struct Levels {
+        levels: Vec<(f64, f64)>,
+        index: usize,
+    }
+
+    impl Levels {
+        fn new(levels: &[(f64, f64)]) -> Self {
+            Self {
+                levels: levels.to_vec(),
+                index: 0,
+            }
+        }
+    }
+
+    impl Reset for Levels {
+        fn reset(&mut self) {
+            self.index = 0;
+        }
+    }
+
+    impl TrailingStop for Levels {
+        fn next_stops<T: High + Low + Close>(&mut self, _bar: &T) -> (f64, f64) {
+            self.index += 1;
+            self.levels[self.index - 1]
+        }
+    }
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_next_long() {
+        let levels = Levels::new(&[(8.0, 12.0), (9.0, 13.0), (8.5, 13.0), (9.5, 13.0)]);
+        let mut manager = TrailingStopManager::new(levels);
+
+        assert_eq!(manager.next(&bar(11.0, 9.0, 10.0)), None);
+        manager.open(PositionSide::Long, 10.0);
+        assert_eq!(manager.side(), Some(PositionSide::Long));
+        assert_eq!(manager.entry_price(), Some(10.0));
+        assert_eq!(manager.stop(), Some(8.0));
+
+        assert_eq!(manager.next(&bar(12.0, 10.0, 11.0)), None);
+        assert_eq!(manager.stop(), Some(9.0));
+
+        // The stop never moves down
+        assert_eq!(manager.next(&bar(12.0, 10.5, 11.5)), None);
+        assert_eq!(manager.stop(), Some(9.0));
+
+        assert_eq!(
+            manager.next(&bar(11.0, 8.8, 9.0)),
+            Some(StopEvent {
+                side: PositionSide::Long,
+                entry_price: 10.0,
+                stop_price: 9.0
+            })
+        );
+        assert!(!manager.is_open());
+        assert_eq!(manager.stop(), None);
+    }
+
+    #[test]
+    fn test_next_short() {
+        let levels = Levels::new(&[(8.0, 12.0), (8.0, 11.0), (8.0, 11.5), (8.0, 10.0)]);
+        let mut manager = TrailingStopManager::new(levels);
+
+        manager.next(&bar(11.0, 9.0, 10.0));
+        manager.open(PositionSide::Short, 10.0);
+        assert_eq!(manager.stop(), Some(12.0));
+
+        assert_eq!(manager.next(&bar(11.5, 9.0, 10.0)), None);
+        assert_eq!(manager.stop(), Some(11.0));
+
+        // The stop never moves up
+        assert_eq!(manager.next(&bar(10.5, 9.0, 10.0)), None);
+        assert_eq!(manager.stop(), Some(11.0));
+
+        let event = manager.next(&bar(11.2, 10.0, 11.0)).unwrap();
+        assert_eq!(event.side, PositionSide::Short);
+        assert_eq!(event.stop_price, 11.0);
+    }
+
+    #[test]
+    fn test_open_before_first_bar() {
+        let levels = Levels::new(&[(8.0, 12.0), (9.0, 11.0)]);
+        let mut manager = TrailingStopManager::new(levels);
+
+        manager.open(PositionSide::Long, 10.0);
+        assert_eq!(manager.stop(), None);
+
+        // Without a stop, the first bar cannot hit it
+        assert_eq!(manager.next(&bar(11.0, 7.0, 10.0)), None);
+        assert_eq!(manager.stop(), Some(8.0));
+    }
+
+    #[test]
+    fn test_close() {
+        let levels = Levels::new(&[(8.0, 12.0), (9.0, 11.0)]);
+        let mut manager = TrailingStopManager::new(levels);
+
+        manager.next(&bar(11.0, 9.0, 10.0));
+        manager.open(PositionSide::Long, 10.0);
+        manager.close();
+
+        assert!(!manager.is_open());
+        assert_eq!(manager.next(&bar(11.0, 7.0, 8.0)), None);
+    }
+
+    #[test]
+    fn test_next_with_chandelier_exit() {
+        let mut manager = TrailingStopManager::new(ChandelierExit::new(3, 1.0).unwrap());
+
+        manager.next(&bar(22.0, 20.0, 21.0));
+        manager.open(PositionSide::Long, 21.0);
+        assert_eq!(manager.stop(), Some(20.0));
+
+        assert_eq!(manager.next(&bar(24.0, 22.0, 23.0)), None);
+        assert_eq!(manager.stop(), Some(21.5));
+
+        let event = manager.next(&bar(22.0, 20.5, 21.0)).unwrap();
+        assert_eq!(event.stop_price, 21.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let levels = Levels::new(&[(8.0, 12.0), (9.0, 11.0)]);
+        let mut manager = TrailingStopManager::new(levels);
+
+        manager.next(&bar(11.0, 9.0, 10.0));
+        manager.open(PositionSide::Long, 10.0);
+
+        manager.reset();
+
+        assert!(!manager.is_open());
+        manager.open(PositionSide::Long, 10.0);
+        assert_eq!(manager.stop(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let manager = TrailingStopManager::new(ChandelierExit::default());
+        assert_eq!(format!("{}", manager), "TRAILING_STOP(CE(22, 3))");
+    }
+}
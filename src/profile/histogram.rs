@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of levels a bar can span, beyond which it is ignored, so that tiny tick
+/// sizes or extreme prices cannot exhaust the memory.
+pub(super) const MAX_LEVELS: i64 = 100_000;
+
+/// Returns the levels of the low and the high of a bar, or `None` when a price is not finite
+/// or the bar spans more than `MAX_LEVELS` levels.
+pub(super) fn levels(low: f64, high: f64, tick_size: f64) -> Option<(i64, i64)> {
+    if !(low.is_finite() && high.is_finite()) {
+        return None;
+    }
+    let low = (low / tick_size).round() as i64;
+    let high = ((high / tick_size).round() as i64).max(low);
+    fits(low, high).then_some((low, high))
+}
+
+/// Returns true if `low..=high` spans at most `MAX_LEVELS` levels.
+pub(super) fn fits(low: i64, high: i64) -> bool {
+    high.checked_sub(low)
+        .is_some_and(|span| (0..MAX_LEVELS).contains(&span))
+}
+
+/// Amounts accumulated at price levels, where a level is a price divided by the tick size.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub(super) struct Histogram {
+    // Level -> (amount, number of contributions)
+    levels: BTreeMap<i64, (f64, usize)>,
+    total: f64,
+}
+
+impl Histogram {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn total(&self) -> f64 {
+        self.total
+    }
+
+    /// Adds the amount to every level in `low..=high`.
+    pub(super) fn add(&mut self, low: i64, high: i64, amount: f64) {
+        for level in low..=high {
+            let entry = self.levels.entry(level).or_insert((0.0, 0));
+            entry.0 += amount;
+            entry.1 += 1;
+        }
+        self.total += amount * (high - low + 1) as f64;
+    }
+
+    /// Reverts a previous `add` with the same arguments.
+    pub(super) fn remove(&mut self, low: i64, high: i64, amount: f64) {
+        for level in low..=high {
+            if let Some(entry) = self.levels.get_mut(&level) {
+                entry.0 -= amount;
+                entry.1 -= 1;
+                if entry.1 == 0 {
+                    self.levels.remove(&level);
+                }
+            }
+        }
+        self.total -= amount * (high - low + 1) as f64;
+        if self.levels.is_empty() {
+            self.total = 0.0;
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.levels.clear();
+        self.total = 0.0;
+    }
+
+    /// Returns (level, amount) pairs in ascending order of levels.
+    pub(super) fn iter(&self) -> impl Iterator<Item = (i64, f64)> + '_ {
+        self.levels
+            .iter()
+            .map(|(&level, &(amount, _))| (level, amount))
+    }
+
+    /// Returns the level with the largest amount (the lowest one on ties), or `None` when empty.
+    pub(super) fn point_of_control(&self) -> Option<i64> {
+        let mut poc: Option<(i64, f64)> = None;
+        for (level, amount) in self.iter() {
+            if poc.is_none_or(|(_, max)| amount > max) {
+                poc = Some((level, amount));
+            }
+        }
+        poc.map(|(level, _)| level)
+    }
+
+    /// Returns the (low, high) levels of the value area, which contains at least the given
+    /// percentage of the total amount.
+    ///
+    /// Starting at the point of control, the area is extended one level at a time to the
+    /// adjacent level with the larger amount, preferring the upper level on ties.
+    pub(super) fn value_area(&self, percentage: f64) -> Option<(i64, i64)> {
+        let poc = self.point_of_control()?;
+        let first = *self.levels.keys().next()?;
+        let last = *self.levels.keys().next_back()?;
+
+        let amount = |level: i64| self.levels.get(&level).map_or(0.0, |&(amount, _)| amount);
+        let target = self.total * percentage / 100.0;
+
+        let (mut low, mut high) = (poc, poc);
+        let mut sum = amount(poc);
+        while sum < target && (low > first || high < last) {
+            let above = if high < last { amount(high + 1) } else { -1.0 };
+            let below = if low > first { amount(low - 1) } else { -1.0 };
+            if above >= below {
+                high += 1;
+                sum += above;
+            } else {
+                low -= 1;
+                sum += below;
+            }
+        }
+
+        Some((low, high))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(levels(9.9, 10.6, 0.5), Some((20, 21)));
+        assert_eq!(levels(10.0, 9.0, 1.0), Some((10, 10)));
+        assert_eq!(levels(f64::NAN, 10.0, 1.0), None);
+        assert_eq!(levels(10.0, f64::INFINITY, 1.0), None);
+        assert_eq!(levels(0.0, 1.0, 1e-9), None);
+        assert_eq!(levels(-1e300, 1e300, 1.0), None);
+
+        assert!(fits(0, MAX_LEVELS - 1));
+        assert!(!fits(0, MAX_LEVELS));
+        assert!(!fits(i64::MIN, i64::MAX));
+    }
+
+    #[test]
+    fn test_add_remove() {
+        let mut histogram = Histogram::new();
+        assert_eq!(histogram.point_of_control(), None);
+        assert_eq!(histogram.value_area(70.0), None);
+
+        histogram.add(10, 12, 10.0);
+        histogram.add(11, 11, 20.0);
+        assert_eq!(histogram.total(), 50.0);
+        assert_eq!(
+            histogram.iter().collect::<Vec<_>>(),
+            vec![(10, 10.0), (11, 30.0), (12, 10.0)]
+        );
+
+        histogram.remove(10, 12, 10.0);
+        assert_eq!(histogram.iter().collect::<Vec<_>>(), vec![(11, 20.0)]);
+
+        histogram.remove(11, 11, 20.0);
+        assert_eq!(histogram.iter().count(), 0);
+        assert_eq!(histogram.total(), 0.0);
+    }
+
+    #[test]
+    fn test_value_area() {
+        let mut histogram = Histogram::new();
+
+        histogram.add(10, 10, 10.0);
+        histogram.add(11, 11, 30.0);
+        histogram.add(12, 12, 15.0);
+        histogram.add(13, 13, 5.0);
+        // Gap at 14
+        histogram.add(15, 15, 40.0);
+
+        assert_eq!(histogram.point_of_control(), Some(15));
+        assert_eq!(histogram.value_area(40.0), Some((15, 15)));
+        // 40 + 0 + 5 + 15
+        assert_eq!(histogram.value_area(60.0), Some((12, 15)));
+        assert_eq!(histogram.value_area(100.0), Some((10, 15)));
+    }
+
+    #[test]
+    fn test_value_area_ties() {
+        let mut histogram = Histogram::new();
+
+        histogram.add(10, 12, 10.0);
+
+        assert_eq!(histogram.point_of_control(), Some(10));
+        assert_eq!(histogram.value_area(50.0), Some((10, 11)));
+    }
+}
@@ -0,0 +1,444 @@
+use std::fmt;
+
+use crate::bars::Timeframe;
+use crate::errors::{Result, TaError};
+use crate::profile::histogram::{self, Histogram};
+#[cfg(feature = "chrono")]
+use crate::Timestamp;
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Market profile (MP), also known as time price opportunity (TPO) chart.
+///
+/// Counts the time spent at price levels, which are multiples of the _tick_size_, within
+/// every session. The session is divided into TPO periods, and every price level between the
+/// low and high of a period gets one TPO.
+///
+/// Besides the histogram of the TPO counts, the profile identifies:
+///
+/// * point of control (POC) - price level with the most TPOs (the lowest one on ties)
+/// * value area - range of prices around the POC containing the _value_area_ percentage of the
+///   TPOs, built the same way as for the [volume profile](struct.VolumeProfile.html)
+/// * initial balance - range of prices of the first _initial_balance_ TPO periods of the session
+///
+/// The inputs are `(timestamp, bar)` pairs, where timestamps are Unix milliseconds.
+/// The profile starts over with the first bar of every new session. Bars with a NaN or
+/// infinite price, or extending the TPO period to more than 100 000 levels, are ignored.
+///
+/// With the `chrono` feature, bars with a [Timestamp](../trait.Timestamp.html) can be given
+/// directly. Bars without a timestamp are then added to the TPO period in progress, or
+/// ignored when there is none, with NaN outputs.
+///
+/// # Parameters
+///
+/// * _tick_size_ - size of a price level (number greater than 0). Default is 1.0.
+/// * _tpo_period_ - timeframe of the TPO periods. Default is 30 minutes.
+/// * _session_ - timeframe of the sessions. Default is 1 day.
+/// * _initial_balance_ - number of TPO periods in the initial balance (integer greater than 0). Default is 2.
+/// * _value_area_ - percentage of the TPOs in the value area (number in range (0, 100]). Default is 70.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::bars::Timeframe;
+/// use ta::profile::MarketProfile;
+/// use ta::{DataItem, Next};
+///
+/// let mut mp = MarketProfile::new(1.0, Timeframe::Minutes(30), Timeframe::Days(1), 1, 70.0).unwrap();
+///
+/// let bar = |high, low| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(1.0).build().unwrap()
+/// };
+///
+/// mp.next((0, &bar(13.0, 10.0)));
+/// let out = mp.next((30 * 60_000, &bar(12.0, 11.0)));
+///
+/// assert_eq!(out.poc, 11.0);
+/// assert_eq!(out.initial_balance_high, 13.0);
+/// assert_eq!(out.initial_balance_low, 10.0);
+/// assert_eq!(mp.histogram(), vec![(10.0, 1), (11.0, 2), (12.0, 2), (13.0, 1)]);
+/// ```
+///
+/// # Links
+///
+/// * [Market Profile, Wikipedia](https://en.wikipedia.org/wiki/Market_profile)
+///
+#[doc(alias = "MP")]
+#[doc(alias = "TPO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MarketProfile {
+    tick_size: f64,
+    tpo_period: Timeframe,
+    session: Timeframe,
+    initial_balance: usize,
+    value_area: f64,
+    session_start: Option<i64>,
+    period_start: Option<i64>,
+    // Levels of the current TPO period
+    period_low: i64,
+    period_high: i64,
+    periods: usize,
+    initial_balance_high: f64,
+    initial_balance_low: f64,
+    histogram: Histogram,
+}
+
+impl MarketProfile {
+    pub fn new(
+        tick_size: f64,
+        tpo_period: Timeframe,
+        session: Timeframe,
+        initial_balance: usize,
+        value_area: f64,
+    ) -> Result<Self> {
+        let is_valid = tick_size.is_finite()
+            && tick_size > 0.0
+            && tpo_period.is_valid()
+            && session.is_valid()
+            && initial_balance > 0
+            && value_area > 0.0
+            && value_area <= 100.0;
+        if !is_valid {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            tick_size,
+            tpo_period,
+            session,
+            initial_balance,
+            value_area,
+            session_start: None,
+            period_start: None,
+            period_low: 0,
+            period_high: 0,
+            periods: 0,
+            initial_balance_high: f64::NAN,
+            initial_balance_low: f64::NAN,
+            histogram: Histogram::new(),
+        })
+    }
+
+    pub fn tick_size(&self) -> f64 {
+        self.tick_size
+    }
+
+    pub fn tpo_period(&self) -> Timeframe {
+        self.tpo_period
+    }
+
+    pub fn session(&self) -> Timeframe {
+        self.session
+    }
+
+    /// Returns (price, TPO count) pairs of the profile in ascending order of prices.
+    pub fn histogram(&self) -> Vec<(f64, usize)> {
+        self.histogram
+            .iter()
+            .map(|(level, count)| (self.price(level), count.round() as usize))
+            .collect()
+    }
+
+    fn price(&self, level: i64) -> f64 {
+        level as f64 * self.tick_size
+    }
+
+    fn output(&self) -> MarketProfileOutput {
+        let (poc, value_area_high, value_area_low) = match (
+            self.histogram.point_of_control(),
+            self.histogram.value_area(self.value_area),
+        ) {
+            (Some(poc), Some((low, high))) => (self.price(poc), self.price(high), self.price(low)),
+            _ => (f64::NAN, f64::NAN, f64::NAN),
+        };
+
+        MarketProfileOutput {
+            poc,
+            value_area_high,
+            value_area_low,
+            initial_balance_high: self.initial_balance_high,
+            initial_balance_low: self.initial_balance_low,
+        }
+    }
+
+    fn start_session(&mut self) {
+        self.period_start = None;
+        self.periods = 0;
+        self.initial_balance_high = f64::NAN;
+        self.initial_balance_low = f64::NAN;
+        self.histogram.clear();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketProfileOutput {
+    pub poc: f64,
+    pub value_area_high: f64,
+    pub value_area_low: f64,
+    pub initial_balance_high: f64,
+    pub initial_balance_low: f64,
+}
+
+impl From<MarketProfileOutput> for (f64, f64, f64, f64, f64) {
+    fn from(mp: MarketProfileOutput) -> Self {
+        (
+            mp.poc,
+            mp.value_area_high,
+            mp.value_area_low,
+            mp.initial_balance_high,
+            mp.initial_balance_low,
+        )
+    }
+}
+
+impl<T: High + Low> Next<(i64, &T)> for MarketProfile {
+    type Output = MarketProfileOutput;
+
+    fn next(&mut self, (timestamp, input): (i64, &T)) -> Self::Output {
+        let (low, high) = match histogram::levels(input.low(), input.high(), self.tick_size) {
+            Some(levels) => levels,
+            None => return self.output(),
+        };
+
+        let session_start = self.session.saturating_start_of(timestamp);
+        match self.session_start {
+            Some(current) if session_start > current => {
+                self.start_session();
+                self.session_start = Some(session_start);
+            }
+            None => self.session_start = Some(session_start),
+            _ => {}
+        }
+
+        let period_start = self.tpo_period.saturating_start_of(timestamp);
+        let in_period = self
+            .period_start
+            .is_some_and(|current| period_start <= current);
+        if in_period && !histogram::fits(low.min(self.period_low), high.max(self.period_high)) {
+            return self.output();
+        }
+
+        if in_period {
+            // Only the levels the current period has not reached yet get a TPO
+            if low < self.period_low {
+                self.histogram.add(low, self.period_low - 1, 1.0);
+                self.period_low = low;
+            }
+            if high > self.period_high {
+                self.histogram.add(self.period_high + 1, high, 1.0);
+                self.period_high = high;
+            }
+        } else {
+            self.period_start = Some(period_start);
+            self.period_low = low;
+            self.period_high = high;
+            self.periods += 1;
+            self.histogram.add(low, high, 1.0);
+        }
+
+        if self.periods <= self.initial_balance {
+            self.initial_balance_high = self.initial_balance_high.max(input.high());
+            self.initial_balance_low = self.initial_balance_low.min(input.low());
+        }
+
+        self.output()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: High + Low + Timestamp> Next<&T> for MarketProfile {
+    type Output = MarketProfileOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let timestamp = input
+            .timestamp()
+            .map(|timestamp| timestamp.timestamp_millis())
+            .or(self.period_start);
+        match timestamp {
+            Some(timestamp) => self.next((timestamp, input)),
+            None => MarketProfileOutput {
+                poc: f64::NAN,
+                value_area_high: f64::NAN,
+                value_area_low: f64::NAN,
+                initial_balance_high: f64::NAN,
+                initial_balance_low: f64::NAN,
+            },
+        }
+    }
+}
+
+impl Reset for MarketProfile {
+    fn reset(&mut self) {
+        self.session_start = None;
+        self.start_session();
+    }
+}
+
+impl Default for MarketProfile {
+    fn default() -> Self {
+        Self::new(1.0, Timeframe::Minutes(30), Timeframe::Days(1), 2, 70.0).unwrap()
+    }
+}
+
+impl fmt::Display for MarketProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MP({}, {}, {}, {}, {})",
+            self.tick_size, self.tpo_period, self.session, self.initial_balance, self.value_area
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    const MINUTE: i64 = 60_000;
+    const DAY: i64 = 24 * 60 * MINUTE;
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    fn tuple(out: MarketProfileOutput) -> (f64, f64, f64, f64, f64) {
+        out.into()
+    }
+
+    fn market_profile() -> MarketProfile {
+        MarketProfile::new(1.0, Timeframe::Minutes(30), Timeframe::Days(1), 1, 70.0).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let m30 = Timeframe::Minutes(30);
+        let d1 = Timeframe::Days(1);
+
+        assert!(MarketProfile::new(0.0, m30, d1, 2, 70.0).is_err());
+        assert!(MarketProfile::new(1.0, Timeframe::Minutes(0), d1, 2, 70.0).is_err());
+        assert!(MarketProfile::new(1.0, m30, Timeframe::Days(0), 2, 70.0).is_err());
+        assert!(MarketProfile::new(1.0, m30, d1, 0, 70.0).is_err());
+        assert!(MarketProfile::new(1.0, m30, d1, 2, 0.0).is_err());
+        assert!(MarketProfile::new(1.0, m30, d1, 2, 100.5).is_err());
+        assert!(MarketProfile::new(f64::NAN, m30, d1, 2, 70.0).is_err());
+        assert!(MarketProfile::new(f64::INFINITY, m30, d1, 2, 70.0).is_err());
+        assert!(MarketProfile::new(1.0, m30, d1, 2, f64::NAN).is_err());
+        assert!(MarketProfile::new(1.0, m30, d1, 2, f64::INFINITY).is_err());
+        assert!(MarketProfile::new(0.25, m30, d1, 1, 100.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mp = market_profile();
+
+        assert_eq!(
+            tuple(mp.next((0, &bar(12.0, 10.0)))),
+            (10.0, 12.0, 10.0, 12.0, 10.0)
+        );
+        // Extends the first period
+        assert_eq!(
+            tuple(mp.next((10 * MINUTE, &bar(13.0, 11.0)))),
+            (10.0, 12.0, 10.0, 13.0, 10.0)
+        );
+        assert_eq!(
+            mp.histogram(),
+            vec![(10.0, 1), (11.0, 1), (12.0, 1), (13.0, 1)]
+        );
+
+        // Second period, outside of the initial balance
+        assert_eq!(
+            tuple(mp.next((30 * MINUTE, &bar(12.0, 11.0)))),
+            (11.0, 13.0, 11.0, 13.0, 10.0)
+        );
+        assert_eq!(
+            tuple(mp.next((40 * MINUTE, &bar(11.0, 9.0)))),
+            (10.0, 12.0, 10.0, 13.0, 10.0)
+        );
+        assert_eq!(
+            mp.histogram(),
+            vec![(9.0, 1), (10.0, 2), (11.0, 2), (12.0, 2), (13.0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_next_ignored_bars() {
+        let mut mp = market_profile();
+
+        mp.next((0, &bar(12.0, 10.0)));
+        let out = (10.0, 12.0, 10.0, 12.0, 10.0);
+        assert_eq!(tuple(mp.next((MINUTE, &bar(f64::NAN, 10.0)))), out);
+        assert_eq!(tuple(mp.next((2 * MINUTE, &bar(f64::INFINITY, 10.0)))), out);
+        // Would extend the period over too many levels
+        assert_eq!(tuple(mp.next((3 * MINUTE, &bar(1e6, 1e6)))), out);
+        assert_eq!(mp.histogram(), vec![(10.0, 1), (11.0, 1), (12.0, 1)]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_with_timestamps() {
+        let mut mp = market_profile();
+
+        // Ignored without a period in progress
+        assert!(mp.next(&timed_item(None, 12.0, 10.0, 1.0)).poc.is_nan());
+        assert_eq!(mp.histogram(), vec![]);
+
+        assert_eq!(
+            tuple(mp.next(&timed_item(Some(0), 12.0, 10.0, 1.0))),
+            (10.0, 12.0, 10.0, 12.0, 10.0)
+        );
+        // Extends the first period
+        assert_eq!(
+            tuple(mp.next(&timed_item(None, 13.0, 11.0, 1.0))),
+            (10.0, 12.0, 10.0, 13.0, 10.0)
+        );
+        assert_eq!(
+            mp.histogram(),
+            vec![(10.0, 1), (11.0, 1), (12.0, 1), (13.0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_next_new_session() {
+        let mut mp = market_profile();
+
+        mp.next((DAY - 30 * MINUTE, &bar(12.0, 10.0)));
+        mp.next((DAY - MINUTE, &bar(14.0, 13.0)));
+
+        assert_eq!(
+            tuple(mp.next((DAY, &bar(20.0, 20.0)))),
+            (20.0, 20.0, 20.0, 20.0, 20.0)
+        );
+        assert_eq!(mp.histogram(), vec![(20.0, 1)]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mp = market_profile();
+
+        mp.next((0, &bar(12.0, 10.0)));
+        mp.next((30 * MINUTE, &bar(12.0, 11.0)));
+
+        mp.reset();
+
+        assert_eq!(mp.histogram(), vec![]);
+        assert_eq!(
+            tuple(mp.next((0, &bar(5.0, 5.0)))),
+            (5.0, 5.0, 5.0, 5.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        MarketProfile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mp = MarketProfile::default();
+        assert_eq!(format!("{}", mp), "MP(1, 30m, 1d, 2, 70)");
+    }
+}
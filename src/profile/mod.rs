@@ -0,0 +1,16 @@
+//! Profiles, which distribute market activity over price levels.
+//!
+//! Profiles implement [Next<T>](../trait.Next.html) and [Reset](../trait.Reset.html)
+//! like indicators. Besides their output for every bar, the full histogram can be retrieved
+//! at any time.
+
+mod histogram;
+
+mod profile_window;
+pub use self::profile_window::ProfileWindow;
+
+mod volume_profile;
+pub use self::volume_profile::{VolumeProfile, VolumeProfileOutput};
+
+mod market_profile;
+pub use self::market_profile::{MarketProfile, MarketProfileOutput};
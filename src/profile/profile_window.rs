@@ -0,0 +1,61 @@
+use std::fmt;
+
+use crate::bars::Timeframe;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Range of bars a profile is built from.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileWindow {
+    /// All bars given since the profile was created or reset.
+    All,
+    /// The given number of the most recent bars.
+    Bars(usize),
+    /// Bars of the current session, e.g. of the current UTC day for `Timeframe::Days(1)`.
+    Session(Timeframe),
+}
+
+impl ProfileWindow {
+    pub(super) fn is_valid(self) -> bool {
+        match self {
+            ProfileWindow::All => true,
+            ProfileWindow::Bars(n) => n > 0,
+            ProfileWindow::Session(timeframe) => timeframe.is_valid(),
+        }
+    }
+}
+
+impl fmt::Display for ProfileWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProfileWindow::All => write!(f, "ALL"),
+            ProfileWindow::Bars(n) => write!(f, "{}", n),
+            ProfileWindow::Session(timeframe) => write!(f, "{}", timeframe),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid() {
+        assert!(ProfileWindow::All.is_valid());
+        assert!(ProfileWindow::Bars(1).is_valid());
+        assert!(!ProfileWindow::Bars(0).is_valid());
+        assert!(ProfileWindow::Session(Timeframe::Days(1)).is_valid());
+        assert!(!ProfileWindow::Session(Timeframe::Days(0)).is_valid());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", ProfileWindow::All), "ALL");
+        assert_eq!(format!("{}", ProfileWindow::Bars(20)), "20");
+        assert_eq!(
+            format!("{}", ProfileWindow::Session(Timeframe::Days(1))),
+            "1d"
+        );
+    }
+}
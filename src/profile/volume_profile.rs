@@ -0,0 +1,411 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::bars::Timeframe;
+use crate::errors::{Result, TaError};
+use crate::profile::histogram::{self, Histogram};
+use crate::profile::ProfileWindow;
+#[cfg(feature = "chrono")]
+use crate::Timestamp;
+use crate::{High, Low, Next, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume profile (VP).
+///
+/// Distributes the traded volume over price levels, which are multiples of the _tick_size_.
+/// The volume of every bar is spread evenly over the levels between its low and high. Bars
+/// with a NaN or infinite price or spanning more than 100 000 levels are ignored.
+///
+/// Besides the histogram, the profile identifies:
+///
+/// * point of control (POC) - price level with the largest volume (the lowest one on ties)
+/// * value area - range of prices around the POC containing the _value_area_ percentage of the
+///   total volume. It is built by extending the range from the POC one level at a time towards
+///   the adjacent level with the larger volume, preferring the upper level on ties.
+///
+/// The inputs are `(timestamp, bar)` pairs, where timestamps are Unix milliseconds.
+/// They are only used when the profile is built over a
+/// [session](enum.ProfileWindow.html#variant.Session).
+///
+/// With the `chrono` feature, bars with a [Timestamp](../trait.Timestamp.html) can be given
+/// directly. Over a session, bars without a timestamp are then added to the session in
+/// progress, or ignored when there is none. Bars older than the session in progress are
+/// ignored as well.
+///
+/// # Parameters
+///
+/// * _tick_size_ - size of a price level (number greater than 0). Default is 1.0.
+/// * _value_area_ - percentage of the volume in the value area (number in range (0, 100]). Default is 70.0.
+/// * _window_ - range of bars the profile is built from. Default is `ProfileWindow::Session(Timeframe::Days(1))`.
+///
+/// # Example
+///
+/// ```
+/// use ta::profile::{ProfileWindow, VolumeProfile};
+/// use ta::{DataItem, Next};
+///
+/// let mut vp = VolumeProfile::new(1.0, 70.0, ProfileWindow::All).unwrap();
+///
+/// let bar = |high, low, volume| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(volume).build().unwrap()
+/// };
+///
+/// vp.next((0, &bar(12.0, 10.0, 30.0)));
+/// vp.next((60_000, &bar(11.0, 11.0, 20.0)));
+/// let out = vp.next((120_000, &bar(13.0, 12.0, 10.0)));
+///
+/// assert_eq!(out.poc, 11.0);
+/// assert_eq!(out.value_area_low, 11.0);
+/// assert_eq!(out.value_area_high, 12.0);
+/// assert_eq!(vp.histogram(), vec![(10.0, 10.0), (11.0, 30.0), (12.0, 15.0), (13.0, 5.0)]);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Profile, TradingView](https://www.tradingview.com/support/solutions/43000502040-volume-profile/)
+///
+#[doc(alias = "VP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeProfile {
+    tick_size: f64,
+    value_area: f64,
+    window: ProfileWindow,
+    session_start: Option<i64>,
+    // (low level, high level, volume per level) of the bars in the window
+    bars: VecDeque<(i64, i64, f64)>,
+    histogram: Histogram,
+}
+
+impl VolumeProfile {
+    pub fn new(tick_size: f64, value_area: f64, window: ProfileWindow) -> Result<Self> {
+        let is_valid = tick_size.is_finite()
+            && tick_size > 0.0
+            && value_area > 0.0
+            && value_area <= 100.0
+            && window.is_valid();
+        if !is_valid {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            tick_size,
+            value_area,
+            window,
+            session_start: None,
+            bars: VecDeque::new(),
+            histogram: Histogram::new(),
+        })
+    }
+
+    pub fn tick_size(&self) -> f64 {
+        self.tick_size
+    }
+
+    pub fn window(&self) -> ProfileWindow {
+        self.window
+    }
+
+    /// Returns the total volume of the profile.
+    pub fn total_volume(&self) -> f64 {
+        self.histogram.total()
+    }
+
+    /// Returns (price, volume) pairs of the profile in ascending order of prices.
+    pub fn histogram(&self) -> Vec<(f64, f64)> {
+        self.histogram
+            .iter()
+            .map(|(level, volume)| (self.price(level), volume))
+            .collect()
+    }
+
+    fn price(&self, level: i64) -> f64 {
+        level as f64 * self.tick_size
+    }
+
+    fn output(&self) -> VolumeProfileOutput {
+        match (
+            self.histogram.point_of_control(),
+            self.histogram.value_area(self.value_area),
+        ) {
+            (Some(poc), Some((low, high))) => VolumeProfileOutput {
+                poc: self.price(poc),
+                value_area_high: self.price(high),
+                value_area_low: self.price(low),
+            },
+            _ => VolumeProfileOutput {
+                poc: f64::NAN,
+                value_area_high: f64::NAN,
+                value_area_low: f64::NAN,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeProfileOutput {
+    pub poc: f64,
+    pub value_area_high: f64,
+    pub value_area_low: f64,
+}
+
+impl From<VolumeProfileOutput> for (f64, f64, f64) {
+    fn from(vp: VolumeProfileOutput) -> Self {
+        (vp.poc, vp.value_area_high, vp.value_area_low)
+    }
+}
+
+impl<T: High + Low + Volume> Next<(i64, &T)> for VolumeProfile {
+    type Output = VolumeProfileOutput;
+
+    fn next(&mut self, (timestamp, input): (i64, &T)) -> Self::Output {
+        let (low, high) = match histogram::levels(input.low(), input.high(), self.tick_size) {
+            Some(levels) => levels,
+            None => return self.output(),
+        };
+
+        match self.window {
+            ProfileWindow::Session(session) => {
+                let start = session.saturating_start_of(timestamp);
+                match self.session_start {
+                    Some(current) if start < current => return self.output(),
+                    Some(current) if start > current => self.histogram.clear(),
+                    _ => {}
+                }
+                self.session_start = Some(start);
+            }
+            ProfileWindow::Bars(period) => {
+                if self.bars.len() == period {
+                    if let Some((low, high, volume)) = self.bars.pop_front() {
+                        self.histogram.remove(low, high, volume);
+                    }
+                }
+            }
+            ProfileWindow::All => {}
+        }
+
+        let volume = input.volume() / (high - low + 1) as f64;
+
+        self.histogram.add(low, high, volume);
+        if let ProfileWindow::Bars(_) = self.window {
+            self.bars.push_back((low, high, volume));
+        }
+
+        self.output()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: High + Low + Volume + Timestamp> Next<&T> for VolumeProfile {
+    type Output = VolumeProfileOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let timestamp = input
+            .timestamp()
+            .map(|timestamp| timestamp.timestamp_millis())
+            .or(self.session_start);
+        match (timestamp, self.window) {
+            (Some(timestamp), _) => self.next((timestamp, input)),
+            // The timestamps are only used over sessions
+            (None, ProfileWindow::Bars(_) | ProfileWindow::All) => self.next((0, input)),
+            (None, ProfileWindow::Session(_)) => self.output(),
+        }
+    }
+}
+
+impl Reset for VolumeProfile {
+    fn reset(&mut self) {
+        self.session_start = None;
+        self.bars.clear();
+        self.histogram.clear();
+    }
+}
+
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self::new(1.0, 70.0, ProfileWindow::Session(Timeframe::Days(1))).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "VP({}, {}, {})",
+            self.tick_size, self.value_area, self.window
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    const MINUTE: i64 = 60_000;
+    const DAY: i64 = 24 * 60 * MINUTE;
+
+    fn bar(high: f64, low: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).volume(volume)
+    }
+
+    fn tuple(out: VolumeProfileOutput) -> (f64, f64, f64) {
+        out.into()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeProfile::new(0.0, 70.0, ProfileWindow::All).is_err());
+        assert!(VolumeProfile::new(1.0, 0.0, ProfileWindow::All).is_err());
+        assert!(VolumeProfile::new(1.0, 100.1, ProfileWindow::All).is_err());
+        assert!(VolumeProfile::new(f64::NAN, 70.0, ProfileWindow::All).is_err());
+        assert!(VolumeProfile::new(f64::INFINITY, 70.0, ProfileWindow::All).is_err());
+        assert!(VolumeProfile::new(1.0, f64::NAN, ProfileWindow::All).is_err());
+        assert!(VolumeProfile::new(1.0, 70.0, ProfileWindow::Bars(0)).is_err());
+        assert!(VolumeProfile::new(1.0, 100.0, ProfileWindow::Bars(1)).is_ok());
+        assert!(VolumeProfile::new(0.25, 70.0, ProfileWindow::All).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vp = VolumeProfile::new(1.0, 70.0, ProfileWindow::All).unwrap();
+
+        assert_eq!(
+            tuple(vp.next((0, &bar(12.0, 10.0, 30.0)))),
+            (10.0, 12.0, 10.0)
+        );
+        assert_eq!(
+            tuple(vp.next((MINUTE, &bar(11.0, 11.0, 20.0)))),
+            (11.0, 12.0, 11.0)
+        );
+        assert_eq!(
+            tuple(vp.next((2 * MINUTE, &bar(13.0, 12.0, 10.0)))),
+            (11.0, 12.0, 11.0)
+        );
+        assert_eq!(vp.total_volume(), 60.0);
+        assert_eq!(
+            vp.histogram(),
+            vec![(10.0, 10.0), (11.0, 30.0), (12.0, 15.0), (13.0, 5.0)]
+        );
+    }
+
+    #[test]
+    fn test_next_tick_size() {
+        let mut vp = VolumeProfile::new(0.5, 70.0, ProfileWindow::All).unwrap();
+
+        vp.next((0, &bar(10.6, 9.9, 30.0)));
+
+        assert_eq!(vp.histogram(), vec![(10.0, 15.0), (10.5, 15.0)]);
+    }
+
+    #[test]
+    fn test_next_with_bars_window() {
+        let mut vp = VolumeProfile::new(1.0, 70.0, ProfileWindow::Bars(2)).unwrap();
+
+        vp.next((0, &bar(12.0, 10.0, 30.0)));
+        vp.next((MINUTE, &bar(11.0, 11.0, 20.0)));
+        assert_eq!(
+            tuple(vp.next((2 * MINUTE, &bar(13.0, 12.0, 10.0)))),
+            (11.0, 12.0, 11.0)
+        );
+        assert_eq!(vp.histogram(), vec![(11.0, 20.0), (12.0, 5.0), (13.0, 5.0)]);
+        assert_eq!(vp.total_volume(), 30.0);
+    }
+
+    #[test]
+    fn test_next_with_session() {
+        let mut vp =
+            VolumeProfile::new(1.0, 70.0, ProfileWindow::Session(Timeframe::Days(1))).unwrap();
+
+        vp.next((DAY - 2 * MINUTE, &bar(12.0, 10.0, 30.0)));
+        vp.next((DAY - MINUTE, &bar(11.0, 11.0, 20.0)));
+        assert_eq!(
+            tuple(vp.next((DAY, &bar(15.0, 15.0, 5.0)))),
+            (15.0, 15.0, 15.0)
+        );
+        assert_eq!(vp.histogram(), vec![(15.0, 5.0)]);
+    }
+
+    #[test]
+    fn test_next_with_session_out_of_order() {
+        let mut vp =
+            VolumeProfile::new(1.0, 70.0, ProfileWindow::Session(Timeframe::Days(1))).unwrap();
+
+        vp.next((DAY, &bar(15.0, 15.0, 5.0)));
+        // A late bar of the previous session is ignored
+        assert_eq!(
+            tuple(vp.next((DAY - MINUTE, &bar(11.0, 11.0, 20.0)))),
+            (15.0, 15.0, 15.0)
+        );
+        vp.next((DAY + MINUTE, &bar(16.0, 16.0, 10.0)));
+        assert_eq!(vp.histogram(), vec![(15.0, 5.0), (16.0, 10.0)]);
+    }
+
+    #[test]
+    fn test_next_ignored_bars() {
+        let mut vp = VolumeProfile::new(0.01, 70.0, ProfileWindow::Bars(2)).unwrap();
+
+        vp.next((0, &bar(10.0, 10.0, 5.0)));
+        vp.next((MINUTE, &bar(f64::NAN, 10.0, 5.0)));
+        vp.next((2 * MINUTE, &bar(f64::INFINITY, 10.0, 5.0)));
+        assert_eq!(
+            tuple(vp.next((3 * MINUTE, &bar(1e4, 1.0, 5.0)))),
+            (10.0, 10.0, 10.0)
+        );
+        assert_eq!(vp.histogram(), vec![(10.0, 5.0)]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_with_timestamps() {
+        let mut vp =
+            VolumeProfile::new(1.0, 70.0, ProfileWindow::Session(Timeframe::Days(1))).unwrap();
+
+        // Ignored without a session in progress
+        assert!(vp.next(&timed_item(None, 12.0, 10.0, 30.0)).poc.is_nan());
+
+        vp.next(&timed_item(Some(DAY - MINUTE), 12.0, 10.0, 30.0));
+        vp.next(&timed_item(None, 11.0, 11.0, 20.0));
+        assert_eq!(
+            vp.histogram(),
+            vec![(10.0, 10.0), (11.0, 30.0), (12.0, 10.0)]
+        );
+        assert_eq!(
+            tuple(vp.next(&timed_item(Some(DAY), 15.0, 15.0, 5.0))),
+            (15.0, 15.0, 15.0)
+        );
+
+        // The timestamps are not needed without sessions
+        let mut vp = VolumeProfile::new(1.0, 70.0, ProfileWindow::All).unwrap();
+        assert_eq!(
+            tuple(vp.next(&timed_item(None, 12.0, 12.0, 5.0))),
+            (12.0, 12.0, 12.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vp = VolumeProfile::new(1.0, 70.0, ProfileWindow::Bars(2)).unwrap();
+
+        vp.next((0, &bar(12.0, 10.0, 30.0)));
+        vp.next((MINUTE, &bar(11.0, 11.0, 20.0)));
+
+        vp.reset();
+
+        assert_eq!(vp.histogram(), vec![]);
+        assert_eq!(tuple(vp.next((0, &bar(5.0, 5.0, 1.0)))), (5.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeProfile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vp = VolumeProfile::new(0.5, 70.0, ProfileWindow::Bars(20)).unwrap();
+        assert_eq!(format!("{}", vp), "VP(0.5, 70, 20)");
+    }
+}
@@ -0,0 +1,249 @@
+//! [PyO3](https://pyo3.rs) bindings, so that Python code can compute the indicators of the
+//! [registry](../registry/index.html) over numpy arrays with the same code.
+//!
+//! Requires the `python` feature. The `python` directory of the repository builds the `ta`
+//! Python module with [maturin](https://www.maturin.rs), registering the classes with
+//! [register](fn.register.html):
+//!
+//! ```python
+//! import numpy as np
+//! import ta
+//!
+//! rsi = ta.Indicator("rsi", 14)
+//! rsi.next(101.5)                 # float
+//! values = rsi.compute(closes)    # numpy array
+//!
+//! bb = ta.Indicator.from_spec("bb(20, 2)")
+//! bb.fields                       # ["average", "upper", "lower"]
+//! bands = bb.compute(closes)      # numpy array of shape (len(closes), 3)
+//! ```
+
+use numpy::ndarray::{Array2, ArrayView1};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{DynIndicator, IndicatorRegistry, IndicatorSpec};
+use crate::{DataItem, Next, Reset};
+
+/// Adds the classes and functions of the bindings to the Python module.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Indicator>()?;
+    module.add_function(wrap_pyfunction!(indicators, module)?)?;
+    Ok(())
+}
+
+/// Returns the names of the indicators which can be created.
+#[pyfunction]
+fn indicators() -> Vec<String> {
+    IndicatorRegistry::new()
+        .names()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Indicator of the registry, created by name and parameters.
+///
+/// The outputs of an indicator with a single field are floats and 1-dimensional arrays, and
+/// those of an indicator with many fields are lists and 2-dimensional arrays with a column for
+/// every field. The indicators are bound to the thread which created them.
+#[pyclass(module = "ta", unsendable)]
+#[derive(Debug)]
+pub struct Indicator {
+    indicator: DynIndicator,
+}
+
+/// Output of a single bar.
+#[derive(IntoPyObject)]
+enum Output {
+    Value(f64),
+    Values(Vec<f64>),
+}
+
+#[pymethods]
+impl Indicator {
+    /// Creates the named indicator with the parameters, e.g. `Indicator("rsi", 14)`.
+    #[new]
+    #[pyo3(signature = (name, *params))]
+    fn py_new(name: &str, params: Vec<f64>) -> PyResult<Self> {
+        Self::create(&IndicatorSpec::new(name, &params)?)
+    }
+
+    /// Creates the indicator of the specification, e.g. `Indicator.from_spec("rsi(14)")`.
+    #[staticmethod]
+    fn from_spec(spec: &str) -> PyResult<Self> {
+        Self::create(&spec.parse()?)
+    }
+
+    /// Label of the indicator, e.g. `"RSI(14)"`.
+    #[getter]
+    fn label(&self) -> String {
+        self.indicator.to_string()
+    }
+
+    /// Names of the fields of the outputs.
+    #[getter]
+    fn fields(&self) -> Vec<&'static str> {
+        self.indicator.fields().to_vec()
+    }
+
+    /// Feeds a value, taken as every price of a bar without volume.
+    fn next(&mut self, value: f64) -> Output {
+        self.output(&value_bar(value))
+    }
+
+    /// Feeds a bar, which is not validated.
+    fn next_bar(&mut self, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Output {
+        self.output(&DataItem::unchecked(open, high, low, close, volume))
+    }
+
+    /// Feeds the values of the array.
+    fn compute<'py>(
+        &mut self,
+        py: Python<'py>,
+        values: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let values = values.as_array();
+        let outputs = self.outputs(values.iter().map(|&value| value_bar(value)));
+        self.to_array(py, outputs)
+    }
+
+    /// Feeds the bars of the arrays, which must have the same length.
+    fn compute_bars<'py>(
+        &mut self,
+        py: Python<'py>,
+        open: PyReadonlyArray1<'py, f64>,
+        high: PyReadonlyArray1<'py, f64>,
+        low: PyReadonlyArray1<'py, f64>,
+        close: PyReadonlyArray1<'py, f64>,
+        volume: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let outputs = self.bar_outputs([
+            open.as_array(),
+            high.as_array(),
+            low.as_array(),
+            close.as_array(),
+            volume.as_array(),
+        ])?;
+        self.to_array(py, outputs)
+    }
+
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Indicator({})", self.indicator)
+    }
+}
+
+impl Indicator {
+    fn create(spec: &IndicatorSpec) -> PyResult<Self> {
+        let indicator = IndicatorRegistry::new().create_from(spec)?;
+        Ok(Self { indicator })
+    }
+
+    fn output(&mut self, bar: &DataItem) -> Output {
+        match self.indicator.next(bar).as_slice() {
+            [value] => Output::Value(*value),
+            values => Output::Values(values.to_vec()),
+        }
+    }
+
+    // Outputs of the bars, a row of the fields after the other
+    fn outputs<B: IntoIterator<Item = DataItem>>(&mut self, bars: B) -> Vec<f64> {
+        let mut outputs = Vec::new();
+        for bar in bars {
+            outputs.extend(self.indicator.next(&bar));
+        }
+        outputs
+    }
+
+    fn bar_outputs(&mut self, arrays: [ArrayView1<f64>; 5]) -> Result<Vec<f64>> {
+        let len = arrays[3].len();
+        if arrays.iter().any(|array| array.len() != len) {
+            return Err(TaError::InvalidParameter);
+        }
+        let [open, high, low, close, volume] = arrays;
+        let bars =
+            (0..len).map(|i| DataItem::unchecked(open[i], high[i], low[i], close[i], volume[i]));
+        Ok(self.outputs(bars))
+    }
+
+    fn to_array<'py>(&self, py: Python<'py>, outputs: Vec<f64>) -> PyResult<Bound<'py, PyAny>> {
+        let fields = self.indicator.fields().len();
+        if fields == 1 {
+            return Ok(PyArray1::from_vec(py, outputs).into_any());
+        }
+        let shape = (outputs.len() / fields, fields);
+        let array = Array2::from_shape_vec(shape, outputs)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(array.into_pyarray(py).into_any())
+    }
+}
+
+impl From<TaError> for PyErr {
+    fn from(err: TaError) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+fn value_bar(value: f64) -> DataItem {
+    DataItem::unchecked(value, value, value, value, 0.0)
+}
+
+// The Python values need an interpreter with numpy, the tests cover the Rust side
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use numpy::ndarray::Array1;
+
+    fn indicator(spec: &str) -> Indicator {
+        Indicator::create(&spec.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        assert!(Indicator::py_new("rsi", vec![14.0]).is_ok());
+        assert!(Indicator::from_spec("rsi(14)").is_ok());
+
+        let bb = indicator("bb(20, 2)");
+        assert_eq!(bb.label(), "BB(20, 2)");
+        assert_eq!(bb.fields(), ["average", "upper", "lower"]);
+        assert_eq!(bb.__repr__(), "Indicator(BB(20, 2))");
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = indicator("sma(2)");
+        assert!(matches!(sma.next(4.0), Output::Value(value) if value == 4.0));
+        assert_eq!(sma.outputs([6.0, 8.0].map(value_bar)), [5.0, 7.0]);
+
+        sma.reset();
+        assert!(matches!(sma.next(6.0), Output::Value(value) if value == 6.0));
+
+        let mut macd = indicator("macd(3, 6, 4)");
+        assert!(matches!(macd.next(2.0), Output::Values(values) if values.len() == 3));
+    }
+
+    #[test]
+    fn test_bar_outputs() {
+        let mut atr = indicator("atr(3)");
+        let arrays = [
+            Array1::from(vec![10.0, 11.0]),
+            Array1::from(vec![11.0, 13.0]),
+            Array1::from(vec![9.0, 10.0]),
+            Array1::from(vec![10.0, 12.0]),
+            Array1::from(vec![0.0, 0.0]),
+        ];
+        let outputs = atr.bar_outputs(arrays.each_ref().map(|array| array.view()));
+        assert_eq!(outputs.unwrap(), [2.0, 2.5]);
+
+        let close = Array1::from(vec![10.0]);
+        let mut arrays = arrays.each_ref().map(|array| array.view());
+        arrays[3] = close.view();
+        assert!(atr.bar_outputs(arrays).is_err());
+    }
+}
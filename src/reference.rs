@@ -0,0 +1,838 @@
+//! Naive implementations of indicators, recomputing every output from its whole window, to
+//! verify the incremental algorithms of the [indicators](../indicators/index.html).
+//!
+//! The indicators update running sums, sorted windows or recursive averages on every input,
+//! which is fast but easy to get subtly wrong. The functions of this module compute the same
+//! outputs over a slice of inputs the straightforward way, in O(N·period), or in O(N²) for the
+//! exponential averages, which are computed from their closed form. They follow the same
+//! conventions as the indicators, e.g. averaging the inputs available while fewer than
+//! `period` inputs were consumed.
+//!
+//! References are given for indicators of numbers, e.g. the SMA or the MACD, and of bars,
+//! e.g. the ATR, the stochastics or the Keltner channel. Indicators with several outputs
+//! return the output structs of the indicators.
+//!
+//! [cross_check](fn.cross_check.html) runs an indicator over arbitrary inputs, e.g. generated
+//! by a fuzzer, and compares its outputs with those of a reference implementation, field by
+//! field for indicators with several outputs. [cross_check_bars](fn.cross_check_bars.html)
+//! does the same over bars.
+//!
+//! Requires the `reference` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::StandardDeviation;
+//! use ta::reference::{cross_check, standard_deviation};
+//!
+//! let inputs = [10.0, 12.5, 11.0, 9.0, 14.0, 13.5, 8.0];
+//! let result = cross_check(
+//!     StandardDeviation::new(3).unwrap(),
+//!     |inputs| standard_deviation(inputs, 3),
+//!     &inputs,
+//!     1e-9,
+//! );
+//! assert!(result.is_ok());
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::helpers::max3;
+use crate::indicators::{
+    BollingerBandsOutput, KeltnerChannelOutput, MovingAverageConvergenceDivergenceOutput,
+};
+use crate::{Close, DataItem, High, Low, Next, OutputValues, Volume};
+
+/// Output of an indicator differing from its reference implementation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// Index of the input of the output.
+    pub index: usize,
+    /// Index of the field of the output, as listed by its
+    /// [OutputValues](../trait.OutputValues.html), 0 for indicators with a single output.
+    pub field: usize,
+    /// Output of the reference implementation.
+    pub expected: f64,
+    /// Output of the indicator.
+    pub actual: f64,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "input {}", self.index)?;
+        if self.field > 0 {
+            write!(f, ", field {}", self.field)?;
+        }
+        write!(f, ": expected {}, got {}", self.expected, self.actual)
+    }
+}
+
+impl Error for Mismatch {}
+
+/// Difference between an indicator and its reference implementation, found by
+/// [cross_check](fn.cross_check.html).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrossCheckError {
+    /// First output differing from the reference.
+    Mismatch(Mismatch),
+    /// The reference returned a different number of outputs than there are inputs.
+    OutputCount { expected: usize, actual: usize },
+    /// The output of the input at the index has a different number of fields than the
+    /// reference.
+    FieldCount {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl Display for CrossCheckError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CrossCheckError::Mismatch(mismatch) => mismatch.fmt(f),
+            CrossCheckError::OutputCount { expected, actual } => {
+                write!(f, "expected {} outputs, got {}", expected, actual)
+            }
+            CrossCheckError::FieldCount {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "input {}: expected {} fields, got {}",
+                index, expected, actual
+            ),
+        }
+    }
+}
+
+impl Error for CrossCheckError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CrossCheckError::Mismatch(mismatch) => Some(mismatch),
+            CrossCheckError::OutputCount { .. } | CrossCheckError::FieldCount { .. } => None,
+        }
+    }
+}
+
+/// Runs the indicator over the inputs and compares its outputs with those of the reference
+/// implementation, returning the first mismatch.
+///
+/// Outputs match when every field differs by at most `tolerance` times the largest of 1 and
+/// the absolute expected value, or when both are NaN. The reference must return one output
+/// per input, with as many fields as the outputs of the indicator.
+pub fn cross_check<I, F, O>(
+    mut indicator: I,
+    reference: F,
+    inputs: &[f64],
+    tolerance: f64,
+) -> Result<(), CrossCheckError>
+where
+    I: Next<f64>,
+    I::Output: OutputValues,
+    F: Fn(&[f64]) -> Vec<O>,
+    O: OutputValues,
+{
+    compare(
+        inputs
+            .iter()
+            .map(|&input| indicator.next(input).output_values()),
+        reference(inputs),
+        tolerance,
+    )
+}
+
+/// Runs the indicator over the bars and compares its outputs with those of the reference
+/// implementation, returning the first mismatch, as [cross_check](fn.cross_check.html) does.
+pub fn cross_check_bars<I, F, O>(
+    mut indicator: I,
+    reference: F,
+    bars: &[DataItem],
+    tolerance: f64,
+) -> Result<(), CrossCheckError>
+where
+    I: for<'a> Next<&'a DataItem>,
+    for<'a> <I as Next<&'a DataItem>>::Output: OutputValues,
+    F: Fn(&[DataItem]) -> Vec<O>,
+    O: OutputValues,
+{
+    compare(
+        bars.iter().map(|bar| indicator.next(bar).output_values()),
+        reference(bars),
+        tolerance,
+    )
+}
+
+// Compares the values of the fields of the outputs
+fn compare<O: OutputValues>(
+    actual: impl Iterator<Item = Vec<f64>>,
+    expected: Vec<O>,
+    tolerance: f64,
+) -> Result<(), CrossCheckError> {
+    let actual: Vec<Vec<f64>> = actual.collect();
+    if actual.len() != expected.len() {
+        return Err(CrossCheckError::OutputCount {
+            expected: expected.len(),
+            actual: actual.len(),
+        });
+    }
+
+    for (index, (actual, expected)) in actual.into_iter().zip(&expected).enumerate() {
+        let expected = expected.output_values();
+        if actual.len() != expected.len() {
+            return Err(CrossCheckError::FieldCount {
+                index,
+                expected: expected.len(),
+                actual: actual.len(),
+            });
+        }
+
+        for (field, (actual, expected)) in actual.into_iter().zip(expected).enumerate() {
+            let matches = if expected.is_nan() || actual.is_nan() {
+                expected.is_nan() && actual.is_nan()
+            } else {
+                (actual - expected).abs() <= tolerance * expected.abs().max(1.0)
+            };
+            if !matches {
+                return Err(CrossCheckError::Mismatch(Mismatch {
+                    index,
+                    field,
+                    expected,
+                    actual,
+                }));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Applies the function to the window of every input: the input and up to `period - 1`
+// previous ones
+fn rolling<F: Fn(&[f64]) -> f64>(inputs: &[f64], period: usize, f: F) -> Vec<f64> {
+    (0..inputs.len())
+        .map(|i| f(&inputs[(i + 1).saturating_sub(period)..=i]))
+        .collect()
+}
+
+fn mean(window: &[f64]) -> f64 {
+    window.iter().sum::<f64>() / window.len() as f64
+}
+
+/// [Simple moving average](../indicators/struct.SimpleMovingAverage.html).
+pub fn simple_moving_average(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling(inputs, period, mean)
+}
+
+/// [Weighted moving average](../indicators/struct.WeightedMovingAverage.html).
+pub fn weighted_moving_average(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling(inputs, period, |window| {
+        let weighted: f64 = (1..)
+            .zip(window)
+            .map(|(weight, value)| weight as f64 * value)
+            .sum();
+        let count = window.len() as f64;
+        weighted / (count * (count + 1.0) / 2.0)
+    })
+}
+
+/// [Exponential moving average](../indicators/struct.ExponentialMovingAverage.html), seeded
+/// with the first input.
+///
+/// Every output is the sum of the inputs weighted by `k·(1 - k)^age`, the first input taking
+/// the remaining weight `(1 - k)^age`, where `k = 2 / (period + 1)`.
+pub fn exponential_moving_average(inputs: &[f64], period: usize) -> Vec<f64> {
+    exponential(inputs, 2.0 / (period + 1) as f64)
+}
+
+fn exponential(inputs: &[f64], k: f64) -> Vec<f64> {
+    (0..inputs.len())
+        .map(|i| {
+            let seed = inputs[0] * (1.0 - k).powi(i as i32);
+            seed + (1..=i)
+                .map(|j| inputs[j] * k * (1.0 - k).powi((i - j) as i32))
+                .sum::<f64>()
+        })
+        .collect()
+}
+
+/// [Relative strength index](../indicators/struct.RelativeStrengthIndex.html), with the
+/// exponential moving averages of the gains and losses seeded with 0.1.
+pub fn relative_strength_index(inputs: &[f64], period: usize) -> Vec<f64> {
+    if inputs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut gains = vec![0.1];
+    let mut losses = vec![0.1];
+    for pair in inputs.windows(2) {
+        let change = pair[1] - pair[0];
+        gains.push(change.max(0.0));
+        losses.push((-change).max(0.0));
+    }
+
+    let k = 2.0 / (period + 1) as f64;
+    exponential(&gains, k)
+        .into_iter()
+        .zip(exponential(&losses, k))
+        .map(|(gain, loss)| 100.0 * gain / (gain + loss))
+        .collect()
+}
+
+/// [Standard deviation](../indicators/struct.StandardDeviation.html) of the population.
+pub fn standard_deviation(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling(inputs, period, |window| {
+        let mean = mean(window);
+        let variance = window
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / window.len() as f64;
+        variance.sqrt()
+    })
+}
+
+/// [Mean absolute deviation](../indicators/struct.MeanAbsoluteDeviation.html).
+pub fn mean_absolute_deviation(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling(inputs, period, |window| {
+        let mean = mean(window);
+        window.iter().map(|value| (value - mean).abs()).sum::<f64>() / window.len() as f64
+    })
+}
+
+/// [Maximum](../indicators/struct.Maximum.html).
+pub fn maximum(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling(inputs, period, |window| {
+        window.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+    })
+}
+
+/// [Minimum](../indicators/struct.Minimum.html).
+pub fn minimum(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling(inputs, period, |window| {
+        window.iter().copied().fold(f64::INFINITY, f64::min)
+    })
+}
+
+/// [Rolling percentile](../indicators/struct.RollingPercentile.html), interpolating linearly
+/// between the closest ranks.
+pub fn rolling_percentile(inputs: &[f64], period: usize, percentile: f64) -> Vec<f64> {
+    rolling(inputs, period, |window| {
+        let mut sorted = window.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let rank = percentile.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+    })
+}
+
+/// [Rolling median](../indicators/struct.RollingMedian.html).
+pub fn rolling_median(inputs: &[f64], period: usize) -> Vec<f64> {
+    rolling_percentile(inputs, period, 50.0)
+}
+
+/// [Percent rank](../indicators/struct.PercentRank.html) of every input among up to `period`
+/// previous ones, 0 for the first input.
+pub fn percent_rank(inputs: &[f64], period: usize) -> Vec<f64> {
+    (0..inputs.len())
+        .map(|i| {
+            let previous = &inputs[i.saturating_sub(period)..i];
+            if previous.is_empty() {
+                return 0.0;
+            }
+            let lower = previous.iter().filter(|&&value| value < inputs[i]).count();
+            lower as f64 / previous.len() as f64 * 100.0
+        })
+        .collect()
+}
+
+/// [Momentum](../indicators/struct.Momentum.html): the change from the input `period` inputs
+/// earlier, or from the first input.
+pub fn momentum(inputs: &[f64], period: usize) -> Vec<f64> {
+    (0..inputs.len())
+        .map(|i| inputs[i] - inputs[i.saturating_sub(period)])
+        .collect()
+}
+
+/// [Rate of change](../indicators/struct.RateOfChange.html), in percent, from the input
+/// `period` inputs earlier, or from the first input.
+pub fn rate_of_change(inputs: &[f64], period: usize) -> Vec<f64> {
+    (0..inputs.len())
+        .map(|i| {
+            let previous = inputs[i.saturating_sub(period)];
+            (inputs[i] - previous) / previous * 100.0
+        })
+        .collect()
+}
+
+/// [Moving average convergence divergence](../indicators/struct.MovingAverageConvergenceDivergence.html).
+pub fn moving_average_convergence_divergence(
+    inputs: &[f64],
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> Vec<MovingAverageConvergenceDivergenceOutput> {
+    let macd: Vec<f64> = exponential_moving_average(inputs, fast_period)
+        .into_iter()
+        .zip(exponential_moving_average(inputs, slow_period))
+        .map(|(fast, slow)| fast - slow)
+        .collect();
+    let signal = exponential_moving_average(&macd, signal_period);
+
+    macd.into_iter()
+        .zip(signal)
+        .map(|(macd, signal)| MovingAverageConvergenceDivergenceOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        })
+        .collect()
+}
+
+/// [Bollinger bands](../indicators/struct.BollingerBands.html).
+pub fn bollinger_bands(
+    inputs: &[f64],
+    period: usize,
+    multiplier: f64,
+) -> Vec<BollingerBandsOutput> {
+    simple_moving_average(inputs, period)
+        .into_iter()
+        .zip(standard_deviation(inputs, period))
+        .map(|(average, sd)| BollingerBandsOutput {
+            average,
+            upper: average + sd * multiplier,
+            lower: average - sd * multiplier,
+        })
+        .collect()
+}
+
+fn typical_price<T: High + Low + Close>(bar: &T) -> f64 {
+    (bar.close() + bar.high() + bar.low()) / 3.0
+}
+
+/// [True range](../indicators/struct.TrueRange.html): the range of the first bar, then the
+/// largest distance between the high, the low and the previous close.
+pub fn true_range<T: High + Low + Close>(bars: &[T]) -> Vec<f64> {
+    (0..bars.len())
+        .map(|i| {
+            let (high, low) = (bars[i].high(), bars[i].low());
+            match i.checked_sub(1) {
+                Some(prev) => {
+                    let prev_close = bars[prev].close();
+                    max3(
+                        high - low,
+                        (high - prev_close).abs(),
+                        (low - prev_close).abs(),
+                    )
+                }
+                None => high - low,
+            }
+        })
+        .collect()
+}
+
+/// [Average true range](../indicators/struct.AverageTrueRange.html), the exponential moving
+/// average of the true ranges.
+pub fn average_true_range<T: High + Low + Close>(bars: &[T], period: usize) -> Vec<f64> {
+    exponential_moving_average(&true_range(bars), period)
+}
+
+/// [Fast stochastic](../indicators/struct.FastStochastic.html), 50 when the highest high and
+/// the lowest low of the window are equal.
+pub fn fast_stochastic<T: High + Low + Close>(bars: &[T], period: usize) -> Vec<f64> {
+    (0..bars.len())
+        .map(|i| {
+            let window = &bars[(i + 1).saturating_sub(period)..=i];
+            let highest = window
+                .iter()
+                .map(|bar| bar.high())
+                .fold(f64::NEG_INFINITY, f64::max);
+            let lowest = window
+                .iter()
+                .map(|bar| bar.low())
+                .fold(f64::INFINITY, f64::min);
+            if highest == lowest {
+                50.0
+            } else {
+                (bars[i].close() - lowest) / (highest - lowest) * 100.0
+            }
+        })
+        .collect()
+}
+
+/// [Slow stochastic](../indicators/struct.SlowStochastic.html), the exponential moving
+/// average of the fast stochastic.
+pub fn slow_stochastic<T: High + Low + Close>(
+    bars: &[T],
+    stochastic_period: usize,
+    ema_period: usize,
+) -> Vec<f64> {
+    exponential_moving_average(&fast_stochastic(bars, stochastic_period), ema_period)
+}
+
+/// [Commodity channel index](../indicators/struct.CommodityChannelIndex.html), dividing by the
+/// mean absolute deviation of the closes as the indicator does, 0 when it is 0.
+pub fn commodity_channel_index<T: High + Low + Close>(bars: &[T], period: usize) -> Vec<f64> {
+    let typical: Vec<f64> = bars.iter().map(typical_price).collect();
+    let closes: Vec<f64> = bars.iter().map(|bar| bar.close()).collect();
+
+    typical
+        .iter()
+        .zip(simple_moving_average(&typical, period))
+        .zip(mean_absolute_deviation(&closes, period))
+        .map(|((tp, sma), mad)| {
+            if mad == 0.0 {
+                0.0
+            } else {
+                (tp - sma) / (mad * 0.015)
+            }
+        })
+        .collect()
+}
+
+/// [Money flow index](../indicators/struct.MoneyFlowIndex.html), 50 for the first bar, whose
+/// money flow is 0.
+pub fn money_flow_index<T: High + Low + Close + Volume>(bars: &[T], period: usize) -> Vec<f64> {
+    // Signed money flows, positive when the typical price rises
+    let flows: Vec<f64> = (0..bars.len())
+        .map(|i| {
+            let tp = typical_price(&bars[i]);
+            match i.checked_sub(1).map(|prev| typical_price(&bars[prev])) {
+                Some(prev) if tp > prev => tp * bars[i].volume(),
+                Some(prev) if tp < prev => -tp * bars[i].volume(),
+                _ => 0.0,
+            }
+        })
+        .collect();
+
+    (0..bars.len())
+        .map(|i| {
+            if i == 0 {
+                return 50.0;
+            }
+            let window = &flows[(i + 1).saturating_sub(period)..=i];
+            let positive: f64 = window.iter().filter(|&&flow| flow > 0.0).sum();
+            let negative: f64 = -window.iter().filter(|&&flow| flow < 0.0).sum::<f64>();
+            positive / (positive + negative) * 100.0
+        })
+        .collect()
+}
+
+/// [On balance volume](../indicators/struct.OnBalanceVolume.html), the previous close of the
+/// first bar being 0.
+pub fn on_balance_volume<T: Close + Volume>(bars: &[T]) -> Vec<f64> {
+    let mut obv = 0.0;
+    (0..bars.len())
+        .map(|i| {
+            let prev_close = i.checked_sub(1).map_or(0.0, |prev| bars[prev].close());
+            let close = bars[i].close();
+            if close > prev_close {
+                obv += bars[i].volume();
+            } else if close < prev_close {
+                obv -= bars[i].volume();
+            }
+            obv
+        })
+        .collect()
+}
+
+/// [Keltner channel](../indicators/struct.KeltnerChannel.html) around the exponential moving
+/// average of the typical prices.
+pub fn keltner_channel<T: High + Low + Close>(
+    bars: &[T],
+    period: usize,
+    multiplier: f64,
+) -> Vec<KeltnerChannelOutput> {
+    let typical: Vec<f64> = bars.iter().map(typical_price).collect();
+
+    exponential_moving_average(&typical, period)
+        .into_iter()
+        .zip(average_true_range(bars, period))
+        .map(|(average, atr)| KeltnerChannelOutput {
+            average,
+            upper: average + atr * multiplier,
+            lower: average - atr * multiplier,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_inputs(seed: u8) -> Vec<f64> {
+        let mut rng = StdRng::from_seed([seed; 32]);
+        let mut price = 100.0;
+        (0..300)
+            .map(|_| {
+                price += rng.gen_range(-2.0, 2.0);
+                price
+            })
+            .collect()
+    }
+
+    fn random_bars(seed: u8) -> Vec<DataItem> {
+        let mut rng = StdRng::from_seed([seed; 32]);
+        let mut close = 100.0;
+        (0..300)
+            .map(|_| {
+                let open = close;
+                close += rng.gen_range(-2.0, 2.0);
+                DataItem::builder()
+                    .open(open)
+                    .high(open.max(close) + rng.gen_range(0.0, 1.0))
+                    .low(open.min(close) - rng.gen_range(0.0, 1.0))
+                    .close(close)
+                    .volume(rng.gen_range(0.0, 1000.0))
+                    .build()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rolling() {
+        let inputs = [1.0, 2.0, 6.0, 3.0];
+        assert_eq!(simple_moving_average(&inputs, 2), [1.0, 1.5, 4.0, 4.5]);
+        assert_eq!(maximum(&inputs, 3), [1.0, 2.0, 6.0, 6.0]);
+        assert_eq!(rolling_median(&inputs, 3), [1.0, 1.5, 2.0, 3.0]);
+        assert_eq!(percent_rank(&inputs, 2), [0.0, 100.0, 100.0, 50.0]);
+        assert_eq!(momentum(&inputs, 2), [0.0, 1.0, 5.0, 1.0]);
+    }
+
+    #[test]
+    fn test_exponential_moving_average() {
+        assert_eq!(
+            exponential_moving_average(&[2.0, 5.0, 1.0, 6.25], 3),
+            [2.0, 3.5, 2.25, 4.25]
+        );
+    }
+
+    // Cross-checks the indicator with its reference implementation over random inputs
+    macro_rules! check {
+        ($indicator:expr, $reference:expr) => {
+            for seed in 0..4 {
+                let inputs = random_inputs(seed);
+                for &period in &[1, 2, 5, 14] {
+                    let result = cross_check(
+                        $indicator(period),
+                        |inputs| $reference(inputs, period),
+                        &inputs,
+                        1e-9,
+                    );
+                    if let Err(mismatch) = result {
+                        panic!(
+                            "{} with period {}: {}",
+                            stringify!($indicator),
+                            period,
+                            mismatch
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let new = |period| SimpleMovingAverage::new(period).unwrap();
+        check!(new, simple_moving_average);
+        let new = |period| WeightedMovingAverage::new(period).unwrap();
+        check!(new, weighted_moving_average);
+        let new = |period| ExponentialMovingAverage::new(period).unwrap();
+        check!(new, exponential_moving_average);
+        let new = |period| RelativeStrengthIndex::new(period).unwrap();
+        check!(new, relative_strength_index);
+        let new = |period| StandardDeviation::new(period).unwrap();
+        check!(new, standard_deviation);
+        let new = |period| MeanAbsoluteDeviation::new(period).unwrap();
+        check!(new, mean_absolute_deviation);
+        let new = |period| Maximum::new(period).unwrap();
+        check!(new, maximum);
+        let new = |period| Minimum::new(period).unwrap();
+        check!(new, minimum);
+        let new = |period| RollingMedian::new(period).unwrap();
+        check!(new, rolling_median);
+        let new = |period| RollingPercentile::new(period, 90.0).unwrap();
+        check!(new, |inputs, period| rolling_percentile(
+            inputs, period, 90.0
+        ));
+        let new = |period| PercentRank::new(period).unwrap();
+        check!(new, percent_rank);
+        let new = |period| Momentum::new(period).unwrap();
+        check!(new, momentum);
+        let new = |period| RateOfChange::new(period).unwrap();
+        check!(new, rate_of_change);
+    }
+
+    // Cross-checks the indicator of bars with its reference implementation over random bars
+    macro_rules! check_bars {
+        ($indicator:expr, $reference:expr) => {
+            for seed in 0..4 {
+                let bars = random_bars(seed);
+                for &period in &[1, 2, 5, 14] {
+                    let result = cross_check_bars(
+                        $indicator(period),
+                        |bars| $reference(bars, period),
+                        &bars,
+                        1e-9,
+                    );
+                    if let Err(mismatch) = result {
+                        panic!(
+                            "{} with period {}: {}",
+                            stringify!($indicator),
+                            period,
+                            mismatch
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_cross_check_outputs() {
+        let new = |period| MovingAverageConvergenceDivergence::new(period, period * 2, 9).unwrap();
+        check!(new, |inputs, period| {
+            moving_average_convergence_divergence(inputs, period, period * 2, 9)
+        });
+        let new = |period| BollingerBands::new(period, 2.0).unwrap();
+        check!(new, |inputs, period| bollinger_bands(inputs, period, 2.0));
+    }
+
+    #[test]
+    fn test_cross_check_bars() {
+        let new = |_| TrueRange::new();
+        check_bars!(new, |bars: &[DataItem], _| true_range(bars));
+        let new = |period| AverageTrueRange::new(period).unwrap();
+        check_bars!(new, average_true_range);
+        let new = |period| FastStochastic::new(period).unwrap();
+        check_bars!(new, fast_stochastic);
+        let new = |period| SlowStochastic::new(period, 3).unwrap();
+        check_bars!(new, |bars, period| slow_stochastic(bars, period, 3));
+        let new = |period| CommodityChannelIndex::new(period).unwrap();
+        check_bars!(new, commodity_channel_index);
+        let new = |period| MoneyFlowIndex::new(period).unwrap();
+        check_bars!(new, money_flow_index);
+        let new = |_| OnBalanceVolume::new();
+        check_bars!(new, |bars: &[DataItem], _| on_balance_volume(bars));
+        let new = |period| KeltnerChannel::new(period, 2.0).unwrap();
+        check_bars!(new, |bars, period| keltner_channel(bars, period, 2.0));
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let err = cross_check(
+            SimpleMovingAverage::new(2).unwrap(),
+            |inputs| maximum(inputs, 2),
+            &[1.0, 3.0, 2.0],
+            1e-9,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            CrossCheckError::Mismatch(Mismatch {
+                index: 1,
+                field: 0,
+                expected: 3.0,
+                actual: 2.0
+            })
+        );
+        assert_eq!(err.to_string(), "input 1: expected 3, got 2");
+
+        // The lower band differs
+        let err = cross_check(
+            BollingerBands::new(2, 2.0).unwrap(),
+            |inputs| {
+                let mut bands = bollinger_bands(inputs, 2, 2.0);
+                bands[1].lower = 1.0;
+                bands
+            },
+            &[1.0, 3.0, 2.0],
+            1e-9,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            CrossCheckError::Mismatch(Mismatch {
+                index: 1,
+                field: 2,
+                ..
+            })
+        ));
+        assert_eq!(err.to_string(), "input 1, field 2: expected 1, got 0");
+    }
+
+    #[test]
+    fn test_count_mismatch() {
+        let inputs = [1.0, 3.0, 2.0];
+
+        let err = cross_check(
+            SimpleMovingAverage::new(2).unwrap(),
+            |inputs| simple_moving_average(&inputs[1..], 2),
+            &inputs,
+            1e-9,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            CrossCheckError::OutputCount {
+                expected: 2,
+                actual: 3
+            }
+        );
+        assert_eq!(err.to_string(), "expected 2 outputs, got 3");
+
+        let err = cross_check(
+            SimpleMovingAverage::new(2).unwrap(),
+            |_| Vec::<f64>::new(),
+            &inputs,
+            1e-9,
+        )
+        .unwrap_err();
+        assert!(matches!(err, CrossCheckError::OutputCount { .. }));
+
+        // Three fields expected of a single output
+        let err = cross_check(
+            SimpleMovingAverage::new(2).unwrap(),
+            |inputs| moving_average_convergence_divergence(inputs, 2, 3, 2),
+            &inputs,
+            1e-9,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            CrossCheckError::FieldCount {
+                index: 0,
+                expected: 3,
+                actual: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_rolling_percentile_nan() {
+        let inputs = [1.0, f64::NAN, 3.0, 2.0];
+
+        // NaN sorts above every number
+        let medians = rolling_median(&inputs, 3);
+        assert_eq!(medians[0], 1.0);
+        assert!(medians[1].is_nan());
+        assert_eq!(medians[2], 3.0);
+        assert_eq!(medians[3], 3.0);
+
+        let inputs = [1.0, f64::NAN, 3.0, 2.0, 4.0, 5.0, 6.0];
+        for &percentile in &[0.0, 50.0, 100.0] {
+            let indicator = RollingPercentile::new(3, percentile).unwrap();
+            let reference = |inputs: &[f64]| rolling_percentile(inputs, 3, percentile);
+            assert_eq!(cross_check(indicator, reference, &inputs, 1e-9), Ok(()));
+        }
+
+        assert_eq!(rolling_percentile(&[1.0, 2.0], 2, 150.0), vec![1.0, 2.0]);
+        assert_eq!(rolling_percentile(&[1.0, 2.0], 2, -10.0), vec![1.0, 1.0]);
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+use crate::indicator_set::{Entry, Mapped};
+use crate::{DataItem, Next, Reset};
+
+/// Indicator of any type, created at runtime, e.g. by an
+/// [IndicatorRegistry](struct.IndicatorRegistry.html).
+///
+/// The output of the indicator is mapped to a list of numbers, one for every field. Indicators
+/// returning a number have a single field named `value`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{BollingerBands, SimpleMovingAverage as Sma};
+/// use ta::registry::DynIndicator;
+/// use ta::{DataItem, Next};
+///
+/// let mut indicators = vec![
+///     DynIndicator::new(Sma::new(2).unwrap()),
+///     DynIndicator::with_fields(
+///         BollingerBands::new(2, 1.0).unwrap(),
+///         ["upper", "lower"],
+///         |out| [out.upper, out.lower],
+///     ),
+/// ];
+///
+/// let bar = |close| {
+///     DataItem::builder()
+///         .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+/// };
+/// for indicator in indicators.iter_mut() {
+///     indicator.next(&bar(10.0));
+/// }
+///
+/// assert_eq!(format!("{}", indicators[1]), "BB(2, 1)");
+/// assert_eq!(indicators[1].fields(), ["upper", "lower"]);
+/// assert_eq!(indicators[0].next(&bar(12.0)), vec![11.0]);
+/// assert_eq!(indicators[1].next(&bar(12.0)), vec![12.0, 10.0]);
+/// ```
+pub struct DynIndicator {
+    label: String,
+    fields: Vec<&'static str>,
+    entry: Box<dyn Entry>,
+}
+
+impl DynIndicator {
+    /// Wraps an indicator returning a number.
+    pub fn new<I>(indicator: I) -> Self
+    where
+        I: for<'a> Next<&'a DataItem, Output = f64> + Reset + fmt::Display + 'static,
+    {
+        Self::with_fields(indicator, ["value"], |value| [value])
+    }
+
+    /// Wraps an indicator whose output is mapped to the values of the _fields_ by _map_.
+    pub fn with_fields<I, F, const N: usize>(
+        indicator: I,
+        fields: [&'static str; N],
+        map: F,
+    ) -> Self
+    where
+        I: for<'a> Next<&'a DataItem> + Reset + fmt::Display + 'static,
+        F: for<'a> Fn(<I as Next<&'a DataItem>>::Output) -> [f64; N] + 'static,
+    {
+        Self {
+            label: indicator.to_string(),
+            fields: fields.to_vec(),
+            entry: Box::new(Mapped { indicator, map }),
+        }
+    }
+
+    /// Returns the names of the values returned by `next`.
+    pub fn fields(&self) -> &[&'static str] {
+        &self.fields
+    }
+
+    /// Returns the labels of the values returned by `next`, e.g. for the columns of a table:
+    /// the label of the indicator, followed by the name of the field for indicators with
+    /// several fields, e.g. `"BB(20, 2).upper"`.
+    pub fn field_labels(&self) -> Vec<String> {
+        match self.fields.as_slice() {
+            [_] => vec![self.label.clone()],
+            fields => fields
+                .iter()
+                .map(|field| format!("{}.{}", self.label, field))
+                .collect(),
+        }
+    }
+}
+
+impl Next<&DataItem> for DynIndicator {
+    type Output = Vec<f64>;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let mut values = Vec::with_capacity(self.fields.len());
+        self.entry.next(input, &mut values);
+        values
+    }
+}
+
+impl Reset for DynIndicator {
+    fn reset(&mut self) {
+        self.entry.reset();
+    }
+}
+
+impl fmt::Display for DynIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl fmt::Debug for DynIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DynIndicator")
+            .field("label", &self.label)
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{Maximum, MovingAverageConvergenceDivergence as Macd};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut max = DynIndicator::new(Maximum::new(2).unwrap());
+        assert_eq!(max.fields(), ["value"]);
+        assert_eq!(max.next(&item(10.0)), vec![10.0]);
+        assert_eq!(max.next(&item(8.0)), vec![10.0]);
+        assert_eq!(max.next(&item(7.0)), vec![8.0]);
+    }
+
+    #[test]
+    fn test_next_fields() {
+        let mut macd = DynIndicator::with_fields(
+            Macd::new(3, 6, 4).unwrap(),
+            ["macd", "signal", "histogram"],
+            |out| [out.macd, out.signal, out.histogram],
+        );
+        assert_eq!(macd.fields(), ["macd", "signal", "histogram"]);
+
+        macd.next(&item(2.0));
+        let values: Vec<f64> = macd.next(&item(3.0)).into_iter().map(round).collect();
+        assert_eq!(values, vec![0.214, 0.086, 0.129]);
+    }
+
+    #[test]
+    fn test_field_labels() {
+        let max = DynIndicator::new(Maximum::new(2).unwrap());
+        assert_eq!(max.field_labels(), ["MAX(2)"]);
+
+        let macd =
+            DynIndicator::with_fields(Macd::new(3, 6, 4).unwrap(), ["macd", "signal"], |out| {
+                [out.macd, out.signal]
+            });
+        assert_eq!(
+            macd.field_labels(),
+            ["MACD(3, 6, 4).macd", "MACD(3, 6, 4).signal"]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut max = DynIndicator::new(Maximum::new(2).unwrap());
+        max.next(&item(10.0));
+
+        max.reset();
+
+        assert_eq!(max.next(&item(7.0)), vec![7.0]);
+    }
+
+    #[test]
+    fn test_display() {
+        let max = DynIndicator::new(Maximum::new(2).unwrap());
+        assert_eq!(format!("{}", max), "MAX(2)");
+        assert_eq!(
+            format!("{:?}", max),
+            "DynIndicator { label: \"MAX(2)\", fields: [\"value\"] }"
+        );
+    }
+}
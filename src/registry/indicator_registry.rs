@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::*;
+use crate::registry::spec::is_valid_name;
+use crate::registry::{DynIndicator, IndicatorSpec};
+
+type Constructor = Box<dyn Fn(&[f64]) -> Result<DynIndicator>>;
+
+/// Registry creating indicators from [text specifications](struct.IndicatorSpec.html), such as
+/// `"ema(21)"`, `"macd(12, 26, 9)"` or `"bb(20, 2.0)"`.
+///
+/// Every name is bound to a constructor taking the parameters of the specification. The
+/// registry returned by `new` knows the built-in indicators under the lowercase name of their
+/// label, e.g. `sma` for [SMA](../indicators/struct.SimpleMovingAverage.html) or
+/// `fast_stoch` for [FAST_STOCH](../indicators/struct.FastStochastic.html). They take the
+/// parameters of their `new` function, periods being integers up to 1 000 000, or no
+/// parameters for the default ones. Indicators with several outputs have a field for every line, e.g. `macd`,
+/// `signal` and `histogram` for the MACD.
+///
+/// More indicators, or other parameterizations, can be added with `register`.
+///
+/// # Example
+///
+/// ```
+/// use ta::registry::IndicatorRegistry;
+/// use ta::{DataItem, Next};
+///
+/// let registry = IndicatorRegistry::new();
+/// let mut ema = registry.create("ema(3)").unwrap();
+/// let mut bb = registry.create("bb").unwrap();
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(10.0).low(10.0).close(10.0).volume(0.0).build().unwrap();
+///
+/// assert_eq!(format!("{}", ema), "EMA(3)");
+/// assert_eq!(ema.next(&bar), vec![10.0]);
+/// assert_eq!(format!("{}", bb), "BB(9, 2)");
+/// assert_eq!(bb.fields(), ["average", "upper", "lower"]);
+/// assert!(registry.create("ema(3.5)").is_err());
+/// assert!(registry.create("unknown(3)").is_err());
+/// ```
+pub struct IndicatorRegistry {
+    constructors: HashMap<String, Constructor>,
+}
+
+// Returns the default indicator without parameters, or calls new with all of them
+fn build<I: Default, const N: usize>(
+    params: &[f64],
+    new: impl Fn([f64; N]) -> Result<I>,
+) -> Result<I> {
+    if params.is_empty() {
+        return Ok(I::default());
+    }
+    let params: [f64; N] = params.try_into().map_err(|_| TaError::InvalidParameter)?;
+    new(params)
+}
+
+// Largest period of a specification, bounding the memory allocated by the indicators
+const MAX_PERIOD: usize = 1_000_000;
+
+// Converts a parameter to a period
+fn period(value: f64) -> Result<usize> {
+    if !(value >= 0.0 && value.fract() == 0.0 && value <= MAX_PERIOD as f64) {
+        return Err(TaError::InvalidParameter);
+    }
+    Ok(value as usize)
+}
+
+impl IndicatorRegistry {
+    /// Creates a registry of the built-in indicators.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register_builtins();
+        registry
+    }
+
+    /// Creates a registry without any indicator.
+    pub fn empty() -> Self {
+        Self {
+            constructors: HashMap::new(),
+        }
+    }
+
+    /// Binds the _name_ to the _constructor_, replacing the previous constructor of the name.
+    /// Returns an error when the name is not valid in a specification.
+    pub fn register<F>(&mut self, name: &str, constructor: F) -> Result<()>
+    where
+        F: Fn(&[f64]) -> Result<DynIndicator> + 'static,
+    {
+        if !is_valid_name(name) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.constructors
+            .insert(name.to_ascii_lowercase(), Box::new(constructor));
+        Ok(())
+    }
+
+    /// Returns the registered names in alphabetical order.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.constructors.keys().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.contains_key(&name.to_ascii_lowercase())
+    }
+
+    /// Creates the indicator of the text specification _spec_. Returns an error when the
+    /// specification is not valid, its name is unknown or its parameters are invalid.
+    pub fn create(&self, spec: &str) -> Result<DynIndicator> {
+        self.create_from(&spec.parse()?)
+    }
+
+    /// Creates the indicator of the specification _spec_.
+    pub fn create_from(&self, spec: &IndicatorSpec) -> Result<DynIndicator> {
+        let constructor = self
+            .constructors
+            .get(spec.name())
+            .ok_or(TaError::InvalidParameter)?;
+        constructor(spec.params())
+    }
+
+    fn register_single<I, F, const N: usize>(&mut self, name: &str, new: F)
+    where
+        I: for<'a> crate::Next<&'a crate::DataItem, Output = f64>
+            + crate::Reset
+            + Default
+            + fmt::Display
+            + 'static,
+        F: Fn([f64; N]) -> Result<I> + Copy + 'static,
+    {
+        self.register(name, move |params| {
+            Ok(DynIndicator::new(build(params, new)?))
+        })
+        .unwrap();
+    }
+
+    fn register_builtins(&mut self) {
+        self.register_single("sma", |[n]| SimpleMovingAverage::new(period(n)?));
+        self.register_single("ema", |[n]| ExponentialMovingAverage::new(period(n)?));
+        self.register_single("wma", |[n]| WeightedMovingAverage::new(period(n)?));
+        self.register_single("smma", |[n]| SmoothedMovingAverage::new(period(n)?));
+        self.register_single("md", |[n]| McGinleyDynamic::new(period(n)?));
+        self.register_single("t3", |[n, factor]| T3MovingAverage::new(period(n)?, factor));
+        self.register_single("kama", |[er, fast, slow]| {
+            KaufmanAdaptiveMovingAverage::new(period(er)?, period(fast)?, period(slow)?)
+        });
+        self.register_single("kalman", |[process, measurement]| {
+            KalmanFilter::new(process, measurement)
+        });
+
+        self.register_single("rsi", |[n]| RelativeStrengthIndex::new(period(n)?));
+        self.register_single("fast_stoch", |[n]| FastStochastic::new(period(n)?));
+        self.register_single("slow_stoch", |[n, ema]| {
+            SlowStochastic::new(period(n)?, period(ema)?)
+        });
+        self.register_single("cci", |[n]| CommodityChannelIndex::new(period(n)?));
+        self.register_single("mfi", |[n]| MoneyFlowIndex::new(period(n)?));
+        self.register_single("roc", |[n]| RateOfChange::new(period(n)?));
+        self.register_single("mom", |[n]| Momentum::new(period(n)?));
+        self.register_single("er", |[n]| EfficiencyRatio::new(period(n)?));
+        self.register_single("uo", |[short, medium, long]| {
+            UltimateOscillator::new(period(short)?, period(medium)?, period(long)?)
+        });
+        self.register_single("ao", |[fast, slow]| {
+            AwesomeOscillator::new(period(fast)?, period(slow)?)
+        });
+        self.register_single("ac", |[fast, slow, signal]| {
+            AcceleratorOscillator::new(period(fast)?, period(slow)?, period(signal)?)
+        });
+        self.register_single("coppock", |[long, short, wma]| {
+            CoppockCurve::new(period(long)?, period(short)?, period(wma)?)
+        });
+
+        self.register_single("tr", |[]| Ok(TrueRange::new()));
+        self.register_single("atr", |[n]| AverageTrueRange::new(period(n)?));
+        self.register_single("natr", |[n]| NormalizedAverageTrueRange::new(period(n)?));
+        self.register_single("sd", |[n]| StandardDeviation::new(period(n)?));
+        self.register_single("mad", |[n]| MeanAbsoluteDeviation::new(period(n)?));
+        self.register_single("parkinson", |[n]| ParkinsonVolatility::new(period(n)?));
+        self.register_single("gk", |[n]| GarmanKlassVolatility::new(period(n)?));
+        self.register_single("yz", |[n]| YangZhangVolatility::new(period(n)?));
+
+        self.register_single("max", |[n]| Maximum::new(period(n)?));
+        self.register_single("min", |[n]| Minimum::new(period(n)?));
+        self.register_single("midprice", |[n]| MidPrice::new(period(n)?));
+        self.register_single("medprice", |[]| Ok(MedianPrice::new()));
+        self.register_single("median", |[n]| RollingMedian::new(period(n)?));
+        self.register_single("percentile", |[n, percentile]| {
+            RollingPercentile::new(period(n)?, percentile)
+        });
+        self.register_single("pr", |[n]| PercentRank::new(period(n)?));
+        self.register_single("zscore", |[n]| ZScore::new(period(n)?));
+        self.register_single("skew", |[n]| RollingSkewness::new(period(n)?));
+        self.register_single("kurt", |[n]| RollingKurtosis::new(period(n)?));
+        self.register_single("rsq", |[n]| RollingRSquared::new(period(n)?));
+        self.register_single("fdi", |[n]| FractalDimensionIndex::new(period(n)?));
+
+        self.register_single("obv", |[]| Ok(OnBalanceVolume::new()));
+        self.register_single("ad", |[]| Ok(AccumulationDistribution::new()));
+
+        self.register("macd", |params| {
+            let macd = build(params, |[fast, slow, signal]| {
+                MovingAverageConvergenceDivergence::new(
+                    period(fast)?,
+                    period(slow)?,
+                    period(signal)?,
+                )
+            })?;
+            Ok(DynIndicator::with_fields(
+                macd,
+                ["macd", "signal", "histogram"],
+                |out| [out.macd, out.signal, out.histogram],
+            ))
+        })
+        .unwrap();
+        self.register("ppo", |params| {
+            let ppo = build(params, |[fast, slow, signal]| {
+                PercentagePriceOscillator::new(period(fast)?, period(slow)?, period(signal)?)
+            })?;
+            Ok(DynIndicator::with_fields(
+                ppo,
+                ["ppo", "signal", "histogram"],
+                |out| [out.ppo, out.signal, out.histogram],
+            ))
+        })
+        .unwrap();
+        self.register("bb", |params| {
+            let bb = build(params, |[n, multiplier]| {
+                BollingerBands::new(period(n)?, multiplier)
+            })?;
+            Ok(DynIndicator::with_fields(
+                bb,
+                ["average", "upper", "lower"],
+                |out| [out.average, out.upper, out.lower],
+            ))
+        })
+        .unwrap();
+        self.register("kc", |params| {
+            let kc = build(params, |[n, multiplier]| {
+                KeltnerChannel::new(period(n)?, multiplier)
+            })?;
+            Ok(DynIndicator::with_fields(
+                kc,
+                ["average", "upper", "lower"],
+                |out| [out.average, out.upper, out.lower],
+            ))
+        })
+        .unwrap();
+        self.register("aroon", |params| {
+            let aroon = build(params, |[n]| Aroon::new(period(n)?))?;
+            Ok(DynIndicator::with_fields(
+                aroon,
+                ["up", "down", "oscillator"],
+                |out| [out.up, out.down, out.oscillator],
+            ))
+        })
+        .unwrap();
+        self.register("stoch_rsi", |params| {
+            let stoch_rsi = build(params, |[rsi, stoch, k, d]| {
+                StochasticRsi::new(period(rsi)?, period(stoch)?, period(k)?, period(d)?)
+            })?;
+            Ok(DynIndicator::with_fields(stoch_rsi, ["k", "d"], |out| {
+                [out.k, out.d]
+            }))
+        })
+        .unwrap();
+        self.register("smi", |params| {
+            let smi = build(params, |[k, d]| {
+                StochasticMomentumIndex::new(period(k)?, period(d)?)
+            })?;
+            Ok(DynIndicator::with_fields(smi, ["smi", "signal"], |out| {
+                [out.smi, out.signal]
+            }))
+        })
+        .unwrap();
+        self.register("ce", |params| {
+            let ce = build(params, |[n, multiplier]| {
+                ChandelierExit::new(period(n)?, multiplier)
+            })?;
+            Ok(DynIndicator::with_fields(ce, ["long", "short"], |out| {
+                [out.long, out.short]
+            }))
+        })
+        .unwrap();
+        self.register("linreg", |params| {
+            let linreg = build(params, |[n]| LinearRegression::new(period(n)?))?;
+            Ok(DynIndicator::with_fields(
+                linreg,
+                ["slope", "intercept", "forecast", "r_squared"],
+                |out| [out.slope, out.intercept, out.forecast, out.r_squared],
+            ))
+        })
+        .unwrap();
+    }
+}
+
+impl Default for IndicatorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for IndicatorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IndicatorRegistry")
+            .field("names", &self.names())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::Next;
+
+    #[test]
+    fn test_create() {
+        let registry = IndicatorRegistry::new();
+
+        let sma = registry.create("SMA(3)").unwrap();
+        assert_eq!(format!("{}", sma), "SMA(3)");
+        assert_eq!(sma.fields(), ["value"]);
+
+        let macd = registry.create("macd(12, 26, 9)").unwrap();
+        assert_eq!(format!("{}", macd), "MACD(12, 26, 9)");
+        assert_eq!(macd.fields(), ["macd", "signal", "histogram"]);
+
+        assert_eq!(format!("{}", registry.create("rsi").unwrap()), "RSI(14)");
+        assert_eq!(format!("{}", registry.create("obv()").unwrap()), "OBV");
+        assert_eq!(
+            format!("{}", registry.create("bb(20, 2.5)").unwrap()),
+            "BB(20, 2.5)"
+        );
+    }
+
+    #[test]
+    fn test_create_invalid() {
+        let registry = IndicatorRegistry::new();
+
+        assert!(registry.create("sma(").is_err());
+        assert!(registry.create("foo(3)").is_err());
+        assert!(registry.create("sma(0)").is_err());
+        assert!(registry.create("sma(-3)").is_err());
+        assert!(registry.create("sma(2.5)").is_err());
+        assert!(registry.create("sma(4000000000)").is_err());
+        assert!(registry.create("sma(1000001)").is_err());
+        assert!(registry.create("macd(12, 4000000000, 9)").is_err());
+        assert!(registry.create("sma(3, 4)").is_err());
+        assert!(registry.create("macd(12, 26)").is_err());
+        assert!(registry.create("obv(3)").is_err());
+    }
+
+    #[test]
+    fn test_builtins() {
+        let registry = IndicatorRegistry::new();
+
+        for name in registry.names() {
+            let mut indicator = registry.create(name).unwrap();
+            let values = indicator.next(&item(10.0));
+            assert_eq!(values.len(), indicator.fields().len(), "{}", name);
+        }
+        assert!(registry.contains("EMA"));
+        assert!(registry.names().len() > 40);
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = IndicatorRegistry::empty();
+        assert!(registry.names().is_empty());
+        assert!(registry.register("fast ema", |_| unreachable!()).is_err());
+
+        registry
+            .register("Fast_EMA", |params| match params {
+                [] => Ok(DynIndicator::new(ExponentialMovingAverage::new(5)?)),
+                _ => Err(TaError::InvalidParameter),
+            })
+            .unwrap();
+
+        assert_eq!(registry.names(), vec!["fast_ema"]);
+        assert_eq!(
+            format!("{}", registry.create("fast_ema").unwrap()),
+            "EMA(5)"
+        );
+        assert!(registry.create("fast_ema(3)").is_err());
+        assert!(registry.create("sma(3)").is_err());
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut registry = IndicatorRegistry::empty();
+        registry
+            .register("b", |_| Ok(DynIndicator::new(TrueRange::new())))
+            .unwrap();
+        registry
+            .register("a", |_| Ok(DynIndicator::new(TrueRange::new())))
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", registry),
+            "IndicatorRegistry { names: [\"a\", \"b\"] }"
+        );
+    }
+}
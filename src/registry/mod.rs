@@ -0,0 +1,15 @@
+//! Creation of indicators at runtime from text specifications.
+//!
+//! An [IndicatorRegistry](struct.IndicatorRegistry.html) parses specifications like
+//! `"rsi(14)"` or `"bb(20, 2.0)"` into [DynIndicator](struct.DynIndicator.html)s, so
+//! configuration files, command line tools or web services can create indicators without
+//! recompiling.
+
+mod spec;
+pub use self::spec::IndicatorSpec;
+
+mod dyn_indicator;
+pub use self::dyn_indicator::DynIndicator;
+
+mod indicator_registry;
+pub use self::indicator_registry::IndicatorRegistry;
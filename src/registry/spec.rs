@@ -0,0 +1,150 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Text specification of an indicator, e.g. `"ema(21)"` or `"bb(20, 2.0)"`.
+///
+/// A specification is a name, made of ASCII letters, digits and underscores and starting with
+/// a letter, optionally followed by numeric parameters in parentheses. Names are case
+/// insensitive and stored in lowercase.
+///
+/// # Example
+///
+/// ```
+/// use ta::registry::IndicatorSpec;
+///
+/// let spec: IndicatorSpec = "BB(20, 2.5)".parse().unwrap();
+/// assert_eq!(spec.name(), "bb");
+/// assert_eq!(spec.params(), [20.0, 2.5]);
+/// assert_eq!(format!("{}", spec), "bb(20, 2.5)");
+///
+/// assert!("rsi(14".parse::<IndicatorSpec>().is_err());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorSpec {
+    name: String,
+    params: Vec<f64>,
+}
+
+pub(super) fn is_valid_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl IndicatorSpec {
+    pub fn new(name: &str, params: &[f64]) -> Result<Self> {
+        if !is_valid_name(name) || params.iter().any(|param| !param.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            name: name.to_ascii_lowercase(),
+            params: params.to_vec(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn params(&self) -> &[f64] {
+        &self.params
+    }
+}
+
+impl FromStr for IndicatorSpec {
+    type Err = TaError;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+
+        let (name, params) = match spec.find('(') {
+            None => (spec, ""),
+            Some(open) => {
+                let params = spec[open + 1..]
+                    .strip_suffix(')')
+                    .ok_or(TaError::InvalidParameter)?;
+                (spec[..open].trim_end(), params.trim())
+            }
+        };
+
+        let params = if params.is_empty() {
+            vec![]
+        } else {
+            params
+                .split(',')
+                .map(|param| param.trim().parse().map_err(|_| TaError::InvalidParameter))
+                .collect::<Result<Vec<f64>>>()?
+        };
+
+        Self::new(name, &params)
+    }
+}
+
+impl fmt::Display for IndicatorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.params.is_empty() {
+            let params: Vec<String> = self.params.iter().map(|p| p.to_string()).collect();
+            write!(f, "({})", params.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(spec: &str) -> Result<IndicatorSpec> {
+        spec.parse()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(IndicatorSpec::new("", &[]).is_err());
+        assert!(IndicatorSpec::new("1ema", &[]).is_err());
+        assert!(IndicatorSpec::new("e-ma", &[]).is_err());
+        assert!(IndicatorSpec::new("ema", &[f64::NAN]).is_err());
+
+        let spec = IndicatorSpec::new("Fast_Stoch", &[14.0]).unwrap();
+        assert_eq!(spec.name(), "fast_stoch");
+        assert_eq!(spec.params(), [14.0]);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            parse("obv").unwrap(),
+            IndicatorSpec::new("obv", &[]).unwrap()
+        );
+        assert_eq!(
+            parse("obv()").unwrap(),
+            IndicatorSpec::new("obv", &[]).unwrap()
+        );
+        assert_eq!(
+            parse(" macd ( 12,26 , 9 ) ").unwrap(),
+            IndicatorSpec::new("macd", &[12.0, 26.0, 9.0]).unwrap()
+        );
+        assert_eq!(parse("bb(20,2.0)").unwrap().params(), [20.0, 2.0]);
+        assert_eq!(parse("kc(10, -1e1)").unwrap().params(), [10.0, -10.0]);
+
+        assert!(parse("").is_err());
+        assert!(parse("ema(").is_err());
+        assert!(parse("ema(9))").is_err());
+        assert!(parse("ema(9,)").is_err());
+        assert!(parse("ema(nine)").is_err());
+        assert!(parse("ema(inf)").is_err());
+        assert!(parse("ema 9").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", parse("OBV()").unwrap()), "obv");
+        assert_eq!(format!("{}", parse("bb(20,2.0)").unwrap()), "bb(20, 2)");
+    }
+}
@@ -0,0 +1,59 @@
+use std::thread;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Clock waiting between the bars of a [replay](struct.Replay.html).
+pub trait Clock {
+    /// Waits for the _duration_.
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// Clock of the system, sleeping the current thread.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Simulated clock, which returns immediately and adds up the time it should have waited.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use ta::replay::{Clock, SimulatedClock};
+///
+/// let mut clock = SimulatedClock::new();
+/// clock.sleep(Duration::from_millis(1500));
+/// clock.sleep(Duration::from_millis(500));
+///
+/// assert_eq!(clock.elapsed(), Duration::from_secs(2));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimulatedClock {
+    elapsed: Duration,
+}
+
+impl SimulatedClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total time waited.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn sleep(&mut self, duration: Duration) {
+        self.elapsed = self.elapsed.saturating_add(duration);
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::replay::{Clock, Speed, SystemClock};
+use crate::Next;
+
+type Hook<'a, T> = Box<dyn FnMut(i64, &T) + 'a>;
+
+/// Replay of recorded bars or ticks.
+///
+/// Feeds `(timestamp, bar)` pairs to the hooks registered with `on_bar`, and to the indicators
+/// or strategies registered with `add`, whose outputs are given to a callback. Timestamps are
+/// Unix milliseconds. The hooks are called in the order they were registered.
+///
+/// `run` replays a recorded series, waiting between the bars on a [clock](trait.Clock.html)
+/// according to the [speed](enum.Speed.html). `push` feeds a single bar without waiting, e.g.
+/// from a live feed, so the same hooks serve research and live code.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use ta::bars::Tick;
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::replay::{Replay, Speed};
+///
+/// let averages = RefCell::new(vec![]);
+/// let mut replay = Replay::new(Speed::Unlimited).unwrap();
+/// replay.add(Sma::new(2).unwrap(), |_, sma| averages.borrow_mut().push(sma));
+///
+/// let ticks = vec![(0, Tick::new(10.0, 1.0)), (1000, Tick::new(12.0, 1.0))];
+/// replay.run(&ticks);
+///
+/// assert_eq!(replay.now(), Some(1000));
+/// assert_eq!(*averages.borrow(), vec![10.0, 11.0]);
+/// ```
+pub struct Replay<'a, T, C = SystemClock> {
+    speed: Speed,
+    clock: C,
+    now: Option<i64>,
+    hooks: Vec<Hook<'a, T>>,
+}
+
+impl<'a, T> Replay<'a, T> {
+    pub fn new(speed: Speed) -> Result<Self> {
+        Self::with_clock(speed, SystemClock)
+    }
+}
+
+impl<'a, T, C: Clock> Replay<'a, T, C> {
+    /// Creates a replay waiting on the given _clock_, e.g. a
+    /// [SimulatedClock](struct.SimulatedClock.html).
+    pub fn with_clock(speed: Speed, clock: C) -> Result<Self> {
+        if !speed.is_valid() {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            speed,
+            clock,
+            now: None,
+            hooks: vec![],
+        })
+    }
+
+    pub fn speed(&self) -> Speed {
+        self.speed
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Returns the timestamp of the last bar, which is the current time of the replay.
+    pub fn now(&self) -> Option<i64> {
+        self.now
+    }
+
+    /// Registers a hook called with the timestamp of every bar and the bar.
+    pub fn on_bar<F>(&mut self, hook: F)
+    where
+        F: FnMut(i64, &T) + 'a,
+    {
+        self.hooks.push(Box::new(hook));
+    }
+
+    /// Registers an indicator or a strategy, which is given every bar. The _callback_ is
+    /// called with the timestamp of the bar and the output.
+    pub fn add<I, F>(&mut self, mut indicator: I, mut callback: F)
+    where
+        I: for<'b> Next<&'b T> + 'a,
+        F: for<'b> FnMut(i64, <I as Next<&'b T>>::Output) + 'a,
+    {
+        self.on_bar(move |timestamp, bar| callback(timestamp, indicator.next(bar)));
+    }
+
+    /// Feeds a bar to the hooks without waiting.
+    pub fn push(&mut self, timestamp: i64, bar: &T) {
+        self.now = Some(timestamp);
+        for hook in self.hooks.iter_mut() {
+            hook(timestamp, bar);
+        }
+    }
+
+    /// Replays the _bars_, waiting between them according to the speed.
+    pub fn run(&mut self, bars: &[(i64, T)]) {
+        for (timestamp, bar) in bars {
+            if let Some(delay) = self
+                .now
+                .and_then(|previous| self.speed.delay(previous, *timestamp))
+            {
+                self.clock.sleep(delay);
+            }
+            self.push(*timestamp, bar);
+        }
+    }
+}
+
+impl<'a, T, C: fmt::Debug> fmt::Debug for Replay<'a, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Replay")
+            .field("speed", &self.speed)
+            .field("clock", &self.clock)
+            .field("now", &self.now)
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bars::Tick;
+    use crate::indicators::{Maximum, SimpleMovingAverage as Sma};
+    use crate::replay::SimulatedClock;
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    fn ticks(timestamps: &[i64]) -> Vec<(i64, Tick)> {
+        timestamps
+            .iter()
+            .enumerate()
+            .map(|(i, &timestamp)| (timestamp, Tick::new(10.0 + i as f64, 1.0)))
+            .collect()
+    }
+
+    fn elapsed(speed: Speed, timestamps: &[i64]) -> Duration {
+        let mut replay = Replay::with_clock(speed, SimulatedClock::new()).unwrap();
+        replay.run(&ticks(timestamps));
+        replay.clock().elapsed()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Replay::<Tick>::new(Speed::Multiplier(0.0)).is_err());
+        assert!(Replay::<Tick>::new(Speed::Multiplier(f64::INFINITY)).is_err());
+
+        let replay = Replay::<Tick>::new(Speed::Multiplier(2.0)).unwrap();
+        assert_eq!(replay.speed(), Speed::Multiplier(2.0));
+        assert_eq!(replay.now(), None);
+    }
+
+    #[test]
+    fn test_run_speed() {
+        let timestamps = [0, 1000, 3000, 3000];
+
+        assert_eq!(elapsed(Speed::Unlimited, &timestamps), Duration::ZERO);
+        assert_eq!(
+            elapsed(Speed::Multiplier(1.0), &timestamps),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            elapsed(Speed::Multiplier(4.0), &timestamps),
+            Duration::from_millis(750)
+        );
+        assert_eq!(
+            elapsed(Speed::Interval(Duration::from_millis(100)), &timestamps),
+            Duration::from_millis(300)
+        );
+    }
+
+    #[test]
+    fn test_run_speed_overflow() {
+        assert_eq!(
+            elapsed(Speed::Multiplier(1e-300), &[0, 1000, 2000]),
+            Duration::MAX
+        );
+        assert_eq!(
+            elapsed(Speed::Multiplier(1.0), &[i64::MIN, i64::MAX]),
+            Duration::from_secs_f64(u64::MAX as f64 / 1000.0)
+        );
+    }
+
+    #[test]
+    fn test_hooks() {
+        let events = RefCell::new(vec![]);
+        let mut replay = Replay::with_clock(Speed::Unlimited, SimulatedClock::new()).unwrap();
+
+        replay.on_bar(|timestamp, tick: &Tick| {
+            events
+                .borrow_mut()
+                .push(format!("bar {} {}", timestamp, tick.price))
+        });
+        replay.add(Sma::new(2).unwrap(), |timestamp, sma| {
+            events
+                .borrow_mut()
+                .push(format!("sma {} {}", timestamp, sma))
+        });
+        replay.add(Maximum::new(3).unwrap(), |_, max| {
+            events.borrow_mut().push(format!("max {}", max))
+        });
+
+        replay.run(&ticks(&[0, 1000]));
+        // Live bars go through the same hooks
+        replay.push(2000, &Tick::new(9.0, 1.0));
+
+        assert_eq!(replay.now(), Some(2000));
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                "bar 0 10",
+                "sma 0 10",
+                "max 10",
+                "bar 1000 11",
+                "sma 1000 10.5",
+                "max 11",
+                "bar 2000 9",
+                "sma 2000 10",
+                "max 11",
+            ]
+        );
+    }
+}
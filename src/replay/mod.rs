@@ -0,0 +1,14 @@
+//! Replay of recorded bars and ticks.
+//!
+//! A [Replay](struct.Replay.html) feeds a recorded series through registered hooks, indicators
+//! and strategies, as fast as possible or at a [speed](enum.Speed.html) following the
+//! timestamps of the bars. The same hooks can be fed with live bars.
+
+mod clock;
+pub use self::clock::{Clock, SimulatedClock, SystemClock};
+
+mod speed;
+pub use self::speed::Speed;
+
+mod driver;
+pub use self::driver::Replay;
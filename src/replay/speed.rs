@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Speed of a [replay](struct.Replay.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Speed {
+    /// Replays the bars as fast as possible, without waiting.
+    #[default]
+    Unlimited,
+    /// Waits the time between the timestamps of the bars divided by the multiplier, e.g. 1 for
+    /// the original pace or 60 to replay an hour in a minute. The multiplier must be greater
+    /// than 0.
+    Multiplier(f64),
+    /// Waits the same time between all the bars.
+    Interval(Duration),
+}
+
+impl Speed {
+    pub(crate) fn is_valid(self) -> bool {
+        match self {
+            Speed::Multiplier(multiplier) => multiplier > 0.0 && multiplier.is_finite(),
+            Speed::Unlimited | Speed::Interval(_) => true,
+        }
+    }
+
+    // Time to wait between bars at the given Unix milliseconds timestamps, saturating to
+    // `Duration::MAX` for tiny multipliers
+    pub(crate) fn delay(self, previous: i64, timestamp: i64) -> Option<Duration> {
+        match self {
+            Speed::Unlimited => None,
+            Speed::Multiplier(multiplier) if timestamp > previous => {
+                let seconds = (timestamp as f64 - previous as f64) / 1000.0 / multiplier;
+                Some(Duration::try_from_secs_f64(seconds).unwrap_or(Duration::MAX))
+            }
+            Speed::Multiplier(_) => None,
+            Speed::Interval(interval) => Some(interval),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Computation of indicators over many independent series.
+//!
+//! [compute_all](fn.compute_all.html) runs a fresh indicator, created by a factory, over every
+//! series of a collection keyed e.g. by symbol, and returns the outputs of every series under
+//! its key. With the `rayon` feature, [par_compute_all](fn.par_compute_all.html) computes the
+//! series in parallel, e.g. to screen thousands of symbols.
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use ta::indicators::RelativeStrengthIndex as Rsi;
+//! use ta::series::compute_all;
+//!
+//! let mut closes = HashMap::new();
+//! closes.insert("AAA", vec![10.0, 10.5, 10.0, 9.5]);
+//! closes.insert("BBB", vec![20.0, 21.0, 22.0, 23.0]);
+//!
+//! let rsi = compute_all(closes, || Rsi::new(3).unwrap());
+//! assert_eq!(rsi["BBB"].len(), 4);
+//! assert!(rsi["BBB"][3] > rsi["AAA"][3]);
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Next;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Runs an indicator created by `factory` over every series and returns the outputs of every
+/// series under its key.
+///
+/// The series are given as `(key, series)` pairs, e.g. a `HashMap<K, Vec<f64>>` of closes, or
+/// a reference to a `HashMap<K, Vec<DataItem>>` of bars, whose series yield `&DataItem`.
+pub fn compute_all<M, K, S, I, F>(series: M, factory: F) -> HashMap<K, Vec<I::Output>>
+where
+    M: IntoIterator<Item = (K, S)>,
+    K: Eq + Hash,
+    S: IntoIterator,
+    I: Next<S::Item>,
+    F: Fn() -> I,
+{
+    series
+        .into_iter()
+        .map(|(key, series)| (key, compute(series, &factory)))
+        .collect()
+}
+
+/// Same as `compute_all`, computing the series in parallel.
+#[cfg(feature = "rayon")]
+pub fn par_compute_all<M, K, S, I, F>(series: M, factory: F) -> HashMap<K, Vec<I::Output>>
+where
+    M: IntoParallelIterator<Item = (K, S)>,
+    K: Eq + Hash + Send,
+    S: IntoIterator,
+    I: Next<S::Item>,
+    I::Output: Send,
+    F: Fn() -> I + Sync,
+{
+    series
+        .into_par_iter()
+        .map(|(key, series)| (key, compute(series, &factory)))
+        .collect()
+}
+
+fn compute<S, I, F>(series: S, factory: &F) -> Vec<I::Output>
+where
+    S: IntoIterator,
+    I: Next<S::Item>,
+    F: Fn() -> I,
+{
+    let mut indicator = factory();
+    series
+        .into_iter()
+        .map(|input| indicator.next(input))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange as Atr, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    fn closes() -> HashMap<String, Vec<f64>> {
+        let mut closes = HashMap::new();
+        closes.insert("AAA".to_string(), vec![1.0, 2.0, 3.0]);
+        closes.insert("BBB".to_string(), vec![4.0, 6.0]);
+        closes.insert("CCC".to_string(), vec![]);
+        closes
+    }
+
+    #[test]
+    fn test_compute_all() {
+        let sma = compute_all(closes(), || Sma::new(2).unwrap());
+        assert_eq!(sma.len(), 3);
+        assert_eq!(sma["AAA"], vec![1.0, 1.5, 2.5]);
+        assert_eq!(sma["BBB"], vec![4.0, 5.0]);
+        assert!(sma["CCC"].is_empty());
+    }
+
+    #[test]
+    fn test_compute_all_bars() {
+        let mut bars: HashMap<&str, Vec<DataItem>> = HashMap::new();
+        bars.insert("AAA", vec![item(1.0), item(3.0)]);
+        bars.insert("BBB", vec![item(2.0)]);
+
+        let atr = compute_all(&bars, || Atr::new(3).unwrap());
+        assert_eq!(atr[&"AAA"], vec![0.0, 1.0]);
+        assert_eq!(atr[&"BBB"], vec![0.0]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_compute_all() {
+        assert_eq!(
+            par_compute_all(closes(), || Sma::new(2).unwrap()),
+            compute_all(closes(), || Sma::new(2).unwrap())
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Trading action suggested by a [Signal](trait.Signal.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Buy,
+    Sell,
+    Neutral,
+}
+
+/// Returns the trading action suggested by the most recent value of an indicator.
+///
+/// Bounded oscillators suggest to buy when the value is at or below their oversold threshold
+/// and to sell when it is at or above their overbought threshold. The thresholds can be changed
+/// with the `with_thresholds` method of the oscillators. Until the first value is calculated,
+/// the action is `Neutral`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeStrengthIndex;
+/// use ta::{Action, Next, Signal};
+///
+/// let mut rsi = RelativeStrengthIndex::new(3).unwrap().with_thresholds(20.0, 80.0).unwrap();
+/// assert_eq!(rsi.signal(), Action::Neutral);
+///
+/// rsi.next(10.0);
+/// rsi.next(10.5);
+/// assert_eq!(rsi.signal(), Action::Sell);
+/// ```
+pub trait Signal {
+    fn signal(&self) -> Action;
+}
+
+// Oversold and overbought levels of a bounded oscillator
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Thresholds {
+    oversold: f64,
+    overbought: f64,
+}
+
+impl Thresholds {
+    pub(crate) fn new(oversold: f64, overbought: f64) -> Result<Self> {
+        if !(oversold.is_finite() && overbought.is_finite() && oversold < overbought) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            oversold,
+            overbought,
+        })
+    }
+
+    pub(crate) fn action(&self, value: f64) -> Action {
+        if value <= self.oversold {
+            Action::Buy
+        } else if value >= self.overbought {
+            Action::Sell
+        } else {
+            Action::Neutral
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Thresholds::new(70.0, 30.0).is_err());
+        assert!(Thresholds::new(50.0, 50.0).is_err());
+        assert!(Thresholds::new(f64::NAN, 70.0).is_err());
+        assert!(Thresholds::new(30.0, 70.0).is_ok());
+    }
+
+    #[test]
+    fn test_action() {
+        let thresholds = Thresholds::new(30.0, 70.0).unwrap();
+
+        assert_eq!(thresholds.action(10.0), Action::Buy);
+        assert_eq!(thresholds.action(30.0), Action::Buy);
+        assert_eq!(thresholds.action(50.0), Action::Neutral);
+        assert_eq!(thresholds.action(70.0), Action::Sell);
+        assert_eq!(thresholds.action(90.0), Action::Sell);
+        assert_eq!(thresholds.action(f64::NAN), Action::Neutral);
+    }
+}
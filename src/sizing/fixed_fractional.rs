@@ -0,0 +1,104 @@
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fixed fractional position sizing.
+///
+/// Risks a fixed percentage of the equity on every trade: the quantity is chosen so that
+/// hitting the stop loses _risk_ percent of the equity.
+///
+/// # Formula
+///
+/// quantity = min(equity * risk / 100 / |entry - stop|, equity / entry)
+///
+/// The quantity is 0 when the stop is at the entry price.
+///
+/// # Parameters
+///
+/// * _risk_ - percentage of the equity risked on a trade (number in range (0, 100]). Default is 1.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::sizing::FixedFractional;
+///
+/// let sizing = FixedFractional::new(2.0).unwrap();
+/// assert_eq!(sizing.quantity(10_000.0, 50.0, 45.0), 40.0);
+/// ```
+///
+/// # Links
+///
+/// * [Fixed fractional position sizing, Investopedia](https://www.investopedia.com/articles/trading/09/determine-position-size.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FixedFractional {
+    risk: f64,
+}
+
+impl FixedFractional {
+    pub fn new(risk: f64) -> Result<Self> {
+        if !(risk > 0.0 && risk <= 100.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self { risk })
+    }
+
+    pub fn risk(&self) -> f64 {
+        self.risk
+    }
+
+    /// Returns the quantity to buy or sell at the _entry_ price with a stop at the _stop_ price.
+    pub fn quantity(&self, equity: f64, entry: f64, stop: f64) -> f64 {
+        let risk_per_unit = (entry - stop).abs();
+        if risk_per_unit == 0.0 || entry <= 0.0 || equity <= 0.0 {
+            return 0.0;
+        }
+
+        (equity * self.risk / 100.0 / risk_per_unit).min(equity / entry)
+    }
+}
+
+impl Default for FixedFractional {
+    fn default() -> Self {
+        Self::new(1.0).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(FixedFractional::new(0.0).is_err());
+        assert!(FixedFractional::new(100.1).is_err());
+        assert!(FixedFractional::new(f64::NAN).is_err());
+        assert!(FixedFractional::new(100.0).is_ok());
+    }
+
+    #[test]
+    fn test_quantity() {
+        let sizing = FixedFractional::new(1.0).unwrap();
+
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 48.0), 50.0);
+        // Short positions have the stop above the entry
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 52.0), 50.0);
+        // Capped at the equity
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 49.9), 200.0);
+    }
+
+    #[test]
+    fn test_quantity_invalid() {
+        let sizing = FixedFractional::new(1.0).unwrap();
+
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 50.0), 0.0);
+        assert_eq!(sizing.quantity(0.0, 50.0, 48.0), 0.0);
+        assert_eq!(sizing.quantity(10_000.0, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(FixedFractional::default().risk(), 1.0);
+    }
+}
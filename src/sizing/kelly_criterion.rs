@@ -0,0 +1,162 @@
+use crate::errors::{Result, TaError};
+use crate::performance::PerformanceReport;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kelly criterion position sizing.
+///
+/// Invests the fraction of the equity maximizing the long term growth, given the win rate and
+/// the payoff ratio of a strategy. As the full Kelly fraction is very aggressive and sensitive
+/// to errors in the estimates, only a _fraction_ of it is used.
+///
+/// # Formula
+///
+/// Kelly = W - (1 - W) / R
+///
+/// quantity = equity * clamp(Kelly * fraction, 0, 1) / price
+///
+/// Where:
+///
+/// * _W_ - probability of a winning trade
+/// * _R_ - payoff ratio: average win divided by average loss
+///
+/// # Parameters
+///
+/// * _fraction_ - fraction of the Kelly criterion used (number in range (0, 1]). Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use ta::sizing::KellyCriterion;
+///
+/// let kelly = KellyCriterion::new(0.5).unwrap();
+/// assert_eq!(kelly.fraction_of_equity(50.0, 2.0), 0.125);
+/// assert_eq!(kelly.quantity(10_000.0, 50.0, 50.0, 2.0), 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kelly criterion, Wikipedia](https://en.wikipedia.org/wiki/Kelly_criterion)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KellyCriterion {
+    fraction: f64,
+}
+
+impl KellyCriterion {
+    pub fn new(fraction: f64) -> Result<Self> {
+        if !(fraction > 0.0 && fraction <= 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self { fraction })
+    }
+
+    pub fn fraction(&self) -> f64 {
+        self.fraction
+    }
+
+    /// Returns the fraction of the equity to invest, in range [0, 1], for the _win_rate_ in
+    /// percent and the _payoff_ ratio.
+    pub fn fraction_of_equity(&self, win_rate: f64, payoff: f64) -> f64 {
+        if payoff.is_nan() || payoff <= 0.0 {
+            return 0.0;
+        }
+
+        let win_probability = win_rate / 100.0;
+        let kelly = win_probability - (1.0 - win_probability) / payoff;
+        (kelly * self.fraction).clamp(0.0, 1.0)
+    }
+
+    /// Returns the quantity to buy or sell at the _price_ for the _win_rate_ in percent and the
+    /// _payoff_ ratio.
+    pub fn quantity(&self, equity: f64, price: f64, win_rate: f64, payoff: f64) -> f64 {
+        if price <= 0.0 || equity <= 0.0 {
+            return 0.0;
+        }
+
+        equity * self.fraction_of_equity(win_rate, payoff) / price
+    }
+
+    /// Returns the quantity to buy or sell at the _price_ using the win rate and the payoff
+    /// ratio of a [performance report](../performance/struct.PerformanceReport.html).
+    pub fn quantity_from_report(&self, equity: f64, price: f64, report: &PerformanceReport) -> f64 {
+        let payoff = if report.average_loss == 0.0 {
+            f64::INFINITY
+        } else {
+            report.average_win / report.average_loss
+        };
+
+        self.quantity(equity, price, report.win_rate, payoff)
+    }
+}
+
+impl Default for KellyCriterion {
+    fn default() -> Self {
+        Self::new(0.5).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::Trade;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(KellyCriterion::new(0.0).is_err());
+        assert!(KellyCriterion::new(1.1).is_err());
+        assert!(KellyCriterion::new(1.0).is_ok());
+    }
+
+    #[test]
+    fn test_fraction_of_equity() {
+        let kelly = KellyCriterion::new(1.0).unwrap();
+
+        assert_eq!(round(kelly.fraction_of_equity(60.0, 2.0)), 0.4);
+        assert_eq!(round(kelly.fraction_of_equity(50.0, 1.5)), 0.167);
+        // No edge
+        assert_eq!(kelly.fraction_of_equity(50.0, 1.0), 0.0);
+        assert_eq!(kelly.fraction_of_equity(30.0, 1.0), 0.0);
+        // Never invests more than the equity
+        assert_eq!(kelly.fraction_of_equity(100.0, f64::INFINITY), 1.0);
+        assert_eq!(kelly.fraction_of_equity(60.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_quantity() {
+        let kelly = KellyCriterion::new(0.5).unwrap();
+
+        assert_eq!(round(kelly.quantity(10_000.0, 50.0, 60.0, 2.0)), 40.0);
+        assert_eq!(kelly.quantity(10_000.0, 0.0, 60.0, 2.0), 0.0);
+        assert_eq!(kelly.quantity(0.0, 50.0, 60.0, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_quantity_from_report() {
+        let trade = |profit: f64| Trade {
+            entry_index: 0,
+            entry_price: 10.0,
+            exit_index: 1,
+            exit_price: 10.0 + profit,
+            quantity: 1.0,
+            commission: 0.0,
+            profit,
+        };
+        let trades = vec![trade(2.0), trade(2.0), trade(2.0), trade(-1.0), trade(-1.0)];
+        let report = PerformanceReport::new(&trades, &[]);
+
+        let kelly = KellyCriterion::new(0.5).unwrap();
+
+        assert_eq!(
+            round(kelly.quantity_from_report(10_000.0, 50.0, &report)),
+            40.0
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(KellyCriterion::default().fraction(), 0.5);
+    }
+}
@@ -0,0 +1,15 @@
+//! Position sizing.
+//!
+//! The calculators return the quantity to trade for the current equity of an account and
+//! the values of the relevant indicators, e.g. the
+//! [Average True Range](../indicators/struct.AverageTrueRange.html). None of them uses leverage:
+//! the value of a position never exceeds the equity.
+
+mod fixed_fractional;
+pub use self::fixed_fractional::FixedFractional;
+
+mod volatility_target;
+pub use self::volatility_target::VolatilityTarget;
+
+mod kelly_criterion;
+pub use self::kelly_criterion::KellyCriterion;
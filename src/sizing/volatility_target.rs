@@ -0,0 +1,117 @@
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility targeted position sizing.
+///
+/// Sizes positions by the volatility measured with the
+/// [Average True Range](../indicators/struct.AverageTrueRange.html), so that a move of
+/// _multiplier_ ATRs against the position costs _risk_ percent of the equity. Positions are
+/// smaller in volatile markets and larger in quiet ones.
+///
+/// # Formula
+///
+/// quantity = min(equity * risk / 100 / (ATR * multiplier), equity / price)
+///
+/// The quantity is 0 when the ATR is 0.
+///
+/// # Parameters
+///
+/// * _risk_ - percentage of the equity risked on a trade (number in range (0, 100]). Default is 1.0.
+/// * _multiplier_ - number of ATRs the risk is measured with (number greater than 0). Default is 2.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AverageTrueRange;
+/// use ta::sizing::VolatilityTarget;
+/// use ta::Next;
+///
+/// let mut atr = AverageTrueRange::new(3).unwrap();
+/// let value = atr.next(&ta::DataItem::builder()
+///     .open(50.0).high(51.0).low(49.0).close(50.0).volume(0.0).build().unwrap());
+///
+/// let sizing = VolatilityTarget::new(1.0, 2.0).unwrap();
+/// assert_eq!(sizing.quantity(10_000.0, 50.0, value), 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility-based position sizing, Investopedia](https://www.investopedia.com/articles/trading/09/determine-position-size.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolatilityTarget {
+    risk: f64,
+    multiplier: f64,
+}
+
+impl VolatilityTarget {
+    pub fn new(risk: f64, multiplier: f64) -> Result<Self> {
+        if !(risk > 0.0 && risk <= 100.0 && multiplier > 0.0 && multiplier.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self { risk, multiplier })
+    }
+
+    pub fn risk(&self) -> f64 {
+        self.risk
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// Returns the quantity to buy or sell at the _price_ for the current value of the _atr_.
+    pub fn quantity(&self, equity: f64, price: f64, atr: f64) -> f64 {
+        if atr.is_nan() || atr <= 0.0 || price <= 0.0 || equity <= 0.0 {
+            return 0.0;
+        }
+
+        (equity * self.risk / 100.0 / (atr * self.multiplier)).min(equity / price)
+    }
+}
+
+impl Default for VolatilityTarget {
+    fn default() -> Self {
+        Self::new(1.0, 2.0).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VolatilityTarget::new(0.0, 2.0).is_err());
+        assert!(VolatilityTarget::new(1.0, 0.0).is_err());
+        assert!(VolatilityTarget::new(1.0, f64::INFINITY).is_err());
+        assert!(VolatilityTarget::new(1.0, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_quantity() {
+        let sizing = VolatilityTarget::new(1.0, 2.0).unwrap();
+
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 2.5), 20.0);
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 5.0), 10.0);
+        // Capped at the equity
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 0.1), 200.0);
+    }
+
+    #[test]
+    fn test_quantity_invalid() {
+        let sizing = VolatilityTarget::new(1.0, 2.0).unwrap();
+
+        assert_eq!(sizing.quantity(10_000.0, 50.0, 0.0), 0.0);
+        assert_eq!(sizing.quantity(10_000.0, 50.0, f64::NAN), 0.0);
+        assert_eq!(sizing.quantity(-1.0, 50.0, 2.5), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        let sizing = VolatilityTarget::default();
+        assert_eq!((sizing.risk(), sizing.multiplier()), (1.0, 2.0));
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt;
+
+use crate::strategy::Condition;
+use crate::{DataItem, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Condition met when both conditions are met.
+///
+/// Both conditions are evaluated for every bar, so the indicators they are built from are
+/// always up to date.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct And<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A: Condition, B: Condition> And<A, B> {
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<A: Condition, B: Condition> Next<&DataItem> for And<A, B> {
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let left = self.left.next(input);
+        let right = self.right.next(input);
+        left && right
+    }
+}
+
+impl<A: Condition, B: Condition> Reset for And<A, B> {
+    fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for And<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({} AND {})", self.left, self.right)
+    }
+}
+
+/// Condition met when at least one of the conditions is met.
+///
+/// Both conditions are evaluated for every bar, so the indicators they are built from are
+/// always up to date.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Or<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A: Condition, B: Condition> Or<A, B> {
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<A: Condition, B: Condition> Next<&DataItem> for Or<A, B> {
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let left = self.left.next(input);
+        let right = self.right.next(input);
+        left || right
+    }
+}
+
+impl<A: Condition, B: Condition> Reset for Or<A, B> {
+    fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Or<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({} OR {})", self.left, self.right)
+    }
+}
+
+/// Condition met when the inner condition is not.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Not<A> {
+    inner: A,
+}
+
+impl<A: Condition> Not<A> {
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A: Condition> Next<&DataItem> for Not<A> {
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        !self.inner.next(input)
+    }
+}
+
+impl<A: Condition> Reset for Not<A> {
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<A: fmt::Display> fmt::Display for Not<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NOT {}", self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::strategy::Threshold;
+    use crate::test_helper::*;
+
+    // Condition met for the given sequence of bars, counting the bars it was evaluated for
+    struct Sequence {
+        values: Vec<bool>,
+        count: usize,
+    }
+
+    impl Sequence {
+        fn new(values: &[bool]) -> Self {
+            Self {
+                values: values.to_vec(),
+                count: 0,
+            }
+        }
+    }
+
+    impl Next<&DataItem> for Sequence {
+        type Output = bool;
+
+        fn next(&mut self, _input: &DataItem) -> Self::Output {
+            self.count += 1;
+            self.values[self.count - 1]
+        }
+    }
+
+    impl Reset for Sequence {
+        fn reset(&mut self) {
+            self.count = 0;
+        }
+    }
+
+    fn evaluate<C: Condition>(condition: &mut C, bars: usize) -> Vec<bool> {
+        (0..bars).map(|_| condition.next(&item(1.0))).collect()
+    }
+
+    #[test]
+    fn test_and() {
+        let a = Sequence::new(&[true, true, false, false]);
+        let b = Sequence::new(&[true, false, true, false]);
+        let mut and = a.and(b);
+
+        assert_eq!(evaluate(&mut and, 4), vec![true, false, false, false]);
+        assert_eq!(and.left.count, 4);
+        assert_eq!(and.right.count, 4);
+    }
+
+    #[test]
+    fn test_or() {
+        let a = Sequence::new(&[true, true, false, false]);
+        let b = Sequence::new(&[true, false, true, false]);
+        let mut or = a.or(b);
+
+        assert_eq!(evaluate(&mut or, 4), vec![true, true, true, false]);
+        assert_eq!(or.left.count, 4);
+        assert_eq!(or.right.count, 4);
+    }
+
+    #[test]
+    fn test_not() {
+        let mut not = Sequence::new(&[true, false]).not();
+
+        assert_eq!(evaluate(&mut not, 2), vec![false, true]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let a = Sequence::new(&[true, false]);
+        let b = Sequence::new(&[true, true]);
+        let mut or = a.not().or(b);
+
+        assert_eq!(evaluate(&mut or, 2), vec![true, true]);
+
+        or.reset();
+
+        assert_eq!(or.left.inner.count, 0);
+        assert_eq!(or.right.count, 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let above = Threshold::above(Sma::new(3).unwrap(), 10.0);
+        let below = Threshold::below(Sma::new(5).unwrap(), 20.0);
+        let condition = above.clone().and(below.clone()).or(above.not());
+
+        assert_eq!(
+            format!("{}", condition),
+            "((SMA(3) > 10 AND SMA(5) < 20) OR NOT SMA(3) > 10)"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt;
+
+use crate::indicators::{Cross, CrossEvent};
+use crate::{DataItem, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Above,
+    Below,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Comparison::Above => write!(f, ">"),
+            Comparison::Below => write!(f, "<"),
+        }
+    }
+}
+
+impl Comparison {
+    fn check(self, a: f64, b: f64) -> bool {
+        match self {
+            Comparison::Above => a > b,
+            Comparison::Below => a < b,
+        }
+    }
+}
+
+/// Condition met when an indicator is above or below a fixed level.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeStrengthIndex as Rsi;
+/// use ta::strategy::Threshold;
+///
+/// let oversold = Threshold::below(Rsi::new(14).unwrap(), 30.0);
+/// assert_eq!(format!("{}", oversold), "RSI(14) < 30");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Threshold<I> {
+    indicator: I,
+    comparison: Comparison,
+    level: f64,
+}
+
+impl<I> Threshold<I>
+where
+    I: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    /// Condition met when the indicator is strictly above the _level_.
+    pub fn above(indicator: I, level: f64) -> Self {
+        Self {
+            indicator,
+            comparison: Comparison::Above,
+            level,
+        }
+    }
+
+    /// Condition met when the indicator is strictly below the _level_.
+    pub fn below(indicator: I, level: f64) -> Self {
+        Self {
+            indicator,
+            comparison: Comparison::Below,
+            level,
+        }
+    }
+}
+
+impl<I> Next<&DataItem> for Threshold<I>
+where
+    I: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let value = self.indicator.next(input);
+        self.comparison.check(value, self.level)
+    }
+}
+
+impl<I: Reset> Reset for Threshold<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Threshold<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.indicator, self.comparison, self.level)
+    }
+}
+
+/// Condition met when one indicator is above or below another one.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+/// use ta::strategy::Compare;
+///
+/// let condition = Compare::above(Ema::new(9).unwrap(), Sma::new(20).unwrap());
+/// assert_eq!(format!("{}", condition), "EMA(9) > SMA(20)");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Compare<A, B> {
+    a: A,
+    b: B,
+    comparison: Comparison,
+}
+
+impl<A, B> Compare<A, B>
+where
+    A: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+    B: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    /// Condition met when _a_ is strictly above _b_.
+    pub fn above(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            comparison: Comparison::Above,
+        }
+    }
+
+    /// Condition met when _a_ is strictly below _b_.
+    pub fn below(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            comparison: Comparison::Below,
+        }
+    }
+}
+
+impl<A, B> Next<&DataItem> for Compare<A, B>
+where
+    A: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+    B: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let a = self.a.next(input);
+        let b = self.b.next(input);
+        self.comparison.check(a, b)
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Compare<A, B> {
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Compare<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.a, self.comparison, self.b)
+    }
+}
+
+/// Condition met on the bar when one indicator crosses over or under another one.
+///
+/// The crosses are found by [Cross](../indicators/struct.Cross.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ExponentialMovingAverage as Ema;
+/// use ta::strategy::Crossover;
+///
+/// let golden_cross = Crossover::over(Ema::new(50).unwrap(), Ema::new(200).unwrap());
+/// assert_eq!(format!("{}", golden_cross), "EMA(50) CROSSES OVER EMA(200)");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Crossover<A, B> {
+    a: A,
+    b: B,
+    cross: Cross,
+    event: CrossEvent,
+}
+
+impl<A, B> Crossover<A, B>
+where
+    A: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+    B: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    /// Condition met when _a_ crosses above _b_.
+    pub fn over(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            cross: Cross::new(),
+            event: CrossEvent::Over,
+        }
+    }
+
+    /// Condition met when _a_ crosses below _b_.
+    pub fn under(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            cross: Cross::new(),
+            event: CrossEvent::Under,
+        }
+    }
+}
+
+impl<A, B> Next<&DataItem> for Crossover<A, B>
+where
+    A: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+    B: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    type Output = bool;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let a = self.a.next(input);
+        let b = self.b.next(input);
+        self.cross.next((a, b)) == self.event
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Crossover<A, B> {
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+        self.cross.reset();
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Crossover<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = match self.event {
+            CrossEvent::Under => "UNDER",
+            _ => "OVER",
+        };
+        write!(f, "{} CROSSES {} {}", self.a, direction, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{Maximum, Minimum, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    fn evaluate<C: for<'a> Next<&'a DataItem, Output = bool>>(
+        condition: &mut C,
+        closes: &[f64],
+    ) -> Vec<bool> {
+        closes
+            .iter()
+            .map(|&close| condition.next(&item(close)))
+            .collect()
+    }
+
+    #[test]
+    fn test_threshold() {
+        let mut above = Threshold::above(Sma::new(2).unwrap(), 10.0);
+        assert_eq!(
+            evaluate(&mut above, &[8.0, 12.0, 10.0, 11.0]),
+            vec![false, false, true, true]
+        );
+
+        let mut below = Threshold::below(Sma::new(2).unwrap(), 10.0);
+        assert_eq!(
+            evaluate(&mut below, &[8.0, 12.0, 10.0, 9.0]),
+            vec![true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut above = Compare::above(Sma::new(1).unwrap(), Sma::new(3).unwrap());
+        assert_eq!(
+            evaluate(&mut above, &[10.0, 11.0, 9.0, 12.0]),
+            vec![false, true, false, true]
+        );
+
+        let mut below = Compare::below(Sma::new(1).unwrap(), Sma::new(3).unwrap());
+        assert_eq!(
+            evaluate(&mut below, &[10.0, 11.0, 9.0, 12.0]),
+            vec![false, false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_crossover() {
+        let closes = [10.0, 9.0, 11.0, 12.0, 8.0, 8.0];
+
+        let mut over = Crossover::over(Sma::new(1).unwrap(), Sma::new(2).unwrap());
+        assert_eq!(
+            evaluate(&mut over, &closes),
+            vec![false, false, true, false, false, false]
+        );
+
+        let mut under = Crossover::under(Sma::new(1).unwrap(), Sma::new(2).unwrap());
+        assert_eq!(
+            evaluate(&mut under, &closes),
+            vec![false, false, false, false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut crossover = Crossover::over(Minimum::new(2).unwrap(), Maximum::new(2).unwrap());
+        let mut threshold = Threshold::above(Sma::new(2).unwrap(), 10.0);
+
+        evaluate(&mut crossover, &[10.0, 12.0]);
+        evaluate(&mut threshold, &[20.0, 20.0]);
+
+        crossover.reset();
+        threshold.reset();
+
+        assert_eq!(evaluate(&mut threshold, &[9.0]), vec![false]);
+        assert_eq!(evaluate(&mut crossover, &[10.0]), vec![false]);
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = Sma::new(3).unwrap();
+        assert_eq!(
+            format!("{}", Threshold::above(sma.clone(), 1.5)),
+            "SMA(3) > 1.5"
+        );
+        assert_eq!(
+            format!("{}", Compare::below(sma.clone(), Sma::new(5).unwrap())),
+            "SMA(3) < SMA(5)"
+        );
+        assert_eq!(
+            format!("{}", Crossover::under(sma, Sma::new(5).unwrap())),
+            "SMA(3) CROSSES UNDER SMA(5)"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::strategy::{And, Not, Or};
+use crate::{DataItem, Next, Reset};
+
+/// Condition evaluated for every bar, the building block of a [Strategy](struct.Strategy.html).
+///
+/// Every type implementing `Next<&DataItem>` with `bool` output and
+/// [Reset](../trait.Reset.html) is a condition. Conditions are combined with
+/// [and](#method.and), [or](#method.or) and [not](#method.not).
+pub trait Condition: for<'a> Next<&'a DataItem, Output = bool> + Reset {
+    /// Returns a condition which is met when both conditions are met.
+    fn and<C: Condition>(self, other: C) -> And<Self, C>
+    where
+        Self: Sized,
+    {
+        And::new(self, other)
+    }
+
+    /// Returns a condition which is met when at least one of the conditions is met.
+    fn or<C: Condition>(self, other: C) -> Or<Self, C>
+    where
+        Self: Sized,
+    {
+        Or::new(self, other)
+    }
+
+    /// Returns a condition which is met when this condition is not.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not::new(self)
+    }
+}
+
+impl<T> Condition for T where T: for<'a> Next<&'a DataItem, Output = bool> + Reset {}
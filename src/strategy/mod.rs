@@ -0,0 +1,45 @@
+//! Declarative trading strategies built from conditions on indicators.
+//!
+//! [Conditions](trait.Condition.html) compare indicators with levels or with each other
+//! ([Threshold](struct.Threshold.html), [Compare](struct.Compare.html),
+//! [Crossover](struct.Crossover.html)) and are combined with AND, OR and NOT into a
+//! [Strategy](struct.Strategy.html), which returns an [Action](../enum.Action.html)
+//! for every bar.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::{ExponentialMovingAverage as Ema, RelativeStrengthIndex as Rsi};
+//! use ta::strategy::{Condition, Crossover, Strategy, Threshold};
+//! use ta::{Action, DataItem, Next};
+//!
+//! let buy = Crossover::over(Ema::new(2).unwrap(), Ema::new(4).unwrap())
+//!     .and(Threshold::below(Rsi::new(3).unwrap(), 90.0));
+//! let sell = Crossover::under(Ema::new(2).unwrap(), Ema::new(4).unwrap());
+//! let mut strategy = Strategy::new(buy, sell);
+//!
+//! let bar = |close| {
+//!     DataItem::builder()
+//!         .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+//! };
+//!
+//! let actions: Vec<_> = [10.0, 9.0, 8.0, 9.0, 10.0]
+//!     .iter()
+//!     .map(|&close| strategy.next(&bar(close)))
+//!     .collect();
+//!
+//! assert_eq!(actions[4], Action::Buy);
+//! assert!(actions[..4].iter().all(|&action| action == Action::Neutral));
+//! ```
+
+mod condition;
+pub use self::condition::Condition;
+
+mod combinators;
+pub use self::combinators::{And, Not, Or};
+
+mod comparisons;
+pub use self::comparisons::{Compare, Crossover, Threshold};
+
+mod rules;
+pub use self::rules::Strategy;
@@ -0,0 +1,146 @@
+use std::fmt;
+
+use crate::strategy::Condition;
+use crate::{Action, DataItem, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Trading strategy defined by a buy and a sell [condition](trait.Condition.html).
+///
+/// Both conditions are evaluated for every bar. The strategy returns `Action::Buy` when only
+/// the buy condition is met, `Action::Sell` when only the sell condition is met and
+/// `Action::Neutral` otherwise, including when both are met.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeStrengthIndex as Rsi;
+/// use ta::strategy::{Strategy, Threshold};
+/// use ta::{Action, DataItem, Next};
+///
+/// let mut strategy = Strategy::new(
+///     Threshold::below(Rsi::new(3).unwrap(), 30.0),
+///     Threshold::above(Rsi::new(3).unwrap(), 70.0),
+/// );
+///
+/// let bar = |close| {
+///     DataItem::builder()
+///         .open(close).high(close).low(close).close(close).volume(0.0).build().unwrap()
+/// };
+///
+/// assert_eq!(strategy.next(&bar(10.0)), Action::Neutral);
+/// assert_eq!(strategy.next(&bar(10.5)), Action::Sell);
+/// assert_eq!(strategy.next(&bar(10.0)), Action::Neutral);
+/// assert_eq!(strategy.next(&bar(9.5)), Action::Buy);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Strategy<B, S> {
+    buy: B,
+    sell: S,
+}
+
+impl<B: Condition, S: Condition> Strategy<B, S> {
+    pub fn new(buy: B, sell: S) -> Self {
+        Self { buy, sell }
+    }
+}
+
+impl<B: Condition, S: Condition> Next<&DataItem> for Strategy<B, S> {
+    type Output = Action;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let buy = self.buy.next(input);
+        let sell = self.sell.next(input);
+
+        match (buy, sell) {
+            (true, false) => Action::Buy,
+            (false, true) => Action::Sell,
+            _ => Action::Neutral,
+        }
+    }
+}
+
+impl<B: Condition, S: Condition> Reset for Strategy<B, S> {
+    fn reset(&mut self) {
+        self.buy.reset();
+        self.sell.reset();
+    }
+}
+
+impl<B: fmt::Display, S: fmt::Display> fmt::Display for Strategy<B, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "STRATEGY(BUY: {}, SELL: {})", self.buy, self.sell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::strategy::{Compare, Threshold};
+    use crate::test_helper::*;
+
+    fn actions<B: Condition, S: Condition>(
+        strategy: &mut Strategy<B, S>,
+        closes: &[f64],
+    ) -> Vec<Action> {
+        closes
+            .iter()
+            .map(|&close| strategy.next(&item(close)))
+            .collect()
+    }
+
+    #[test]
+    fn test_next() {
+        let mut strategy = Strategy::new(
+            Threshold::below(Sma::new(1).unwrap(), 10.0),
+            Threshold::above(Sma::new(1).unwrap(), 20.0),
+        );
+
+        assert_eq!(
+            actions(&mut strategy, &[15.0, 9.0, 21.0, 20.0]),
+            vec![Action::Neutral, Action::Buy, Action::Sell, Action::Neutral]
+        );
+    }
+
+    #[test]
+    fn test_next_both_conditions() {
+        let mut strategy = Strategy::new(
+            Threshold::above(Sma::new(1).unwrap(), 10.0),
+            Compare::above(Sma::new(1).unwrap(), Sma::new(2).unwrap()),
+        );
+
+        assert_eq!(
+            actions(&mut strategy, &[12.0, 14.0, 13.0]),
+            vec![Action::Buy, Action::Neutral, Action::Buy]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut strategy = Strategy::new(
+            Threshold::below(Sma::new(2).unwrap(), 10.0),
+            Threshold::above(Sma::new(2).unwrap(), 20.0),
+        );
+
+        assert_eq!(actions(&mut strategy, &[30.0]), vec![Action::Sell]);
+
+        strategy.reset();
+
+        assert_eq!(actions(&mut strategy, &[5.0]), vec![Action::Buy]);
+    }
+
+    #[test]
+    fn test_display() {
+        let strategy = Strategy::new(
+            Threshold::below(Sma::new(2).unwrap(), 10.0),
+            Threshold::above(Sma::new(2).unwrap(), 20.0),
+        );
+
+        assert_eq!(
+            format!("{}", strategy),
+            "STRATEGY(BUY: SMA(2) < 10, SELL: SMA(2) > 20)"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Adapter running indicators over asynchronous streams.
+//!
+//! Requires the `futures` feature.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::Next;
+
+/// Extension of streams of bars, e.g. [DataItem](../struct.DataItem.html)s, with indicators.
+///
+/// # Example
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::stream::IndicatorStreamExt;
+/// use ta::DataItem;
+///
+/// let bars = stream::iter([1.0, 2.0, 3.0]).map(|close| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(0.0)
+///         .build()
+///         .unwrap()
+/// });
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let outputs: Vec<f64> = block_on(bars.indicator(sma).collect());
+/// assert_eq!(outputs, [1.0, 1.5, 2.5]);
+/// ```
+pub trait IndicatorStreamExt: Stream {
+    /// Returns a stream of the outputs of the indicator fed with the items of the stream.
+    fn indicator<I>(self, indicator: I) -> IndicatorStream<Self, I>
+    where
+        I: for<'a> Next<&'a Self::Item>,
+        Self: Sized,
+    {
+        IndicatorStream {
+            stream: self,
+            indicator,
+        }
+    }
+}
+
+impl<S: Stream> IndicatorStreamExt for S {}
+
+pin_project! {
+    /// Stream of the outputs of an indicator, returned by
+    /// [IndicatorStreamExt::indicator](trait.IndicatorStreamExt.html#method.indicator).
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct IndicatorStream<S, I> {
+        #[pin]
+        stream: S,
+        indicator: I,
+    }
+}
+
+impl<S, I> IndicatorStream<S, I> {
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Returns the stream and the indicator, in its current state.
+    pub fn into_inner(self) -> (S, I) {
+        (self.stream, self.indicator)
+    }
+}
+
+impl<S, I, O> Stream for IndicatorStream<S, I>
+where
+    S: Stream,
+    I: for<'a> Next<&'a S::Item, Output = O>,
+{
+    type Item = O;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<O>> {
+        let this = self.project();
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(this.indicator.next(&item))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange, SimpleMovingAverage};
+    use crate::test_helper::*;
+    use crate::IndicatorSet;
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+
+    #[test]
+    fn test_indicator() {
+        let bars = stream::iter([
+            Bar::new().high(11).low(9).close(10),
+            Bar::new().high(13).low(10).close(12),
+        ]);
+        let atr = AverageTrueRange::new(3).unwrap();
+        let outputs: Vec<f64> = block_on(bars.indicator(atr).collect());
+        assert_eq!(outputs, [2.0, 2.5]);
+    }
+
+    #[test]
+    fn test_indicator_set() {
+        let set = IndicatorSet::new()
+            .add("sma", SimpleMovingAverage::new(2).unwrap())
+            .unwrap();
+        let stream = stream::iter([item(2.0), item(4.0)]).indicator(set);
+        assert_eq!(stream.size_hint(), (2, Some(2)));
+
+        let outputs: Vec<_> = block_on(stream.collect());
+        assert_eq!(outputs[1].get("sma"), Some(3.0));
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut stream = stream::iter([item(2.0), item(4.0)]).indicator(sma);
+        assert_eq!(block_on(stream.next()), Some(2.0));
+
+        let (rest, mut sma) = stream.into_inner();
+        assert_eq!(block_on(rest.count()), 1);
+        assert_eq!(sma.next(6.0), 4.0);
+    }
+}
@@ -0,0 +1,262 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap};
+use std::fmt;
+use std::hash::Hash;
+
+use crate::{Current, Next, Reset};
+
+/// Indicators of many symbols, created on demand.
+///
+/// Holds an indicator for every symbol, created by the factory the first time `next` is
+/// called with the symbol, and gives every input to the indicator of its symbol, e.g. to run
+/// the same strategy over many assets from a single feed of bars. Symbols are `String`s by
+/// default, and can be looked up by `&str`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage as Sma;
+/// use ta::SymbolManager;
+///
+/// let mut manager = SymbolManager::new(|| Sma::new(2).unwrap());
+///
+/// assert_eq!(manager.next("AAA", 10.0), 10.0);
+/// assert_eq!(manager.next("BBB", 20.0), 20.0);
+/// assert_eq!(manager.next("AAA", 12.0), 11.0);
+///
+/// assert_eq!(manager.len(), 2);
+/// let mut values: Vec<_> = manager.current_values().collect();
+/// values.sort_by(|a, b| a.0.cmp(b.0));
+/// assert_eq!(values, [(&"AAA".to_string(), 11.0), (&"BBB".to_string(), 20.0)]);
+/// ```
+pub struct SymbolManager<I, K = String> {
+    indicators: HashMap<K, I>,
+    factory: Box<dyn Fn() -> I>,
+}
+
+impl<I, K: Eq + Hash> SymbolManager<I, K> {
+    /// Creates a manager whose indicators are created by _factory_.
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn() -> I + 'static,
+    {
+        Self {
+            indicators: HashMap::new(),
+            factory: Box::new(factory),
+        }
+    }
+
+    /// Gives the input to the indicator of the symbol, created first if needed, and returns
+    /// its output.
+    pub fn next<Q, T>(&mut self, symbol: &Q, input: T) -> I::Output
+    where
+        I: Next<T>,
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if !self.indicators.contains_key(symbol) {
+            self.indicators.insert(symbol.to_owned(), (self.factory)());
+        }
+        self.indicators
+            .get_mut(symbol)
+            .expect("indicator of the symbol")
+            .next(input)
+    }
+
+    /// Returns the indicator of the symbol, if any.
+    pub fn get<Q>(&self, symbol: &Q) -> Option<&I>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indicators.get(symbol)
+    }
+
+    pub fn contains<Q>(&self, symbol: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indicators.contains_key(symbol)
+    }
+
+    /// Resets the indicator of the symbol. Returns `false` when the symbol has no indicator.
+    pub fn reset_symbol<Q>(&mut self, symbol: &Q) -> bool
+    where
+        I: Reset,
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.indicators.get_mut(symbol) {
+            Some(indicator) => {
+                indicator.reset();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a copy of the indicator of the symbol in its current state, e.g. to
+    /// [restore](#method.restore) it later or to save it with serde.
+    pub fn snapshot<Q>(&self, symbol: &Q) -> Option<I>
+    where
+        I: Clone,
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indicators.get(symbol).cloned()
+    }
+
+    /// Sets the indicator of the symbol, e.g. from a snapshot, and returns the previous one.
+    pub fn restore(&mut self, symbol: K, indicator: I) -> Option<I> {
+        self.indicators.insert(symbol, indicator)
+    }
+
+    /// Removes the symbol and returns its indicator.
+    pub fn remove<Q>(&mut self, symbol: &Q) -> Option<I>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indicators.remove(symbol)
+    }
+
+    /// Returns the number of symbols.
+    pub fn len(&self) -> usize {
+        self.indicators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indicators.is_empty()
+    }
+
+    /// Returns an iterator over the symbols, in arbitrary order.
+    pub fn symbols(&self) -> hash_map::Keys<'_, K, I> {
+        self.indicators.keys()
+    }
+
+    /// Returns an iterator over the symbols and their indicators, in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, I> {
+        self.indicators.iter()
+    }
+
+    /// Returns an iterator over the symbols and the latest outputs of their indicators, in
+    /// arbitrary order, skipping the indicators without output.
+    pub fn current_values(&self) -> impl Iterator<Item = (&K, <I as Current>::Output)>
+    where
+        I: Current,
+    {
+        self.indicators
+            .iter()
+            .filter_map(|(symbol, indicator)| Some((symbol, indicator.current()?)))
+    }
+}
+
+impl<'a, I, K> IntoIterator for &'a SymbolManager<I, K> {
+    type Item = (&'a K, &'a I);
+    type IntoIter = hash_map::Iter<'a, K, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.indicators.iter()
+    }
+}
+
+/// Resets the indicators of all the symbols, which are kept.
+impl<I: Reset, K> Reset for SymbolManager<I, K> {
+    fn reset(&mut self) {
+        for indicator in self.indicators.values_mut() {
+            indicator.reset();
+        }
+    }
+}
+
+impl<I: fmt::Debug, K: fmt::Debug> fmt::Debug for SymbolManager<I, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SymbolManager")
+            .field("indicators", &self.indicators)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange as Atr, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn manager() -> SymbolManager<Sma> {
+        SymbolManager::new(|| Sma::new(2).unwrap())
+    }
+
+    #[test]
+    fn test_next() {
+        let created = Rc::new(Cell::new(0));
+        let counter = created.clone();
+        let mut manager: SymbolManager<Atr> = SymbolManager::new(move || {
+            counter.set(counter.get() + 1);
+            Atr::new(3).unwrap()
+        });
+
+        assert_eq!(manager.next("AAA", &item(1.0)), 0.0);
+        assert_eq!(manager.next("BBB", &item(5.0)), 0.0);
+        assert_eq!(manager.next("AAA", &item(3.0)), 1.0);
+        assert_eq!(created.get(), 2);
+
+        assert!(manager.contains("AAA"));
+        assert!(!manager.contains("CCC"));
+        assert_eq!(manager.get("BBB").unwrap().to_string(), "ATR(3)");
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut manager: SymbolManager<Sma, u32> = SymbolManager::new(|| Sma::new(2).unwrap());
+        manager.next(&1, 2.0);
+        assert_eq!(manager.next(&1, 4.0), 3.0);
+        assert_eq!(manager.symbols().collect::<Vec<_>>(), [&1]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut manager = manager();
+        manager.next("AAA", 2.0);
+        let snapshot = manager.snapshot("AAA").unwrap();
+        assert!(manager.snapshot("BBB").is_none());
+
+        assert_eq!(manager.next("AAA", 4.0), 3.0);
+        assert!(manager.restore("AAA".to_string(), snapshot).is_some());
+        assert_eq!(manager.next("AAA", 6.0), 4.0);
+
+        assert!(manager.remove("AAA").is_some());
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn test_current_values() {
+        let mut manager = manager();
+        manager.next("AAA", 2.0);
+        manager.next("AAA", 4.0);
+        manager.restore("BBB".to_string(), Sma::new(3).unwrap());
+
+        let values: Vec<_> = manager.current_values().collect();
+        assert_eq!(values, [(&"AAA".to_string(), 3.0)]);
+        assert_eq!((&manager).into_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut manager = manager();
+        manager.next("AAA", 2.0);
+        manager.next("BBB", 4.0);
+
+        assert!(manager.reset_symbol("AAA"));
+        assert!(!manager.reset_symbol("CCC"));
+        assert_eq!(manager.next("AAA", 6.0), 6.0);
+        assert_eq!(manager.next("BBB", 6.0), 5.0);
+
+        manager.reset();
+        assert_eq!(manager.len(), 2);
+        assert_eq!(manager.next("BBB", 8.0), 8.0);
+    }
+}
@@ -0,0 +1,502 @@
+//! Indicators following the conventions of [TA-Lib](https://ta-lib.org).
+//!
+//! The indicators of the [indicators](../indicators/index.html) module return a value from the
+//! first input, seeding their averages with it. TA-Lib seeds them with the simple average of
+//! the first period instead, smooths the RSI and the ATR with Wilder's smoothing, and returns
+//! no value during the warm-up. The indicators of this module follow the conventions of TA-Lib
+//! with its default settings, performing the floating-point operations of its C routines in
+//! the same order, so that their outputs match those of TA-Lib up to rounding:
+//!
+//! * their outputs are NaN until the [lookback](../trait.Lookback.html) inputs are consumed,
+//!   where TA-Lib starts its output arrays,
+//! * the EMA is seeded with the simple average of the first `period` inputs,
+//! * the RSI and the ATR average their first `period` changes and true ranges, then smooth them
+//!   with Wilder's smoothing, i.e. an EMA with a factor of `1 / period`.
+//!
+//! The outputs are tested against a transliteration of the C routines of TA-Lib, not against
+//! TA-Lib itself, so identical bits are not guaranteed, e.g. across compilers of TA-Lib.
+//!
+//! The ADX of TA-Lib has no counterpart in the crate.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::talib::ExponentialMovingAverage;
+//! use ta::Next;
+//!
+//! let mut ema = ExponentialMovingAverage::new(3).unwrap();
+//! assert!(ema.next(2.0).is_nan());
+//! assert!(ema.next(5.0).is_nan());
+//! assert_eq!(ema.next(2.0), 3.0);
+//! assert_eq!(ema.next(7.0), 5.0);
+//! ```
+
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Lookback, Low, Next, OutputInfo, Pane, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Simple moving average (SMA) of TA-Lib, maintaining a running sum of the period.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+#[doc(alias = "SMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SimpleMovingAverage {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl SimpleMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Next<f64> for SimpleMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = (self.index + 1) % self.period;
+        self.sum += input;
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < self.period {
+            return f64::NAN;
+        }
+
+        // The oldest input of the period is removed after the average, as TA-Lib does
+        let average = self.sum / self.period as f64;
+        self.sum -= self.deque[self.index];
+        average
+    }
+}
+
+impl<T: Close> Next<&T> for SimpleMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SimpleMovingAverage {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        for value in self.deque.iter_mut() {
+            *value = 0.0;
+        }
+    }
+}
+
+impl Lookback for SimpleMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+/// Exponential moving average (EMA) of TA-Lib, seeded with the simple average of the first
+/// period.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+#[doc(alias = "EMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ExponentialMovingAverage {
+    period: usize,
+    k: f64,
+    count: usize,
+    // Sum of the inputs during the warm-up, then the average
+    current: f64,
+}
+
+impl ExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                k: 2.0 / (period + 1) as f64,
+                count: 0,
+                current: 0.0,
+            }),
+        }
+    }
+}
+
+impl Next<f64> for ExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count >= self.period {
+            self.current += (input - self.current) * self.k;
+            return self.current;
+        }
+
+        self.count += 1;
+        self.current += input;
+        if self.count < self.period {
+            return f64::NAN;
+        }
+        self.current /= self.period as f64;
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for ExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = 0.0;
+    }
+}
+
+impl Lookback for ExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+// Wilder's smoothing of the averages of the RSI and the ATR, seeded with the simple average
+// of the first period
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct Wilder {
+    period: usize,
+    count: usize,
+    // Sum of the inputs during the warm-up, then the average
+    current: f64,
+}
+
+impl Wilder {
+    fn new(period: usize) -> Self {
+        Self {
+            period,
+            count: 0,
+            current: 0.0,
+        }
+    }
+
+    // Returns the average once the period is complete
+    fn next(&mut self, input: f64) -> Option<f64> {
+        let period = self.period as f64;
+        if self.count >= self.period {
+            self.current *= period - 1.0;
+            self.current += input;
+            self.current /= period;
+            return Some(self.current);
+        }
+
+        self.count += 1;
+        self.current += input;
+        if self.count < self.period {
+            return None;
+        }
+        self.current /= period;
+        Some(self.current)
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = 0.0;
+    }
+}
+
+/// Relative strength index (RSI) of TA-Lib, with Wilder's smoothing of the gains and losses.
+///
+/// The output is 0 when the average gain and loss are both 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+#[doc(alias = "RSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RelativeStrengthIndex {
+    period: usize,
+    gain: Wilder,
+    loss: Wilder,
+    previous: Option<f64>,
+}
+
+impl RelativeStrengthIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                gain: Wilder::new(period),
+                loss: Wilder::new(period),
+                previous: None,
+            }),
+        }
+    }
+}
+
+impl Next<f64> for RelativeStrengthIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let change = match self.previous.replace(input) {
+            Some(previous) => input - previous,
+            None => return f64::NAN,
+        };
+
+        let gain = self.gain.next(if change < 0.0 { 0.0 } else { change });
+        let loss = self.loss.next(if change < 0.0 { -change } else { 0.0 });
+        match (gain, loss) {
+            (Some(gain), Some(loss)) => {
+                let sum = gain + loss;
+                // TA-Lib treats sums within 1e-8 of 0 as 0
+                if sum.abs() < 1e-8 {
+                    0.0
+                } else {
+                    100.0 * (gain / sum)
+                }
+            }
+            _ => f64::NAN,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for RelativeStrengthIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RelativeStrengthIndex {
+    fn reset(&mut self) {
+        self.gain.reset();
+        self.loss.reset();
+        self.previous = None;
+    }
+}
+
+impl Lookback for RelativeStrengthIndex {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+/// Average true range (ATR) of TA-Lib, with Wilder's smoothing of the true ranges.
+///
+/// The true range is only defined from the second bar, which has a previous close.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+#[doc(alias = "ATR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageTrueRange {
+    period: usize,
+    average: Wilder,
+    prev_close: Option<f64>,
+}
+
+impl AverageTrueRange {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                average: Wilder::new(period),
+                prev_close: None,
+            }),
+        }
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let prev_close = match self.prev_close.replace(input.close()) {
+            Some(prev_close) => prev_close,
+            None => return f64::NAN,
+        };
+
+        let true_range = (input.high() - input.low())
+            .max((prev_close - input.high()).abs())
+            .max((input.low() - prev_close).abs());
+        self.average.next(true_range).unwrap_or(f64::NAN)
+    }
+}
+
+impl Reset for AverageTrueRange {
+    fn reset(&mut self) {
+        self.average.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Lookback for AverageTrueRange {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+// Implements the traits shared by the indicators, with the same display and output info as
+// their counterparts of the indicators module, and the default periods of TA-Lib
+macro_rules! talib_indicator {
+    ($($name:ident, $label:expr, $default:expr, $pane:ident $(, $min:expr, $max:expr)?;)*) => {
+        $(
+            impl Period for $name {
+                fn period(&self) -> usize {
+                    self.period
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::new($default).unwrap()
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{}({})", $label, self.period)
+                }
+            }
+
+            impl OutputInfo for $name {
+                fn output_fields(&self) -> &[&'static str] {
+                    &["value"]
+                }
+
+                $(
+                    fn output_range(&self) -> Option<(f64, f64)> {
+                        Some(($min, $max))
+                    }
+                )?
+
+                fn pane(&self) -> Pane {
+                    Pane::$pane
+                }
+            }
+        )*
+    };
+}
+
+talib_indicator! {
+    SimpleMovingAverage, "SMA", 30, Overlay;
+    ExponentialMovingAverage, "EMA", 30, Overlay;
+    RelativeStrengthIndex, "RSI", 14, Separate, 0.0, 100.0;
+    AverageTrueRange, "ATR", 14, Separate;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn run<I: Next<f64, Output = f64>>(indicator: &mut I, inputs: &[f64]) -> Vec<f64> {
+        inputs.iter().map(|&input| indicator.next(input)).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SimpleMovingAverage::new(0).is_err());
+        assert!(ExponentialMovingAverage::new(0).is_err());
+        assert!(RelativeStrengthIndex::new(0).is_err());
+        assert!(AverageTrueRange::new(0).is_err());
+    }
+
+    #[test]
+    fn test_sma() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let outputs = run(&mut sma, &[2.0, 4.0, 6.0, 3.0]);
+        assert!(outputs[0].is_nan());
+        assert_eq!(&outputs[1..], [3.0, 5.0, 4.5]);
+        assert_eq!(sma.lookback(), 1);
+    }
+
+    #[test]
+    fn test_ema() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        let outputs = run(&mut ema, &[2.0, 5.0, 2.0, 7.0, 1.0]);
+        assert!(outputs[..2].iter().all(|output| output.is_nan()));
+        assert_eq!(&outputs[2..], [3.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn test_rsi() {
+        let mut rsi = RelativeStrengthIndex::new(2).unwrap();
+        let outputs = run(&mut rsi, &[10.0, 11.0, 10.0, 12.0]);
+        assert!(outputs[..2].iter().all(|output| output.is_nan()));
+        // Gains 0.5 and losses 0.5, then gains 1.25 and losses 0.25
+        assert_eq!(&outputs[2..], [50.0, 100.0 * (1.25 / 1.5)]);
+        assert_eq!(rsi.lookback(), 2);
+
+        // Flat prices
+        let mut rsi = RelativeStrengthIndex::new(2).unwrap();
+        assert_eq!(run(&mut rsi, &[10.0, 10.0, 10.0])[2], 0.0);
+    }
+
+    #[test]
+    fn test_atr() {
+        let mut atr = AverageTrueRange::new(2).unwrap();
+        let bars = [
+            Bar::new().high(10.0).low(9.0).close(9.5),
+            Bar::new().high(11.0).low(10.0).close(10.5), // true range 1.5
+            Bar::new().high(11.0).low(10.5).close(11.0), // true range 0.5
+            Bar::new().high(12.0).low(11.0).close(11.5), // true range 1
+        ];
+        let outputs: Vec<f64> = bars.iter().map(|bar| atr.next(bar)).collect();
+        assert!(outputs[..2].iter().all(|output| output.is_nan()));
+        assert_eq!(&outputs[2..], [1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ema = ExponentialMovingAverage::new(2).unwrap();
+        run(&mut ema, &[2.0, 4.0, 6.0]);
+        ema.reset();
+        assert!(ema.next(8.0).is_nan());
+        assert_eq!(ema.next(4.0), 6.0);
+
+        let mut rsi = RelativeStrengthIndex::new(1).unwrap();
+        run(&mut rsi, &[2.0, 4.0]);
+        rsi.reset();
+        assert!(rsi.next(8.0).is_nan());
+        assert_eq!(rsi.next(4.0), 0.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RelativeStrengthIndex::default()), "RSI(14)");
+        assert_eq!(format!("{}", SimpleMovingAverage::default()), "SMA(30)");
+    }
+}
@@ -1,4 +1,7 @@
-use super::{Close, High, Low, Open, Volume};
+// Without std, most of the helpers are only used by the std indicators
+#![cfg_attr(not(feature = "std"), allow(dead_code))]
+
+use super::{Close, DataItem, High, Low, Next, NextSlice, Open, Volume};
 
 #[derive(Debug, PartialEq)]
 pub struct Bar {
@@ -20,10 +23,10 @@ impl Bar {
         }
     }
 
-    //pub fn open<T: Into<f64>>(mut self, val :T ) -> Self {
-    //    self.open = val.into();
-    //    self
-    //}
+    pub fn open<T: Into<f64>>(mut self, val: T) -> Self {
+        self.open = val.into();
+        self
+    }
 
     pub fn high<T: Into<f64>>(mut self, val: T) -> Self {
         self.high = val.into();
@@ -76,10 +79,67 @@ impl Volume for Bar {
     }
 }
 
+// Data item with all prices equal to the close price and no volume
+pub fn item(close: f64) -> DataItem {
+    DataItem::builder()
+        .open(close)
+        .high(close)
+        .low(close)
+        .close(close)
+        .volume(0.0)
+        .build()
+        .unwrap()
+}
+
+// Data item from the low to the high, closing at the high, with an optional timestamp in Unix
+// milliseconds
+#[cfg(feature = "chrono")]
+pub fn timed_item(timestamp: Option<i64>, high: f64, low: f64, volume: f64) -> DataItem {
+    let builder = DataItem::builder()
+        .open(low)
+        .high(high)
+        .low(low)
+        .close(high)
+        .volume(volume);
+    match timestamp {
+        Some(timestamp) => {
+            builder.timestamp(chrono::DateTime::from_timestamp_millis(timestamp).unwrap())
+        }
+        None => builder,
+    }
+    .build()
+    .unwrap()
+}
+
 pub fn round(num: f64) -> f64 {
     (num * 1000.0).round() / 1000.00
 }
 
+// Checks that next_slice matches next over batches of various lengths
+pub fn assert_next_slice<I>(mut indicator: I)
+where
+    I: Next<f64, Output = f64> + NextSlice + Clone,
+{
+    let inputs: Vec<f64> = (0..200)
+        .map(|i| 100.0 + 10.0 * (i as f64 * 0.7).sin() + (i % 7) as f64)
+        .collect();
+    let mut expected = indicator.clone();
+    let mut start = 0;
+    for len in [0, 1, 2, 3, 5, 8, 13, 21, 34, 55, 58].iter() {
+        let batch = &inputs[start..start + len];
+        start += len;
+
+        let mut out = vec![0.0; *len];
+        indicator.next_slice(batch, &mut out);
+        for (&input, &output) in batch.iter().zip(out.iter()) {
+            let next = expected.next(input);
+            assert!((next - output).abs() < 1e-9, "{} != {}", next, output);
+        }
+    }
+    assert!((indicator.next(1.0) - expected.next(1.0)).abs() < 1e-9);
+}
+
+#[cfg(feature = "std")]
 macro_rules! test_indicator {
     ($i:tt) => {
         #[test]
@@ -100,7 +160,7 @@ macro_rules! test_indicator {
             assert_eq!(indicator.next(12.3), first_output);
 
             // ensure Display is implemented
-            format!("{}", indicator);
+            let _ = format!("{}", indicator);
         }
     };
 }
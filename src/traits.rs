@@ -1,6 +1,12 @@
 // Indicator traits
 //
 
+use crate::errors::Result;
+use crate::DataItem;
+use alloc::vec::Vec;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
 /// Resets an indicator to the initial state.
 pub trait Reset {
     fn reset(&mut self);
@@ -11,6 +17,35 @@ pub trait Period {
     fn period(&self) -> usize;
 }
 
+/// Returns the latest output of an indicator without consuming an input.
+///
+/// `current` can be called any number of times between two calls of `next`, e.g. to display
+/// the value, and returns `None` until the indicator has consumed its first input.
+pub trait Current {
+    type Output;
+    fn current(&self) -> Option<Self::Output>;
+}
+
+/// Number of leading outputs of an indicator which are not stable yet.
+///
+/// The output is considered stable from the input at index `lookback()`, counted from 0, so
+/// a backtest can skip the first `lookback()` outputs. Unlike the
+/// [period](trait.Period.html), the lookback adds up through chained indicators, e.g. the
+/// signal line of the MACD is stable after the slow and the signal averages, and counts
+/// indicators converging asymptotically, like the EMA, as stable after their period.
+pub trait Lookback {
+    fn lookback(&self) -> usize;
+}
+
+/// Tells whether an indicator has consumed enough inputs for a stable output.
+///
+/// Implemented by indicators counting their inputs, like moving windows. Any indicator
+/// with a [lookback](trait.Lookback.html) gets it when wrapped in a
+/// [Warmup](compose/struct.Warmup.html).
+pub trait IsReady {
+    fn is_ready(&self) -> bool;
+}
+
 /// Consumes a data item of type `T` and returns `Output`.
 ///
 /// Typically `T` can be `f64` or a struct similar to [DataItem](struct.DataItem.html), that implements
@@ -25,6 +60,149 @@ pub trait Next<T> {
     fn next(&mut self, input: T) -> Self::Output;
 }
 
+/// Consumes a batch of inputs at once, e.g. to backfill years of history.
+///
+/// Writes the same outputs as calling `next` for every input into `out`, up to the rounding
+/// of floating-point numbers, and leaves the indicator in the same state.
+///
+/// Implementations use batch algorithms where the indicator allows it, e.g. differences of
+/// lagged inputs for SMA and ROC, or the van Herk/Gil-Werman algorithm for Maximum and
+/// Minimum. They are plain loops without explicit SIMD, which the compiler may vectorize.
+/// Recursive indicators like EMA process the batch sequentially. `benches/next_slice.rs`
+/// compares them with calling `next` for every input.
+///
+/// # Panics
+///
+/// Panics when `out` and `inputs` have different lengths.
+pub trait NextSlice {
+    fn next_slice(&mut self, inputs: &[f64], out: &mut [f64]);
+}
+
+/// Consumes a data item, reporting errors instead of returning NaN or infinite values.
+///
+/// `try_next` returns an `InvalidInput` error for NaN or infinite inputs, which are not
+/// consumed, and a `DivisionByZero` error when the output is undefined, e.g. the rate of
+/// change from a price of 0. Such values would otherwise surface far downstream.
+pub trait TryNext<T> {
+    type Output;
+    fn try_next(&mut self, input: T) -> Result<Self::Output>;
+}
+
+/// Revises the latest input of an indicator.
+///
+/// Live feeds send the still-forming bar several times until it closes. `update_last`
+/// recomputes the latest output as if the revised input had been given to `next` instead
+/// of the previous one, without starting a new period. When the indicator has not consumed
+/// any input yet, it behaves like `next`.
+pub trait UpdateLast<T>: Next<T> {
+    fn update_last(&mut self, input: T) -> Self::Output;
+}
+
+/// Restores the state of an indicator prior to its latest input.
+///
+/// Only the latest input can be undone, calling `undo` again has no effect until the next
+/// input. Any indicator gets this capability when wrapped in an
+/// [Undoable](compose/struct.Undoable.html).
+pub trait Rollback {
+    fn undo(&mut self);
+}
+
+/// Initializes an indicator from stored history.
+///
+/// `init_with` resets the indicator and feeds it the history in order, oldest first, so a
+/// live system reaches the steady state of its indicators on startup. Implemented for every
+/// indicator consuming numbers, with `&[f64]` history, or bars, with `&[DataItem]` history.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use ta::indicators::ExponentialMovingAverage as Ema;
+/// use ta::{InitWith, Next};
+///
+/// let closes = [10.0, 11.0, 12.0, 11.0];
+/// let mut ema = Ema::new(3).unwrap().initialized_with(&closes);
+///
+/// let mut expected = Ema::new(3).unwrap();
+/// for &close in &closes {
+///     expected.next(close);
+/// }
+/// assert_eq!(ema.next(13.0), expected.next(13.0));
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// ```
+pub trait InitWith<T> {
+    fn init_with(&mut self, history: &[T]);
+
+    /// Returns the indicator initialized from the history.
+    fn initialized_with(mut self, history: &[T]) -> Self
+    where
+        Self: Sized,
+    {
+        self.init_with(history);
+        self
+    }
+}
+
+impl<I: Next<f64> + Reset> InitWith<f64> for I {
+    fn init_with(&mut self, history: &[f64]) {
+        self.reset();
+        for &input in history {
+            self.next(input);
+        }
+    }
+}
+
+impl<I: for<'a> Next<&'a DataItem> + Reset> InitWith<DataItem> for I {
+    fn init_with(&mut self, history: &[DataItem]) {
+        self.reset();
+        for input in history {
+            self.next(input);
+        }
+    }
+}
+
+/// Where the output of an indicator is drawn on a chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    /// Over the prices, sharing their scale, like moving averages and bands.
+    Overlay,
+    /// In a pane of its own, like oscillators and volume indicators.
+    Separate,
+}
+
+/// Describes the output of an indicator, so that charting and export layers can handle any
+/// indicator generically.
+///
+/// Indicators returning a number have a single field named `value`. Output structs describe
+/// their fields, the same way as the indicators returning them.
+pub trait OutputInfo {
+    /// Names of the fields of the output.
+    fn output_fields(&self) -> &[&'static str];
+
+    /// Number of fields of the output.
+    fn output_count(&self) -> usize {
+        self.output_fields().len()
+    }
+
+    /// Typical range of the values, e.g. `(0.0, 100.0)` for the RSI, or `None` when unbounded.
+    fn output_range(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Where the output is drawn on a chart.
+    fn pane(&self) -> Pane;
+}
+
+/// Numeric values of an output, one for every field of its [OutputInfo](trait.OutputInfo.html).
+///
+/// Missing values, like the `None` of an `Option<f64>`, are NaN.
+pub trait OutputValues {
+    fn output_values(&self) -> Vec<f64>;
+}
+
 /// Open price of a particular period.
 pub trait Open {
     fn open(&self) -> f64;
@@ -49,3 +227,74 @@ pub trait High {
 pub trait Volume {
     fn volume(&self) -> f64;
 }
+
+/// Open interest of a futures or options contract at the end of a particular trading period,
+/// if known.
+pub trait OpenInterest {
+    fn open_interest(&self) -> Option<f64>;
+}
+
+/// Best bid and ask quotes at the end of a particular trading period, if known.
+pub trait BidAsk {
+    fn bid(&self) -> Option<f64>;
+    fn ask(&self) -> Option<f64>;
+
+    /// Difference between the ask and the bid, if both are known.
+    fn spread(&self) -> Option<f64> {
+        Some(self.ask()? - self.bid()?)
+    }
+}
+
+/// Number of trades of a particular trading period, if known.
+pub trait TradeCount {
+    fn trade_count(&self) -> Option<u64>;
+}
+
+/// Start time of a particular trading period, if known.
+///
+/// Lets time aware indicators, e.g. resetting at the start of a session, read the time of
+/// the bars. Requires the `chrono` feature.
+#[cfg(feature = "chrono")]
+pub trait Timestamp {
+    fn timestamp(&self) -> Option<DateTime<Utc>>;
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::indicators::{OnBalanceVolume as Obv, SimpleMovingAverage as Sma};
+
+    #[test]
+    fn test_init_with_numbers() {
+        let mut sma = Sma::new(3).unwrap();
+        sma.next(100.0);
+
+        sma.init_with(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(sma.current(), Some(3.0));
+        assert_eq!(sma.next(8.0), 5.0);
+
+        sma.init_with(&[] as &[f64]);
+        assert_eq!(sma.current(), None);
+    }
+
+    #[test]
+    fn test_init_with_bars() {
+        fn bar(close: f64) -> DataItem {
+            DataItem::builder()
+                .open(close)
+                .high(close)
+                .low(close)
+                .close(close)
+                .volume(100.0)
+                .build()
+                .unwrap()
+        }
+
+        let history: Vec<DataItem> = [2.0, 3.0, 1.0].iter().map(|&close| bar(close)).collect();
+        let mut obv = Obv::new().initialized_with(&history);
+        let mut sma = Sma::new(2).unwrap().initialized_with(&history);
+
+        assert_eq!(obv.next(&bar(4.0)), 200.0);
+        assert_eq!(sma.next(&bar(5.0)), 3.0);
+    }
+}
@@ -0,0 +1,225 @@
+//! [wasm-bindgen](https://rustwasm.github.io/docs/wasm-bindgen/) bindings, so that web front-ends
+//! can compute the indicators of the [registry](../registry/index.html) with the same code.
+//!
+//! Requires the `wasm` feature.
+//!
+//! ```js
+//! import { Indicator, indicators } from "ta";
+//!
+//! const bb = Indicator.fromSpec("bb(20, 2)");
+//! bb.fields;                      // ["average", "upper", "lower"]
+//! const [average, upper, lower] = bb.nextValues(closes);  // Float64Arrays
+//! bb.next(101.5);                 // Float64Array of the 3 fields
+//!
+//! const atr = new Indicator("atr", [14]);
+//! const [values] = atr.nextBars(open, high, low, close, volume);
+//! ```
+
+use js_sys::{Array, Float64Array};
+use wasm_bindgen::prelude::*;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{DynIndicator, IndicatorRegistry, IndicatorSpec};
+use crate::{DataItem, Next, Reset};
+
+/// Returns the names of the indicators which can be created.
+#[wasm_bindgen]
+pub fn indicators() -> Vec<String> {
+    IndicatorRegistry::new()
+        .names()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Indicator of the registry, created by name and parameters.
+///
+/// The outputs are `Float64Array`s, with a value for every field of the indicator.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct Indicator {
+    indicator: DynIndicator,
+}
+
+#[wasm_bindgen]
+impl Indicator {
+    /// Creates the named indicator with the parameters, e.g. `new Indicator("rsi", [14])`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: &str, params: &[f64]) -> std::result::Result<Indicator, JsError> {
+        Ok(Self::create(&IndicatorSpec::new(name, params)?)?)
+    }
+
+    /// Creates the indicator of the specification, e.g. `Indicator.fromSpec("rsi(14)")`.
+    #[wasm_bindgen(js_name = fromSpec)]
+    pub fn from_spec(spec: &str) -> std::result::Result<Indicator, JsError> {
+        Ok(Self::create(&spec.parse()?)?)
+    }
+
+    /// Label of the indicator, e.g. `"RSI(14)"`.
+    #[wasm_bindgen(getter)]
+    pub fn label(&self) -> String {
+        self.indicator.to_string()
+    }
+
+    /// Names of the fields of the outputs.
+    #[wasm_bindgen(getter)]
+    pub fn fields(&self) -> Vec<String> {
+        self.indicator
+            .fields()
+            .iter()
+            .map(|field| field.to_string())
+            .collect()
+    }
+
+    /// Feeds a value, taken as every price of a bar without volume.
+    pub fn next(&mut self, value: f64) -> Vec<f64> {
+        self.indicator.next(&value_bar(value))
+    }
+
+    /// Feeds a bar, which is not validated.
+    #[wasm_bindgen(js_name = nextBar)]
+    pub fn next_bar(
+        &mut self,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> Vec<f64> {
+        self.indicator
+            .next(&DataItem::unchecked(open, high, low, close, volume))
+    }
+
+    /// Feeds the values, returning an array of the outputs of every field.
+    #[wasm_bindgen(js_name = nextValues)]
+    pub fn next_values(&mut self, values: &[f64]) -> Array {
+        to_arrays(self.outputs(values.iter().map(|&value| value_bar(value))))
+    }
+
+    /// Feeds the bars of the arrays, which must have the same length, returning an array of the
+    /// outputs of every field.
+    #[wasm_bindgen(js_name = nextBars)]
+    pub fn next_bars(
+        &mut self,
+        open: &[f64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+    ) -> std::result::Result<Array, JsError> {
+        Ok(to_arrays(self.bar_outputs(open, high, low, close, volume)?))
+    }
+
+    pub fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl Indicator {
+    fn create(spec: &IndicatorSpec) -> Result<Self> {
+        let indicator = IndicatorRegistry::new().create_from(spec)?;
+        Ok(Self { indicator })
+    }
+
+    // Outputs of every field for the bars
+    fn outputs<B: IntoIterator<Item = DataItem>>(&mut self, bars: B) -> Vec<Vec<f64>> {
+        let mut outputs = vec![Vec::new(); self.indicator.fields().len()];
+        for bar in bars {
+            for (output, value) in outputs.iter_mut().zip(self.indicator.next(&bar)) {
+                output.push(value);
+            }
+        }
+        outputs
+    }
+
+    fn bar_outputs(
+        &mut self,
+        open: &[f64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+    ) -> Result<Vec<Vec<f64>>> {
+        let len = close.len();
+        if [open, high, low, volume]
+            .iter()
+            .any(|array| array.len() != len)
+        {
+            return Err(TaError::InvalidParameter);
+        }
+        let bars =
+            (0..len).map(|i| DataItem::unchecked(open[i], high[i], low[i], close[i], volume[i]));
+        Ok(self.outputs(bars))
+    }
+}
+
+fn value_bar(value: f64) -> DataItem {
+    DataItem::unchecked(value, value, value, value, 0.0)
+}
+
+fn to_arrays(outputs: Vec<Vec<f64>>) -> Array {
+    outputs
+        .iter()
+        .map(|output| Float64Array::from(output.as_slice()))
+        .collect()
+}
+
+// The JavaScript values can only be created on a wasm32 target, the tests cover the Rust side
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indicator(spec: &str) -> Indicator {
+        Indicator::create(&spec.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_indicators() {
+        let names = indicators();
+        assert!(names.iter().any(|name| name == "sma"));
+        assert!(names.iter().any(|name| name == "bb"));
+    }
+
+    #[test]
+    fn test_create() {
+        assert!(Indicator::create(&IndicatorSpec::new("rsi", &[14.0]).unwrap()).is_ok());
+        assert!(Indicator::create(&IndicatorSpec::new("foo", &[14.0]).unwrap()).is_err());
+
+        let bb = indicator("bb(20, 2)");
+        assert_eq!(bb.label(), "BB(20, 2)");
+        assert_eq!(bb.fields(), ["average", "upper", "lower"]);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = indicator("sma(2)");
+        assert_eq!(sma.next(4.0), [4.0]);
+        assert_eq!(sma.next(6.0), [5.0]);
+        assert_eq!(sma.outputs([8.0, 10.0].map(value_bar)), [[7.0, 9.0]]);
+
+        sma.reset();
+        assert_eq!(sma.next(6.0), [6.0]);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut atr = indicator("atr(3)");
+        assert_eq!(atr.next_bar(10.0, 11.0, 9.0, 10.0, 0.0), [2.0]);
+
+        atr.reset();
+        let outputs = atr
+            .bar_outputs(
+                &[10.0, 11.0],
+                &[11.0, 13.0],
+                &[9.0, 10.0],
+                &[10.0, 12.0],
+                &[0.0, 0.0],
+            )
+            .unwrap();
+        assert_eq!(outputs, [[2.0, 2.5]]);
+
+        assert!(atr
+            .bar_outputs(&[10.0], &[11.0], &[9.0], &[10.0, 12.0], &[0.0])
+            .is_err());
+    }
+}
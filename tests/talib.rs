@@ -0,0 +1,255 @@
+#![cfg(feature = "std")]
+
+// Compatibility suite of the talib module: the expected outputs were computed over the bars
+// below by a transliteration of the TA_SMA, TA_EMA, TA_RSI and TA_ATR routines of TA-Lib 0.4
+// with the default settings, performing the same floating-point operations in the same order,
+// NaN where TA-Lib gives no output. They were not produced by TA-Lib itself. They are compared
+// bit for bit, to catch any change in the order of the operations.
+
+use ta::talib::{
+    AverageTrueRange, ExponentialMovingAverage, RelativeStrengthIndex, SimpleMovingAverage,
+};
+use ta::{DataItem, Lookback, Next};
+
+const NAN: f64 = f64::NAN;
+
+const HIGH: [f64; 40] = [
+    44.08, 43.81, 43.85, 43.89, 42.75, 43.27, 42.99, 42.65, 42.02, 41.75, 41.16, 40.02, 40.29,
+    40.59, 41.33, 41.9, 42.6, 42.82, 42.25, 42.12, 42.14, 43.03, 42.8, 41.73, 41.61, 41.16, 40.17,
+    40.57, 39.9, 39.44, 39.11, 38.45, 38.22, 39.02, 39.55, 39.58, 39.65, 39.73, 39.96, 38.8,
+];
+
+const LOW: [f64; 40] = [
+    43.73, 43.22, 43.45, 42.21, 42.0, 41.84, 41.73, 41.47, 41.33, 40.37, 39.65, 39.57, 39.44,
+    39.88, 40.07, 40.76, 41.22, 41.39, 41.14, 41.55, 41.69, 41.67, 41.5, 40.82, 40.47, 39.61, 39.4,
+    39.07, 39.13, 38.14, 36.96, 37.28, 38.09, 37.85, 38.09, 38.65, 39.36, 39.36, 38.12, 38.16,
+];
+
+const CLOSE: [f64; 40] = [
+    43.74, 43.46, 43.56, 42.38, 42.53, 42.66, 42.0, 41.75, 41.34, 40.73, 39.98, 39.72, 40.21,
+    40.36, 41.21, 41.54, 42.32, 41.58, 41.83, 41.99, 41.96, 42.56, 41.6, 41.24, 40.64, 39.8, 40.15,
+    39.38, 39.3, 38.61, 37.37, 38.19, 38.15, 38.33, 39.29, 39.4, 39.57, 39.58, 38.67, 38.39,
+];
+
+const SMA_5: [f64; 40] = [
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    43.134,
+    42.91799999999999,
+    42.62599999999999,
+    42.263999999999996,
+    42.056,
+    41.69599999999999,
+    41.15999999999999,
+    40.70399999999999,
+    40.395999999999994,
+    40.19999999999999,
+    40.29599999999999,
+    40.60799999999999,
+    41.12799999999999,
+    41.40199999999999,
+    41.695999999999984,
+    41.85199999999998,
+    41.935999999999986,
+    41.98399999999999,
+    41.987999999999985,
+    41.86999999999998,
+    41.59999999999998,
+    41.16799999999997,
+    40.68599999999997,
+    40.241999999999976,
+    39.85399999999997,
+    39.44799999999998,
+    38.961999999999975,
+    38.56999999999998,
+    38.32399999999998,
+    38.129999999999974,
+    38.26599999999997,
+    38.67199999999997,
+    38.947999999999965,
+    39.233999999999966,
+    39.30199999999998,
+    39.12199999999998,
+];
+
+const EMA_10: [f64; 40] = [
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    42.415,
+    41.972272727272724,
+    41.56276859504132,
+    41.31681066867017,
+    41.14284509254832,
+    41.15505507572135,
+    41.22504506195383,
+    41.42412777796223,
+    41.452468181969095,
+    41.52111033070199,
+    41.60636299784708,
+    41.67066063460216,
+    41.83235870103813,
+    41.79011166448574,
+    41.69009136185197,
+    41.49916565969706,
+    41.19022644884305,
+    41.00109436723522,
+    40.706349936828815,
+    40.450649948314485,
+    40.11598632134822,
+    39.616716081103085,
+    39.35731315726616,
+    39.13780167412686,
+    38.99092864246743,
+    39.045305252927896,
+    39.10979520694101,
+    39.193468805679004,
+    39.26374720464646,
+    39.15579316743801,
+    39.01655804608565,
+];
+
+const RSI_14: [f64; 40] = [
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    29.824561403508753,
+    33.58876117496804,
+    41.56706507304116,
+    37.02283356762177,
+    39.43189590959907,
+    40.987760078923095,
+    40.77625490740982,
+    46.700151378580316,
+    39.83458249868942,
+    37.60210859605074,
+    34.16537588083009,
+    30.027600544318073,
+    33.63420446047362,
+    29.9738617664921,
+    29.613274025240994,
+    26.636849329495348,
+    22.29919502367366,
+    30.37380569963882,
+    30.20889012762059,
+    31.99817793147946,
+    40.726382935748255,
+    41.65054330505947,
+    43.12637800551487,
+    43.21735932862541,
+    37.36032135173783,
+    35.75466058777334,
+];
+
+const ATR_14: [f64; 40] = [
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    NAN,
+    1.0100000000000011,
+    1.0192857142857155,
+    1.0450510204081644,
+    1.0725473760932955,
+    1.075222563515203,
+    1.0391352375498315,
+    0.9970541491534152,
+    1.022978852785314,
+    1.042766077586363,
+    1.0332827863301939,
+    1.0409054444494659,
+    1.0772693412745038,
+    1.055321531183468,
+    1.0870842789560775,
+    1.064435401887786,
+    1.0812614446100868,
+    1.1575999128522234,
+    1.158485633362779,
+    1.085022373836866,
+    1.09109220427709,
+    1.1174427611144402,
+    1.1040539924634087,
+    1.0459072787160224,
+    0.9976281873791635,
+    1.0577976025663662,
+    1.0279549166687687,
+];
+
+fn bars() -> Vec<DataItem> {
+    (0..CLOSE.len())
+        .map(|i| {
+            DataItem::builder()
+                .open(CLOSE[i])
+                .high(HIGH[i])
+                .low(LOW[i])
+                .close(CLOSE[i])
+                .volume(1000.0)
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn assert_outputs<I>(mut indicator: I, expected: &[f64])
+where
+    I: for<'a> Next<&'a DataItem, Output = f64> + Lookback,
+{
+    let lookback = indicator.lookback();
+    for (i, (bar, &expected)) in bars().iter().zip(expected).enumerate() {
+        let output = indicator.next(bar);
+        assert_eq!(expected.is_nan(), i < lookback, "warm-up of bar {}", i);
+        if expected.is_nan() {
+            assert!(output.is_nan(), "bar {}: {} instead of NaN", i, output);
+        } else {
+            assert_eq!(output.to_bits(), expected.to_bits(), "bar {}", i);
+        }
+    }
+}
+
+#[test]
+fn test_sma() {
+    assert_outputs(SimpleMovingAverage::new(5).unwrap(), &SMA_5);
+}
+
+#[test]
+fn test_ema() {
+    assert_outputs(ExponentialMovingAverage::new(10).unwrap(), &EMA_10);
+}
+
+#[test]
+fn test_rsi() {
+    assert_outputs(RelativeStrengthIndex::new(14).unwrap(), &RSI_14);
+}
+
+#[test]
+fn test_atr() {
+    assert_outputs(AverageTrueRange::new(14).unwrap(), &ATR_14);
+}